@@ -0,0 +1,237 @@
+use crate::{
+    Arc, ArcPolygon, ArcVertex, Capsule, Circle, Closed, CopyIterator, Disk, GenericPath,
+    Integrable, LineSegment, Moment, Path, Polygon, Region, Triangle, impl_approx_eq,
+};
+use glam::Vec2;
+
+/// Axis-aligned bounding box.
+///
+/// ```text
+///        +-------* max
+///        |       |
+///        |       |
+///    min *-------+
+/// ```
+///
+/// Box is empty if any component of `min` is greater than the one of `max`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Aabb {
+    /// Corner with minimal coordinates.
+    pub min: Vec2,
+    /// Corner with maximal coordinates.
+    pub max: Vec2,
+}
+
+impl Default for Aabb {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl Aabb {
+    /// Box that contains no points.
+    pub const EMPTY: Self = Self {
+        min: Vec2::INFINITY,
+        max: Vec2::NEG_INFINITY,
+    };
+
+    /// Create a new box from its corners.
+    pub fn new(min: Vec2, max: Vec2) -> Self {
+        Self { min, max }
+    }
+
+    /// Smallest box containing all the `points`.
+    pub fn from_points<I: IntoIterator<Item = Vec2>>(points: I) -> Self {
+        points.into_iter().fold(Self::EMPTY, Self::expand)
+    }
+
+    /// Check that the box contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.min.cmpgt(self.max).any()
+    }
+
+    /// Width and height of the box.
+    pub fn size(&self) -> Vec2 {
+        (self.max - self.min).max(Vec2::ZERO)
+    }
+
+    /// Center of the box.
+    pub fn center(&self) -> Vec2 {
+        0.5 * (self.min + self.max)
+    }
+
+    /// Smallest box containing the box and the `point`.
+    pub fn expand(self, point: Vec2) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    /// Smallest box containing both boxes.
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Box extended by `margin` in each direction.
+    pub fn inflate(self, margin: f32) -> Self {
+        Self {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// Check that boxes have common points, touching counts.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.cmple(other.max).all() && other.min.cmple(self.max).all()
+    }
+
+    /// Corners of the box in counterclockwise order starting from `min`.
+    pub fn corners(&self) -> [Vec2; 4] {
+        [
+            self.min,
+            Vec2::new(self.max.x, self.min.y),
+            self.max,
+            Vec2::new(self.min.x, self.max.y),
+        ]
+    }
+
+    /// Representation of the box as a counterclockwise polygon.
+    pub fn polygon(&self) -> Polygon<[Vec2; 4]> {
+        Polygon::new(self.corners())
+    }
+}
+
+impl Closed for Aabb {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        if point.cmpge(self.min).all() && point.cmple(self.max).all() {
+            2
+        } else {
+            0
+        }
+    }
+}
+
+impl Integrable for Aabb {
+    fn moment(&self) -> Moment {
+        Moment {
+            area: self.size().element_product(),
+            centroid: self.center(),
+        }
+    }
+}
+
+impl_approx_eq!(Aabb, f32, min, max);
+
+/// Shape with known bounding box.
+pub trait Bounded {
+    /// Smallest axis-aligned box containing the shape.
+    fn aabb(&self) -> Aabb;
+}
+
+impl Bounded for Vec2 {
+    fn aabb(&self) -> Aabb {
+        Aabb::new(*self, *self)
+    }
+}
+
+impl Bounded for LineSegment {
+    fn aabb(&self) -> Aabb {
+        Aabb::from_points([self.0, self.1])
+    }
+}
+
+impl Bounded for Arc {
+    fn aabb(&self) -> Aabb {
+        let ends = self.chord().aabb();
+        let circle = match self.circle() {
+            Some(circle) => circle,
+            None => return ends,
+        };
+        // Extreme points of the circle that lie on the arc
+        [Vec2::X, Vec2::Y, Vec2::NEG_X, Vec2::NEG_Y]
+            .into_iter()
+            .map(|dir| circle.center + dir * circle.radius)
+            .filter(|&p| (0.0..=1.0).contains(&self.param_of(p)))
+            .fold(ends, Aabb::expand)
+    }
+}
+
+impl Bounded for Circle {
+    fn aabb(&self) -> Aabb {
+        Aabb::new(self.center - self.radius, self.center + self.radius)
+    }
+}
+
+impl Bounded for Disk {
+    fn aabb(&self) -> Aabb {
+        self.edge().aabb()
+    }
+}
+
+impl Bounded for Capsule {
+    fn aabb(&self) -> Aabb {
+        self.segment.aabb().inflate(self.radius)
+    }
+}
+
+impl Bounded for Triangle {
+    fn aabb(&self) -> Aabb {
+        Aabb::from_points(self.vertices())
+    }
+}
+
+impl Bounded for Aabb {
+    fn aabb(&self) -> Aabb {
+        *self
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Bounded for Polygon<V> {
+    fn aabb(&self) -> Aabb {
+        Aabb::from_points(self.vertices())
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Bounded for ArcPolygon<V> {
+    fn aabb(&self) -> Aabb {
+        self.edges()
+            .map(|arc| arc.aabb())
+            .fold(Aabb::EMPTY, Aabb::union)
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Bounded for GenericPath<V, Vec2> {
+    fn aabb(&self) -> Aabb {
+        Aabb::from_points(self.vertices())
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Bounded for Path<V> {
+    fn aabb(&self) -> Aabb {
+        self.edges().map(|arc| arc.aabb()).fold(
+            Aabb::from_points(self.vertices().map(|v| v.point)),
+            Aabb::union,
+        )
+    }
+}
+
+impl<C: ?Sized> Bounded for Region<C>
+where
+    for<'a> &'a C: IntoIterator<Item: Bounded>,
+{
+    fn aabb(&self) -> Aabb {
+        self.components()
+            .map(|contour| contour.aabb())
+            .fold(Aabb::EMPTY, Aabb::union)
+    }
+}
+
+impl<T: Bounded + ?Sized> Bounded for &T {
+    fn aabb(&self) -> Aabb {
+        (**self).aabb()
+    }
+}
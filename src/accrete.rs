@@ -0,0 +1,225 @@
+use crate::{
+    Aabb, Bounded, Closed, CopyIterator, Disk, Integrable, LineSegment, Moment, Polygon, Region,
+    segment_intersections,
+};
+use alloc::{vec, vec::Vec};
+use glam::Vec2;
+
+/// Relative distance at which edge sides are probed, and intersections are snapped to the edge ends.
+const PROBE: f32 = 1e-5;
+
+/// Number of vertices below which the region is never simplified.
+const MIN_SIMPLIFY: usize = 64;
+
+/// Region growing by the union with many added shapes, e.g. the area covered by a spray or explored in a game.
+///
+/// The region is kept as its outline: counterclockwise components and clockwise holes.
+/// Adding a shape splits only the edges crossing it, and the parts of the outline covered by the shape are dropped.
+/// Each part of the split edges is tested for containment in the other outline, so each addition takes
+/// `O((n + k) m + (m + k) n)` time, where `n` is the number of the region vertices,
+/// `m` is the number of the shape vertices and `k` is the number of their crossings.
+///
+/// Outlines of many overlapping shapes accumulate vertices, so the outline is simplified within the tolerance
+/// each time the number of its vertices doubles since the last simplification.
+/// Disks are added as inscribed regular polygons within the same tolerance.
+#[derive(Clone, Debug)]
+pub struct AccretedRegion {
+    region: Region<Vec<Polygon<Vec<Vec2>>>>,
+    tolerance: f32,
+    /// Number of vertices after the last simplification.
+    simplified: usize,
+}
+
+impl AccretedRegion {
+    /// Create an empty region with the `tolerance` of simplification.
+    pub fn new(tolerance: f32) -> Self {
+        Self {
+            region: Region::default(),
+            tolerance,
+            simplified: 0,
+        }
+    }
+
+    /// Contours of the region outline.
+    pub fn contours(&self) -> &[Polygon<Vec<Vec2>>] {
+        &self.region.contours
+    }
+
+    /// The outline as [`Region`].
+    pub fn region(&self) -> &Region<Vec<Polygon<Vec<Vec2>>>> {
+        &self.region
+    }
+
+    /// Check that nothing was added.
+    pub fn is_empty(&self) -> bool {
+        self.region.is_empty()
+    }
+
+    /// Total number of vertices of the contours.
+    pub fn vertex_count(&self) -> usize {
+        self.region.components().map(|contour| contour.len()).sum()
+    }
+
+    /// Add the simple `polygon` of any orientation to the region.
+    pub fn add_polygon<V: CopyIterator<Item = Vec2> + ?Sized>(&mut self, polygon: &Polygon<V>) {
+        let mut shape: Vec<Vec2> = polygon.vertices().collect();
+        if shape.len() < 3 {
+            return;
+        }
+        if Polygon::new(shape.to_ref()).signed_area() < 0.0 {
+            shape.reverse();
+        }
+        self.unite(Polygon::new(shape));
+        if self.vertex_count() > MIN_SIMPLIFY.max(2 * self.simplified) {
+            self.simplify();
+        }
+    }
+
+    /// Add the `disk` to the region.
+    pub fn add_disk(&mut self, disk: &Disk) {
+        self.add_polygon(&disk.to_polygon::<Vec<Vec2>>(self.tolerance));
+    }
+
+    /// Remove the outline vertices deviating from it less than the tolerance.
+    ///
+    /// It is called automatically, so it is needed only to get the simplest outline right away.
+    /// Contours collapsing within the tolerance are removed.
+    pub fn simplify(&mut self) {
+        let tolerance = self.tolerance;
+        self.region.contours = self
+            .region
+            .contours
+            .iter()
+            .map(|contour| contour.simplify::<Vec<Vec2>>(tolerance))
+            .filter(|contour| {
+                contour.len() >= 3 && contour.signed_area().abs() > tolerance * tolerance
+            })
+            .collect();
+        self.simplified = self.vertex_count();
+    }
+
+    /// Unite the region with the counterclockwise `shape`.
+    fn unite(&mut self, shape: Polygon<Vec<Vec2>>) {
+        let shape_bounds = Aabb::from_points(shape.vertices());
+        let bounds = self.aabb().union(shape_bounds);
+        let probe = PROBE * bounds.size().max_element();
+
+        // Edges of the region come first, then the edges of the shape
+        let edges: Vec<LineSegment> = self
+            .region
+            .components()
+            .flat_map(|contour| contour.edges())
+            .chain(shape.edges())
+            .collect();
+        let count = edges.len() - shape.len();
+        let mut splits: Vec<Vec<Vec2>> = vec![Vec::new(); edges.len()];
+        // Only the edges near the shape may cross it, so the rest are not passed to the sweep
+        let indices: Vec<usize> = (0..edges.len())
+            .filter(|&i| Aabb::from_points([edges[i].0, edges[i].1]).intersects(&shape_bounds))
+            .collect();
+        let candidates = indices.iter().map(|&i| edges[i]);
+        for (i, j, point) in segment_intersections(candidates) {
+            let (i, j) = (indices[i], indices[j]);
+            if (i < count) == (j < count) {
+                continue;
+            }
+            // Point close to an edge end is replaced by it, so both edges are split at exactly the same point
+            let point = [edges[i].0, edges[i].1, edges[j].0, edges[j].1]
+                .into_iter()
+                .find(|end| end.distance(point) < probe)
+                .unwrap_or(point);
+            splits[i].push(point);
+            splits[j].push(point);
+        }
+
+        let region = &self.region;
+        let mut kept = Vec::new();
+        for (i, edge) in edges.iter().enumerate() {
+            let mut points = core::mem::take(&mut splits[i]);
+            points.push(edge.0);
+            points.push(edge.1);
+            let direction = edge.1 - edge.0;
+            points.sort_by(|a, b| {
+                (*a - edge.0)
+                    .dot(direction)
+                    .total_cmp(&(*b - edge.0).dot(direction))
+            });
+            points.dedup();
+            for pair in points.windows(2) {
+                let part = LineSegment(pair[0], pair[1]);
+                let (middle, normal) = (part.center(), direction.perp().normalize_or_zero());
+                let (left, right) = (middle + normal * probe, middle - normal * probe);
+                // Outline of the union goes where the outside of either contour is not covered by the other one,
+                // and the shape edge lying on the region outline is dropped in favour of the region one
+                let keep = if i < count {
+                    !shape.contains(right)
+                } else {
+                    !region.contains(left) && !region.contains(right)
+                };
+                if keep {
+                    kept.push(part);
+                }
+            }
+        }
+        self.region.contours = link(kept);
+    }
+}
+
+/// Join the edges into closed contours by their ends.
+///
+/// Where several edges start at the same point, the one turning left the most is taken,
+/// so the contours touching at a vertex are separated, since each of them keeps its inside on the left.
+pub(crate) fn link(mut edges: Vec<LineSegment>) -> Vec<Polygon<Vec<Vec2>>> {
+    let key = |p: Vec2| (p.x.to_bits(), p.y.to_bits());
+    edges.sort_by_key(|edge| key(edge.0));
+    let mut used = vec![false; edges.len()];
+    let mut contours = Vec::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        let mut points = Vec::new();
+        let mut i = first;
+        loop {
+            used[i] = true;
+            let edge = edges[i];
+            points.push(edge.0);
+            let start = edges.partition_point(|e| key(e.0) < key(edge.1));
+            let next = (start..edges.len())
+                .take_while(|&j| key(edges[j].0) == key(edge.1))
+                .filter(|&j| !used[j] || j == first)
+                .max_by(|&a, &b| {
+                    let turn = |j: usize| edge.vec().angle_to(edges[j].vec());
+                    turn(a).total_cmp(&turn(b))
+                });
+            match next {
+                Some(j) if j != first => i = j,
+                _ => break,
+            }
+        }
+        if points.len() >= 3 {
+            contours.push(Polygon::new(points));
+        }
+    }
+    contours
+}
+
+impl Closed for AccretedRegion {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        self.region.winding_number_2(point)
+    }
+}
+
+impl Integrable for AccretedRegion {
+    fn moment(&self) -> Moment {
+        self.region.moment()
+    }
+}
+
+impl Bounded for AccretedRegion {
+    fn aabb(&self) -> Aabb {
+        self.region.components().fold(Aabb::EMPTY, |a, contour| {
+            a.union(Aabb::from_points(contour.vertices()))
+        })
+    }
+}
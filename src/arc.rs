@@ -1,7 +1,21 @@
-use crate::{Closed, Disk, EPS, Edge, Integrable, LineSegment, Moment, Vertex, impl_approx_eq};
+use crate::{
+    Circle, Closed, Disk, EPS, Edge, Integrable, Line, LineSegment, Moment, Vertex, impl_approx_eq,
+};
 use core::{f32::consts::PI, ops::Deref};
+use either::Either;
 use glam::Vec2;
 
+/// Maximal number of segments an arc or a circle is approximated by.
+pub(crate) const MAX_SEGMENT_COUNT: usize = 1 << 20;
+
+/// The largest sweep angle of an arc of the `radius`, which chord deviates from it by no more than the `tolerance`.
+///
+/// Chord of the `angle` sweep deviates from the arc by `radius * (1 - cos(angle / 2)) = 2 * radius * sin(angle / 4)^2`.
+/// The latter form doesn't lose precision for tolerances much smaller than the radius, unlike `acos(1 - tolerance / radius)`.
+pub(crate) fn chord_angle(radius: f32, tolerance: f32) -> f32 {
+    4.0 * (tolerance / (2.0 * radius)).clamp(0.0, 1.0).sqrt().asin()
+}
+
 /// Circular arc.
 ///
 /// Defined by:
@@ -39,10 +53,222 @@ pub struct Arc {
 }
 
 impl Arc {
+    /// Construct the arc of the circle going from `a` to `b`.
+    ///
+    /// Both points must lie on the circle.
+    /// If `ccw` is `true` then the arc goes counterclockwise around the `center`, otherwise — clockwise.
+    pub fn from_circle_points(center: Vec2, radius: f32, a: Vec2, b: Vec2, ccw: bool) -> Self {
+        let sign = if ccw { 1.0 } else { -1.0 };
+        let sagitta = if (b - a).abs().max_element() < EPS {
+            0.0
+        } else {
+            sign * radius - Line(a, b).signed_distance(center)
+        };
+        Self {
+            points: (a, b),
+            sagitta,
+        }
+    }
+
+    /// Construct the arc of the circle starting at the `start` angle and turning by the `sweep` angle.
+    ///
+    /// Angles are measured counterclockwise from the X axis, so positive `sweep` gives a counterclockwise arc.
+    /// The `sweep` must be less than a full turn by its absolute value.
+    pub fn from_center_angles(center: Vec2, radius: f32, start: f32, sweep: f32) -> Self {
+        let point = |angle: f32| center + radius * Vec2::from_angle(angle);
+        Self {
+            points: (point(start), point(start + sweep)),
+            sagitta: sweep.signum() * radius * (1.0 - (0.5 * sweep).cos()),
+        }
+    }
+
+    /// Construct the arc going from `a` to `b` through the `m` point.
+    ///
+    /// If the points are collinear, then the arc is flat.
+    pub fn from_three_points(a: Vec2, m: Vec2, b: Vec2) -> Self {
+        match Circle::from_points(a, m, b) {
+            Either::Left(circle) => Self::from_circle_points(
+                circle.center,
+                circle.radius,
+                a,
+                b,
+                (m - a).perp_dot(b - m) > 0.0,
+            ),
+            Either::Right(_) => Self {
+                points: (a, b),
+                sagitta: 0.0,
+            },
+        }
+    }
+
     /// Get the chord connecting the endpoints of this arc.
     pub fn chord(&self) -> LineSegment {
         LineSegment(self.points.0, self.points.1)
     }
+
+    /// Check that the arc is indistinguishable from its chord.
+    pub fn is_flat(&self) -> bool {
+        self.sagitta.abs() < EPS
+    }
+
+    /// Check that the arc goes counterclockwise around its center.
+    pub fn is_ccw(&self) -> bool {
+        self.sagitta > 0.0
+    }
+
+    /// Unit normal of the chord pointing towards the arc midpoint.
+    pub fn normal(&self) -> Vec2 {
+        let (a, b) = self.points;
+        -(b - a).perp().normalize_or_zero() * self.sagitta.signum()
+    }
+
+    /// Radius of the arc circle.
+    ///
+    /// It is infinite for a flat arc.
+    pub fn radius(&self) -> f32 {
+        let s = self.sagitta.abs();
+        let h = 0.5 * (self.points.1 - self.points.0).length();
+        (h.powi(2) + s.powi(2)) / (2.0 * s)
+    }
+
+    /// Center of the arc circle.
+    ///
+    /// The arc must not be flat.
+    pub fn center(&self) -> Vec2 {
+        self.chord().center() + self.normal() * (self.sagitta.abs() - self.radius())
+    }
+
+    /// Circle that the arc lies on.
+    ///
+    /// Returns `None` if the arc is flat.
+    pub fn circle(&self) -> Option<Circle> {
+        if self.is_flat() {
+            None
+        } else {
+            Some(Circle {
+                center: self.center(),
+                radius: self.radius(),
+            })
+        }
+    }
+
+    /// Midpoint of the arc.
+    pub fn midpoint(&self) -> Vec2 {
+        self.chord().center() + self.normal() * self.sagitta.abs()
+    }
+
+    /// Angle of the arc, signed by the direction of rotation (positive is counterclockwise).
+    pub fn sweep(&self) -> f32 {
+        if self.is_flat() {
+            return 0.0;
+        }
+        let h = 0.5 * (self.points.1 - self.points.0).length();
+        2.0 * 2.0 * (self.sagitta.abs()).atan2(h) * self.sagitta.signum()
+    }
+
+    /// Tangent of the quarter of the arc sweep.
+    ///
+    /// This is how arcs are defined in DXF polylines, positive bulge means counterclockwise arc.
+    pub fn bulge(&self) -> f32 {
+        let h = 0.5 * (self.points.1 - self.points.0).length();
+        if h < EPS { 0.0 } else { self.sagitta / h }
+    }
+
+    /// Length of the arc.
+    pub fn length(&self) -> f32 {
+        if self.is_flat() {
+            self.chord().vec().length()
+        } else {
+            self.radius() * self.sweep().abs()
+        }
+    }
+
+    /// Point on the arc at the fraction `t` of its sweep, where `0` is the first end and `1` is the second one.
+    pub fn point_at(&self, t: f32) -> Vec2 {
+        if self.is_flat() {
+            return self.points.0.lerp(self.points.1, t);
+        }
+        let center = self.center();
+        center + Vec2::from_angle(self.sweep() * t).rotate(self.points.0 - center)
+    }
+
+    /// Fraction of the arc sweep at which the `point` lying on the arc circle is located.
+    ///
+    /// The result is in range `[0, 1]` for points lying on the arc
+    /// and greater than `1` for other points of the circle.
+    pub fn param_of(&self, point: Vec2) -> f32 {
+        if self.is_flat() {
+            let r = self.points.1 - self.points.0;
+            return (point - self.points.0).dot(r) / r.length_squared();
+        }
+        let center = self.center();
+        let (u, v) = (self.points.0 - center, point - center);
+        let sweep = self.sweep();
+        let angle = (u.perp_dot(v).atan2(u.dot(v)) * sweep.signum()).rem_euclid(2.0 * PI);
+        angle / sweep.abs()
+    }
+
+    /// Get the point of the arc closest to the given `point`.
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let circle = match self.circle() {
+            Some(circle) => circle,
+            None => return self.chord().closest_point(point),
+        };
+        let projection = match (point - circle.center).try_normalize() {
+            Some(dir) => circle.center + dir * circle.radius,
+            None => return self.midpoint(),
+        };
+        if self.param_of(projection) <= 1.0 {
+            projection
+        } else {
+            let (a, b) = self.points;
+            if a.distance_squared(point) < b.distance_squared(point) {
+                a
+            } else {
+                b
+            }
+        }
+    }
+
+    /// Number of equal parts the arc must be split into, so that their chords deviate from the arc by no more than the `tolerance`.
+    ///
+    /// Flat arc is a single segment. The count is limited by `2^20`, which is reached only for
+    /// the tolerance close to the precision of the arc coordinates.
+    pub fn segment_count(&self, tolerance: f32) -> usize {
+        if self.is_flat() {
+            return 1;
+        }
+        let count = (self.sweep().abs() / chord_angle(self.radius(), tolerance)).ceil();
+        // Zero tolerance gives infinite count which is saturated
+        count.max(1.0).min(MAX_SEGMENT_COUNT as f32) as usize
+    }
+
+    /// Points of the polyline approximating the arc so that it deviates from the arc by no more than the `tolerance`.
+    ///
+    /// Points are evenly spaced along the arc, see [`Arc::segment_count`].
+    /// The first and the last points are exactly the arc ends. The `tolerance` must be positive.
+    pub fn flatten(&self, tolerance: f32) -> impl Iterator<Item = Vec2> + use<> {
+        let arc = *self;
+        let count = self.segment_count(tolerance);
+        (0..=count).map(move |i| match i {
+            0 => arc.points.0,
+            i if i == count => arc.points.1,
+            i => arc.point_at(i as f32 / count as f32),
+        })
+    }
+
+    /// Part of the arc between the points lying on it.
+    pub fn sub_arc(&self, a: Vec2, b: Vec2) -> Self {
+        match self.circle() {
+            Some(circle) => {
+                Self::from_circle_points(circle.center, circle.radius, a, b, self.is_ccw())
+            }
+            None => Self {
+                points: (a, b),
+                sagitta: 0.0,
+            },
+        }
+    }
 }
 
 /// Start point of an [`Arc`] with its sagitta.
@@ -73,6 +299,16 @@ impl Vertex for ArcVertex {
     }
 }
 
+/// Vertex of a straight edge.
+impl From<Vec2> for ArcVertex {
+    fn from(point: Vec2) -> Self {
+        Self {
+            point,
+            sagitta: 0.0,
+        }
+    }
+}
+
 impl Deref for ArcVertex {
     type Target = Vec2;
     fn deref(&self) -> &Self::Target {
@@ -117,7 +353,7 @@ impl Closed for DiskSegment {
 }
 
 /// Maximum ratio between sagitta and radius where the circle arc can be approximated by the parabola.
-const APPROX_CIRCLE: f32 = 1e-4;
+pub(crate) const APPROX_CIRCLE: f32 = 1e-4;
 
 impl Integrable for DiskSegment {
     fn moment(&self) -> Moment {
@@ -151,9 +387,11 @@ impl Integrable for DiskSegment {
             }
         };
 
-        let normal = -(b - a).perp() / (2.0 * h) * self.0.sagitta.signum();
+        // Negative sagitta produces a negatively oriented segment
+        let sign = self.0.sagitta.signum();
+        let normal = -(b - a).perp() / (2.0 * h) * sign;
         Moment {
-            area: area * radius.powi(2),
+            area: sign * area * radius.powi(2),
             centroid: c + normal * (s + radius * (offset - 1.0)),
         }
     }
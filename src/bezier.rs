@@ -0,0 +1,318 @@
+use crate::{Aabb, Bounded, Intersect, Line, LineSegment, impl_approx_eq};
+use arrayvec::ArrayVec;
+use core::f32::consts::PI;
+use glam::Vec2;
+
+/// Quadratic Bézier curve defined by its end points and a control point between them.
+///
+/// ```text
+///         c
+///         *
+///       .   .
+///     .  ...  .
+///   .:'       ':.
+///  *             *
+///  a             b
+/// QuadBezier(a, c, b)
+/// ```
+///
+/// The curve starts at the first point and ends at the last one, touching the lines to the control point.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct QuadBezier(pub Vec2, pub Vec2, pub Vec2);
+
+/// Cubic Bézier curve defined by its end points and two control points between them.
+///
+/// ```text
+///    c0         c1
+///    *----------*
+///    |  ......  |
+///    |.'      '.|
+///    *          *
+///    a          b
+/// CubicBezier(a, c0, c1, b)
+/// ```
+///
+/// The curve starts at the first point and ends at the last one,
+/// its tangents at the ends point to the adjacent control points.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CubicBezier(pub Vec2, pub Vec2, pub Vec2, pub Vec2);
+
+impl QuadBezier {
+    /// Point of the curve at the parameter `t` in `[0, 1]`.
+    pub fn point_at(&self, t: f32) -> Vec2 {
+        let s = 1.0 - t;
+        s * s * self.0 + 2.0 * s * t * self.1 + t * t * self.2
+    }
+
+    /// Derivative of the curve with respect to the parameter `t`, it is tangent to the curve.
+    pub fn derivative(&self, t: f32) -> Vec2 {
+        2.0 * ((1.0 - t) * (self.1 - self.0) + t * (self.2 - self.1))
+    }
+
+    /// Coefficients of the curve in the power basis, starting from the constant one.
+    fn coefficients(&self) -> [Vec2; 3] {
+        let Self(a, b, c) = *self;
+        [a, 2.0 * (b - a), a - 2.0 * b + c]
+    }
+
+    /// Points of the polyline approximating the curve so that it deviates from the curve by no more than the `tolerance`.
+    ///
+    /// Parameter is sampled uniformly, the number of segments is chosen by the bound of the curve second derivative.
+    /// The first and the last points are the curve ends. The `tolerance` must be positive.
+    pub fn flatten(&self, tolerance: f32) -> impl Iterator<Item = Vec2> + '_ {
+        // Deviation of the chord is bounded by the second derivative `2 * |a - 2b + c|` times `dt^2 / 8`
+        let curvature = (self.0 - 2.0 * self.1 + self.2).length();
+        flatten(|t| self.point_at(t), curvature / (4.0 * tolerance))
+    }
+}
+
+impl CubicBezier {
+    /// Point of the curve at the parameter `t` in `[0, 1]`.
+    pub fn point_at(&self, t: f32) -> Vec2 {
+        let s = 1.0 - t;
+        s * s * s * self.0 + 3.0 * s * t * (s * self.1 + t * self.2) + t * t * t * self.3
+    }
+
+    /// Derivative of the curve with respect to the parameter `t`, it is tangent to the curve.
+    pub fn derivative(&self, t: f32) -> Vec2 {
+        QuadBezier(self.1 - self.0, self.2 - self.1, self.3 - self.2).point_at(t) * 3.0
+    }
+
+    /// Coefficients of the curve in the power basis, starting from the constant one.
+    fn coefficients(&self) -> [Vec2; 4] {
+        let Self(a, b, c, d) = *self;
+        [
+            a,
+            3.0 * (b - a),
+            3.0 * (a - 2.0 * b + c),
+            d - 3.0 * c + 3.0 * b - a,
+        ]
+    }
+
+    /// Points of the polyline approximating the curve so that it deviates from the curve by no more than the `tolerance`.
+    ///
+    /// Parameter is sampled uniformly, the number of segments is chosen by the bound of the curve second derivative.
+    /// The first and the last points are the curve ends. The `tolerance` must be positive.
+    pub fn flatten(&self, tolerance: f32) -> impl Iterator<Item = Vec2> + '_ {
+        // Second derivative is the lerp of `6 * (a - 2b + c)` and `6 * (b - 2c + d)`
+        let curvature = (self.0 - 2.0 * self.1 + self.2)
+            .length()
+            .max((self.1 - 2.0 * self.2 + self.3).length());
+        flatten(|t| self.point_at(t), 3.0 * curvature / (4.0 * tolerance))
+    }
+}
+
+/// Sample the curve uniformly into `ceil(sqrt(squared_count))` segments.
+fn flatten<F: Fn(f32) -> Vec2>(curve: F, squared_count: f32) -> impl Iterator<Item = Vec2> {
+    let count = if squared_count.is_finite() {
+        squared_count.sqrt().ceil().max(1.0) as usize
+    } else {
+        1
+    };
+    (0..=count).map(move |i| curve(i as f32 / count as f32))
+}
+
+/// Real roots of the polynomial with the `coefficients` starting from the constant one, lying in `[0, 1]`.
+///
+/// Roots are sorted in ascending order.
+fn unit_roots<const N: usize>(coefficients: [f32; N]) -> ArrayVec<f32, 3> {
+    let eval = |t: f32| coefficients.iter().rev().fold(0.0, |acc, &c| acc * t + c);
+    let derivative = |t: f32| {
+        coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .rev()
+            .fold(0.0, |acc, (i, &c)| acc * t + i as f32 * c)
+    };
+    let mut roots = ArrayVec::<f32, 3>::new();
+    let mut degree = N - 1;
+    let scale = coefficients.iter().fold(0.0f32, |m, c| m.max(c.abs()));
+    // Leading coefficients negligible relative to the others reduce the degree
+    while degree > 0 && coefficients[degree].abs() <= 1e-6 * scale {
+        degree -= 1;
+    }
+    let c = |i: usize| coefficients[i] / coefficients[degree];
+    match degree {
+        1 => roots.push(-c(0)),
+        2 => {
+            let (p, q) = (c(1), c(0));
+            let disc = p * p - 4.0 * q;
+            if disc >= 0.0 {
+                // Stable form avoiding cancellation
+                let u = -0.5 * (p + p.signum() * disc.sqrt());
+                if u != 0.0 {
+                    roots.extend([u, q / u]);
+                } else {
+                    roots.push(0.0);
+                }
+            }
+        }
+        3 => {
+            let (a, b, d) = (c(2), c(1), c(0));
+            // Depressed cubic `x^3 + px + q` with `t = x - a / 3`
+            let shift = a / 3.0;
+            let p = b - a * a / 3.0;
+            let q = 2.0 * a * a * a / 27.0 - a * b / 3.0 + d;
+            let disc = q * q / 4.0 + p * p * p / 27.0;
+            if disc > 0.0 {
+                let r = disc.sqrt();
+                roots.push((-0.5 * q + r).cbrt() + (-0.5 * q - r).cbrt() - shift);
+            } else if p == 0.0 {
+                roots.push(-shift);
+            } else {
+                let m = 2.0 * (-p / 3.0).sqrt();
+                let phi = (3.0 * q / (p * m)).clamp(-1.0, 1.0).acos() / 3.0;
+                roots.extend((0..3).map(|k| m * (phi - 2.0 * PI * k as f32 / 3.0).cos() - shift));
+            }
+        }
+        _ => (),
+    }
+    // Polish the roots against the original polynomial
+    for t in roots.iter_mut() {
+        for _ in 0..2 {
+            let d = derivative(*t);
+            if d != 0.0 {
+                *t -= eval(*t) / d;
+            }
+        }
+    }
+    roots.retain(|t| (-1e-4..=1.0 + 1e-4).contains(t));
+    for t in roots.iter_mut() {
+        *t = t.clamp(0.0, 1.0);
+    }
+    roots.sort_by(f32::total_cmp);
+    roots
+}
+
+/// Points of the curve given by the power basis `coefficients` lying on the `line`, in the order of the curve parameter.
+fn cross_line<const N: usize, const M: usize>(
+    coefficients: [Vec2; N],
+    line: &Line,
+) -> ArrayVec<Vec2, M> {
+    let normal = (line.1 - line.0).perp();
+    let mut distance = coefficients.map(|c| c.dot(normal));
+    distance[0] -= line.0.dot(normal);
+    unit_roots(distance)
+        .into_iter()
+        .map(|t| {
+            coefficients
+                .iter()
+                .rev()
+                .fold(Vec2::ZERO, |acc, &c| acc * t + c)
+        })
+        .collect()
+}
+
+/// Intersection of a quadratic Bézier curve with a line.
+///
+/// Returns up to two points ordered along the curve, or `None` if the line doesn't cross the curve.
+impl Intersect<Line> for QuadBezier {
+    type Output = ArrayVec<Vec2, 2>;
+    fn intersect(&self, line: &Line) -> Option<Self::Output> {
+        if line.is_degenerate() {
+            return None;
+        }
+        Some(cross_line(self.coefficients(), line)).filter(|points| !points.is_empty())
+    }
+}
+
+impl Intersect<QuadBezier> for Line {
+    type Output = ArrayVec<Vec2, 2>;
+    fn intersect(&self, curve: &QuadBezier) -> Option<Self::Output> {
+        curve.intersect(self)
+    }
+}
+
+/// Intersection of a cubic Bézier curve with a line.
+///
+/// Returns up to three points ordered along the curve, or `None` if the line doesn't cross the curve.
+impl Intersect<Line> for CubicBezier {
+    type Output = ArrayVec<Vec2, 3>;
+    fn intersect(&self, line: &Line) -> Option<Self::Output> {
+        if line.is_degenerate() {
+            return None;
+        }
+        Some(cross_line(self.coefficients(), line)).filter(|points| !points.is_empty())
+    }
+}
+
+impl Intersect<CubicBezier> for Line {
+    type Output = ArrayVec<Vec2, 3>;
+    fn intersect(&self, curve: &CubicBezier) -> Option<Self::Output> {
+        curve.intersect(self)
+    }
+}
+
+/// Points of the line intersection lying on the segment.
+fn on_segment<const M: usize>(
+    points: ArrayVec<Vec2, M>,
+    segment: &LineSegment,
+) -> Option<ArrayVec<Vec2, M>> {
+    Some(
+        points
+            .into_iter()
+            .filter(|&p| segment.is_between(p))
+            .collect(),
+    )
+    .filter(|points: &ArrayVec<Vec2, M>| !points.is_empty())
+}
+
+impl Intersect<LineSegment> for QuadBezier {
+    type Output = ArrayVec<Vec2, 2>;
+    fn intersect(&self, segment: &LineSegment) -> Option<Self::Output> {
+        on_segment(self.intersect(&Line(segment.0, segment.1))?, segment)
+    }
+}
+
+impl Intersect<QuadBezier> for LineSegment {
+    type Output = ArrayVec<Vec2, 2>;
+    fn intersect(&self, curve: &QuadBezier) -> Option<Self::Output> {
+        curve.intersect(self)
+    }
+}
+
+impl Intersect<LineSegment> for CubicBezier {
+    type Output = ArrayVec<Vec2, 3>;
+    fn intersect(&self, segment: &LineSegment) -> Option<Self::Output> {
+        on_segment(self.intersect(&Line(segment.0, segment.1))?, segment)
+    }
+}
+
+impl Intersect<CubicBezier> for LineSegment {
+    type Output = ArrayVec<Vec2, 3>;
+    fn intersect(&self, curve: &CubicBezier) -> Option<Self::Output> {
+        curve.intersect(self)
+    }
+}
+
+impl_approx_eq!(QuadBezier, f32, 0, 1, 2);
+impl_approx_eq!(CubicBezier, f32, 0, 1, 2, 3);
+
+/// Bounding box of the curve given by the power basis `coefficients` and the `curve` itself.
+fn bounds<const N: usize, F: Fn(f32) -> Vec2>(coefficients: [Vec2; N], curve: F) -> Aabb {
+    // Coordinates are extreme either at the ends or where their derivatives vanish
+    [0, 1]
+        .into_iter()
+        .flat_map(|axis| {
+            let mut derivative = [0.0; 3];
+            for (i, c) in coefficients.iter().enumerate().skip(1) {
+                derivative[i - 1] = i as f32 * c[axis];
+            }
+            unit_roots(derivative)
+        })
+        .map(&curve)
+        .fold(Aabb::from_points([curve(0.0), curve(1.0)]), Aabb::expand)
+}
+
+impl Bounded for QuadBezier {
+    fn aabb(&self) -> Aabb {
+        bounds(self.coefficients(), |t| self.point_at(t))
+    }
+}
+
+impl Bounded for CubicBezier {
+    fn aabb(&self) -> Aabb {
+        bounds(self.coefficients(), |t| self.point_at(t))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{ArcPolygon, ArcVertex, CopyIterator, Disk, LineSegment, Polygon};
+use core::f32::consts::TAU;
+use glam::Vec2;
+
+/// Shape in between the `disk` at `t = 0` and the `polygon` at `t = 1`.
+///
+/// Each polygon vertex is moved from its central projection onto the disk boundary to its actual position,
+/// and each edge is bent from the circle arc between the projections to the straight segment.
+/// The sagitta to chord ratio of the arc is scaled by `1 - t`, so the edges flatten smoothly.
+///
+/// The polygon must be counterclockwise and star-shaped with respect to the disk center,
+/// otherwise the intermediate shapes may self-intersect.
+/// The result has the same number of vertices as the polygon, so more vertices give a rounder disk end.
+pub fn blend<V, W>(disk: &Disk, polygon: &Polygon<V>, t: f32) -> ArcPolygon<W>
+where
+    V: CopyIterator<Item = Vec2> + ?Sized,
+    W: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>,
+{
+    let direction = |v: Vec2| (v - disk.center).try_normalize().unwrap_or(Vec2::X);
+    let project = |v: Vec2| disk.center + disk.radius * direction(v);
+    ArcPolygon::new(
+        polygon
+            .edges()
+            .map(|LineSegment(a, b)| {
+                // Sagitta to chord ratio of the arc between the projections
+                let angle = direction(a).angle_to(direction(b)).rem_euclid(TAU);
+                let chord = 2.0 * disk.radius * (0.5 * angle).sin();
+                let ratio = if chord > 0.0 {
+                    disk.radius * (1.0 - (0.5 * angle).cos()) / chord
+                } else {
+                    0.0
+                };
+                let (start, end) = (project(a).lerp(a, t), project(b).lerp(b, t));
+                ArcVertex {
+                    point: start,
+                    sagitta: (1.0 - t) * ratio * start.distance(end),
+                }
+            })
+            .collect(),
+    )
+}
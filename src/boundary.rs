@@ -0,0 +1,437 @@
+use crate::{
+    Aabb, Arc, ArcPolygon, ArcVertex, Bounded, Capsule, Circle, CopyIterator, Disk, DiskSegment,
+    Edge, LineSegment, Polygon, Region, Triangle,
+};
+#[cfg(feature = "alloc")]
+use crate::{EPS, Path};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use arrayvec::ArrayVec;
+use core::f32::consts::PI;
+use either::Either;
+use glam::Vec2;
+
+/// Edge of a shape boundary.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BoundaryEdge {
+    /// Straight edge.
+    Segment(LineSegment),
+    /// Circular arc edge.
+    Arc(Arc),
+}
+
+impl BoundaryEdge {
+    /// Start point of the edge.
+    pub fn start(&self) -> Vec2 {
+        match self {
+            Self::Segment(segment) => segment.0,
+            Self::Arc(arc) => arc.points.0,
+        }
+    }
+
+    /// End point of the edge.
+    pub fn end(&self) -> Vec2 {
+        match self {
+            Self::Segment(segment) => segment.1,
+            Self::Arc(arc) => arc.points.1,
+        }
+    }
+
+    /// Length of the edge.
+    pub fn length(&self) -> f32 {
+        match self {
+            Self::Segment(segment) => segment.vec().length(),
+            Self::Arc(arc) => arc.length(),
+        }
+    }
+
+    /// Point of the edge at the fraction `t` of its length.
+    pub fn point_at(&self, t: f32) -> Vec2 {
+        match self {
+            Self::Segment(LineSegment(a, b)) => a.lerp(*b, t),
+            Self::Arc(arc) => arc.point_at(t),
+        }
+    }
+
+    /// Get the point of the edge closest to the given `point`.
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        match self {
+            Self::Segment(segment) => segment.closest_point(point),
+            Self::Arc(arc) => arc.closest_point(point),
+        }
+    }
+
+    /// Length of the edge part from its start to the point closest to the given `point`.
+    pub fn length_to(&self, point: Vec2) -> f32 {
+        match self {
+            Self::Segment(segment) => segment.closest_point(point).distance(segment.0),
+            Self::Arc(arc) => {
+                let closest = arc.closest_point(point);
+                let mut t = arc.param_of(closest);
+                // Start point may be rounded to the full circle
+                if t > 1.0 && closest.distance(arc.points.0) < closest.distance(arc.points.1) {
+                    t = 0.0;
+                }
+                t.min(1.0) * arc.length()
+            }
+        }
+    }
+
+    /// Representation of the edge as an arc, straight edges have zero sagitta.
+    pub fn arc(&self) -> Arc {
+        match *self {
+            Self::Segment(LineSegment(a, b)) => Arc {
+                points: (a, b),
+                sagitta: 0.0,
+            },
+            Self::Arc(arc) => arc,
+        }
+    }
+}
+
+impl From<LineSegment> for BoundaryEdge {
+    fn from(segment: LineSegment) -> Self {
+        Self::Segment(segment)
+    }
+}
+
+impl From<Arc> for BoundaryEdge {
+    /// Flat arcs are converted to straight edges.
+    fn from(arc: Arc) -> Self {
+        if arc.is_flat() {
+            Self::Segment(arc.chord())
+        } else {
+            Self::Arc(arc)
+        }
+    }
+}
+
+impl Bounded for BoundaryEdge {
+    fn aabb(&self) -> Aabb {
+        match self {
+            Self::Segment(segment) => segment.aabb(),
+            Self::Arc(arc) => arc.aabb(),
+        }
+    }
+}
+
+/// Closed shape which boundary consists of straight and circular edges.
+pub trait Boundary {
+    /// Edges of the shape boundary.
+    ///
+    /// Each contour goes counterclockwise around the shape interior (clockwise for holes),
+    /// and the contours follow each other.
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_;
+
+    /// Total length of the boundary.
+    fn boundary_length(&self) -> f32 {
+        self.boundary().map(|edge| edge.length()).sum()
+    }
+}
+
+/// Length of the `shape` boundary going from `p0` to `p1` in the positive orientation.
+///
+/// Points are projected to the closest boundary points first, so they may lie slightly off the boundary.
+/// The boundary is treated as a single closed contour, so the shape must have one, like a polygon or an arc polygon.
+/// Returns `0` if both points are the same, and the result is less than [`Boundary::boundary_length`] otherwise.
+pub fn boundary_span<S: Boundary + ?Sized>(shape: &S, p0: Vec2, p1: Vec2) -> f32 {
+    // Distance to the closest point and its position along the boundary for both points
+    let mut closest = [(f32::INFINITY, 0.0); 2];
+    let mut total = 0.0;
+    for edge in shape.boundary() {
+        for (point, closest) in [p0, p1].into_iter().zip(&mut closest) {
+            let distance = edge.closest_point(point).distance(point);
+            if distance < closest.0 {
+                *closest = (distance, total + edge.length_to(point));
+            }
+        }
+        total += edge.length();
+    }
+    if total > 0.0 {
+        (closest[1].1 - closest[0].1).rem_euclid(total)
+    } else {
+        0.0
+    }
+}
+
+/// Part of the `shape` boundary going from `p0` to `p1` in the positive orientation.
+///
+/// Points are projected to the closest boundary points, and the path starts and ends exactly at the projections.
+/// Edges are cut at the points and kept as they are between them, so straight edges have zero sagitta.
+/// Like [`boundary_span`] the boundary is treated as a single closed contour.
+/// Returns the path of a single point if both points are the same, and the empty path if the shape has no boundary.
+#[cfg(feature = "alloc")]
+pub fn boundary_between<S: Boundary + ?Sized>(
+    shape: &S,
+    p0: Vec2,
+    p1: Vec2,
+) -> Path<Vec<ArcVertex>> {
+    let edges: Vec<BoundaryEdge> = shape.boundary().collect();
+    // Index of the edge closest to the point, the position along it and the closest point itself
+    let locate = |point: Vec2| {
+        edges
+            .iter()
+            .enumerate()
+            .map(|(i, edge)| {
+                let closest = edge.closest_point(point);
+                (closest.distance(point), i, edge.length_to(point), closest)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, i, position, closest)| (i, position, closest))
+    };
+    let (Some((i0, s0, q0)), Some((i1, s1, q1))) = (locate(p0), locate(p1)) else {
+        return Path::new(Vec::new());
+    };
+    let n = edges.len();
+    let mut arcs = Vec::new();
+    if i0 == i1 && s0 <= s1 {
+        arcs.push(edges[i0].arc().sub_arc(q0, q1));
+    } else {
+        arcs.push(edges[i0].arc().sub_arc(q0, edges[i0].end()));
+        let mut i = (i0 + 1) % n;
+        while i != i1 {
+            arcs.push(edges[i].arc());
+            i = (i + 1) % n;
+        }
+        arcs.push(edges[i1].arc().sub_arc(edges[i1].start(), q1));
+    }
+    arcs.retain(|arc| arc.points.0.distance(arc.points.1) >= EPS);
+    Path::new(
+        arcs.iter()
+            .map(|arc| ArcVertex {
+                point: arc.points.0,
+                sagitta: arc.sagitta,
+            })
+            .chain([ArcVertex {
+                point: q1,
+                sagitta: 0.0,
+            }])
+            .collect(),
+    )
+}
+
+/// Curve or shape boundary which points can be placed by the length along it.
+///
+/// Closed boundaries start at their first vertex and go in the positive orientation,
+/// circles start at the point with the maximal X coordinate and go counterclockwise.
+pub trait SampleBoundary {
+    /// Point at the length `s` along the boundary from its start.
+    ///
+    /// For closed boundaries `s` wraps around the total length, for open curves it is clamped to their ends.
+    /// Returns `None` if there is no boundary.
+    fn point_at_length(&self, s: f32) -> Option<Vec2>;
+
+    /// Evenly spaced points of the boundary, starting from its start.
+    ///
+    /// The `spacing` is adjusted to divide the boundary length into a whole number of steps,
+    /// so the last point of a closed boundary is one step away from the first one, and open curves end at their end.
+    /// Closed boundaries of zero length give no points, and non-positive `spacing` gives only the start point.
+    fn sample_boundary(&self, spacing: f32) -> impl Iterator<Item = Vec2> + '_;
+}
+
+/// Number of the steps of about the `spacing` length to divide the `length` into.
+fn step_count(length: f32, spacing: f32) -> usize {
+    if length <= 0.0 {
+        0
+    } else if spacing > 0.0 {
+        (length / spacing).round().max(1.0) as usize
+    } else {
+        1
+    }
+}
+
+/// Point at the length `s` along the closed `shape` boundary.
+fn closed_point_at<S: Boundary + ?Sized>(shape: &S, s: f32) -> Option<Vec2> {
+    let total = shape.boundary_length();
+    let mut s = if total > 0.0 {
+        s.rem_euclid(total)
+    } else {
+        0.0
+    };
+    let mut last = None;
+    for edge in shape.boundary() {
+        let length = edge.length();
+        if s <= length {
+            return Some(edge.point_at(if length > 0.0 { s / length } else { 0.0 }));
+        }
+        s -= length;
+        last = Some(edge.end());
+    }
+    // Rounding errors may carry the remainder over the last edge
+    last
+}
+
+/// Evenly spaced points of the closed `shape` boundary, edges are visited once.
+fn sample_closed<S: Boundary + ?Sized>(shape: &S, spacing: f32) -> impl Iterator<Item = Vec2> + '_ {
+    let total = shape.boundary_length();
+    let count = step_count(total, spacing);
+    let step = total / count.max(1) as f32;
+    shape
+        .boundary()
+        .scan(0.0, |position, edge| {
+            let start = *position;
+            *position += edge.length();
+            Some((edge, start, *position))
+        })
+        .flat_map(move |(edge, start, end)| {
+            // Edge lengths are summed in the same order as the total, so the last edge reaches the count
+            let first = ((start / step).ceil() as usize).min(count);
+            let last = ((end / step).ceil() as usize).min(count);
+            (first..last).map(move |k| {
+                let length = end - start;
+                let t = if length > 0.0 {
+                    (k as f32 * step - start) / length
+                } else {
+                    0.0
+                };
+                edge.point_at(t.clamp(0.0, 1.0))
+            })
+        })
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> SampleBoundary for Polygon<V> {
+    fn point_at_length(&self, s: f32) -> Option<Vec2> {
+        closed_point_at(self, s)
+    }
+
+    fn sample_boundary(&self, spacing: f32) -> impl Iterator<Item = Vec2> + '_ {
+        sample_closed(self, spacing)
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> SampleBoundary for ArcPolygon<V> {
+    fn point_at_length(&self, s: f32) -> Option<Vec2> {
+        closed_point_at(self, s)
+    }
+
+    fn sample_boundary(&self, spacing: f32) -> impl Iterator<Item = Vec2> + '_ {
+        sample_closed(self, spacing)
+    }
+}
+
+impl SampleBoundary for Circle {
+    fn point_at_length(&self, s: f32) -> Option<Vec2> {
+        let angle = if self.radius > 0.0 {
+            s / self.radius
+        } else {
+            0.0
+        };
+        Some(self.center + self.radius * Vec2::from_angle(angle))
+    }
+
+    fn sample_boundary(&self, spacing: f32) -> impl Iterator<Item = Vec2> + '_ {
+        let count = step_count(2.0 * PI * self.radius, spacing);
+        (0..count).map(move |k| {
+            self.center + self.radius * Vec2::from_angle(2.0 * PI * k as f32 / count as f32)
+        })
+    }
+}
+
+/// Open curve going from the first point to the second one.
+impl SampleBoundary for Arc {
+    fn point_at_length(&self, s: f32) -> Option<Vec2> {
+        let length = self.length();
+        let t = if length > 0.0 { s / length } else { 0.0 };
+        Some(self.point_at(t.clamp(0.0, 1.0)))
+    }
+
+    fn sample_boundary(&self, spacing: f32) -> impl Iterator<Item = Vec2> + '_ {
+        let count = step_count(self.length(), spacing).max(1);
+        (0..=count).map(move |k| self.point_at(k as f32 / count as f32))
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Boundary for Polygon<V> {
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        self.edges().map(BoundaryEdge::Segment)
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Boundary for ArcPolygon<V> {
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        self.edges().map(BoundaryEdge::from)
+    }
+}
+
+impl Boundary for Triangle {
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        self.edges().into_iter().map(BoundaryEdge::Segment)
+    }
+}
+
+impl Boundary for Aabb {
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        let [a, b, c, d] = self.corners();
+        let empty = self.is_empty();
+        [
+            LineSegment(a, b),
+            LineSegment(b, c),
+            LineSegment(c, d),
+            LineSegment(d, a),
+        ]
+        .into_iter()
+        .filter(move |_| !empty)
+        .map(BoundaryEdge::Segment)
+    }
+}
+
+impl Boundary for Disk {
+    /// Two halves of the circle.
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        let [a, b] = self.polygon::<2>().vertices;
+        [Arc::from_vertices(&a, &b), Arc::from_vertices(&b, &a)]
+            .into_iter()
+            .map(BoundaryEdge::Arc)
+    }
+}
+
+impl Boundary for DiskSegment {
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        let (a, b) = self.points;
+        [
+            BoundaryEdge::from(self.0),
+            BoundaryEdge::Segment(LineSegment(b, a)),
+        ]
+        .into_iter()
+    }
+}
+
+impl Boundary for Capsule {
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        self.polygon()
+            .edges()
+            .map(BoundaryEdge::from)
+            .collect::<ArrayVec<_, 4>>()
+            .into_iter()
+    }
+}
+
+impl<C: ?Sized, P: Boundary> Boundary for Region<C>
+where
+    for<'a> &'a C: IntoIterator<Item = &'a P>,
+{
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        self.components().flat_map(|contour| contour.boundary())
+    }
+}
+
+impl<T: Boundary> Boundary for Option<T> {
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        self.iter().flat_map(|shape| shape.boundary())
+    }
+}
+
+impl<L: Boundary, R: Boundary> Boundary for Either<L, R> {
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        match self {
+            Either::Left(left) => Either::Left(left.boundary()),
+            Either::Right(right) => Either::Right(right.boundary()),
+        }
+    }
+}
+
+impl<T: Boundary + ?Sized> Boundary for &T {
+    fn boundary(&self) -> impl Iterator<Item = BoundaryEdge> + '_ {
+        (**self).boundary()
+    }
+}
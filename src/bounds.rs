@@ -0,0 +1,120 @@
+use crate::{Aabb, Bounded, Disk};
+use glam::Vec2;
+
+/// Bounds of a stream of points and shapes.
+///
+/// Maintains the exact bounding box and an approximate bounding disk in constant memory.
+/// The disk is grown by Ritter's method: when a point falls outside of the disk,
+/// the disk is replaced with the smallest one containing both the old disk and the point.
+/// It is then refined by comparing to the disk circumscribed around the bounding box,
+/// the smaller one is taken.
+///
+/// Due to the refinement the disk radius is at most `sqrt(2)` times larger than the minimal one.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct BoundsAccumulator {
+    aabb: Aabb,
+    disk: Option<Disk>,
+}
+
+impl BoundsAccumulator {
+    /// Create accumulator with no points.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check that nothing was added yet.
+    pub fn is_empty(&self) -> bool {
+        self.disk.is_none()
+    }
+
+    /// Add the `point` to the bounds.
+    pub fn add_point(&mut self, point: Vec2) {
+        self.aabb = self.aabb.expand(point);
+        self.grow(Disk::new(point, 0.0));
+    }
+
+    /// Add the whole `disk` to the bounds.
+    pub fn add_disk(&mut self, disk: Disk) {
+        self.aabb = self.aabb.union(disk.aabb());
+        self.grow(disk);
+    }
+
+    /// Add the `shape` to the bounds.
+    ///
+    /// The shape is approximated by its bounding box.
+    pub fn add<S: Bounded + ?Sized>(&mut self, shape: &S) {
+        let aabb = shape.aabb();
+        if aabb.is_empty() {
+            return;
+        }
+        for corner in aabb.corners() {
+            self.add_point(corner);
+        }
+    }
+
+    /// Combine bounds of two streams.
+    pub fn merge(&mut self, other: &Self) {
+        self.aabb = self.aabb.union(other.aabb);
+        if let Some(disk) = other.disk {
+            self.grow(disk);
+        }
+    }
+
+    /// Bounding box of everything added.
+    ///
+    /// Box is [empty](Aabb::is_empty) if nothing was added.
+    pub fn aabb(&self) -> Aabb {
+        self.aabb
+    }
+
+    /// Disk containing everything added.
+    ///
+    /// Returns `None` if nothing was added.
+    pub fn disk(&self) -> Option<Disk> {
+        let disk = self.disk?;
+        let circumscribed = Disk::new(self.aabb.center(), 0.5 * self.aabb.size().length());
+        Some(if circumscribed.radius < disk.radius {
+            circumscribed
+        } else {
+            disk
+        })
+    }
+
+    fn grow(&mut self, other: Disk) {
+        let disk = match self.disk {
+            Some(disk) => disk,
+            None => {
+                self.disk = Some(other);
+                return;
+            }
+        };
+        let offset = other.center - disk.center;
+        let distance = offset.length();
+        if distance + other.radius <= disk.radius {
+            return;
+        }
+        if distance + disk.radius <= other.radius {
+            self.disk = Some(other);
+            return;
+        }
+        let radius = 0.5 * (distance + disk.radius + other.radius);
+        let center = disk.center + offset * ((radius - disk.radius) / distance);
+        self.disk = Some(Disk::new(center, radius));
+    }
+}
+
+impl Extend<Vec2> for BoundsAccumulator {
+    fn extend<I: IntoIterator<Item = Vec2>>(&mut self, iter: I) {
+        for point in iter {
+            self.add_point(point);
+        }
+    }
+}
+
+impl FromIterator<Vec2> for BoundsAccumulator {
+    fn from_iter<I: IntoIterator<Item = Vec2>>(iter: I) -> Self {
+        let mut bounds = Self::new();
+        bounds.extend(iter);
+        bounds
+    }
+}
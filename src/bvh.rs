@@ -0,0 +1,203 @@
+use crate::{
+    Aabb, Bounded, Closed, CopyIterator, CopyRef, Crossings, Integrable, LineSegment, Moment,
+    Polygon, crossing::cross_edge, polygon::line::scalar_winding_number_2,
+};
+use alloc::{vec, vec::Vec};
+use core::iter;
+use glam::Vec2;
+
+/// Maximum number of edges in a leaf node.
+const LEAF_SIZE: usize = 4;
+
+/// Node of the hierarchy.
+///
+/// Leaf nodes refer to `count` consecutive edges starting from `start`,
+/// inner nodes have `count == 0` and refer to two consecutive child nodes starting from `start`.
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    aabb: Aabb,
+    start: usize,
+    count: usize,
+}
+
+impl Node {
+    fn new(edges: &[LineSegment], start: usize, count: usize) -> Self {
+        Self {
+            aabb: edges[start..start + count]
+                .iter()
+                .fold(Aabb::EMPTY, |aabb, edge| aabb.union(edge.aabb())),
+            start,
+            count,
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.count != 0
+    }
+}
+
+/// Polygon with a bounding volume hierarchy over its edges.
+///
+/// Point containment, closest point and segment crossing queries visit only the edges
+/// which bounding boxes are relevant to the query, so they take about `O(log n)` time
+/// instead of `O(n)` for a polygon with `n` vertices.
+/// Building the hierarchy takes `O(n log n)` time, so it pays off for large polygons
+/// that are queried many times.
+///
+/// Created by [`Polygon::build_index`].
+#[derive(Clone, Debug)]
+pub struct IndexedPolygon {
+    vertices: Vec<Vec2>,
+    /// Edges reordered so that each leaf refers to a contiguous range.
+    edges: Vec<LineSegment>,
+    /// Nodes of the hierarchy, the first one is the root.
+    nodes: Vec<Node>,
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Build a bounding volume hierarchy over the polygon edges.
+    ///
+    /// Edges are split recursively by the median of their centers along the longest axis.
+    pub fn build_index(&self) -> IndexedPolygon {
+        let vertices: Vec<Vec2> = self.vertices().collect();
+        let mut edges: Vec<LineSegment> = self.edges().collect();
+        let mut nodes = Vec::new();
+        let mut stack = Vec::new();
+        if !edges.is_empty() {
+            nodes.push(Node::new(&edges, 0, edges.len()));
+            stack.push(0);
+        }
+
+        while let Some(i) = stack.pop() {
+            let Node { start, count, .. } = nodes[i];
+            if count <= LEAF_SIZE {
+                continue;
+            }
+            let range = &mut edges[start..start + count];
+            let bounds = Aabb::from_points(range.iter().map(|edge| edge.center()));
+            let axis = bounds.size().max_position();
+            range.select_nth_unstable_by(count / 2, |a, b| {
+                a.center()[axis].total_cmp(&b.center()[axis])
+            });
+
+            let child = nodes.len();
+            nodes.push(Node::new(&edges, start, count / 2));
+            nodes.push(Node::new(&edges, start + count / 2, count - count / 2));
+            nodes[i].start = child;
+            nodes[i].count = 0;
+            stack.extend([child, child + 1]);
+        }
+
+        IndexedPolygon {
+            vertices,
+            edges,
+            nodes,
+        }
+    }
+}
+
+impl IndexedPolygon {
+    /// View of the indexed polygon.
+    pub fn as_polygon(&self) -> Polygon<CopyRef<'_, [Vec2]>> {
+        Polygon::new(CopyRef(self.vertices.as_slice()))
+    }
+
+    /// Edges from the leaves which bounding boxes satisfy the `filter`.
+    ///
+    /// The `filter` must also hold for all the ancestors of such leaves.
+    fn query<'a, F: Fn(&Aabb) -> bool + 'a>(
+        &'a self,
+        filter: F,
+    ) -> impl Iterator<Item = LineSegment> + 'a {
+        let mut stack = if self.nodes.is_empty() {
+            Vec::new()
+        } else {
+            vec![0]
+        };
+        iter::from_fn(move || {
+            while let Some(i) = stack.pop() {
+                let node = self.nodes[i];
+                if !filter(&node.aabb) {
+                    continue;
+                }
+                if node.is_leaf() {
+                    return Some(&self.edges[node.start..node.start + node.count]);
+                }
+                stack.extend([node.start, node.start + 1]);
+            }
+            None
+        })
+        .flatten()
+        .copied()
+    }
+
+    /// Get the point of the polygon boundary closest to the given `point`.
+    ///
+    /// Returns `None` if the polygon is empty.
+    pub fn closest_point(&self, point: Vec2) -> Option<Vec2> {
+        let distance = |aabb: &Aabb| (point.clamp(aabb.min, aabb.max) - point).length_squared();
+        let mut best: Option<(f32, Vec2)> = None;
+        let mut stack = if self.nodes.is_empty() {
+            Vec::new()
+        } else {
+            vec![0]
+        };
+        while let Some(i) = stack.pop() {
+            let node = self.nodes[i];
+            if best.is_some_and(|(d, _)| distance(&node.aabb) > d) {
+                continue;
+            }
+            if node.is_leaf() {
+                for edge in &self.edges[node.start..node.start + node.count] {
+                    let p = edge.closest_point(point);
+                    let d = (p - point).length_squared();
+                    if best.is_none_or(|(best, _)| d < best) {
+                        best = Some((d, p));
+                    }
+                }
+            } else {
+                // Nearer child is visited first
+                let (a, b) = (node.start, node.start + 1);
+                if distance(&self.nodes[a].aabb) < distance(&self.nodes[b].aabb) {
+                    stack.extend([b, a]);
+                } else {
+                    stack.extend([a, b]);
+                }
+            }
+        }
+        best.map(|(_, p)| p)
+    }
+}
+
+impl Closed for IndexedPolygon {
+    /// Same as for [`Polygon`], but only the edges crossing the horizontal ray going right from the `point` are checked.
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        scalar_winding_number_2(
+            self.query(|aabb| {
+                aabb.min.y <= point.y && aabb.max.y >= point.y && aabb.max.x >= point.x
+            }),
+            point,
+        )
+    }
+}
+
+impl Integrable for IndexedPolygon {
+    fn moment(&self) -> Moment {
+        self.as_polygon().moment()
+    }
+}
+
+impl Bounded for IndexedPolygon {
+    fn aabb(&self) -> Aabb {
+        self.nodes.first().map_or(Aabb::EMPTY, |root| root.aabb)
+    }
+}
+
+impl Crossings for IndexedPolygon {
+    fn crossings(&self, segment: &LineSegment) -> impl Iterator<Item = f32> + '_ {
+        let segment = *segment;
+        let bounds = segment.aabb();
+        self.query(move |aabb| aabb.intersects(&bounds))
+            .filter_map(move |edge| cross_edge(&segment, &edge))
+    }
+}
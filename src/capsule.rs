@@ -0,0 +1,109 @@
+use crate::{
+    ArcPolygon, ArcTarget, ArcVertex, Closed, Disk, DiskTarget, HalfPlane, Integrable, IntersectTo,
+    LineSegment, Moment, impl_approx_eq,
+};
+use core::f32::consts::PI;
+use glam::Vec2;
+
+/// Capsule (stadium) — set of points lying within `radius` from the segment.
+///
+/// ```text
+///    .-*-------------*-.
+///  /   |             |   \
+/// |    a-------------b    |
+///  \   |      r      |   /
+///    `-*-------------*-`
+/// ```
+///
+/// Where `(a, b)` is the segment, `r` is the radius.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Capsule {
+    /// Axis of the capsule.
+    pub segment: LineSegment,
+    /// Radius of the capsule caps, equal to the half of its width.
+    pub radius: f32,
+}
+
+impl Capsule {
+    /// Create a new capsule with the axis going from `a` to `b`.
+    pub fn new(a: Vec2, b: Vec2, radius: f32) -> Self {
+        Self {
+            segment: LineSegment(a, b),
+            radius,
+        }
+    }
+
+    /// Signed distance from the `point` to the capsule boundary.
+    ///
+    /// The distance is positive if `point` is outside the capsule, and negative if inside.
+    pub fn distance(&self, point: Vec2) -> f32 {
+        (point - self.segment.closest_point(point)).length() - self.radius
+    }
+
+    /// Exact representation of the capsule as a counterclockwise arc polygon.
+    ///
+    /// The polygon frame is the rectangle around the axis, while the caps are semicircle arcs.
+    pub fn polygon(&self) -> ArcPolygon<[ArcVertex; 4]> {
+        let LineSegment(a, b) = self.segment;
+        let left = (b - a).try_normalize().unwrap_or(Vec2::X).perp() * self.radius;
+        ArcPolygon::new([
+            ArcVertex {
+                point: a - left,
+                sagitta: 0.0,
+            },
+            ArcVertex {
+                point: b - left,
+                sagitta: self.radius,
+            },
+            ArcVertex {
+                point: b + left,
+                sagitta: 0.0,
+            },
+            ArcVertex {
+                point: a + left,
+                sagitta: self.radius,
+            },
+        ])
+    }
+}
+
+impl Closed for Capsule {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        if self.distance(point) <= 0.0 { 2 } else { 0 }
+    }
+}
+
+impl Integrable for Capsule {
+    fn moment(&self) -> Moment {
+        Moment {
+            area: 2.0 * self.radius * self.segment.vec().length() + PI * self.radius.powi(2),
+            centroid: self.segment.center(),
+        }
+    }
+}
+
+impl_approx_eq!(Capsule, f32, segment, radius);
+
+impl<T: ArcTarget> IntersectTo<HalfPlane, T> for Capsule {
+    fn intersect_to(&self, plane: &HalfPlane) -> Option<T> {
+        self.polygon().intersect_to(plane)
+    }
+}
+
+impl<T: ArcTarget> IntersectTo<Capsule, T> for HalfPlane {
+    fn intersect_to(&self, capsule: &Capsule) -> Option<T> {
+        capsule.intersect_to(self)
+    }
+}
+
+impl<T: DiskTarget> IntersectTo<Disk, T> for Capsule {
+    fn intersect_to(&self, disk: &Disk) -> Option<T> {
+        self.polygon().intersect_to(disk)
+    }
+}
+
+impl<T: DiskTarget> IntersectTo<Capsule, T> for Disk {
+    fn intersect_to(&self, capsule: &Capsule) -> Option<T> {
+        capsule.intersect_to(self)
+    }
+}
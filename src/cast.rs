@@ -0,0 +1,160 @@
+use crate::{Closed, CopyIterator, Disk, EPS, LineSegment, Polygon};
+use glam::Vec2;
+
+/// Result of a shape cast: the first contact of a moving shape with an obstacle.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Impact {
+    /// Fraction of the displacement traveled before the contact, in `[0, 1]`.
+    ///
+    /// Zero means that the shapes are already overlapping at the start of the motion.
+    pub time: f32,
+    /// Contact point on the boundary of the obstacle.
+    pub point: Vec2,
+    /// Unit normal of the obstacle boundary at the contact point, pointing outside of the obstacle.
+    pub normal: Vec2,
+}
+
+/// Continuous collision of a shape moving along a straight line with another shape.
+pub trait ShapeCast<T: ?Sized> {
+    /// Move `self` by the `displacement` vector and find the first contact with `target`.
+    ///
+    /// Returns `None` if the shapes don't touch during the motion.
+    fn shape_cast(&self, displacement: Vec2, target: &T) -> Option<Impact>;
+}
+
+/// Earliest time in `[0, 1]` when point `start` moving by `displacement` enters the disk
+/// of `radius` centered at `center`.
+fn cast_ray_disk(start: Vec2, displacement: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let rel = start - center;
+    let a = displacement.length_squared();
+    if a < EPS * EPS {
+        return None;
+    }
+    let b = rel.dot(displacement);
+    let c = rel.length_squared() - radius.powi(2);
+    let disc = b.powi(2) - a * c;
+    if disc < 0.0 {
+        return None;
+    }
+    let t = (-b - disc.sqrt()) / a;
+    if (0.0..=1.0).contains(&t) {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+/// Overlap of the disk with the segment at the start of the motion.
+fn overlap_segment(disk: &Disk, segment: &LineSegment) -> Option<Impact> {
+    let point = segment.closest_point(disk.center);
+    let offset = disk.center - point;
+    if offset.length_squared() < disk.radius.powi(2) {
+        Some(Impact {
+            time: 0.0,
+            point,
+            normal: offset.try_normalize().unwrap_or(segment.normal()),
+        })
+    } else {
+        None
+    }
+}
+
+/// Cast the disk against the segment assuming that they don't overlap at the start.
+fn cast_segment(disk: &Disk, displacement: Vec2, segment: &LineSegment) -> Option<Impact> {
+    let mut best: Option<Impact> = None;
+    let mut update = |impact: Impact| {
+        if best.is_none_or(|best| impact.time < best.time) {
+            best = Some(impact);
+        }
+    };
+
+    // Endpoints
+    for point in [segment.0, segment.1] {
+        if let Some(time) = cast_ray_disk(disk.center, displacement, point, disk.radius) {
+            let center = disk.center + displacement * time;
+            update(Impact {
+                time,
+                point,
+                normal: (center - point).normalize_or_zero(),
+            });
+        }
+    }
+
+    // Side of the segment facing the disk
+    if !segment.is_degenerate() {
+        let mut normal = segment.normal();
+        let dist = (disk.center - segment.0).dot(normal);
+        if dist < 0.0 {
+            normal = -normal;
+        }
+        let speed = -displacement.dot(normal);
+        if speed > EPS {
+            let time = (dist.abs() - disk.radius) / speed;
+            let point = disk.center + displacement * time - normal * disk.radius;
+            if (0.0..=1.0).contains(&time) && segment.is_between(point) {
+                update(Impact {
+                    time,
+                    point,
+                    normal,
+                });
+            }
+        }
+    }
+
+    best
+}
+
+impl ShapeCast<LineSegment> for Disk {
+    fn shape_cast(&self, displacement: Vec2, segment: &LineSegment) -> Option<Impact> {
+        overlap_segment(self, segment).or_else(|| cast_segment(self, displacement, segment))
+    }
+}
+
+impl ShapeCast<Disk> for Disk {
+    fn shape_cast(&self, displacement: Vec2, other: &Disk) -> Option<Impact> {
+        let radius = self.radius + other.radius;
+        let offset = self.center - other.center;
+        let time = if offset.length_squared() < radius.powi(2) {
+            0.0
+        } else {
+            cast_ray_disk(self.center, displacement, other.center, radius)?
+        };
+        let normal = (offset + displacement * time)
+            .try_normalize()
+            .unwrap_or(-displacement.normalize_or_zero());
+        Some(Impact {
+            time,
+            point: other.center + normal * other.radius,
+            normal,
+        })
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> ShapeCast<Polygon<V>> for Disk {
+    fn shape_cast(&self, displacement: Vec2, polygon: &Polygon<V>) -> Option<Impact> {
+        if polygon.contains(self.center) {
+            // Disk center is inside, take the nearest boundary point as the contact
+            let point = polygon.closest_point(self.center)?;
+            return Some(Impact {
+                time: 0.0,
+                point,
+                normal: (point - self.center).normalize_or_zero(),
+            });
+        }
+        if let Some(impact) = polygon
+            .edges()
+            .filter_map(|edge| overlap_segment(self, &edge))
+            .min_by(|a, b| {
+                (a.point - self.center)
+                    .length_squared()
+                    .total_cmp(&(b.point - self.center).length_squared())
+            })
+        {
+            return Some(impact);
+        }
+        polygon
+            .edges()
+            .filter_map(|edge| cast_segment(self, displacement, &edge))
+            .min_by(|a, b| a.time.total_cmp(&b.time))
+    }
+}
@@ -1,11 +1,16 @@
 use crate::{
-    Arc, ArcPolygon, ArcVertex, Closed, DiskSegment, EPS, HalfPlane, Integrable, Intersect, Line,
-    LineSegment, Meta, MetaArcPolygon, Moment, impl_approx_eq,
+    Arc, ArcPolygon, ArcVertex, Closed, CopyIterator, DiskSegment, DiskTarget, EPS, HalfPlane,
+    Integrable, Intersect, IntersectTo, Line, LineSegment, Meta, MetaArcPolygon, Moment, Polygon,
+    arc::{MAX_SEGMENT_COUNT, chord_angle},
+    impl_approx_eq,
 };
 use core::{f32::consts::PI, ops::Deref};
 use either::Either;
 use glam::Vec2;
 
+/// Part of the disk cut by a line.
+type DiskPart = Either<DiskSegment, Disk>;
+
 /// A circle defined by its center and radius.
 ///
 /// ```text
@@ -34,6 +39,100 @@ impl Circle {
     pub fn fill(&self) -> Disk {
         Disk(*self)
     }
+
+    /// Number of vertices of the regular polygon inscribed into the circle, so that its edges deviate from the circle by no more than the `tolerance`.
+    ///
+    /// There are at least three vertices, and no more than `2^20` of them.
+    pub fn segment_count(&self, tolerance: f32) -> usize {
+        let count = (2.0 * PI / chord_angle(self.radius, tolerance)).ceil();
+        count.max(3.0).min(MAX_SEGMENT_COUNT as f32) as usize
+    }
+
+    /// Approximate the circle by the inscribed regular polygon deviating from it by no more than the `tolerance`.
+    ///
+    /// See [`Polygon::from_circle`] and [`Circle::segment_count`]. The `tolerance` must be positive.
+    pub fn to_polygon<V: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        tolerance: f32,
+    ) -> Polygon<V> {
+        Polygon::from_circle(*self, self.segment_count(tolerance))
+    }
+
+    /// Circle passing through three points.
+    ///
+    /// If the points are collinear, then the line through the two farthest of them is returned instead,
+    /// as the limit of the circle of infinite radius.
+    /// If all the points coincide, the circle of zero radius is returned.
+    pub fn from_points(a: Vec2, b: Vec2, c: Vec2) -> Either<Circle, Line> {
+        let (r, s) = (b - a, c - a);
+        let den = 2.0 * r.perp_dot(s);
+        // Cross product of nearly parallel vectors is dominated by rounding errors relative to their lengths
+        if den.abs() <= 4.0 * f32::EPSILON * r.length() * s.length() {
+            let (p, q) = [(a, b), (a, c), (b, c)]
+                .into_iter()
+                .max_by(|(p, q), (u, v)| p.distance_squared(*q).total_cmp(&u.distance_squared(*v)))
+                .unwrap();
+            return if p == q {
+                Either::Left(Circle {
+                    center: p,
+                    radius: 0.0,
+                })
+            } else {
+                Either::Right(Line(p, q))
+            };
+        }
+        let center = a + Vec2::new(
+            s.y * r.length_squared() - r.y * s.length_squared(),
+            r.x * s.length_squared() - s.x * r.length_squared(),
+        ) / den;
+        Either::Left(Circle {
+            center,
+            radius: (a - center).length(),
+        })
+    }
+
+    /// Least-squares circle through the `points`.
+    ///
+    /// Uses algebraic (Kåsa) fit which minimizes the sum of squared differences between
+    /// the squared distances from the points to the center and the squared radius.
+    /// It is exact for points lying on a circle and close to the geometric fit for points spread around the whole circle,
+    /// but biased toward smaller radii for noisy points on a short arc.
+    ///
+    /// Points are iterated twice, the first pass finds the centroid to keep the computation precise.
+    /// Returns `None` if there are less than 3 points or they are collinear.
+    pub fn fit<I>(points: I) -> Option<Circle>
+    where
+        I: IntoIterator<Item = Vec2>,
+        I::IntoIter: Clone,
+    {
+        let points = points.into_iter();
+        let (count, sum) = points
+            .clone()
+            .fold((0usize, Vec2::ZERO), |(n, s), p| (n + 1, s + p));
+        if count < 3 {
+            return None;
+        }
+        let mean = sum / count as f32;
+
+        // Second and third central moments
+        let (mut uu, mut uv, mut vv, mut rhs) = (0.0, 0.0, 0.0, Vec2::ZERO);
+        for p in points {
+            let d = p - mean;
+            uu += d.x * d.x;
+            uv += d.x * d.y;
+            vv += d.y * d.y;
+            rhs += 0.5 * d * d.length_squared();
+        }
+        let det = uu * vv - uv * uv;
+        if det <= EPS * (uu + vv).powi(2) {
+            return None;
+        }
+        let offset = Vec2::new(vv * rhs.x - uv * rhs.y, uu * rhs.y - uv * rhs.x) / det;
+        Some(Circle {
+            center: mean + offset,
+            radius: (offset.length_squared() + (uu + vv) / count as f32).sqrt(),
+        })
+    }
 }
 
 /// A filled disk (circle with interior).
@@ -67,6 +166,120 @@ impl Disk {
     pub fn polygon<const N: usize>(&self) -> ArcPolygon<[ArcVertex; N]> {
         ArcPolygon::<[ArcVertex; N]>::from_circle(self.edge())
     }
+
+    /// Approximate the disk by the inscribed regular polygon, see [`Circle::to_polygon`].
+    pub fn to_polygon<V: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        tolerance: f32,
+    ) -> Polygon<V> {
+        self.edge().to_polygon(tolerance)
+    }
+
+    /// Cut the disk by the `line` into the parts lying on its left and right sides.
+    ///
+    /// The parts are complementary disk segments sharing the chord,
+    /// or the whole disk on one side and `None` on the other if the line doesn't cross the disk.
+    /// Sides are the same as of [`HalfPlane::from_edge`], and the `line` points must differ.
+    pub fn split(&self, line: &Line) -> (Option<DiskPart>, Option<DiskPart>) {
+        let left = self.intersect(&HalfPlane::from_edge(*line));
+        let right = match left {
+            None => Some(Either::Right(*self)),
+            Some(Either::Right(_)) => None,
+            // The rest of the disk is bounded by the other arc of the same chord
+            Some(Either::Left(DiskSegment(Arc {
+                points: (a, b),
+                sagitta,
+            }))) => {
+                let sagitta = 2.0 * self.radius - sagitta;
+                (sagitta >= EPS).then_some(Either::Left(DiskSegment(Arc {
+                    points: (b, a),
+                    sagitta,
+                })))
+            }
+        };
+        (left, right)
+    }
+
+    /// Lens-shaped intersection of two disks.
+    ///
+    /// Returns a counterclockwise polygon bounded by two arcs,
+    /// or the smaller disk if one of the disks contains another.
+    /// Returns `None` if the disks don't intersect.
+    pub fn lens(&self, other: &Disk) -> Option<ArcPolygon<[ArcVertex; 2]>> {
+        Some(match self.intersect(other)? {
+            Either::Left(lens) => lens,
+            Either::Right(disk) => disk.polygon::<2>(),
+        })
+    }
+
+    /// Crescent-shaped difference of two disks: the part of `self` lying outside of `other`.
+    ///
+    /// Returns:
+    /// - `None` if `self` is completely inside `other`,
+    /// - a polygon bounded by two arcs (the second one is concave) if the disks intersect,
+    /// - the whole `self` as a polygon if the disks don't intersect,
+    /// - a polygon with a hole if `other` is completely inside `self`.
+    ///   The hole is traversed clockwise and connected to the outer boundary by a zero-width bridge,
+    ///   so that winding number and moment of the polygon are still exact.
+    pub fn crescent<W: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>>(
+        &self,
+        other: &Disk,
+    ) -> Option<ArcPolygon<W>> {
+        let rel_pos = other.center - self.center;
+        let distance = rel_pos.length();
+        if distance >= self.radius + other.radius {
+            return Some(self.polygon::<2>().vertices().collect());
+        }
+        if distance <= other.radius - self.radius {
+            return None;
+        }
+        if distance <= self.radius - other.radius {
+            // Direction to the bridge between boundaries, any one is suitable for concentric disks
+            let dir = if distance > EPS {
+                rel_pos / distance
+            } else {
+                Vec2::X
+            };
+            let outer = (
+                self.center + dir * self.radius,
+                self.center - dir * self.radius,
+            );
+            let inner = (
+                other.center + dir * other.radius,
+                other.center - dir * other.radius,
+            );
+            let vertex = |point, sagitta| ArcVertex { point, sagitta };
+            return Some(ArcPolygon::from_iter([
+                vertex(outer.1, self.radius),
+                vertex(outer.0, 0.0),
+                vertex(inner.0, -other.radius),
+                vertex(inner.1, -other.radius),
+                vertex(inner.0, 0.0),
+                vertex(outer.0, self.radius),
+            ]));
+        }
+
+        let dir = rel_pos / distance;
+        // Common chord apothems
+        let self_apothem =
+            0.5 * (distance + (self.radius.powi(2) - other.radius.powi(2)) / distance);
+        let other_apothem = distance - self_apothem;
+        // Half length of the common chord
+        let h = (self.radius.powi(2) - self_apothem.powi(2)).max(0.0).sqrt();
+        // Midpoint of the common chord
+        let m = self.center + dir * self_apothem;
+
+        Some(ArcPolygon::from_iter([
+            ArcVertex {
+                point: m + dir.perp() * h,
+                sagitta: self.radius + self_apothem,
+            },
+            ArcVertex {
+                point: m - dir.perp() * h,
+                sagitta: other_apothem - other.radius,
+            },
+        ]))
+    }
 }
 
 impl Deref for Disk {
@@ -152,6 +365,35 @@ impl Intersect<Circle> for LineSegment {
     }
 }
 
+/// Intersection of two circles.
+///
+/// Returns the two intersection points, or `None` if the circles don't intersect
+/// or are concentric. For tangent circles both points are equal.
+///
+/// When looking from the center of `self` to the center of `other`,
+/// the first point is on the right side and the second one is on the left side.
+impl Intersect<Circle> for Circle {
+    type Output = [Vec2; 2];
+    fn intersect(&self, other: &Circle) -> Option<Self::Output> {
+        let rel_pos = other.center - self.center;
+        let distance = rel_pos.length();
+        if distance < EPS
+            || distance > self.radius + other.radius
+            || distance < (self.radius - other.radius).abs()
+        {
+            return None;
+        }
+        let dir = rel_pos / distance;
+        // Apothem of the common chord
+        let apothem = 0.5 * (distance + (self.radius.powi(2) - other.radius.powi(2)) / distance);
+        // Half length of the common chord
+        let h = (self.radius.powi(2) - apothem.powi(2)).max(0.0).sqrt();
+        // Midpoint of the common chord
+        let m = self.center + dir * apothem;
+        Some([m - dir.perp() * h, m + dir.perp() * h])
+    }
+}
+
 impl Intersect<HalfPlane> for Circle {
     type Output = Either<Arc, Circle>;
     fn intersect(&self, plane: &HalfPlane) -> Option<Self::Output> {
@@ -204,6 +446,39 @@ impl Intersect<Disk> for HalfPlane {
     }
 }
 
+impl Intersect<Disk> for LineSegment {
+    /// Part of the segment lying inside of the disk, in the same direction as the segment.
+    ///
+    /// The segment touching the circle gives the degenerate segment at the touching point.
+    type Output = LineSegment;
+    fn intersect(&self, disk: &Disk) -> Option<LineSegment> {
+        let (r, p) = (self.vec(), self.0 - disk.center);
+        let (a, b, c) = (
+            r.length_squared(),
+            p.dot(r),
+            p.length_squared() - disk.radius.powi(2),
+        );
+        if a < EPS * EPS {
+            return (c <= 0.0).then_some(*self);
+        }
+        // Roots of `a * t^2 + 2 * b * t + c`
+        let det = b * b - a * c;
+        if det < 0.0 {
+            return None;
+        }
+        let h = det.sqrt();
+        let (t0, t1) = (((-b - h) / a).max(0.0), ((-b + h) / a).min(1.0));
+        (t0 <= t1).then(|| LineSegment(self.0.lerp(self.1, t0), self.0.lerp(self.1, t1)))
+    }
+}
+
+impl Intersect<LineSegment> for Disk {
+    type Output = LineSegment;
+    fn intersect(&self, segment: &LineSegment) -> Option<LineSegment> {
+        segment.intersect(self)
+    }
+}
+
 impl Intersect<Disk> for Disk {
     type Output = Either<ArcPolygon<[ArcVertex; 2]>, Disk>;
     fn intersect(&self, other: &Disk) -> Option<Self::Output> {
@@ -266,3 +541,39 @@ impl<M: Copy> Intersect<Meta<Disk, M>> for Meta<Disk, M> {
         }
     }
 }
+
+impl<T: DiskTarget> IntersectTo<HalfPlane, T> for Disk {
+    fn intersect_to(&self, plane: &HalfPlane) -> Option<T> {
+        Some(match self.intersect(plane)? {
+            Either::Left(DiskSegment(Arc {
+                points: (a, b),
+                sagitta,
+            })) => T::from_arc_vertices(
+                [
+                    ArcVertex { point: a, sagitta },
+                    ArcVertex {
+                        point: b,
+                        sagitta: 0.0,
+                    },
+                ]
+                .into_iter(),
+            ),
+            Either::Right(disk) => T::from_disk(disk),
+        })
+    }
+}
+
+impl<T: DiskTarget> IntersectTo<Disk, T> for HalfPlane {
+    fn intersect_to(&self, disk: &Disk) -> Option<T> {
+        disk.intersect_to(self)
+    }
+}
+
+impl<T: DiskTarget> IntersectTo<Disk, T> for Disk {
+    fn intersect_to(&self, other: &Disk) -> Option<T> {
+        Some(match self.intersect(other)? {
+            Either::Left(lens) => T::from_arc_vertices(lens.vertices()),
+            Either::Right(disk) => T::from_disk(disk),
+        })
+    }
+}
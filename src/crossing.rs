@@ -0,0 +1,319 @@
+use crate::{
+    Arc, ArcPolygon, ArcVertex, Closed, CopyIterator, Disk, EPS, Intersect, Line, LineSegment,
+    Polygon,
+};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use arrayvec::ArrayVec;
+use glam::Vec2;
+
+/// Shape which boundary crossings with a line segment can be found.
+pub trait Crossings {
+    /// Parameters `t` of points where the `segment` crosses the shape boundary, in arbitrary order.
+    ///
+    /// The crossing point is `segment.0.lerp(segment.1, t)`, where `t` is in `[0, 1]`.
+    /// The parity of the number of crossings tells whether the segment passes through the boundary:
+    /// polygon vertices lying on the segment line are taken as lying on its left side,
+    /// so passing through a vertex is counted once, and touching it from either side is counted zero or two times.
+    /// Segments collinear with a boundary edge don't cross it.
+    /// Arc polygons count each vertex once, as each arc includes its start vertex but not its end one.
+    fn crossings(&self, segment: &LineSegment) -> impl Iterator<Item = f32> + '_;
+}
+
+/// Crossing parameter of the moving `segment` with the `edge` of a polygon.
+///
+/// The edge crosses the segment line if its ends are on the different sides of it,
+/// where the points on the line are on the left side.
+pub(crate) fn cross_edge(segment: &LineSegment, edge: &LineSegment) -> Option<f32> {
+    let r = segment.vec();
+    let (a, b) = (
+        r.perp_dot(edge.0 - segment.0),
+        r.perp_dot(edge.1 - segment.0),
+    );
+    if (a >= 0.0) == (b >= 0.0) {
+        return None;
+    }
+    let point = edge.0.lerp(edge.1, a / (a - b));
+    let t = (point - segment.0).dot(r) / r.length_squared();
+    if (0.0..=1.0).contains(&t) {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+/// Crossing parameters of the moving `segment` with the arc `edge`.
+fn cross_arc(segment: &LineSegment, edge: &Arc) -> [Option<f32>; 2] {
+    let circle = match edge.circle() {
+        Some(circle) => circle,
+        None => return [cross_edge(segment, &edge.chord()), None],
+    };
+    if segment.is_degenerate() {
+        return [None; 2];
+    }
+    let points = match circle.intersect(&Line(segment.0, segment.1)) {
+        Some(points) => points,
+        None => return [None; 2],
+    };
+    let r = segment.vec();
+    points.map(|p| {
+        let t = (p - segment.0).dot(r) / r.length_squared();
+        let u = edge.param_of(p);
+        if (0.0..=1.0).contains(&t) && (0.0..1.0).contains(&u) {
+            Some(t)
+        } else {
+            None
+        }
+    })
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Crossings for Polygon<V> {
+    fn crossings(&self, segment: &LineSegment) -> impl Iterator<Item = f32> + '_ {
+        let segment = *segment;
+        self.edges()
+            .filter_map(move |edge| cross_edge(&segment, &edge))
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Crossings for ArcPolygon<V> {
+    fn crossings(&self, segment: &LineSegment) -> impl Iterator<Item = f32> + '_ {
+        let segment = *segment;
+        self.edges()
+            .flat_map(move |edge| cross_arc(&segment, &edge))
+            .flatten()
+    }
+}
+
+impl Crossings for Disk {
+    fn crossings(&self, segment: &LineSegment) -> impl Iterator<Item = f32> + '_ {
+        let r = segment.vec();
+        let points = if segment.is_degenerate() {
+            None
+        } else {
+            self.edge().intersect(&Line(segment.0, segment.1))
+        };
+        let segment = *segment;
+        points
+            .into_iter()
+            .flatten()
+            .map(move |p| (p - segment.0).dot(r) / r.length_squared())
+            .filter(|t| (0.0..=1.0).contains(t))
+    }
+}
+
+/// Sorted parameters `t` of points where the `segment` crosses the `shape` boundary.
+///
+/// If there are more than `N` crossings then only the first `N` of them along the segment are returned.
+pub fn crossing_parameters<S: Crossings + ?Sized, const N: usize>(
+    segment: &LineSegment,
+    shape: &S,
+) -> ArrayVec<f32, N> {
+    let mut params = ArrayVec::new();
+    for t in shape.crossings(segment) {
+        let i = params.partition_point(|&p| p <= t);
+        if i == N {
+            continue;
+        }
+        if params.is_full() {
+            params.pop();
+        }
+        params.insert(i, t);
+    }
+    params
+}
+
+/// Parameter `t` of the point where the point moving along the `segment` leaves the `shape` for the first time.
+///
+/// Returns `0` if the motion starts outside of the shape, and `None` if it stays inside all the way.
+/// The motion is checked exactly rather than by sampling: the segment is split at its crossings with the boundary,
+/// and the part following each crossing is checked to be inside by its middle point,
+/// so touching the boundary from inside is not an exit.
+/// Starting or ending at the boundary is unspecified.
+///
+/// Takes `O(n k)` time, where `k` is the number of the crossings up to the exit.
+pub fn first_exit<S: Crossings + Closed + ?Sized>(shape: &S, segment: &LineSegment) -> Option<f32> {
+    if !shape.contains(segment.0) {
+        return Some(0.0);
+    }
+    let mut t = 0.0;
+    loop {
+        // The next crossing is found by scanning all of them, so no storage is needed
+        let next = shape
+            .crossings(segment)
+            .filter(|&u| u > t + EPS)
+            .min_by(f32::total_cmp);
+        let end = next.unwrap_or(1.0);
+        if t > 0.0 && !shape.contains(segment.0.lerp(segment.1, 0.5 * (t + end))) {
+            return Some(t);
+        }
+        t = next?;
+    }
+}
+
+/// Check that the point moving along the `segment` stays inside of the `shape`, see [`first_exit`].
+pub fn stays_inside<S: Crossings + Closed + ?Sized>(shape: &S, segment: &LineSegment) -> bool {
+    first_exit(shape, segment).is_none()
+}
+
+/// Event met while walking along the polygon boundary, see [`walk_boundary`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BoundaryEvent {
+    /// Vertex of the walked polygon.
+    Vertex {
+        /// Index of the vertex, it is also the index of the edge starting at it.
+        index: usize,
+        /// Position of the vertex.
+        point: Vec2,
+        /// Whether the vertex lies inside of the other shape.
+        inside: bool,
+    },
+    /// The boundary goes into the other shape.
+    Entry {
+        /// Index of the crossed edge.
+        edge: usize,
+        /// Parameter of the crossing point along the edge in `[0, 1)`.
+        t: f32,
+        /// Position of the crossing point.
+        point: Vec2,
+    },
+    /// The boundary goes out of the other shape.
+    Exit {
+        /// Index of the crossed edge.
+        edge: usize,
+        /// Parameter of the crossing point along the edge in `[0, 1)`.
+        t: f32,
+        /// Position of the crossing point.
+        point: Vec2,
+    },
+}
+
+/// Walk along the `subject` boundary and report its vertices and the points where it enters or exits the `clip` shape.
+///
+/// Events are yielded in the boundary order, the crossings of each edge follow its start vertex.
+/// Crossings are classified by testing the containment of the edge parts between them,
+/// so the boundary touching the `clip` boundary without passing through it produces no events,
+/// and entries and exits always alternate.
+/// This is the first phase of Greiner–Hormann-like boolean operations.
+#[cfg(feature = "alloc")]
+pub fn walk_boundary<'a, V: CopyIterator<Item = Vec2> + ?Sized, S: Crossings + Closed + ?Sized>(
+    subject: &'a Polygon<V>,
+    clip: &'a S,
+) -> impl Iterator<Item = BoundaryEvent> + 'a {
+    // Crossings sorted along the edge, the crossing at the edge end belongs to the next edge
+    let crossings = move |edge: &LineSegment| {
+        let mut params: Vec<f32> = clip.crossings(edge).filter(|&t| t < 1.0 - EPS).collect();
+        params.sort_by(f32::total_cmp);
+        params.dedup_by(|a, b| *a - *b < EPS);
+        params
+    };
+    // Whether the edge part between the parameters lies inside of the clip shape
+    let inside =
+        move |edge: &LineSegment, t: f32, u: f32| clip.contains(edge.0.lerp(edge.1, 0.5 * (t + u)));
+    let mut state = subject.edges().last().is_some_and(|edge| {
+        let last = crossings(&edge).last().copied().unwrap_or(0.0);
+        inside(&edge, last, 1.0)
+    });
+    subject.edges().enumerate().flat_map(move |(index, edge)| {
+        let params = crossings(&edge);
+        let mut events = vec![BoundaryEvent::Vertex {
+            index,
+            point: edge.0,
+            inside: clip.contains(edge.0),
+        }];
+        for (j, &t) in params.iter().enumerate() {
+            let after = inside(&edge, t, params.get(j + 1).copied().unwrap_or(1.0));
+            if after == state {
+                continue;
+            }
+            state = after;
+            let (point, edge) = (edge.0.lerp(edge.1, t), index);
+            events.push(if after {
+                BoundaryEvent::Entry { edge, t, point }
+            } else {
+                BoundaryEvent::Exit { edge, t, point }
+            });
+        }
+        events
+    })
+}
+
+/// Result of a single [`BoundaryCrossingTracker`] update.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CrossingReport {
+    /// Number of times the motion crossed the shape boundary.
+    pub count: usize,
+    /// Whether the previous position was inside the shape.
+    pub was_inside: bool,
+    /// Whether the current position is inside the shape.
+    pub is_inside: bool,
+}
+
+impl CrossingReport {
+    /// Check that the boundary was crossed at least once, even if the point returned back.
+    pub fn crossed(&self) -> bool {
+        self.count > 0
+    }
+
+    /// Check that the point has left the shape.
+    pub fn escaped(&self) -> bool {
+        self.was_inside && !self.is_inside
+    }
+
+    /// Check that the point has come into the shape.
+    pub fn entered(&self) -> bool {
+        !self.was_inside && self.is_inside
+    }
+}
+
+/// Tracker of a moving point reporting crossings of the shape boundary.
+///
+/// The motion between consecutive positions is considered linear,
+/// so that passing through thin features of the shape is detected even if
+/// both positions are on the same side of the boundary.
+#[derive(Clone, Debug)]
+pub struct BoundaryCrossingTracker<S> {
+    /// The tracked shape.
+    pub shape: S,
+    position: Vec2,
+    inside: bool,
+}
+
+impl<S: Closed + Crossings> BoundaryCrossingTracker<S> {
+    /// Start tracking the point at the `position`.
+    pub fn new(shape: S, position: Vec2) -> Self {
+        let inside = shape.contains(position);
+        Self {
+            shape,
+            position,
+            inside,
+        }
+    }
+
+    /// Last known position of the point.
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Whether the point is inside the shape.
+    pub fn is_inside(&self) -> bool {
+        self.inside
+    }
+
+    /// Move the point to the new `position` and report the boundary crossings.
+    pub fn update(&mut self, position: Vec2) -> CrossingReport {
+        let count = self
+            .shape
+            .crossings(&LineSegment(self.position, position))
+            .count();
+        let was_inside = self.inside;
+        // Parity of crossings is more robust than containment test for tiny steps near the boundary
+        let is_inside = was_inside ^ !count.is_multiple_of(2);
+        self.position = position;
+        self.inside = is_inside;
+        CrossingReport {
+            count,
+            was_inside,
+            is_inside,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    Aabb, ArcPolygon, ArcVertex, Bounded, Capsule, Closed, CopyIterator, Decompose, Disk,
+    DiskSegment, HalfPlane, Integrable, Moment, MomentAccumulator, Piece, Polygon, Triangle,
+    difference::overlap_pieces, intersection_moment,
+};
+use core::ops::{BitAnd, BitOr, Not, Sub};
+use glam::Vec2;
+
+/// Winding number of the composite shape: `2` for points inside and `0` for points outside.
+fn inside_winding(inside: bool) -> i32 {
+    if inside { 2 } else { 0 }
+}
+
+/// Union of two shapes.
+///
+/// Point is inside of the union if it is inside of any of the shapes.
+/// Also made by the `|` operator, e.g. `polygon | disk`.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct Union<A, B>(pub A, pub B);
+
+impl<A: Closed, B: Closed> Closed for Union<A, B> {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        inside_winding(self.0.contains(point) || self.1.contains(point))
+    }
+}
+
+/// Intersection of two shapes.
+///
+/// Point is inside of the intersection if it is inside of both shapes.
+/// Also made by the `&` operator.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct Intersection<A, B>(pub A, pub B);
+
+impl<A: Closed, B: Closed> Closed for Intersection<A, B> {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        inside_winding(self.0.contains(point) && self.1.contains(point))
+    }
+}
+
+/// Difference of two shapes.
+///
+/// Point is inside of the difference if it is inside of the first shape and outside of the second one.
+/// Also made by the `-` operator.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct Difference<A, B>(pub A, pub B);
+
+impl<A: Closed, B: Closed> Closed for Difference<A, B> {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        inside_winding(self.0.contains(point) && !self.1.contains(point))
+    }
+}
+
+/// Complement of the shape, i.e. the whole plane except the shape.
+///
+/// It is unbounded, so it only provides the containment test.
+/// Intersection with the complement is the same as [`Difference`], which is also integrable.
+/// Also made by the `!` operator.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct Complement<A>(pub A);
+
+impl<A: Closed> Closed for Complement<A> {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        inside_winding(!self.0.contains(point))
+    }
+}
+
+/// Pieces of the intersection of two shapes made of each pair of their pieces.
+fn intersection_pieces<'a, A: Decompose, B: Decompose>(
+    a: &'a A,
+    b: &'a B,
+) -> impl Iterator<Item = Piece> + 'a {
+    a.pieces()
+        .flat_map(move |p| b.pieces().flat_map(move |q| overlap_pieces(&p, &q)))
+}
+
+impl<A: Decompose, B: Decompose> Integrable for Union<A, B> {
+    /// Sum of the shapes moments without their intersection.
+    fn moment(&self) -> Moment {
+        let mut accumulator = MomentAccumulator::new();
+        accumulator.add(self.0.piecewise_moment());
+        accumulator.add(self.1.piecewise_moment());
+        accumulator.remove(intersection_moment(&self.0, &self.1));
+        accumulator.moment()
+    }
+}
+
+impl<A: Decompose, B: Decompose> Integrable for Intersection<A, B> {
+    fn moment(&self) -> Moment {
+        intersection_moment(&self.0, &self.1)
+    }
+}
+
+impl<A: Decompose, B: Decompose> Integrable for Difference<A, B> {
+    fn moment(&self) -> Moment {
+        self.0
+            .piecewise_moment()
+            .remove(intersection_moment(&self.0, &self.1))
+    }
+}
+
+/// Pieces of both shapes and the reversed pieces of their intersection.
+///
+/// Pieces of the intersection are made for each pair of the shapes pieces,
+/// so the number of the pieces of nested composites grows as the product of the numbers of the pieces of their parts.
+impl<A: Decompose, B: Decompose> Decompose for Union<A, B> {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        self.0
+            .pieces()
+            .chain(self.1.pieces())
+            .chain(intersection_pieces(&self.0, &self.1).map(Piece::reverse))
+    }
+}
+
+/// Intersections of each pair of the shapes pieces.
+impl<A: Decompose, B: Decompose> Decompose for Intersection<A, B> {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        intersection_pieces(&self.0, &self.1)
+    }
+}
+
+/// Pieces of the first shape and the reversed pieces of the shapes intersection.
+impl<A: Decompose, B: Decompose> Decompose for Difference<A, B> {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        self.0
+            .pieces()
+            .chain(intersection_pieces(&self.0, &self.1).map(Piece::reverse))
+    }
+}
+
+impl<A: Bounded, B: Bounded> Bounded for Union<A, B> {
+    fn aabb(&self) -> Aabb {
+        self.0.aabb().union(self.1.aabb())
+    }
+}
+
+impl<A: Bounded, B: Bounded> Bounded for Intersection<A, B> {
+    /// Intersection of the shapes boxes, it may be larger than the box of the shapes intersection.
+    fn aabb(&self) -> Aabb {
+        let (a, b) = (self.0.aabb(), self.1.aabb());
+        Aabb::new(a.min.max(b.min), a.max.min(b.max))
+    }
+}
+
+impl<A: Bounded, B> Bounded for Difference<A, B> {
+    fn aabb(&self) -> Aabb {
+        self.0.aabb()
+    }
+}
+
+/// Implement set operators building [`Union`], [`Intersection`], [`Difference`] and [`Complement`] for the shape type.
+///
+/// Generic parameters of the impl are given in brackets, each followed by a comma.
+macro_rules! impl_csg_ops {
+    ($( [$($g:tt)*] $Self:ty ),* $(,)?) => {
+        $(
+            impl<$($g)* Rhs> BitOr<Rhs> for $Self {
+                type Output = Union<Self, Rhs>;
+                fn bitor(self, rhs: Rhs) -> Self::Output {
+                    Union(self, rhs)
+                }
+            }
+
+            impl<$($g)* Rhs> BitAnd<Rhs> for $Self {
+                type Output = Intersection<Self, Rhs>;
+                fn bitand(self, rhs: Rhs) -> Self::Output {
+                    Intersection(self, rhs)
+                }
+            }
+
+            impl<$($g)* Rhs> Sub<Rhs> for $Self {
+                type Output = Difference<Self, Rhs>;
+                fn sub(self, rhs: Rhs) -> Self::Output {
+                    Difference(self, rhs)
+                }
+            }
+
+            impl<$($g)*> Not for $Self {
+                type Output = Complement<Self>;
+                fn not(self) -> Self::Output {
+                    Complement(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_csg_ops!(
+    [] Disk,
+    [] DiskSegment,
+    [] HalfPlane,
+    [] Capsule,
+    [] Triangle,
+    [] Aabb,
+    [V: CopyIterator<Item = Vec2>,] Polygon<V>,
+    ['a, V: CopyIterator<Item = Vec2> + ?Sized,] &'a Polygon<V>,
+    [V: CopyIterator<Item = ArcVertex>,] ArcPolygon<V>,
+    ['a, V: CopyIterator<Item = ArcVertex> + ?Sized,] &'a ArcPolygon<V>,
+    [A, B,] Union<A, B>,
+    [A, B,] Intersection<A, B>,
+    [A, B,] Difference<A, B>,
+    [A,] Complement<A>,
+);
@@ -0,0 +1,502 @@
+//! Visual debugging of shapes.
+
+use crate::{
+    Arc, ArcPolygon, ArcVertex, Boundary, Capsule, Circle, CopyIterator, Disk, GenericPath,
+    HalfPlane, Integrable, Line, LineSegment, Path, Polygon, Region, Triangle,
+};
+use core::fmt::{self, Write};
+use either::Either;
+use glam::Vec2;
+
+/// Hint on how the primitive should be drawn.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum DebugStyle {
+    /// Edge of the closed shape boundary, the shape interior is on the left side.
+    #[default]
+    Boundary,
+    /// Standalone curve or point, e.g. a segment or an open path.
+    Open,
+    /// Auxiliary construction which is not a part of the shape, e.g. a half-plane normal.
+    Auxiliary,
+}
+
+/// Receiver of primitives drawn by [`DebugDraw`] shapes.
+///
+/// Implement it to render shapes into your visualization backend.
+pub trait DebugCanvas {
+    /// Draw straight segment going from the first point to the second one.
+    fn segment(&mut self, segment: LineSegment, style: DebugStyle);
+    /// Draw circular arc.
+    fn arc(&mut self, arc: Arc, style: DebugStyle);
+    /// Draw point marker.
+    fn point(&mut self, point: Vec2, style: DebugStyle);
+    /// Draw text label at the `position`.
+    fn label(&mut self, position: Vec2, text: &str);
+}
+
+/// Shape that can be drawn for debugging purposes.
+pub trait DebugDraw {
+    /// Draw the shape into the `canvas`.
+    ///
+    /// Boundary edges are drawn in the direction of boundary traversal.
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas);
+}
+
+impl DebugDraw for Vec2 {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        canvas.point(*self, DebugStyle::Open);
+    }
+}
+
+impl DebugDraw for Line {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        canvas.segment(LineSegment(self.0, self.1), DebugStyle::Auxiliary);
+    }
+}
+
+impl DebugDraw for LineSegment {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        canvas.segment(*self, DebugStyle::Open);
+    }
+}
+
+impl DebugDraw for Arc {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        canvas.arc(*self, DebugStyle::Open);
+    }
+}
+
+impl DebugDraw for HalfPlane {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        let Line(a, b) = self.edge();
+        canvas.segment(LineSegment(b, a), DebugStyle::Boundary);
+        let p = self.boundary_point();
+        canvas.segment(LineSegment(p, p + 0.5 * self.normal), DebugStyle::Auxiliary);
+    }
+}
+
+impl DebugDraw for Circle {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        for edge in self.fill().polygon::<2>().edges() {
+            canvas.arc(edge, DebugStyle::Open);
+        }
+    }
+}
+
+impl DebugDraw for Disk {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        self.polygon::<2>().debug_draw(canvas);
+        canvas.point(self.center, DebugStyle::Auxiliary);
+    }
+}
+
+impl DebugDraw for Capsule {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        self.polygon().debug_draw(canvas);
+        canvas.segment(self.segment, DebugStyle::Auxiliary);
+    }
+}
+
+impl DebugDraw for Triangle {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        for edge in self.edges() {
+            canvas.segment(edge, DebugStyle::Boundary);
+        }
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> DebugDraw for Polygon<V> {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        for edge in self.edges() {
+            canvas.segment(edge, DebugStyle::Boundary);
+        }
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> DebugDraw for ArcPolygon<V> {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        for edge in self.edges() {
+            canvas.arc(edge, DebugStyle::Boundary);
+        }
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> DebugDraw for GenericPath<V, Vec2> {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        for edge in self.edges() {
+            canvas.segment(edge, DebugStyle::Open);
+        }
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> DebugDraw for Path<V> {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        for edge in self.edges() {
+            canvas.arc(edge, DebugStyle::Open);
+        }
+    }
+}
+
+impl<C: ?Sized> DebugDraw for Region<C>
+where
+    for<'a> &'a C: IntoIterator<Item: DebugDraw>,
+{
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        for contour in self.components() {
+            contour.debug_draw(canvas);
+        }
+    }
+}
+
+impl<T: DebugDraw + ?Sized> DebugDraw for &T {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        (**self).debug_draw(canvas);
+    }
+}
+
+impl<T: DebugDraw> DebugDraw for Option<T> {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        if let Some(shape) = self {
+            shape.debug_draw(canvas);
+        }
+    }
+}
+
+impl<L: DebugDraw, R: DebugDraw> DebugDraw for Either<L, R> {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        match self {
+            Either::Left(left) => left.debug_draw(canvas),
+            Either::Right(right) => right.debug_draw(canvas),
+        }
+    }
+}
+
+/// Shape together with a text label placed at its first point.
+pub struct Labeled<'a, S: ?Sized>(pub &'a str, pub S);
+
+impl<S: DebugDraw> DebugDraw for Labeled<'_, S> {
+    fn debug_draw(&self, canvas: &mut dyn DebugCanvas) {
+        let mut first = FirstPoint(None);
+        self.1.debug_draw(&mut first);
+        if let Some(position) = first.0 {
+            canvas.label(position, self.0);
+        }
+        self.1.debug_draw(canvas);
+    }
+}
+
+struct FirstPoint(Option<Vec2>);
+
+impl FirstPoint {
+    fn add(&mut self, point: Vec2) {
+        self.0.get_or_insert(point);
+    }
+}
+
+impl DebugCanvas for FirstPoint {
+    fn segment(&mut self, segment: LineSegment, _: DebugStyle) {
+        self.add(segment.0);
+    }
+    fn arc(&mut self, arc: Arc, _: DebugStyle) {
+        self.add(arc.points.0);
+    }
+    fn point(&mut self, point: Vec2, _: DebugStyle) {
+        self.add(point);
+    }
+    fn label(&mut self, _: Vec2, _: &str) {}
+}
+
+struct Bounds {
+    min: Vec2,
+    max: Vec2,
+}
+
+impl Bounds {
+    fn add(&mut self, point: Vec2) {
+        self.min = self.min.min(point);
+        self.max = self.max.max(point);
+    }
+}
+
+impl DebugCanvas for Bounds {
+    fn segment(&mut self, segment: LineSegment, _: DebugStyle) {
+        self.add(segment.0);
+        self.add(segment.1);
+    }
+    fn arc(&mut self, arc: Arc, _: DebugStyle) {
+        self.add(arc.points.0);
+        self.add(arc.points.1);
+        self.add(arc.midpoint());
+    }
+    fn point(&mut self, point: Vec2, _: DebugStyle) {
+        self.add(point);
+    }
+    fn label(&mut self, position: Vec2, _: &str) {
+        self.add(position);
+    }
+}
+
+const PALETTE: [&str; 6] = [
+    "#1f77b4", "#d62728", "#2ca02c", "#ff7f0e", "#9467bd", "#8c564b",
+];
+
+fn svg_style(style: DebugStyle) -> &'static str {
+    match style {
+        DebugStyle::Boundary => r#"marker-end="url(#arrow)""#,
+        DebugStyle::Open => r#"marker-end="url(#arrow)" stroke-linecap="round""#,
+        DebugStyle::Auxiliary => {
+            r#"stroke-dasharray="4 4" vector-effect="non-scaling-stroke" stroke-width="1""#
+        }
+    }
+}
+
+struct SvgCanvas<W: Write> {
+    out: W,
+    scale: f32,
+    result: fmt::Result,
+}
+
+impl<W: Write> DebugCanvas for SvgCanvas<W> {
+    fn segment(&mut self, LineSegment(a, b): LineSegment, style: DebugStyle) {
+        self.result = self.result.and_then(|()| {
+            writeln!(
+                self.out,
+                r#"<path d="M {} {} L {} {}" {}/>"#,
+                a.x,
+                a.y,
+                b.x,
+                b.y,
+                svg_style(style)
+            )
+        });
+    }
+    fn arc(&mut self, arc: Arc, style: DebugStyle) {
+        if arc.is_flat() {
+            return self.segment(arc.chord(), style);
+        }
+        let (a, b) = arc.points;
+        let radius = arc.radius();
+        let large = arc.sweep().abs() > core::f32::consts::PI;
+        self.result = self.result.and_then(|()| {
+            writeln!(
+                self.out,
+                r#"<path d="M {} {} A {} {} 0 {} {} {} {}" {}/>"#,
+                a.x,
+                a.y,
+                radius,
+                radius,
+                large as u8,
+                arc.is_ccw() as u8,
+                b.x,
+                b.y,
+                svg_style(style)
+            )
+        });
+    }
+    fn point(&mut self, point: Vec2, style: DebugStyle) {
+        let radius = match style {
+            DebugStyle::Auxiliary => 1.5,
+            _ => 2.5,
+        };
+        self.result = self.result.and_then(|()| {
+            writeln!(
+                self.out,
+                r#"<circle cx="{}" cy="{}" r="{}" stroke="none" fill-opacity="1"/>"#,
+                point.x,
+                point.y,
+                radius * self.scale
+            )
+        });
+    }
+    fn label(&mut self, position: Vec2, text: &str) {
+        self.result = self.result.and_then(|()| {
+            write!(
+                self.out,
+                r#"<text x="{}" y="{}" font-size="{}" stroke="none" fill-opacity="1" transform="scale(1 -1)">"#,
+                position.x,
+                -position.y,
+                12.0 * self.scale
+            )?;
+            for c in text.chars() {
+                match c {
+                    '<' => write!(self.out, "&lt;")?,
+                    '>' => write!(self.out, "&gt;")?,
+                    '&' => write!(self.out, "&amp;")?,
+                    c => self.out.write_char(c)?,
+                }
+            }
+            writeln!(self.out, "</text>")
+        });
+    }
+}
+
+/// Write SVG image with the `shapes` into the `out`.
+///
+/// Each shape is drawn with its own color, edges have arrows showing their direction,
+/// while auxiliary constructions are dashed.
+/// The Y axis of the image points upwards.
+pub fn debug_svg<'a, I: IntoIterator<Item = &'a dyn DebugDraw>, W: Write>(
+    shapes: I,
+    mut out: W,
+) -> fmt::Result
+where
+    I::IntoIter: Clone,
+{
+    let shapes = shapes.into_iter();
+    let mut bounds = Bounds {
+        min: Vec2::INFINITY,
+        max: Vec2::NEG_INFINITY,
+    };
+    for shape in shapes.clone() {
+        shape.debug_draw(&mut bounds);
+    }
+    if !bounds.min.is_finite() || !bounds.max.is_finite() {
+        bounds = Bounds {
+            min: Vec2::ZERO,
+            max: Vec2::ONE,
+        };
+    }
+    let extent = (bounds.max - bounds.min).max_element().max(1e-3);
+    // Size of a pixel of 400 pixels wide image
+    let scale = extent / 400.0;
+    let (min, size) = (
+        bounds.min - 20.0 * scale,
+        bounds.max - bounds.min + 40.0 * scale,
+    );
+
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}">"#,
+        min.x,
+        -min.y - size.y,
+        size.x,
+        size.y,
+        size.x / scale,
+        size.y / scale,
+    )?;
+    writeln!(
+        out,
+        r#"<defs><marker id="arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="8" markerHeight="8" orient="auto-start-reverse"><path d="M 0 0 L 10 5 L 0 10 z" fill="context-stroke"/></marker></defs>"#
+    )?;
+    writeln!(out, r#"<g transform="scale(1 -1)" fill="none">"#)?;
+    let mut canvas = SvgCanvas {
+        out,
+        scale,
+        result: Ok(()),
+    };
+    for (i, shape) in shapes.enumerate() {
+        let color = PALETTE[i % PALETTE.len()];
+        writeln!(
+            canvas.out,
+            r#"<g stroke="{color}" fill="{color}" fill-opacity="0" stroke-width="{}">"#,
+            1.5 * scale
+        )?;
+        shape.debug_draw(&mut canvas);
+        canvas.result?;
+        writeln!(canvas.out, "</g>")?;
+    }
+    writeln!(canvas.out, "</g>\n</svg>")
+}
+
+/// Description of the difference between two shapes made by [`explain_mismatch`].
+///
+/// It is displayed as a human-readable message, so it can be put into the assertion failure message.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MismatchReport {
+    /// Tolerance the shapes were compared with.
+    pub tolerance: f32,
+    /// Number of boundary edges of the first and the second shapes.
+    pub edge_counts: (usize, usize),
+    /// Area of the second shape minus the area of the first one.
+    pub area_delta: f32,
+    /// Largest distance from a vertex or an edge midpoint of one shape to the boundary of the other one.
+    pub max_deviation: f32,
+    /// Vertex or edge midpoint where the largest deviation is reached, `None` if both shapes are empty.
+    pub max_deviation_point: Option<Vec2>,
+    /// Index of the first edge of the first shape which vertices or midpoint deviate from the second shape more than the tolerance.
+    pub first_differing_edge: Option<usize>,
+}
+
+impl MismatchReport {
+    /// Check that the boundaries of the shapes coincide within the tolerance.
+    pub fn is_match(&self) -> bool {
+        self.max_deviation <= self.tolerance
+    }
+}
+
+impl fmt::Display for MismatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_match() {
+            write!(f, "shapes match within {}", self.tolerance)?;
+        } else {
+            write!(f, "shapes differ by {}", self.max_deviation)?;
+            if let Some(point) = self.max_deviation_point {
+                write!(f, " at ({}, {})", point.x, point.y)?;
+            }
+            write!(f, ", tolerance is {}", self.tolerance)?;
+        }
+        write!(
+            f,
+            "; edges: {} vs {}; area delta: {}",
+            self.edge_counts.0, self.edge_counts.1, self.area_delta
+        )?;
+        if let Some(edge) = self.first_differing_edge {
+            write!(f, "; first differing edge: {edge}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Largest distance from the vertices and the edge midpoints of `a` to the boundary of `b`,
+/// the point where it is reached and the index of the first edge deviating more than the `tolerance`.
+fn deviation<A: Boundary + ?Sized, B: Boundary + ?Sized>(
+    a: &A,
+    b: &B,
+    tolerance: f32,
+) -> (f32, Option<Vec2>, Option<usize>) {
+    let distance = |point: Vec2| {
+        b.boundary()
+            .map(|edge| edge.closest_point(point).distance(point))
+            .fold(f32::INFINITY, f32::min)
+    };
+    let (mut max, mut at, mut first) = (0.0, None, None);
+    for (i, edge) in a.boundary().enumerate() {
+        for point in [edge.start(), edge.point_at(0.5), edge.end()] {
+            let d = distance(point);
+            if d > tolerance && first.is_none() {
+                first = Some(i);
+            }
+            if at.is_none() || d > max {
+                (max, at) = (d, Some(point));
+            }
+        }
+    }
+    (max, at, first)
+}
+
+/// Explain where the shapes `a` and `b` differ, e.g. when their approximate comparison fails.
+///
+/// Boundaries are compared geometrically, so the result doesn't depend on the starting vertex
+/// and a polygon can be compared with an arc polygon.
+/// Comparison takes `O(n * m)` time, where `n` and `m` are the numbers of edges.
+pub fn explain_mismatch<A, B>(a: &A, b: &B, tolerance: f32) -> MismatchReport
+where
+    A: Boundary + Integrable + ?Sized,
+    B: Boundary + Integrable + ?Sized,
+{
+    let (forward, forward_at, first) = deviation(a, b, tolerance);
+    let (backward, backward_at, _) = deviation(b, a, tolerance);
+    let (max_deviation, max_deviation_point) = match (forward_at, backward_at) {
+        (Some(_), Some(_)) if backward > forward => (backward, backward_at),
+        (Some(_), _) => (forward, forward_at),
+        (None, _) => (backward, backward_at),
+    };
+    MismatchReport {
+        tolerance,
+        edge_counts: (a.boundary().count(), b.boundary().count()),
+        area_delta: b.area() - a.area(),
+        max_deviation,
+        max_deviation_point,
+        first_differing_edge: first,
+    }
+}
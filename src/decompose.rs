@@ -0,0 +1,206 @@
+use crate::{
+    Aabb, Arc, ArcPolygon, ArcVertex, Bounded, Capsule, Closed, CopyIterator, Disk, DiskSegment,
+    EPS, Edge, Integrable, Moment, Polygon, Triangle,
+};
+use either::Either;
+use glam::Vec2;
+
+/// Signed primitive piece of a shape.
+///
+/// The sign is given by the orientation: clockwise triangles and disk segments with negative sagitta
+/// have negative area and winding number.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Piece {
+    /// Triangle, its winding number is computed by the same rule as for [`Polygon`].
+    Triangle(Triangle),
+    /// Segment of a disk cut off by the arc chord.
+    Segment(DiskSegment),
+}
+
+impl Piece {
+    /// Piece with the opposite orientation.
+    pub fn reverse(self) -> Self {
+        match self {
+            Piece::Triangle(Triangle(a, b, c)) => Piece::Triangle(Triangle(a, c, b)),
+            Piece::Segment(DiskSegment(Arc {
+                points: (a, b),
+                sagitta,
+            })) => Piece::Segment(DiskSegment(Arc {
+                points: (b, a),
+                sagitta: -sagitta,
+            })),
+        }
+    }
+}
+
+impl Closed for Piece {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        match self {
+            // Triangles sharing an edge in opposite directions give exactly opposite contributions
+            Piece::Triangle(triangle) => triangle.polygon().winding_number_2(point),
+            Piece::Segment(segment) => segment.winding_number_2(point),
+        }
+    }
+}
+
+impl Integrable for Piece {
+    fn moment(&self) -> Moment {
+        match self {
+            Piece::Triangle(triangle) => triangle.moment(),
+            Piece::Segment(segment) => segment.moment(),
+        }
+    }
+}
+
+impl Bounded for Piece {
+    fn aabb(&self) -> Aabb {
+        match self {
+            Piece::Triangle(triangle) => triangle.aabb(),
+            Piece::Segment(segment) => segment.0.aabb(),
+        }
+    }
+}
+
+/// Shape that can be decomposed into signed primitive pieces.
+///
+/// The shape is the signed sum of the pieces, so any additive quantity (area, moments, winding number, coverage)
+/// can be computed by summing it over the pieces.
+/// Implementing this trait for a new shape is enough to get its [`Integrable`] implementation
+/// via [`Decompose::piecewise_moment`].
+pub trait Decompose {
+    /// Pieces of the shape in arbitrary order.
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_;
+
+    /// Moment of the shape computed as the sum of its pieces moments.
+    ///
+    /// The area is negative if the shape is clockwise.
+    fn piecewise_moment(&self) -> Moment {
+        moment_of_pieces(self.pieces())
+    }
+
+    /// Winding number computed as the sum of its pieces winding numbers.
+    fn piecewise_winding_number_2(&self, point: Vec2) -> i32 {
+        self.pieces()
+            .map(|piece| piece.winding_number_2(point))
+            .sum()
+    }
+}
+
+/// Sum of the `pieces` moments.
+///
+/// First moments are accumulated separately from areas, so pieces cancelling each other don't lose precision.
+pub(crate) fn moment_of_pieces<I: Iterator<Item = Piece>>(pieces: I) -> Moment {
+    let (area, first) = pieces.map(|piece| piece.moment()).fold(
+        (0.0, Vec2::ZERO),
+        |(area, first), Moment { area: a, centroid }| (area + a, first + centroid * a),
+    );
+    if area.abs() < EPS {
+        Moment::default()
+    } else {
+        Moment {
+            area,
+            centroid: first / area,
+        }
+    }
+}
+
+/// Pieces of the arc polygon given by a single pass over its `vertices`.
+///
+/// The frame polygon is split into a fan of triangles sharing the first vertex,
+/// and each curved edge gives a disk segment.
+pub(crate) fn arc_pieces<I: Iterator<Item = ArcVertex>>(
+    mut vertices: I,
+) -> impl Iterator<Item = Piece> {
+    let first = vertices.next();
+    let mut prev = first;
+    vertices.chain(first).flat_map(move |curr| {
+        let (origin, prev) = (first.unwrap().point, prev.replace(curr).unwrap());
+        // The first and the last triangles are degenerate
+        let triangle = Some(Triangle(origin, prev.point, curr.point))
+            .filter(|_| prev.point != origin && curr.point != origin);
+        let segment = Some(DiskSegment(Arc::from_vertices(&prev, &curr)))
+            .filter(|segment| segment.sagitta != 0.0);
+        triangle
+            .map(Piece::Triangle)
+            .into_iter()
+            .chain(segment.map(Piece::Segment))
+    })
+}
+
+impl Decompose for Piece {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        [*self].into_iter()
+    }
+}
+
+impl Decompose for Triangle {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        [Piece::Triangle(*self)].into_iter()
+    }
+}
+
+impl Decompose for DiskSegment {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        [Piece::Segment(*self)].into_iter()
+    }
+}
+
+impl Decompose for Disk {
+    /// Two halves of the disk.
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        arc_pieces(self.polygon::<2>().vertices.into_iter())
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Decompose for Polygon<V> {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        arc_pieces(self.vertices().map(|point| ArcVertex {
+            point,
+            sagitta: 0.0,
+        }))
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Decompose for ArcPolygon<V> {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        arc_pieces(self.vertices())
+    }
+}
+
+impl Decompose for Aabb {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        let [a, b, c, d] = self.corners();
+        let empty = self.is_empty();
+        [Triangle(a, b, c), Triangle(a, c, d)]
+            .into_iter()
+            .filter(move |_| !empty)
+            .map(Piece::Triangle)
+    }
+}
+
+impl Decompose for Capsule {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        arc_pieces(self.polygon().vertices.into_iter())
+    }
+}
+
+impl<T: Decompose> Decompose for Option<T> {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        self.iter().flat_map(|shape| shape.pieces())
+    }
+}
+
+impl<L: Decompose, R: Decompose> Decompose for Either<L, R> {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        match self {
+            Either::Left(left) => Either::Left(left.pieces()),
+            Either::Right(right) => Either::Right(right.pieces()),
+        }
+    }
+}
+
+impl<T: Decompose + ?Sized> Decompose for &T {
+    fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        (**self).pieces()
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{Aabb, ConvexRegion, CopyIterator, HalfPlane, Polygon, intersect_half_planes};
+use alloc::vec::Vec;
+use core::f32::consts::{PI, TAU};
+use glam::Vec2;
+
+/// Tukey (halfspace) depth of the `point` in the set of `points`.
+///
+/// It is the least number of the points lying in a closed half-plane which boundary goes through the `point`.
+/// Points of the hull boundary have depth `1`, points outside of the hull have depth `0`,
+/// and points equal to the `point` are counted in every half-plane.
+///
+/// The half-plane is rotated around the `point`, so that the directions to the other points are sorted once,
+/// and the number of points inside of it is found by bisection for each critical direction.
+/// Takes `O(n log n)` time.
+pub fn halfspace_depth<I: IntoIterator<Item = Vec2>>(point: Vec2, points: I) -> usize {
+    let mut coincident = 0;
+    let mut angles = Vec::new();
+    for p in points {
+        let d = p - point;
+        if d == Vec2::ZERO {
+            coincident += 1;
+        } else {
+            angles.push(d.y.atan2(d.x).rem_euclid(TAU));
+        }
+    }
+    angles.sort_by(f32::total_cmp);
+    let n = angles.len();
+    // Angles of the second turn, so that half-turn ranges don't wrap around
+    angles.extend_from_within(..);
+    for angle in &mut angles[n..] {
+        *angle += TAU;
+    }
+    // Number of points in the open half-plane is constant between the critical directions,
+    // the half-plane next to the critical direction `a` covers the angles in `(a, a + PI]`
+    let count = |a: f32| {
+        let upper = |b: f32| angles.partition_point(|&x| x <= b);
+        upper(a + PI) - upper(a)
+    };
+    let least = angles[..n]
+        .iter()
+        .flat_map(|&a| [a, (a + PI).rem_euclid(TAU)])
+        .map(count)
+        .min()
+        .unwrap_or(0);
+    coincident + least
+}
+
+/// Tukey depth region of the `points`: the convex polygon of all points which [`halfspace_depth`] is at least `depth`.
+///
+/// The region is the intersection of all closed half-planes containing more than `n - depth` points.
+/// The least such half-plane in each direction is bounded by a line going through one of the points,
+/// and it changes only at the directions orthogonal to the lines through pairs of points,
+/// so the region is cut out of the points bounding box by the half-planes of these directions.
+/// Takes `O(n^3)` time.
+///
+/// Depth region `1` is the convex hull of the points, and the regions of larger depths are nested in it.
+/// Returns `None` if the `depth` is zero, since the region is the whole plane, or if no point has such depth.
+/// The region may degenerate into a point or a segment, e.g. at the largest depth of a symmetric set,
+/// then it may be returned as `None` too.
+pub fn depth_contour<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+    points: &[Vec2],
+    depth: usize,
+) -> Option<Polygon<W>> {
+    let n = points.len();
+    if depth == 0 || depth > n {
+        return None;
+    }
+    let mut projections = Vec::with_capacity(n);
+    let mut planes = Vec::new();
+    for (i, &a) in points.iter().enumerate() {
+        for &b in &points[i + 1..] {
+            let normal = (b - a).perp().normalize_or_zero();
+            if normal == Vec2::ZERO {
+                continue;
+            }
+            for normal in [normal, -normal] {
+                projections.clear();
+                projections.extend(points.iter().map(|p| p.dot(normal)));
+                // Least offset covering `n - depth + 1` points
+                let (_, &mut offset, _) =
+                    projections.select_nth_unstable_by(n - depth, f32::total_cmp);
+                planes.push(HalfPlane { normal, offset });
+            }
+        }
+    }
+    let bounds = Aabb::from_points(points.iter().copied());
+    match intersect_half_planes(planes, Some(bounds)) {
+        ConvexRegion::Polygon(polygon) => Some(polygon),
+        ConvexRegion::Empty | ConvexRegion::Unbounded => None,
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    Aabb, Bounded, Closed, CopyIterator, Disk, EPS, HalfPlane, Integrable, IntersectTo, Line,
+    Moment, MomentAccumulator, Polygon, Region, Triangle,
+};
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+use glam::Vec2;
+
+/// Split the simple polygon into triangles by ear clipping.
+///
+/// Polygon may be of any orientation, the triangles are counterclockwise.
+/// Slivers of nearly zero area are kept, so the triangles sum up to the polygon exactly,
+/// except for the degenerate corners cut when no ear is left, which may be slightly clockwise.
+///
+/// Each ear is searched among all the corners and checked against all the vertices,
+/// so it takes `O(n^3)` time in the worst case.
+pub(crate) fn triangulate(mut points: Vec<Vec2>) -> Vec<Triangle> {
+    points.dedup_by(|a, b| a.distance(*b) < EPS);
+    if points.len() > 1 && points[0].distance(points[points.len() - 1]) < EPS {
+        points.pop();
+    }
+    if Polygon::new(points.to_ref()).signed_area() < 0.0 {
+        points.reverse();
+    }
+    let mut triangles = Vec::new();
+    while points.len() > 3 {
+        let n = points.len();
+        let corner = |i: usize| (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+        let is_ear = |i: usize| {
+            let (a, b, c) = corner(i);
+            if (b - a).perp_dot(c - b) <= 0.0 {
+                return false;
+            }
+            // No other vertex lies inside or on the boundary of the ear
+            points.iter().all(|&p| {
+                p == a
+                    || p == b
+                    || p == c
+                    || (b - a).perp_dot(p - a) < 0.0
+                    || (c - b).perp_dot(p - b) < 0.0
+                    || (a - c).perp_dot(p - c) < 0.0
+            })
+        };
+        // Degenerate polygons may have no ears, then the flattest corner is cut
+        let i = (0..n).find(|&i| is_ear(i)).unwrap_or_else(|| {
+            (0..n)
+                .min_by(|&i, &j| {
+                    let area = |i| {
+                        let (a, b, c) = corner(i);
+                        (b - a).perp_dot(c - b).abs()
+                    };
+                    area(i).total_cmp(&area(j))
+                })
+                .unwrap()
+        });
+        let (a, b, c) = corner(i);
+        triangles.push(Triangle(a, b, c));
+        points.remove(i);
+    }
+    if let [a, b, c] = points[..] {
+        triangles.push(Triangle(a, b, c));
+    }
+    triangles.retain(|triangle| triangle.signed_area() != 0.0);
+    triangles
+}
+
+/// Region that can be cut by disks and polygons many times.
+///
+/// The region is kept as a set of non-overlapping counterclockwise triangles, up to the rounding errors of slivers.
+/// A cut replaces only the triangles it touches with the triangulation of what's left of them,
+/// and the area and the centroid are updated by the contributions of the replaced triangles,
+/// so the cost of a cut doesn't depend on how many cuts were made before, except for the growing number of triangles.
+/// Each cut still visits all the triangles to reject ones outside of its bounding box.
+///
+/// Cut outlines are polygons, disks are replaced by circumscribed regular polygons within the given tolerance.
+#[derive(Clone, Debug)]
+pub struct DestructibleRegion {
+    triangles: Vec<Triangle>,
+    moment: MomentAccumulator,
+}
+
+impl DestructibleRegion {
+    /// Triangulate the `region`.
+    ///
+    /// Counterclockwise contours are filled and clockwise contours are cut out.
+    /// Contours must be simple polygons.
+    pub fn new<C: ?Sized, V: CopyIterator<Item = Vec2> + ?Sized>(region: &Region<C>) -> Self
+    where
+        for<'a> &'a C: IntoIterator<Item = &'a Polygon<V>>,
+    {
+        let mut this = Self::from_triangles(
+            region
+                .components()
+                .filter(|contour| contour.signed_area() > 0.0)
+                .flat_map(|contour| triangulate(contour.vertices().collect())),
+        );
+        for hole in region
+            .components()
+            .filter(|contour| contour.signed_area() < 0.0)
+        {
+            this.subtract_polygon(hole);
+        }
+        this
+    }
+
+    /// Region made of non-overlapping `triangles`.
+    ///
+    /// Clockwise triangles are reversed.
+    pub fn from_triangles<I: IntoIterator<Item = Triangle>>(triangles: I) -> Self {
+        let mut this = Self {
+            triangles: Vec::new(),
+            moment: MomentAccumulator::new(),
+        };
+        for Triangle(a, b, c) in triangles {
+            this.push(if (b - a).perp_dot(c - a) < 0.0 {
+                Triangle(a, c, b)
+            } else {
+                Triangle(a, b, c)
+            });
+        }
+        this
+    }
+
+    /// Triangles the region consists of.
+    pub fn triangles(&self) -> &[Triangle] {
+        &self.triangles
+    }
+
+    /// Check that nothing is left of the region.
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    /// Add the `triangle` unless it has exactly zero area.
+    ///
+    /// Slivers are kept, otherwise their area would be lost from the moment.
+    fn push(&mut self, triangle: Triangle) {
+        if triangle.signed_area() != 0.0 {
+            self.moment.add_shape(&triangle);
+            self.triangles.push(triangle);
+        }
+    }
+
+    /// Cut the simple `polygon` out of the region.
+    ///
+    /// Polygon may be of any orientation. Non-convex polygons are triangulated and cut out triangle by triangle.
+    pub fn subtract_polygon<V: CopyIterator<Item = Vec2> + ?Sized>(
+        &mut self,
+        polygon: &Polygon<V>,
+    ) {
+        let mut points: Vec<Vec2> = polygon.vertices().collect();
+        if Polygon::new(points.to_ref()).signed_area() < 0.0 {
+            points.reverse();
+        }
+        if Polygon::new(points.to_ref()).is_convex() {
+            self.subtract_convex(&points);
+        } else {
+            for triangle in triangulate(points) {
+                self.subtract_convex(&triangle.vertices());
+            }
+        }
+    }
+
+    /// Cut the `disk` out of the region.
+    ///
+    /// The disk is replaced by the circumscribed regular polygon which edges are within the `tolerance` from the circle,
+    /// so the whole disk is removed. The `tolerance` must be positive.
+    pub fn subtract_disk(&mut self, disk: &Disk, tolerance: f32) {
+        // Circumscribed polygon is the inscribed one scaled by `1 / cos(PI / n)`,
+        // so its deviation is `radius * (1 / cos(PI / n) - 1)` which is within the `tolerance`
+        // when the inscribed one is within `tolerance * radius / (radius + tolerance)`
+        let n = disk.segment_count(tolerance * disk.radius / (disk.radius + tolerance));
+        let radius = disk.radius / (PI / n as f32).cos();
+        let polygon = Polygon::<Vec<Vec2>>::regular(disk.center, radius, n, 0.0);
+        let points = polygon.vertices;
+        self.subtract_convex(&points);
+    }
+
+    /// Cut out the convex counterclockwise polygon given by its `points`.
+    fn subtract_convex(&mut self, points: &[Vec2]) {
+        let n = points.len();
+        if n < 3 {
+            return;
+        }
+        let planes: Vec<HalfPlane> = (0..n)
+            .filter(|&i| points[i].distance(points[(i + 1) % n]) >= EPS)
+            .map(|i| HalfPlane::from_edge(Line(points[i], points[(i + 1) % n])))
+            .collect();
+        let bounds = Aabb::from_points(points.iter().copied());
+
+        let mut pieces = Vec::new();
+        let mut kept = Vec::with_capacity(self.triangles.len());
+        for triangle in core::mem::take(&mut self.triangles) {
+            let vertices = triangle.vertices();
+            // Triangle lying outside of any cut edge is not touched
+            if !triangle.aabb().intersects(&bounds)
+                || planes
+                    .iter()
+                    .any(|plane| vertices.iter().all(|&p| plane.distance(p) >= -EPS))
+            {
+                kept.push(triangle);
+                continue;
+            }
+            self.moment.remove_shape(&triangle);
+            // Parts outside of each edge are kept, and the part inside goes on to the next edge
+            let mut rest = Some(Polygon::new(vertices.to_vec()));
+            for plane in &planes {
+                let polygon = match &rest {
+                    Some(polygon) => polygon,
+                    None => break,
+                };
+                let outside: Option<Polygon<Vec<Vec2>>> = polygon.intersect_to(&plane.complement());
+                if let Some(outside) = outside {
+                    let mut vertices = outside.vertices();
+                    if let Some(first) = vertices.next() {
+                        let fan: Vec<Vec2> = vertices.collect();
+                        pieces.extend(fan.windows(2).map(|w| Triangle(first, w[0], w[1])));
+                    }
+                }
+                rest = polygon.intersect_to(plane);
+            }
+        }
+        self.triangles = kept;
+        for piece in pieces {
+            self.push(piece);
+        }
+    }
+
+    /// Moment of the remaining region.
+    pub fn moment(&self) -> Moment {
+        self.moment.moment()
+    }
+}
+
+impl Integrable for DestructibleRegion {
+    fn moment(&self) -> Moment {
+        self.moment.moment()
+    }
+}
+
+impl Closed for DestructibleRegion {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        // Point on the edge shared by two triangles is counted once
+        if self.triangles.iter().any(|triangle| {
+            triangle
+                .barycentric(point)
+                .is_some_and(|b| b.min_element() >= 0.0)
+        }) {
+            2
+        } else {
+            0
+        }
+    }
+}
+
+impl Bounded for DestructibleRegion {
+    fn aabb(&self) -> Aabb {
+        self.triangles
+            .iter()
+            .fold(Aabb::EMPTY, |a, triangle| a.union(triangle.aabb()))
+    }
+}
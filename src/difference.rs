@@ -0,0 +1,200 @@
+use crate::{
+    Arc, ArcPolygon, ArcTarget, ArcVertex, Bounded, Decompose, Disk, EPS, HalfPlane, IntersectTo,
+    Line, Moment, MomentAccumulator, Piece, Polygon, Transform, Triangle, decompose::arc_pieces,
+};
+use arrayvec::ArrayVec;
+use glam::{Affine2, Vec2};
+
+/// Storage for intermediate clipping results of two pieces.
+type Clipped = ArcPolygon<ArrayVec<ArcVertex, 16>>;
+
+/// Counterclockwise triangle and the sign of its orientation.
+fn unsigned_triangle(triangle: &Triangle) -> (Polygon<[Vec2; 3]>, f32) {
+    let Triangle(a, b, c) = *triangle;
+    if triangle.signed_area() < 0.0 {
+        (Polygon::new([a, c, b]), -1.0)
+    } else {
+        (Polygon::new([a, b, c]), 1.0)
+    }
+}
+
+/// Counterclockwise disk segment split into its disk and its chord half-plane, and the sign of its orientation.
+fn unsigned_segment(arc: &Arc) -> Option<(ArcPolygon<[ArcVertex; 2]>, Disk, HalfPlane, f32)> {
+    let circle = arc.circle()?;
+    let (a, b) = arc.points;
+    let ((a, b), sign) = if arc.sagitta < 0.0 {
+        ((b, a), -1.0)
+    } else {
+        ((a, b), 1.0)
+    };
+    let polygon = ArcPolygon::new([
+        ArcVertex {
+            point: a,
+            sagitta: arc.sagitta.abs(),
+        },
+        ArcVertex {
+            point: b,
+            sagitta: 0.0,
+        },
+    ]);
+    Some((
+        polygon,
+        Disk(circle),
+        HalfPlane::from_edge(Line(b, a)),
+        sign,
+    ))
+}
+
+/// Intersection of two pieces written to the `T` as a counterclockwise arc polygon, and the product of the pieces orientations.
+fn clip_pieces<T: ArcTarget>(p: &Piece, q: &Piece) -> Option<(T, f32)> {
+    if !p.aabb().intersects(&q.aabb()) {
+        return None;
+    }
+    match (p, q) {
+        (Piece::Triangle(p), Piece::Triangle(q)) => {
+            let ((p, s), (q, t)) = (unsigned_triangle(p), unsigned_triangle(q));
+            let overlap: Polygon<ArrayVec<Vec2, 8>> = p.intersect_to(&q)?;
+            let vertices = overlap.vertices().map(|point| ArcVertex {
+                point,
+                sagitta: 0.0,
+            });
+            Some((T::from_arc_vertices(vertices), s * t))
+        }
+        (Piece::Triangle(p), Piece::Segment(q)) | (Piece::Segment(q), Piece::Triangle(p)) => {
+            let (p, s) = unsigned_triangle(p);
+            let (_, disk, chord, t) = unsigned_segment(q)?;
+            let clipped: Clipped = p.intersect_to(&disk)?;
+            Some((clipped.intersect_to(&chord)?, s * t))
+        }
+        (Piece::Segment(p), Piece::Segment(q)) => {
+            let ((p, _, _, s), (_, disk, chord, t)) = (unsigned_segment(p)?, unsigned_segment(q)?);
+            let clipped: Clipped = p.intersect_to(&disk)?;
+            Some((clipped.intersect_to(&chord)?, s * t))
+        }
+    }
+}
+
+/// Signed moment of the intersection of two pieces.
+///
+/// The sign of the area is the product of the pieces orientations.
+pub(crate) fn piece_overlap(p: &Piece, q: &Piece) -> Moment {
+    clip_pieces(p, q).map_or(Moment::default(), |(Moment { area, centroid }, sign)| {
+        Moment {
+            area: sign * area,
+            centroid,
+        }
+    })
+}
+
+/// Signed pieces of the intersection of two pieces.
+///
+/// They are oriented by the product of the pieces orientations.
+pub(crate) fn overlap_pieces(p: &Piece, q: &Piece) -> impl Iterator<Item = Piece> + use<> {
+    clip_pieces(p, q)
+        .into_iter()
+        .flat_map(|(mut overlap, sign): (Clipped, f32)| {
+            if sign < 0.0 {
+                overlap.reverse();
+            }
+            arc_pieces(overlap.vertices.into_iter())
+        })
+}
+
+/// Area of the intersection of two shapes.
+///
+/// The shapes are decomposed into signed pieces and the intersection areas of all overlapping pairs of pieces are summed up,
+/// so the shapes may be non-convex and consist of multiple contours.
+/// Takes `O(n * m)` time, where `n` and `m` are the numbers of the shapes pieces,
+/// but the pairs with disjoint bounding boxes are skipped cheaply.
+///
+/// Shapes must be counterclockwise, for clockwise ones the sign of the result is flipped.
+pub fn intersection_area<A: Decompose + ?Sized, B: Decompose + ?Sized>(a: &A, b: &B) -> f32 {
+    a.pieces()
+        .map(|p| b.pieces().map(|q| piece_overlap(&p, &q).area).sum::<f32>())
+        .sum()
+}
+
+/// Moment of the intersection of two shapes.
+///
+/// Computed the same way as [`intersection_area`], the moments of the pieces overlaps are accumulated.
+pub fn intersection_moment<A: Decompose + ?Sized, B: Decompose + ?Sized>(a: &A, b: &B) -> Moment {
+    let accumulator: MomentAccumulator = a
+        .pieces()
+        .flat_map(|p| b.pieces().map(move |q| piece_overlap(&p, &q)))
+        .collect();
+    accumulator.moment()
+}
+
+/// Area covered by exactly one of two shapes given their areas and the area of their intersection.
+fn difference(a: f32, b: f32, overlap: f32) -> f32 {
+    // Rounding errors for coinciding shapes may give small negative values
+    (a + b - 2.0 * overlap).max(0.0)
+}
+
+/// Area of the symmetric difference of two shapes, i.e. the area covered by exactly one of them.
+///
+/// It is zero for coinciding shapes and is the sum of their areas for disjoint ones,
+/// so it can be used as a measure of the shapes mismatch.
+/// Shapes must be counterclockwise.
+pub fn symmetric_difference_area<A: Decompose + ?Sized, B: Decompose + ?Sized>(
+    a: &A,
+    b: &B,
+) -> f32 {
+    difference(
+        a.piecewise_moment().area,
+        b.piecewise_moment().area,
+        intersection_area(a, b),
+    )
+}
+
+/// Areas of two shapes and of their intersection, made non-negative regardless of the shapes orientations.
+fn unsigned_areas<A: Decompose + ?Sized, B: Decompose + ?Sized>(a: &A, b: &B) -> (f32, f32, f32) {
+    let (a_area, b_area) = (a.piecewise_moment().area, b.piecewise_moment().area);
+    let overlap = intersection_area(a, b) * a_area.signum() * b_area.signum();
+    let (a_area, b_area) = (a_area.abs(), b_area.abs());
+    // Rounding errors may put the intersection area slightly out of its bounds
+    (a_area, b_area, overlap.clamp(0.0, a_area.min(b_area)))
+}
+
+/// Intersection over union (Jaccard index) of two shapes, e.g. to score a detected footprint against the reference one.
+///
+/// It is `1` for coinciding shapes and `0` for disjoint ones.
+/// Shapes may be of any orientation. Returns `0` if both shapes have zero area.
+pub fn intersection_over_union<A: Decompose + ?Sized, B: Decompose + ?Sized>(a: &A, b: &B) -> f32 {
+    let (a_area, b_area, overlap) = unsigned_areas(a, b);
+    let union = a_area + b_area - overlap;
+    if union < EPS { 0.0 } else { overlap / union }
+}
+
+/// Fraction of the area of the shape `a` covered by the shape `b`.
+///
+/// It is `1` if `a` is inside of `b` and `0` if they are disjoint.
+/// Shapes may be of any orientation. Returns `0` if `a` has zero area.
+pub fn overlap_fraction<A: Decompose + ?Sized, B: Decompose + ?Sized>(a: &A, b: &B) -> f32 {
+    let (a_area, _, overlap) = unsigned_areas(a, b);
+    if a_area < EPS { 0.0 } else { overlap / a_area }
+}
+
+/// Symmetric difference area between the shape `a` and the shape `b` transformed by `transform(t)` for each parameter `t`.
+///
+/// The area of `a` is computed only once.
+/// The profile can be minimized over `t` to register `b` onto `a`, or inspected to check the tolerance of the fit.
+pub fn difference_profile<'a, A, B, F, I>(
+    a: &'a A,
+    b: &'a B,
+    transform: F,
+    params: I,
+) -> impl Iterator<Item = f32> + 'a
+where
+    A: Decompose + ?Sized,
+    B: Decompose + Transform,
+    F: Fn(f32) -> Affine2 + 'a,
+    I: IntoIterator<Item = f32>,
+    I::IntoIter: 'a,
+{
+    let area = a.piecewise_moment().area;
+    params.into_iter().map(move |t| {
+        let b = b.transform(transform(t));
+        difference(area, b.piecewise_moment().area, intersection_area(a, &b))
+    })
+}
@@ -0,0 +1,143 @@
+use crate::{ArcPolygon, ArcVertex, Closed, CopyIterator, Disk, Intersect, LineSegment, Polygon};
+use glam::Vec2;
+
+/// Mutual position of two shapes with respect to the distance tolerance.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Clearance {
+    /// Shapes have common interior points.
+    Overlapping,
+    /// Shapes touch each other at the point within tolerance.
+    Tangent(Vec2),
+    /// Shapes are separated by the distance.
+    Separated(f32),
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Check the clearance between the polygon and the disk.
+    ///
+    /// Shapes are considered tangent when the gap between them
+    /// (or the penetration depth) is not greater than `tolerance`.
+    /// The tangent point lies on the polygon boundary.
+    pub fn clearance(&self, disk: &Disk, tolerance: f32) -> Clearance {
+        let point = match self.closest_point(disk.center) {
+            Some(point) => point,
+            None => return Clearance::Separated(f32::INFINITY),
+        };
+        let distance = (point - disk.center).length();
+        let gap = if self.contains(disk.center) {
+            -distance - disk.radius
+        } else {
+            distance - disk.radius
+        };
+        if gap.abs() <= tolerance {
+            Clearance::Tangent(point)
+        } else if gap < 0.0 {
+            Clearance::Overlapping
+        } else {
+            Clearance::Separated(gap)
+        }
+    }
+}
+
+/// Minimum distance between two shapes.
+///
+/// Shapes are closed sets, i.e. polygons and disks include their interior,
+/// so the distance is zero if the shapes overlap or one of them is inside of the other.
+pub trait Distance<T: Distance<Self> + ?Sized> {
+    /// Pair of the closest points, the first one belongs to `self` and the second one to the `other`.
+    ///
+    /// Overlapping shapes give some common point twice.
+    /// Returns `None` if either shape is empty.
+    fn closest_points(&self, other: &T) -> Option<(Vec2, Vec2)>;
+
+    /// Distance between the closest points, infinite if either shape is empty.
+    fn distance_to(&self, other: &T) -> f32 {
+        match self.closest_points(other) {
+            Some((a, b)) => a.distance(b),
+            None => f32::INFINITY,
+        }
+    }
+}
+
+/// The closest of the pairs of points.
+fn nearest<I: IntoIterator<Item = (Vec2, Vec2)>>(pairs: I) -> Option<(Vec2, Vec2)> {
+    pairs
+        .into_iter()
+        .min_by(|(a, b), (c, d)| a.distance_squared(*b).total_cmp(&c.distance_squared(*d)))
+}
+
+impl Distance<LineSegment> for LineSegment {
+    fn closest_points(&self, other: &LineSegment) -> Option<(Vec2, Vec2)> {
+        if let Some(point) = self.intersect(other) {
+            return Some((point, point));
+        }
+        // Closest points of non-crossing segments include an end of either of them
+        nearest([
+            (self.0, other.closest_point(self.0)),
+            (self.1, other.closest_point(self.1)),
+            (self.closest_point(other.0), other.0),
+            (self.closest_point(other.1), other.1),
+        ])
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Distance<Polygon<V>> for Disk {
+    fn closest_points(&self, polygon: &Polygon<V>) -> Option<(Vec2, Vec2)> {
+        if polygon.contains(self.center) {
+            return Some((self.center, self.center));
+        }
+        let point = polygon.closest_point(self.center)?;
+        let offset = point - self.center;
+        let length = offset.length();
+        if length <= self.radius {
+            Some((point, point))
+        } else {
+            Some((self.center + offset * (self.radius / length), point))
+        }
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Distance<Disk> for Polygon<V> {
+    fn closest_points(&self, disk: &Disk) -> Option<(Vec2, Vec2)> {
+        disk.closest_points(self).map(|(a, b)| (b, a))
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized, W: CopyIterator<Item = Vec2> + ?Sized>
+    Distance<Polygon<W>> for Polygon<V>
+{
+    /// Takes `O(n m)` time.
+    fn closest_points(&self, other: &Polygon<W>) -> Option<(Vec2, Vec2)> {
+        let (a, b) = (self.vertices().next()?, other.vertices().next()?);
+        // Polygons with no crossing edges overlap only if one of them is inside of the other one
+        if other.contains(a) {
+            return Some((a, a));
+        }
+        if self.contains(b) {
+            return Some((b, b));
+        }
+        nearest(self.edges().flat_map(|edge| {
+            other
+                .edges()
+                .filter_map(move |other_edge| edge.closest_points(&other_edge))
+        }))
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Distance<ArcPolygon<V>> for Vec2 {
+    fn closest_points(&self, polygon: &ArcPolygon<V>) -> Option<(Vec2, Vec2)> {
+        let point = *self;
+        let closest = nearest(polygon.edges().map(|arc| (point, arc.closest_point(point))))?;
+        if polygon.contains(point) {
+            Some((point, point))
+        } else {
+            Some(closest)
+        }
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Distance<Vec2> for ArcPolygon<V> {
+    fn closest_points(&self, point: &Vec2) -> Option<(Vec2, Vec2)> {
+        point.closest_points(self).map(|(a, b)| (b, a))
+    }
+}
@@ -0,0 +1,169 @@
+//! Export of curved geometry into CAM file formats.
+//!
+//! Writers output text into any [`fmt::Write`] sink, e.g. `String`.
+
+use crate::{Arc, ArcPolygon, ArcVertex, Boundary, CopyIterator, EPS, Path};
+use core::fmt::{self, Write};
+use glam::Vec2;
+
+/// Writer of DXF file with `LWPOLYLINE` entities.
+///
+/// Arc edges are represented by vertex bulges, see [`Arc::bulge`].
+/// The file is marked as AutoCAD 2000 one, the earliest version supporting `LWPOLYLINE`.
+pub struct DxfWriter<W: Write> {
+    out: W,
+}
+
+impl<W: Write> DxfWriter<W> {
+    /// Write DXF header and start entities section.
+    pub fn new(mut out: W) -> Result<Self, fmt::Error> {
+        write!(
+            out,
+            "0\nSECTION\n2\nHEADER\n9\n$ACADVER\n1\nAC1015\n0\nENDSEC\n"
+        )?;
+        write!(out, "0\nSECTION\n2\nENTITIES\n")?;
+        Ok(Self { out })
+    }
+
+    /// Write closed polyline.
+    pub fn polygon<V: CopyIterator<Item = ArcVertex> + ?Sized>(
+        &mut self,
+        polygon: &ArcPolygon<V>,
+    ) -> fmt::Result {
+        self.polyline(polygon.vertices().count(), true, polygon.edges())
+    }
+
+    /// Write open polyline.
+    pub fn path<V: CopyIterator<Item = ArcVertex> + ?Sized>(
+        &mut self,
+        path: &Path<V>,
+    ) -> fmt::Result {
+        let last = path.vertices().last().map(|v| Arc {
+            points: (v.point, v.point),
+            sagitta: 0.0,
+        });
+        self.polyline(path.vertices().count(), false, path.edges().chain(last))
+    }
+
+    fn polyline(
+        &mut self,
+        count: usize,
+        closed: bool,
+        edges: impl Iterator<Item = Arc>,
+    ) -> fmt::Result {
+        write!(
+            self.out,
+            "0\nLWPOLYLINE\n100\nAcDbEntity\n8\n0\n100\nAcDbPolyline\n90\n{}\n70\n{}\n",
+            count, closed as u8
+        )?;
+        for edge in edges {
+            let point = edge.points.0;
+            write!(self.out, "10\n{}\n20\n{}\n", point.x, point.y)?;
+            if !edge.is_flat() {
+                write!(self.out, "42\n{}\n", edge.bulge())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finish the section and the file.
+    pub fn finish(mut self) -> Result<W, fmt::Error> {
+        write!(self.out, "0\nENDSEC\n0\nEOF\n")?;
+        Ok(self.out)
+    }
+}
+
+/// Writer of Gerber file with outlines drawn by a circular aperture.
+///
+/// Coordinates are in millimeters, arc edges are written as circular interpolation.
+pub struct GerberWriter<W: Write> {
+    out: W,
+}
+
+impl<W: Write> GerberWriter<W> {
+    /// Write file header, `aperture` is the diameter of the outline stroke.
+    pub fn new(mut out: W, aperture: f32) -> Result<Self, fmt::Error> {
+        write!(
+            out,
+            "%FSLAX46Y46*%\n%MOMM*%\n%ADD10C,{}*%\nD10*\nG75*\n",
+            aperture
+        )?;
+        Ok(Self { out })
+    }
+
+    /// Write closed outline.
+    pub fn polygon<V: CopyIterator<Item = ArcVertex> + ?Sized>(
+        &mut self,
+        polygon: &ArcPolygon<V>,
+    ) -> fmt::Result {
+        self.outline(polygon.vertices().next(), polygon.edges())
+    }
+
+    /// Write open outline.
+    pub fn path<V: CopyIterator<Item = ArcVertex> + ?Sized>(
+        &mut self,
+        path: &Path<V>,
+    ) -> fmt::Result {
+        self.outline(path.vertices().next(), path.edges())
+    }
+
+    fn outline(
+        &mut self,
+        start: Option<ArcVertex>,
+        edges: impl Iterator<Item = Arc>,
+    ) -> fmt::Result {
+        let start = match start {
+            Some(start) => start.point,
+            None => return Ok(()),
+        };
+        self.move_to(start)?;
+        for edge in edges {
+            self.edge(&edge)?;
+        }
+        Ok(())
+    }
+
+    /// Write the boundary of any shape.
+    ///
+    /// A new outline is started wherever the next edge doesn't continue the previous one.
+    pub fn boundary<S: Boundary + ?Sized>(&mut self, shape: &S) -> fmt::Result {
+        let mut end: Option<Vec2> = None;
+        for edge in shape.boundary() {
+            let edge = edge.arc();
+            if end.is_none_or(|end| (end - edge.points.0).abs().max_element() > EPS) {
+                self.move_to(edge.points.0)?;
+            }
+            self.edge(&edge)?;
+            end = Some(edge.points.1);
+        }
+        Ok(())
+    }
+
+    fn move_to(&mut self, point: Vec2) -> fmt::Result {
+        let (x, y) = gerber_coords(point);
+        writeln!(self.out, "X{x}Y{y}D02*")
+    }
+
+    fn edge(&mut self, edge: &Arc) -> fmt::Result {
+        let (x, y) = gerber_coords(edge.points.1);
+        if edge.is_flat() {
+            writeln!(self.out, "G01X{x}Y{y}D01*")
+        } else {
+            let (i, j) = gerber_coords(edge.center() - edge.points.0);
+            let code = if edge.is_ccw() { "G03" } else { "G02" };
+            writeln!(self.out, "{code}X{x}Y{y}I{i}J{j}D01*")
+        }
+    }
+
+    /// Finish the file.
+    pub fn finish(mut self) -> Result<W, fmt::Error> {
+        writeln!(self.out, "M02*")?;
+        Ok(self.out)
+    }
+}
+
+/// Coordinates in `4.6` fixed-point format.
+fn gerber_coords(point: Vec2) -> (i64, i64) {
+    let v = (point * 1e6).round().as_i64vec2();
+    (v.x, v.y)
+}
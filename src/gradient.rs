@@ -0,0 +1,57 @@
+use crate::{CopyIterator, Disk, Intersect, LineSegment, Polygon};
+use glam::Vec2;
+
+/// Gradient of the intersection area with respect to the translation of the other shape.
+///
+/// Moving the other shape changes the intersection only where its boundary lies inside of this shape,
+/// so the gradient is the integral of its outward normal over that part of the boundary.
+/// The integral of the normal along a curve is the perpendicular of the chord between its ends,
+/// so it is found exactly from the parts of the polygon boundaries inside of the other shape,
+/// without finite differences.
+///
+/// The gradient is zero if the shapes are disjoint or one of them is inside of the other,
+/// and it is undefined where their boundaries overlap.
+pub trait AreaGradient<T: ?Sized> {
+    /// Derivative of the intersection area by the translation of the `other` shape.
+    fn area_gradient(&self, other: &T) -> Vec2;
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Sum of the vectors of the edges parts given by `part`, as if the polygon was counterclockwise.
+    ///
+    /// For the parts lying inside of a shape, its perpendicular is the gradient of the intersection area
+    /// with respect to the translation of the shape.
+    fn inner_chord<F: Fn(LineSegment) -> Vec2>(&self, part: F) -> Vec2 {
+        let chord: Vec2 = self.edges().map(part).sum();
+        if self.signed_area() < 0.0 {
+            -chord
+        } else {
+            chord
+        }
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> AreaGradient<Disk> for Polygon<V> {
+    fn area_gradient(&self, disk: &Disk) -> Vec2 {
+        self.inner_chord(|edge| edge.intersect(disk).map_or(Vec2::ZERO, |part| part.vec()))
+            .perp()
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> AreaGradient<Polygon<V>> for Disk {
+    /// Moving the disk one way is the same as moving the polygon the other way.
+    fn area_gradient(&self, polygon: &Polygon<V>) -> Vec2 {
+        -polygon.area_gradient(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: CopyIterator<Item = Vec2> + ?Sized, W: CopyIterator<Item = Vec2> + ?Sized>
+    AreaGradient<Polygon<W>> for Polygon<V>
+{
+    /// The `other` polygon must be counterclockwise, this one may be of any orientation.
+    fn area_gradient(&self, other: &Polygon<W>) -> Vec2 {
+        self.inner_chord(|edge| edge.clip(other).map(|part| part.vec()).sum())
+            .perp()
+    }
+}
@@ -0,0 +1,235 @@
+#[cfg(feature = "alloc")]
+use crate::Bounded;
+use crate::{Aabb, Closed};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use glam::{UVec2, Vec2};
+
+/// Uniform grid over bounding boxes of shapes for broad-phase queries.
+///
+/// The index doesn't allocate: the boxes and the cell tables are stored in the slices provided by the caller.
+/// Each box is registered in every cell it overlaps, cells are laid out contiguously
+/// so that a query visits only the entries of the cells it touches.
+/// Boxes sticking out of the grid bounds are clamped to the border cells, so they are still found.
+///
+/// Queries yield indices of the boxes, it is up to the caller to check the actual shapes.
+#[derive(Clone, Copy, Debug)]
+pub struct GridIndex<'a> {
+    bounds: Aabb,
+    size: UVec2,
+    boxes: &'a [Aabb],
+    /// Cell `c` refers to `entries[offsets[c]..offsets[c + 1]]`.
+    offsets: &'a [usize],
+    entries: &'a [usize],
+}
+
+/// Grid geometry shared by building and querying.
+#[derive(Clone, Copy)]
+struct Cells {
+    min: Vec2,
+    step: Vec2,
+    size: UVec2,
+}
+
+impl Cells {
+    fn new(bounds: Aabb, size: UVec2) -> Self {
+        let size = size.max(UVec2::ONE);
+        Self {
+            min: bounds.min,
+            step: bounds.size() / size.as_vec2(),
+            size,
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.size.x as usize * self.size.y as usize
+    }
+
+    /// Cell containing the `point`, clamped to the grid.
+    fn cell_of(&self, point: Vec2) -> UVec2 {
+        // Degenerate grid gives NaN which is cast to zero
+        ((point - self.min) / self.step)
+            .floor()
+            .clamp(Vec2::ZERO, (self.size - 1).as_vec2())
+            .as_uvec2()
+    }
+
+    /// Range of the cells overlapped by the `aabb`, inclusive.
+    fn range(&self, aabb: &Aabb) -> (UVec2, UVec2) {
+        (self.cell_of(aabb.min), self.cell_of(aabb.max))
+    }
+
+    fn index(&self, cell: UVec2) -> usize {
+        cell.y as usize * self.size.x as usize + cell.x as usize
+    }
+
+    /// Indices of the cells in the inclusive range.
+    fn cells((min, max): (UVec2, UVec2)) -> impl Iterator<Item = UVec2> {
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| UVec2::new(x, y)))
+    }
+}
+
+impl<'a> GridIndex<'a> {
+    /// Lengths of the `offsets` and `entries` storage required by [`GridIndex::new`].
+    pub fn storage_len(bounds: Aabb, size: UVec2, boxes: &[Aabb]) -> (usize, usize) {
+        let cells = Cells::new(bounds, size);
+        let entries = boxes
+            .iter()
+            .filter(|aabb| !aabb.is_empty())
+            .map(|aabb| {
+                let (min, max) = cells.range(aabb);
+                (max.x - min.x + 1) as usize * (max.y - min.y + 1) as usize
+            })
+            .sum();
+        (cells.count() + 1, entries)
+    }
+
+    /// Build the index of `boxes` in the grid of `size` cells covering the `bounds`.
+    ///
+    /// Returns `None` if `offsets` or `entries` are shorter than [`GridIndex::storage_len`] requires.
+    /// Empty boxes are not indexed.
+    pub fn new(
+        bounds: Aabb,
+        size: UVec2,
+        boxes: &'a [Aabb],
+        offsets: &'a mut [usize],
+        entries: &'a mut [usize],
+    ) -> Option<Self> {
+        let cells = Cells::new(bounds, size);
+        let offsets = offsets.get_mut(..cells.count() + 1)?;
+        let indexed = || {
+            boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, aabb)| !aabb.is_empty())
+        };
+
+        // Count entries of each cell and turn the counts into the cell ends
+        offsets.fill(0);
+        for (_, aabb) in indexed() {
+            for cell in Cells::cells(cells.range(aabb)) {
+                offsets[cells.index(cell)] += 1;
+            }
+        }
+        let mut total = 0;
+        for offset in offsets.iter_mut() {
+            total += *offset;
+            *offset = total;
+        }
+        let entries = entries.get_mut(..total)?;
+
+        // Fill the cells from the ends, so that the offsets become the cell starts
+        for (i, aabb) in indexed().rev() {
+            for cell in Cells::cells(cells.range(aabb)) {
+                let offset = &mut offsets[cells.index(cell)];
+                *offset -= 1;
+                entries[*offset] = i;
+            }
+        }
+
+        Some(Self {
+            bounds,
+            size: cells.size,
+            boxes,
+            offsets,
+            entries,
+        })
+    }
+
+    /// Index built from the storage previously filled by [`GridIndex::new`] with the same parameters.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_parts(
+        bounds: Aabb,
+        size: UVec2,
+        boxes: &'a [Aabb],
+        offsets: &'a [usize],
+        entries: &'a [usize],
+    ) -> Self {
+        Self {
+            bounds,
+            size: size.max(UVec2::ONE),
+            boxes,
+            offsets,
+            entries,
+        }
+    }
+
+    fn cells(&self) -> Cells {
+        Cells::new(self.bounds, self.size)
+    }
+
+    fn cell_entries(&self, cell: UVec2) -> &'a [usize] {
+        let c = self.cells().index(cell);
+        &self.entries[self.offsets[c]..self.offsets[c + 1]]
+    }
+
+    /// Indices of the boxes containing the `point`, in ascending order.
+    pub fn query_point(&self, point: Vec2) -> impl Iterator<Item = usize> + use<'a> {
+        let boxes = self.boxes;
+        self.cell_entries(self.cells().cell_of(point))
+            .iter()
+            .copied()
+            .filter(move |&i| boxes[i].contains(point))
+    }
+
+    /// Indices of the boxes intersecting the `region`, each one is reported once.
+    pub fn query_region(&self, region: Aabb) -> impl Iterator<Item = usize> + use<'a> {
+        let (this, cells) = (*self, self.cells());
+        let range = if region.is_empty() {
+            None
+        } else {
+            Some(cells.range(&region))
+        };
+        range
+            .into_iter()
+            .flat_map(Cells::cells)
+            .flat_map(move |cell| {
+                this.cell_entries(cell).iter().copied().filter(move |&i| {
+                    let aabb = this.boxes[i];
+                    // The box is reported only in the first cell shared by it and the region
+                    aabb.intersects(&region)
+                        && cells.cell_of(aabb.min).max(cells.cell_of(region.min)) == cell
+                })
+            })
+    }
+}
+
+/// Grid of about one cell per box.
+#[cfg(feature = "alloc")]
+pub(crate) fn square_size(count: usize) -> UVec2 {
+    let side = (count as f32).sqrt().ceil() as u32;
+    UVec2::splat(side.max(1))
+}
+
+/// Find which of the `shapes` contain which of the `points`.
+///
+/// Yields pairs of the shape and the point indices, ordered by the point index and then by the shape index.
+///
+/// The shapes are indexed by a [`GridIndex`] of about one cell per shape over their bounding boxes,
+/// so each point is checked only against the shapes which boxes share its cell,
+/// instead of checking all `S * P` pairs.
+/// Pairs are computed eagerly before the first one is yielded.
+#[cfg(feature = "alloc")]
+pub fn spatial_join<S: Bounded + Closed>(
+    shapes: &[S],
+    points: &[Vec2],
+) -> impl Iterator<Item = (usize, usize)> {
+    let boxes: Vec<Aabb> = shapes.iter().map(|shape| shape.aabb()).collect();
+    let bounds = boxes.iter().fold(Aabb::EMPTY, |a, b| a.union(*b));
+    let size = square_size(shapes.len());
+    let (offsets_len, entries_len) = GridIndex::storage_len(bounds, size, &boxes);
+    let (mut offsets, mut entries) = (vec![0; offsets_len], vec![0; entries_len]);
+    let mut pairs = Vec::new();
+    // Storage is sized as required, so the index is always built
+    if let Some(index) = GridIndex::new(bounds, size, &boxes, &mut offsets, &mut entries) {
+        for (j, &point) in points.iter().enumerate() {
+            pairs.extend(
+                index
+                    .query_point(point)
+                    .filter(|&i| shapes[i].contains(point))
+                    .map(|i| (i, j)),
+            );
+        }
+    }
+    pairs.into_iter()
+}
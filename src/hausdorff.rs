@@ -0,0 +1,179 @@
+use crate::{CopyIterator, EPS, LineSegment, Polygon, Polyline};
+use glam::Vec2;
+
+#[cfg(feature = "alloc")]
+use crate::{ArcPolygon, ArcVertex, Path};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Largest distance from the points of the curve given by its `vertices` and `edges` to the other curve,
+/// given as the `distance` to it.
+///
+/// Distance to the other curve changes along an edge no faster than the position,
+/// so it can't exceed the mean of the distances at the edge ends by more than half of the edge length.
+/// Edges are bisected until this bound is within the `tolerance` from the largest distance found.
+fn directed<I, J, D>(vertices: I, edges: J, distance: D, tolerance: f32) -> f32
+where
+    I: Iterator<Item = Vec2>,
+    J: Iterator<Item = LineSegment>,
+    D: Fn(Vec2) -> f32,
+{
+    let tolerance = tolerance.max(EPS);
+    let mut largest = vertices.map(&distance).fold(0.0, f32::max);
+    for edge in edges {
+        refine(
+            edge,
+            distance(edge.0),
+            distance(edge.1),
+            &distance,
+            tolerance,
+            &mut largest,
+        );
+    }
+    largest
+}
+
+/// Find the largest distance from the `edge` points with the distances `d0` and `d1` at its ends.
+fn refine<D: Fn(Vec2) -> f32>(
+    edge: LineSegment,
+    d0: f32,
+    d1: f32,
+    distance: &D,
+    tolerance: f32,
+    largest: &mut f32,
+) {
+    let bound = 0.5 * (d0 + d1 + edge.vec().length());
+    if bound <= *largest + tolerance {
+        return;
+    }
+    let middle = edge.center();
+    let d = distance(middle);
+    *largest = largest.max(d);
+    refine(
+        LineSegment(edge.0, middle),
+        d0,
+        d,
+        distance,
+        tolerance,
+        largest,
+    );
+    refine(
+        LineSegment(middle, edge.1),
+        d,
+        d1,
+        distance,
+        tolerance,
+        largest,
+    );
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polyline<V> {
+    /// Directed Hausdorff distance to the `other` polyline,
+    /// i.e. the largest distance from the points of this polyline to the closest points of the `other` one.
+    ///
+    /// The result is less than the exact value by no more than the `tolerance`, which must be positive.
+    /// It is zero if this polyline is empty, and infinite if the `other` one is empty.
+    pub fn directed_hausdorff<W: CopyIterator<Item = Vec2> + ?Sized>(
+        &self,
+        other: &Polyline<W>,
+        tolerance: f32,
+    ) -> f32 {
+        directed(
+            self.vertices(),
+            self.edges(),
+            |p| {
+                other
+                    .closest_point(p)
+                    .map_or(f32::INFINITY, |q| q.distance(p))
+            },
+            tolerance,
+        )
+    }
+
+    /// Hausdorff distance to the `other` polyline, the largest of the directed distances in both directions.
+    ///
+    /// See [`Polyline::directed_hausdorff`] for details.
+    pub fn hausdorff_distance<W: CopyIterator<Item = Vec2> + ?Sized>(
+        &self,
+        other: &Polyline<W>,
+        tolerance: f32,
+    ) -> f32 {
+        self.directed_hausdorff(other, tolerance)
+            .max(other.directed_hausdorff(self, tolerance))
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Directed Hausdorff distance from the boundary of the polygon to the boundary of the `other` one.
+    ///
+    /// Only the boundaries are compared, so the distance to the polygon containing this one is not zero.
+    /// See [`Polyline::directed_hausdorff`] for details.
+    pub fn directed_hausdorff<W: CopyIterator<Item = Vec2> + ?Sized>(
+        &self,
+        other: &Polygon<W>,
+        tolerance: f32,
+    ) -> f32 {
+        directed(
+            self.vertices(),
+            self.edges(),
+            |p| {
+                other
+                    .closest_point(p)
+                    .map_or(f32::INFINITY, |q| q.distance(p))
+            },
+            tolerance,
+        )
+    }
+
+    /// Hausdorff distance between the polygons boundaries, e.g. to measure how well a computed outline fits the reference one.
+    ///
+    /// See [`Polyline::directed_hausdorff`] for details.
+    pub fn hausdorff_distance<W: CopyIterator<Item = Vec2> + ?Sized>(
+        &self,
+        other: &Polygon<W>,
+        tolerance: f32,
+    ) -> f32 {
+        self.directed_hausdorff(other, tolerance)
+            .max(other.directed_hausdorff(self, tolerance))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> ArcPolygon<V> {
+    /// Hausdorff distance between the polygons boundaries.
+    ///
+    /// Arcs are flattened, so the result differs from the exact value by no more than the `tolerance`,
+    /// which must be positive.
+    pub fn hausdorff_distance<W: CopyIterator<Item = ArcVertex> + ?Sized>(
+        &self,
+        other: &ArcPolygon<W>,
+        tolerance: f32,
+    ) -> f32 {
+        // Flattening of each polygon and the search take a third of the tolerance each
+        let step = tolerance / 3.0;
+        let (a, b) = (
+            self.flatten::<Vec<Vec2>>(step),
+            other.flatten::<Vec<Vec2>>(step),
+        );
+        a.hausdorff_distance(&b, step)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Path<V> {
+    /// Hausdorff distance between the paths.
+    ///
+    /// See [`ArcPolygon::hausdorff_distance`] for details.
+    pub fn hausdorff_distance<W: CopyIterator<Item = ArcVertex> + ?Sized>(
+        &self,
+        other: &Path<W>,
+        tolerance: f32,
+    ) -> f32 {
+        let step = tolerance / 3.0;
+        let (a, b) = (
+            self.flatten::<Vec<Vec2>>(step),
+            other.flatten::<Vec<Vec2>>(step),
+        );
+        a.hausdorff_distance(&b, step)
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{CopyIterator, Polygon};
+#[cfg(feature = "alloc")]
+use crate::{CopyRef, LineSegment};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter;
+use glam::Vec2;
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Vertices of the convex hull of the polygon in counterclockwise order.
+    ///
+    /// Vertices lying on the hull edges are skipped.
+    /// Uses gift wrapping algorithm, so it takes `O(n * h)` time and doesn't allocate,
+    /// where `h` is the number of the hull vertices.
+    pub fn convex_hull(&self) -> impl Iterator<Item = Vec2> + '_ {
+        let start = self
+            .vertices()
+            .min_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        let count = self.vertices().count();
+        iter::successors(start, move |&current| {
+            let next = self
+                .vertices()
+                .filter(|&v| v != current)
+                .reduce(|best, v| {
+                    let cross = (best - current).perp_dot(v - current);
+                    // Take the rightmost vertex, or the farthest one if they are collinear
+                    if cross < 0.0
+                        || (cross == 0.0
+                            && (v - current).length_squared() > (best - current).length_squared())
+                    {
+                        v
+                    } else {
+                        best
+                    }
+                })?;
+            if Some(next) == start {
+                None
+            } else {
+                Some(next)
+            }
+        })
+        .take(count)
+    }
+}
+
+/// Counterclockwise convex hull of the `points` sorted lexicographically without duplicates.
+///
+/// Uses Andrew's monotone chain, points lying on the hull edges are skipped.
+#[cfg(feature = "alloc")]
+fn monotone_chain(points: &[Vec2]) -> Vec<Vec2> {
+    // Chain keeps left turns only, its last point is the first one of the next chain
+    fn chain<'a>(hull: &mut Vec<Vec2>, points: impl Iterator<Item = &'a Vec2>) {
+        let start = hull.len();
+        for &p in points {
+            while let [.., a, b] = hull[start..]
+                && (b - a).perp_dot(p - b) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+    }
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut hull = Vec::with_capacity(points.len() + 1);
+    chain(&mut hull, points.iter());
+    chain(&mut hull, points.iter().rev());
+    hull
+}
+
+/// Sort the points lexicographically and remove duplicates.
+#[cfg(feature = "alloc")]
+fn sorted<I: IntoIterator<Item = Vec2>>(points: I) -> Vec<Vec2> {
+    let mut points: Vec<Vec2> = points.into_iter().collect();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    points
+}
+
+/// Check that each of the `points` is a vertex of their convex hull.
+///
+/// Points lying on the hull edges are not in convex position, while duplicates are counted once.
+/// Takes `O(n log n)` time.
+#[cfg(feature = "alloc")]
+pub fn in_convex_position<I: IntoIterator<Item = Vec2>>(points: I) -> bool {
+    let points = sorted(points);
+    monotone_chain(&points).len() == points.len()
+}
+
+/// Peel the `points` into nested convex layers, from the outermost one to the innermost one.
+///
+/// Each layer is the counterclockwise convex hull of the points left after removing the previous layers.
+/// Points lying on the layer edges belong to it but are not its vertices.
+/// The innermost layer consists of one or two vertices if the rest of the points are collinear,
+/// all of them lie on this segment layer.
+/// Each layer takes `O(n log n)` time, so it's `O(n^2 log n)` in the worst case of `O(n)` layers.
+///
+/// The index of the layer containing a point is its convex hull peeling depth.
+#[cfg(feature = "alloc")]
+pub fn convex_layers<I: IntoIterator<Item = Vec2>>(points: I) -> Vec<Polygon<Vec<Vec2>>> {
+    let mut points = sorted(points);
+    let mut layers = Vec::new();
+    while !points.is_empty() {
+        let hull = monotone_chain(&points);
+        // Points lying on the hull boundary are removed too, only strictly inner ones are left
+        let polygon = Polygon::new(hull.to_ref());
+        points.retain(|&p| {
+            hull.len() >= 3
+                && polygon
+                    .edges()
+                    .all(|LineSegment(a, b)| (b - a).perp_dot(p - a) > 0.0)
+        });
+        layers.push(Polygon::new(hull));
+    }
+    layers
+}
+
+/// Convex hull that is updated as the points arrive.
+///
+/// Only the hull vertices are stored, so inner points are discarded and can't be removed later.
+/// Insertion takes `O(h)` time, where `h` is the number of the hull vertices.
+///
+/// Vertices are kept in counterclockwise order without collinear ones.
+/// While all the points lie on a single line the hull consists of the segment ends only.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Default, Debug)]
+pub struct IncrementalHull {
+    vertices: Vec<Vec2>,
+}
+
+#[cfg(feature = "alloc")]
+impl IncrementalHull {
+    /// Create an empty hull.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of the hull vertices.
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Check that no points were inserted.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// Remove all the points.
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+    }
+
+    /// Hull vertices in counterclockwise order.
+    pub fn vertices(&self) -> impl Iterator<Item = Vec2> + '_ {
+        self.vertices.iter().copied()
+    }
+
+    /// View of the hull as a polygon.
+    pub fn as_polygon(&self) -> Polygon<CopyRef<'_, [Vec2]>> {
+        Polygon::new(CopyRef(self.vertices.as_slice()))
+    }
+
+    /// Add the `point` to the hull.
+    ///
+    /// Returns `true` if the hull has changed, and `false` if the point is inside of it or on its boundary.
+    /// A point which can't be placed reliably due to rounding errors, near a nearly degenerate hull, is skipped too.
+    pub fn insert(&mut self, point: Vec2) -> bool {
+        let n = self.vertices.len();
+        match n {
+            0 => {
+                self.vertices.push(point);
+                return true;
+            }
+            1 => {
+                if self.vertices[0] == point {
+                    return false;
+                }
+                self.vertices.push(point);
+                return true;
+            }
+            2 => {
+                let line = LineSegment(self.vertices[0], self.vertices[1]);
+                if line.vec().perp_dot(point - line.0) == 0.0 {
+                    // Keep extreme points of the segment
+                    let t = (point - line.0).dot(line.vec());
+                    if t < 0.0 {
+                        self.vertices[0] = point;
+                    } else if t > line.vec().length_squared() {
+                        self.vertices[1] = point;
+                    } else {
+                        return false;
+                    }
+                    return true;
+                }
+            }
+            _ => (),
+        }
+
+        // Edge `i` goes from vertex `i` to the next one, it is visible if the point is not on its left side
+        let cross = |i: usize| {
+            let (a, b) = (self.vertices[i], self.vertices[(i + 1) % n]);
+            (b - a).perp_dot(point - a)
+        };
+        if (0..n).all(|i| cross(i) >= 0.0) {
+            return false;
+        }
+        // Visible edges form a contiguous chain, find its ends.
+        // Rounding errors may make all the edges of a nearly degenerate hull visible,
+        // the point is almost collinear with the hull then and it is skipped.
+        let Some(first) = (0..n).find(|&i| cross(i) <= 0.0 && cross((i + n - 1) % n) > 0.0) else {
+            return false;
+        };
+        let Some(last) = (0..n)
+            .map(|k| (first + k) % n)
+            .take_while(|&i| cross(i) <= 0.0)
+            .last()
+        else {
+            return false;
+        };
+        // Inner vertices of the chain are replaced by the point
+        let count = (first + n - last - 1) % n + 1;
+        self.vertices.rotate_left((last + 1) % n);
+        self.vertices.truncate(count);
+        self.vertices.push(point);
+        true
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Extend<Vec2> for IncrementalHull {
+    fn extend<I: IntoIterator<Item = Vec2>>(&mut self, iter: I) {
+        for point in iter {
+            self.insert(point);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<Vec2> for IncrementalHull {
+    fn from_iter<I: IntoIterator<Item = Vec2>>(iter: I) -> Self {
+        let mut hull = Self::new();
+        hull.extend(iter);
+        hull
+    }
+}
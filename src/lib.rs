@@ -53,6 +53,13 @@
 //!
 //! - **`approx`** - Enables approximate equality comparisons using the `approx` crate.
 //!   When enabled, geometric types implement `approx::AbsDiffEq` and `approx::RelativeEq`.
+//! - **`alloc`** (default) - Enables operations that need heap allocation, e.g. [`pairwise_intersections`].
+//! - **`telemetry`** - Enables global counters of degenerate cases and clipping results,
+//!   see `telemetry` module.
+//! - **`simd`** - Vectorizes the inner loop of polygon winding number computation,
+//!   processing four edges per iteration.
+//! - **`serde`** - Enables serialization of [`Moment`] and [`MassProperties`] using the `serde` crate.
+//! - **`rand`** - Enables generation of random simple polygons with any `rand` generator, see [`testgen`] module.
 //!
 //! ## Design Philosophy
 //!
@@ -63,33 +70,173 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod aabb;
+#[cfg(feature = "alloc")]
+mod accrete;
 mod arc;
+mod bezier;
+mod blend;
+mod boundary;
+mod bounds;
+#[cfg(feature = "alloc")]
+mod bvh;
+mod capsule;
+mod cast;
 mod circle;
+mod crossing;
+mod csg;
+pub mod debug;
+mod decompose;
+#[cfg(feature = "alloc")]
+mod depth;
+#[cfg(feature = "alloc")]
+mod destruct;
+mod difference;
+mod distance;
+pub mod export;
+mod gradient;
+mod grid;
+mod hausdorff;
+mod hull;
 mod line;
 mod macros;
+#[cfg(feature = "alloc")]
+mod mask;
+mod mass;
 mod meta;
+mod metrics;
+#[cfg(feature = "alloc")]
+mod morphology;
+#[cfg(feature = "alloc")]
+mod nesting;
+mod normalize;
+mod outline;
+mod overlap;
+#[cfg(feature = "alloc")]
+mod pairwise;
+mod path;
 mod plane;
 mod polygon;
+#[cfg(feature = "alloc")]
+mod prepared;
+#[cfg(feature = "alloc")]
+mod profile;
+mod region;
+#[cfg(feature = "alloc")]
+mod rings;
+#[cfg(feature = "alloc")]
+mod scanline;
+#[cfg(feature = "alloc")]
+mod scene;
+mod simplify;
+#[cfg(feature = "alloc")]
+mod skeleton;
+#[cfg(feature = "alloc")]
+mod slab;
+mod slice;
+#[cfg(feature = "alloc")]
+mod store;
+#[cfg(feature = "alloc")]
+mod subtract;
+#[cfg(feature = "alloc")]
+mod sweep;
+mod target;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
+#[cfg(not(feature = "telemetry"))]
+mod telemetry;
+pub mod testgen;
+#[cfg(feature = "alloc")]
+mod toolpath;
+mod transform;
+mod triangle;
+#[cfg(feature = "alloc")]
+mod union;
 mod vertex;
+#[cfg(feature = "alloc")]
+mod visibility;
 
 #[cfg(test)]
 mod tests;
 
 pub(crate) use self::macros::impl_approx_eq;
 pub use self::{
+    aabb::{Aabb, Bounded},
     arc::{Arc, ArcVertex, DiskSegment},
+    bezier::{CubicBezier, QuadBezier},
+    blend::blend,
+    boundary::{Boundary, BoundaryEdge, SampleBoundary, boundary_span},
+    bounds::BoundsAccumulator,
+    capsule::Capsule,
+    cast::{Impact, ShapeCast},
     circle::{Circle, Disk},
+    crossing::{
+        BoundaryCrossingTracker, BoundaryEvent, CrossingReport, Crossings, crossing_parameters,
+        first_exit, stays_inside,
+    },
+    csg::{Complement, Difference, Intersection, Union},
+    decompose::{Decompose, Piece},
+    difference::{
+        difference_profile, intersection_area, intersection_moment, intersection_over_union,
+        overlap_fraction, symmetric_difference_area,
+    },
+    distance::{Clearance, Distance},
+    gradient::AreaGradient,
+    grid::GridIndex,
     line::{Line, LineSegment},
+    mass::{Inertial, MassProperties, MomentAccumulator},
     meta::{Meta, Unmeta},
-    plane::HalfPlane,
+    metrics::{ShapeMetrics, metrics},
+    normalize::normalize,
+    outline::{VertexKind, detect_corners, vectorize},
+    overlap::{CertifyOverlap, OverlapCertificate, Overlaps},
+    path::{GenericPath, circle::Path, line::Polyline},
+    plane::{ConvexRegion, HalfPlane, intersect_half_planes},
     polygon::{
         FramedPolygon, GenericPolygon,
-        circle::{ArcPolygon, MetaArcPolygon},
+        circle::{ArcPolygon, BulgeError, MetaArcPolygon},
+        integer::{IntegerPolygon, IntegerSegment},
         line::{MetaPolygon, Polygon},
     },
+    region::Region,
+    slice::{angular_decompose, slice},
+    target::{ArcTarget, DiskTarget},
+    transform::Transform,
+    triangle::Triangle,
     vertex::{CopyIterator, CopyMap, CopyRef, Edge, Vertex},
 };
 
+#[cfg(feature = "alloc")]
+pub use self::{
+    accrete::AccretedRegion,
+    boundary::boundary_between,
+    bvh::IndexedPolygon,
+    crossing::walk_boundary,
+    depth::{depth_contour, halfspace_depth},
+    destruct::DestructibleRegion,
+    grid::spatial_join,
+    hull::{IncrementalHull, convex_layers, in_convex_position},
+    mask::{BitGrid, CoverageGrid, from_mask, to_coverage, to_mask},
+    morphology::{close, open},
+    nesting::{NestingTree, nesting_tree},
+    pairwise::{components, pairwise_intersections},
+    path::builder::PathBuilder,
+    prepared::PreparedBoundary,
+    profile::{AreaProfile, area_profile, bisect, bisect_two, equal_area_partition},
+    rings::resolve_rings,
+    scene::{FillRule, LayerRole, Scene, SceneBuilder},
+    skeleton::{Offset, OffsetEvent, OffsetOptions, SkeletonNode, StraightSkeleton},
+    slab::SlabIndex,
+    store::{GeometryStore, GeometryStoreBuilder, ShapeId},
+    sweep::segment_intersections,
+    toolpath::{concentric_offsets, zigzag_fill},
+    union::union_outline,
+    visibility::visibility_grid,
+};
+
 use core::f32;
 use either::Either;
 use glam::Vec2;
@@ -155,6 +302,11 @@ pub trait Intersect<T: Intersect<Self, Output = Self::Output> + ?Sized> {
 /// This trait provides a more flexible intersection operation than [`Intersect`],
 /// allowing you to specify the desired output type `U`. This is useful when
 /// multiple possible intersection result types exist (e.g., `Polygon` with different storages).
+///
+/// Intersections bounded by circular arcs can be written to any [`ArcTarget`] (or [`DiskTarget`]
+/// if the result may be a whole disk), e.g. collected into `ArcPolygon` with a storage of choice
+/// or accumulated into [`Moment`] without storing the vertices.
+/// Pairs implementing [`Intersect`] with a single output type also implement this trait for that output.
 pub trait IntersectTo<T: IntersectTo<Self, U> + ?Sized, U> {
     /// Compute the intersection with a specific output type.
     fn intersect_to(&self, other: &T) -> Option<U>;
@@ -168,6 +320,7 @@ impl<U: Intersect<V, Output = W>, V: Intersect<U, Output = W>, W> IntersectTo<V,
 
 /// Moment of the shape
 #[derive(Clone, Copy, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Moment {
     /// Zeroth moment
     pub area: f32,
@@ -191,6 +344,24 @@ impl Moment {
         let centroid = (self.centroid * self.area + other.centroid * other.area) / area;
         Self { area, centroid }
     }
+
+    /// Remove the moment of the `part` from the combined moment.
+    ///
+    /// This is the inverse of [`Moment::merge`], e.g. it cuts a hole out of the shape.
+    /// The difference of areas loses precision when the `part` is almost the whole shape,
+    /// so if the remaining area is near zero compared to the areas involved, returns a default moment.
+    ///
+    /// To add and remove many parts one by one use [`MomentAccumulator`] which doesn't accumulate the rounding errors.
+    pub fn remove(self, part: Self) -> Self {
+        let area = self.area - part.area;
+        // Rounding error of the difference is proportional to the magnitude of the operands
+        let scale = self.area.abs() + part.area.abs();
+        if area.abs() < EPS.max(16.0 * f32::EPSILON * scale) {
+            return Self::default();
+        }
+        let centroid = (self.centroid * self.area - part.centroid * part.area) / area;
+        Self { area, centroid }
+    }
 }
 
 impl_approx_eq!(Moment, f32, area, centroid);
@@ -230,3 +401,15 @@ impl<L: Integrable, R: Integrable> Integrable for Either<L, R> {
         }
     }
 }
+
+impl<T: Closed + ?Sized> Closed for &T {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        (**self).winding_number_2(point)
+    }
+}
+
+impl<T: Integrable + ?Sized> Integrable for &T {
+    fn moment(&self) -> Moment {
+        (**self).moment()
+    }
+}
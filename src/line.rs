@@ -1,5 +1,9 @@
-use crate::{EPS, Edge, Intersect, Vertex, impl_approx_eq};
-use glam::Vec2;
+#[cfg(feature = "alloc")]
+use crate::{Closed, Crossings};
+use crate::{EPS, Edge, Intersect, Vertex, impl_approx_eq, telemetry};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use glam::{Affine2, Mat2, Vec2};
 
 /// Infinite line defined by two points lying on it.
 ///
@@ -45,6 +49,17 @@ impl Line {
         d.perp_dot(r) * d.length_recip()
     }
 
+    /// Affine transformation mirroring points across the line.
+    ///
+    /// Degenerate line gives the point reflection through its first point.
+    pub fn reflection(&self) -> Affine2 {
+        let matrix = match (self.1 - self.0).try_normalize() {
+            Some(d) => Mat2::from_cols(d * d.x, d * d.y) * 2.0 - Mat2::IDENTITY,
+            None => -Mat2::IDENTITY,
+        };
+        Affine2::from_mat2_translation(matrix, self.0 - matrix * self.0)
+    }
+
     /// Check that point is within EPS-neighbourhood of the line.
     pub fn is_near(&self, point: Vec2) -> bool {
         let r = self.1 - self.0;
@@ -58,6 +73,42 @@ impl Line {
         let cross = r.perp_dot(point - self.0);
         cross.abs() < EPS
     }
+
+    /// Line best fitting the `points` in the total least squares sense.
+    ///
+    /// The line minimizes the sum of squared distances from the points to it,
+    /// so it passes through their centroid along the principal axis of their spread.
+    /// The first defining point of the line is the centroid and the second one is a unit step from it,
+    /// the direction sign is arbitrary.
+    ///
+    /// Returns `None` if there are no points or all of them coincide.
+    pub fn fit(points: impl IntoIterator<Item = Vec2>) -> Option<Line> {
+        let mut points = points.into_iter();
+        // Points are shifted to the first one to keep the sums precise
+        let origin = points.next()?;
+        let (mut count, mut sum) = (1.0, Vec2::ZERO);
+        let (mut xx, mut xy, mut yy) = (0.0, 0.0, 0.0);
+        for p in points {
+            let d = p - origin;
+            count += 1.0;
+            sum += d;
+            xx += d.x * d.x;
+            xy += d.x * d.y;
+            yy += d.y * d.y;
+        }
+        let mean = sum / count;
+        let (xx, xy, yy) = (
+            xx / count - mean.x * mean.x,
+            xy / count - mean.x * mean.y,
+            yy / count - mean.y * mean.y,
+        );
+        if xx + yy <= EPS * mean.length_squared().max(1.0) {
+            return None;
+        }
+        let dir = Vec2::from_angle(0.5 * (2.0 * xy).atan2(xx - yy));
+        let center = origin + mean;
+        Some(Line(center, center + dir))
+    }
 }
 
 impl LineSegment {
@@ -93,6 +144,17 @@ impl LineSegment {
         dot >= 0.0 && dot <= r.length_squared()
     }
 
+    /// Get the point of the segment closest to the given `point`.
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let r = self.1 - self.0;
+        let len_sq = r.length_squared();
+        if len_sq < EPS * EPS {
+            return self.0;
+        }
+        let t = ((point - self.0).dot(r) / len_sq).clamp(0.0, 1.0);
+        self.0 + r * t
+    }
+
     /// Checks is a point is within EPS-neighbourhood of the segment
     pub fn is_near(&self, point: Vec2) -> bool {
         let r = self.1 - self.0;
@@ -114,6 +176,54 @@ impl LineSegment {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl LineSegment {
+    /// Parts of the segment lying inside of the `shape`, in the order along the segment.
+    ///
+    /// The segment is cut at the points where it crosses the shape boundary, so the `shape` may be concave.
+    /// Only the first part is tested for containment, then each crossing toggles between inside and outside,
+    /// and the crossings at the same point toggle by the parity of their number, see [`Crossings::crossings`].
+    /// So parts touching each other at the boundary, e.g. at a reflex vertex, are merged.
+    ///
+    /// Takes `O(n + k log k)` time, where `k` is the number of the crossings.
+    pub fn clip<S: Crossings + Closed + ?Sized>(
+        &self,
+        shape: &S,
+    ) -> impl Iterator<Item = LineSegment> + use<S> {
+        let mut params: Vec<f32> = shape.crossings(self).collect();
+        params.sort_by(f32::total_cmp);
+        let mut toggles: Vec<(f32, bool)> = Vec::with_capacity(params.len());
+        for t in params {
+            match toggles.last_mut() {
+                Some((u, toggle)) if t - *u < EPS => *toggle = !*toggle,
+                _ => toggles.push((t, true)),
+            }
+        }
+        // Crossings at the ends don't bound any part
+        let toggles: Vec<f32> = toggles
+            .into_iter()
+            .filter(|&(t, toggle)| toggle && t > EPS && t < 1.0 - EPS)
+            .map(|(t, _)| t)
+            .collect();
+
+        let first = toggles.first().copied().unwrap_or(1.0);
+        let mut inside = shape.contains(self.0.lerp(self.1, 0.5 * first));
+        let mut parts: Vec<LineSegment> = Vec::new();
+        let mut start = 0.0;
+        for end in toggles.into_iter().chain([1.0]) {
+            if inside {
+                parts.push(LineSegment(
+                    self.0.lerp(self.1, start),
+                    self.0.lerp(self.1, end),
+                ));
+            }
+            inside = !inside;
+            start = end;
+        }
+        parts.into_iter()
+    }
+}
+
 impl Edge for LineSegment {
     type Vertex = Vec2;
     fn from_vertices(a: &Self::Vertex, b: &Self::Vertex) -> Self {
@@ -146,6 +256,7 @@ impl Intersect<Line> for Line {
         if den.abs() > EPS {
             Some(Vec2::lerp(self.0, self.1, pqs / den))
         } else {
+            telemetry::degenerate();
             match (r.abs().max_element() > EPS, s.abs().max_element() > EPS) {
                 (true, true) => {
                     // Lines are parallel
@@ -199,6 +310,7 @@ impl Intersect<Line> for LineSegment {
                 None
             }
         } else {
+            telemetry::degenerate();
             match (r.abs().max_element() > EPS, s.abs().max_element() > EPS) {
                 (true, true) => {
                     // Segment line is parallel to the other line
@@ -264,6 +376,7 @@ impl Intersect<LineSegment> for LineSegment {
                 None
             }
         } else {
+            telemetry::degenerate();
             match (r.abs().max_element() > EPS, s.abs().max_element() > EPS) {
                 (true, true) => {
                     // Segments are parallel
@@ -0,0 +1,314 @@
+use crate::{Aabb, Bounded, Closed, Decompose, Polygon, Region, difference::piece_overlap};
+use alloc::{vec, vec::Vec};
+use glam::{IVec2, UVec2, Vec2};
+
+/// Binary raster covering a rectangular area.
+///
+/// Cell `(x, y)` covers the box from `bounds.min + cell_size * (x, y)` to `bounds.min + cell_size * (x + 1, y + 1)`,
+/// so the row `y = 0` is at the bottom.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BitGrid {
+    bounds: Aabb,
+    size: UVec2,
+    bits: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Create a grid of `size` cells covering the `bounds` with all cells unset.
+    pub fn new(bounds: Aabb, size: UVec2) -> Self {
+        let count = size.x as usize * size.y as usize;
+        Self {
+            bounds,
+            size,
+            bits: vec![0; count.div_ceil(64)],
+        }
+    }
+
+    /// Box covered by the grid.
+    pub fn bounds(&self) -> Aabb {
+        self.bounds
+    }
+
+    /// Number of cells along each axis.
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Size of a single cell.
+    pub fn cell_size(&self) -> Vec2 {
+        self.bounds.size() / self.size.as_vec2()
+    }
+
+    /// Center of the cell.
+    pub fn cell_center(&self, cell: UVec2) -> Vec2 {
+        self.bounds.min + self.cell_size() * (cell.as_vec2() + 0.5)
+    }
+
+    fn index(&self, cell: UVec2) -> usize {
+        assert!(cell.cmplt(self.size).all(), "Cell is out of the grid");
+        cell.y as usize * self.size.x as usize + cell.x as usize
+    }
+
+    /// Check that the cell is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is out of the grid.
+    pub fn get(&self, cell: UVec2) -> bool {
+        let i = self.index(cell);
+        self.bits[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    /// Set or unset the cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is out of the grid.
+    pub fn set(&mut self, cell: UVec2, value: bool) {
+        let i = self.index(cell);
+        if value {
+            self.bits[i / 64] |= 1 << (i % 64);
+        } else {
+            self.bits[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    /// Number of set cells.
+    pub fn count(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Total area of the set cells.
+    pub fn area(&self) -> f32 {
+        self.count() as f32 * self.cell_size().element_product()
+    }
+
+    /// Check the cell allowing it to be out of the grid, such cells are unset.
+    fn sample(&self, cell: IVec2) -> bool {
+        cell.cmpge(IVec2::ZERO).all()
+            && cell.as_uvec2().cmplt(self.size).all()
+            && self.get(cell.as_uvec2())
+    }
+}
+
+/// Rasterize the `shape` into the grid of `size` cells covering the `bounds`.
+///
+/// The cell is set if its center is inside the shape.
+pub fn to_mask<S: Closed + ?Sized>(shape: &S, bounds: Aabb, size: UVec2) -> BitGrid {
+    let mut grid = BitGrid::new(bounds, size);
+    for y in 0..size.y {
+        for x in 0..size.x {
+            let cell = UVec2::new(x, y);
+            grid.set(cell, shape.contains(grid.cell_center(cell)));
+        }
+    }
+    grid
+}
+
+/// Vectorize the set cells of the `grid` into a region.
+///
+/// Contours are traced by marching squares through the cell centers, so the boundary crosses the midpoints
+/// between set and unset cells, and then simplified with the `tolerance` by [`Polygon::simplify`].
+/// Diagonally adjacent set cells are connected.
+///
+/// Outer contours are counterclockwise and holes are clockwise, so the region is consistent with [`to_mask`]:
+/// rasterizing it back at the same resolution gives the same grid if the `tolerance` is less than a half of the cell.
+pub fn from_mask(grid: &BitGrid, tolerance: f32) -> Region<Vec<Polygon<Vec<Vec2>>>> {
+    // Marching squares go between the cell centers including the empty border around the grid
+    let (w, h) = (grid.size.x as i32 + 1, grid.size.y as i32 + 1);
+    // Crossing points lie on the edges between neighbour cell centers,
+    // horizontal edges go first and then the vertical ones
+    let horizontal = |x: i32, y: i32| (y * w + x) as usize;
+    let vertical = |x: i32, y: i32| ((w * (h + 1)) + y * (w + 1) + x) as usize;
+    let count = vertical(0, h);
+    let point = |id: usize| {
+        let (offset, along) = if id < vertical(0, 0) {
+            let id = id as i32;
+            (IVec2::new(id % w, id / w).as_vec2(), Vec2::new(0.5, 0.0))
+        } else {
+            let id = (id - vertical(0, 0)) as i32;
+            (
+                IVec2::new(id % (w + 1), id / (w + 1)).as_vec2(),
+                Vec2::new(0.0, 0.5),
+            )
+        };
+        // Offset is counted from the cell to the left and below the grid
+        grid.bounds.min + grid.cell_size() * (offset + along - 0.5)
+    };
+
+    // Each crossing point starts exactly one segment
+    let mut next = vec![usize::MAX; count];
+    for y in 0..h {
+        for x in 0..w {
+            let corner = |dx: i32, dy: i32| grid.sample(IVec2::new(x + dx - 1, y + dy - 1));
+            // Square sides in counterclockwise order with their ends
+            let sides = [
+                (horizontal(x, y), corner(0, 0), corner(1, 0)),
+                (vertical(x + 1, y), corner(1, 0), corner(1, 1)),
+                (horizontal(x, y + 1), corner(1, 1), corner(0, 1)),
+                (vertical(x, y), corner(0, 1), corner(0, 0)),
+            ];
+            // Boundary goes from the side where we leave the shape to the next side where we enter it,
+            // so the shape is on its left
+            for k in 0..4 {
+                let (exit, inside, outside) = sides[k];
+                if inside && !outside {
+                    let entry = (1..4)
+                        .map(|l| sides[(k + l) % 4])
+                        .find(|&(_, a, b)| !a && b)
+                        .map(|(id, ..)| id)
+                        .unwrap();
+                    next[exit] = entry;
+                }
+            }
+        }
+    }
+
+    let mut contours = Vec::new();
+    for start in 0..count {
+        if next[start] == usize::MAX {
+            continue;
+        }
+        let mut vertices = Vec::new();
+        let mut id = start;
+        while next[id] != usize::MAX {
+            vertices.push(point(id));
+            id = core::mem::replace(&mut next[id], usize::MAX);
+        }
+        contours.push(Polygon::new(vertices).simplify(tolerance));
+    }
+    Region::new(contours)
+}
+
+/// Raster of the fractions of the cells covered by a shape.
+///
+/// Cells are laid out the same way as in [`BitGrid`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct CoverageGrid {
+    bounds: Aabb,
+    size: UVec2,
+    values: Vec<f32>,
+}
+
+impl CoverageGrid {
+    /// Create a grid of `size` cells covering the `bounds` with all cells uncovered.
+    pub fn new(bounds: Aabb, size: UVec2) -> Self {
+        Self {
+            bounds,
+            size,
+            values: vec![0.0; size.x as usize * size.y as usize],
+        }
+    }
+
+    /// Box covered by the grid.
+    pub fn bounds(&self) -> Aabb {
+        self.bounds
+    }
+
+    /// Number of cells along each axis.
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Size of a single cell.
+    pub fn cell_size(&self) -> Vec2 {
+        self.bounds.size() / self.size.as_vec2()
+    }
+
+    /// Center of the cell.
+    pub fn cell_center(&self, cell: UVec2) -> Vec2 {
+        self.bounds.min + self.cell_size() * (cell.as_vec2() + 0.5)
+    }
+
+    fn cell_box(&self, cell: UVec2) -> Aabb {
+        let min = self.bounds.min + self.cell_size() * cell.as_vec2();
+        Aabb::new(min, min + self.cell_size())
+    }
+
+    fn index(&self, cell: UVec2) -> usize {
+        assert!(cell.cmplt(self.size).all(), "Cell is out of the grid");
+        cell.y as usize * self.size.x as usize + cell.x as usize
+    }
+
+    /// Covered fraction of the cell, from `0` to `1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is out of the grid.
+    pub fn get(&self, cell: UVec2) -> f32 {
+        self.values[self.index(cell)]
+    }
+
+    /// Set the covered fraction of the cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is out of the grid.
+    pub fn set(&mut self, cell: UVec2, value: f32) {
+        let i = self.index(cell);
+        self.values[i] = value;
+    }
+
+    /// Covered fractions of all cells row by row, starting from the bottom one.
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Total covered area.
+    pub fn area(&self) -> f32 {
+        self.values.iter().sum::<f32>() * self.cell_size().element_product()
+    }
+}
+
+/// Rasterize the `shape` into the grid of `size` cells covering the `bounds` with antialiasing.
+///
+/// The value of the cell is the exact area of the intersection of the shape with the cell box divided by the cell area,
+/// so the total area of the grid equals the area of the part of the shape inside of the `bounds`.
+/// Shape must be counterclockwise.
+///
+/// Each piece of the shape is clipped only by the cells its bounding box overlaps,
+/// so the time is proportional to the total number of such cells rather than to the number of pieces times the number of cells.
+pub fn to_coverage<S: Decompose + ?Sized>(shape: &S, bounds: Aabb, size: UVec2) -> CoverageGrid {
+    let mut grid = CoverageGrid::new(bounds, size);
+    if grid.values.is_empty() {
+        return grid;
+    }
+    let cell_size = grid.cell_size();
+    let last = size.as_ivec2() - 1;
+    let cell_of = |point: Vec2| {
+        ((point - bounds.min) / cell_size)
+            .floor()
+            .as_ivec2()
+            .clamp(IVec2::ZERO, last)
+    };
+
+    for piece in shape.pieces() {
+        let aabb = piece.aabb();
+        if !aabb.intersects(&bounds) {
+            continue;
+        }
+        let (min, max) = (cell_of(aabb.min), cell_of(aabb.max));
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let cell = UVec2::new(x as u32, y as u32);
+                let area: f32 = grid
+                    .cell_box(cell)
+                    .pieces()
+                    .map(|q| piece_overlap(&piece, &q).area)
+                    .sum();
+                let i = grid.index(cell);
+                grid.values[i] += area;
+            }
+        }
+    }
+
+    let cell_area = cell_size.element_product();
+    for value in &mut grid.values {
+        // Overlaps of the pieces cancel each other only up to rounding errors
+        *value = (*value / cell_area).clamp(0.0, 1.0);
+    }
+    grid
+}
@@ -0,0 +1,337 @@
+use crate::{
+    ArcPolygon, ArcVertex, CopyIterator, Disk, DiskSegment, EPS, FramedPolygon, Integrable,
+    LineSegment, Moment, Polygon, arc::APPROX_CIRCLE, impl_approx_eq,
+};
+use core::f32::consts::PI;
+use glam::{DVec2, Vec2};
+
+/// Area, centroid and second moments of area of the shape.
+///
+/// Describes the shape as a rigid body of unit density.
+/// Second moments are taken about the axes going through the centroid parallel to the coordinate axes.
+///
+/// Like [`Moment`] the properties are signed, clockwise shapes have negative area and second moments,
+/// so that merging them subtracts a hole from the enclosing shape.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MassProperties {
+    /// Zeroth moment.
+    pub area: f32,
+    /// First moment divided by the area.
+    pub centroid: Vec2,
+    /// Second moment about the horizontal axis, the integral of `(y - centroid.y)^2`.
+    pub ixx: f32,
+    /// Second moment about the vertical axis, the integral of `(x - centroid.x)^2`.
+    pub iyy: f32,
+    /// Product of inertia, the integral of `(x - centroid.x) * (y - centroid.y)`.
+    pub ixy: f32,
+}
+
+impl MassProperties {
+    /// Properties of the shape with the given area and centroid, and second moments about the `point`.
+    fn from_second_moments(
+        area: f32,
+        centroid: Vec2,
+        point: Vec2,
+        second: (f32, f32, f32),
+    ) -> Self {
+        // Parallel axis theorem
+        let d = centroid - point;
+        Self {
+            area,
+            centroid,
+            ixx: second.0 - area * d.y * d.y,
+            iyy: second.1 - area * d.x * d.x,
+            ixy: second.2 - area * d.x * d.y,
+        }
+    }
+
+    /// Area and centroid only.
+    pub fn moment(&self) -> Moment {
+        Moment {
+            area: self.area,
+            centroid: self.centroid,
+        }
+    }
+
+    /// Polar moment of inertia about the centroid.
+    pub fn polar(&self) -> f32 {
+        self.ixx + self.iyy
+    }
+
+    /// Distance from the centroid at which the whole area would have the same polar moment.
+    ///
+    /// Returns zero for shapes of zero area.
+    pub fn radius_of_gyration(&self) -> f32 {
+        if self.area.abs() < EPS {
+            0.0
+        } else {
+            (self.polar() / self.area).max(0.0).sqrt()
+        }
+    }
+
+    /// Properties of the shape with the opposite orientation.
+    pub fn reverse(self) -> Self {
+        Self {
+            area: -self.area,
+            centroid: self.centroid,
+            ixx: -self.ixx,
+            iyy: -self.iyy,
+            ixy: -self.ixy,
+        }
+    }
+
+    /// Combine properties of two non-overlapping shapes into the properties of their union.
+    ///
+    /// Like [`Moment::merge`] returns default properties if the combined area is near zero.
+    pub fn merge(self, other: Self) -> Self {
+        let moment = self.moment().merge(other.moment());
+        if moment.area == 0.0 {
+            return Self::default();
+        }
+        let shift = |part: &Self| {
+            let d = part.centroid - moment.centroid;
+            (
+                part.ixx + part.area * d.y * d.y,
+                part.iyy + part.area * d.x * d.x,
+                part.ixy + part.area * d.x * d.y,
+            )
+        };
+        let (a, b) = (shift(&self), shift(&other));
+        Self {
+            area: moment.area,
+            centroid: moment.centroid,
+            ixx: a.0 + b.0,
+            iyy: a.1 + b.1,
+            ixy: a.2 + b.2,
+        }
+    }
+
+    /// Remove the contribution of the shape `part` lying inside of this one.
+    ///
+    /// This is the inverse of [`MassProperties::merge`], so `a.merge(b).remove(b)` gives `a` up to the rounding errors.
+    pub fn remove(self, part: Self) -> Self {
+        self.merge(part.reverse())
+    }
+}
+
+impl From<MassProperties> for Moment {
+    fn from(properties: MassProperties) -> Self {
+        properties.moment()
+    }
+}
+
+impl_approx_eq!(MassProperties, f32, area, centroid, ixx, iyy, ixy);
+
+/// Moment of a composite shape which parts are added and removed over time.
+///
+/// Keeps the sums of areas and first moments of the parts in double precision,
+/// so the composite moment is updated in constant time and the rounding errors don't pile up
+/// over many additions and removals, e.g. as the destructible terrain gets damaged.
+///
+/// The parts must not overlap. Removing a part that wasn't added cuts a hole.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct MomentAccumulator {
+    area: f64,
+    first: DVec2,
+    /// Number of added parts minus the number of removed ones.
+    count: isize,
+}
+
+impl MomentAccumulator {
+    /// Create accumulator with no parts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check that every added part was removed, or nothing was added.
+    ///
+    /// Only the parts are counted, so a part with a hole cut in it is empty too, while its moment is not.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    fn update(&mut self, moment: Moment, count: isize) {
+        self.count += count;
+        let area = count as f64 * moment.area as f64;
+        self.area += area;
+        self.first += moment.centroid.as_dvec2() * area;
+        self.settle();
+    }
+
+    /// Get rid of the remaining rounding errors when all parts are removed.
+    ///
+    /// Parts may balance each other in count but not in area, e.g. a body with a hole cut in it,
+    /// so the count alone doesn't mean that nothing is left.
+    fn settle(&mut self) {
+        if self.count == 0 && self.area.abs() < EPS as f64 {
+            *self = Self::default();
+        }
+    }
+
+    /// Add the `moment` of a part.
+    pub fn add(&mut self, moment: Moment) {
+        self.update(moment, 1);
+    }
+
+    /// Remove the `moment` of a part, the inverse of [`MomentAccumulator::add`].
+    pub fn remove(&mut self, moment: Moment) {
+        self.update(moment, -1);
+    }
+
+    /// Add the `shape` as a part.
+    pub fn add_shape<S: Integrable + ?Sized>(&mut self, shape: &S) {
+        self.add(shape.moment());
+    }
+
+    /// Remove the `shape` added as a part before.
+    pub fn remove_shape<S: Integrable + ?Sized>(&mut self, shape: &S) {
+        self.remove(shape.moment());
+    }
+
+    /// Combine parts of two accumulators.
+    pub fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+        self.area += other.area;
+        self.first += other.first;
+        self.settle();
+    }
+
+    /// Moment of the composite shape.
+    ///
+    /// Like [`Moment::merge`] returns a default moment if the area is near zero.
+    pub fn moment(&self) -> Moment {
+        if self.area.abs() < EPS as f64 {
+            return Moment::default();
+        }
+        Moment {
+            area: self.area as f32,
+            centroid: (self.first / self.area).as_vec2(),
+        }
+    }
+}
+
+impl Extend<Moment> for MomentAccumulator {
+    fn extend<I: IntoIterator<Item = Moment>>(&mut self, iter: I) {
+        for moment in iter {
+            self.add(moment);
+        }
+    }
+}
+
+impl FromIterator<Moment> for MomentAccumulator {
+    fn from_iter<I: IntoIterator<Item = Moment>>(iter: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(iter);
+        accumulator
+    }
+}
+
+/// A shape that has computable second moments of area.
+pub trait Inertial: Integrable {
+    /// Compute the mass properties of the shape.
+    fn mass_properties(&self) -> MassProperties;
+}
+
+impl Inertial for Disk {
+    fn mass_properties(&self) -> MassProperties {
+        let i = 0.25 * PI * self.radius.powi(4);
+        MassProperties {
+            area: self.area(),
+            centroid: self.center,
+            ixx: i,
+            iyy: i,
+            ixy: 0.0,
+        }
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Inertial for Polygon<V> {
+    /// Properties of the polygon preserving its orientation, see [`Polygon::signed_moment`].
+    fn mass_properties(&self) -> MassProperties {
+        // Vertices are taken relative to the first one to reduce rounding errors for polygons far from the origin
+        let origin = match self.vertices().next() {
+            Some(point) => point,
+            None => return MassProperties::default(),
+        };
+        let mut area = 0.0;
+        let mut first = Vec2::ZERO;
+        let (mut xx, mut yy, mut xy) = (0.0, 0.0, 0.0);
+        for LineSegment(a, b) in self.edges() {
+            let (a, b) = (a - origin, b - origin);
+            let cross = a.perp_dot(b);
+            area += cross;
+            first += (a + b) * cross;
+            xx += cross * (a.y * a.y + a.y * b.y + b.y * b.y);
+            yy += cross * (a.x * a.x + a.x * b.x + b.x * b.x);
+            xy += cross * (a.x * b.y + 2.0 * a.x * a.y + 2.0 * b.x * b.y + b.x * a.y);
+        }
+        area *= 0.5;
+        if area.abs() < EPS {
+            return MassProperties::default();
+        }
+        MassProperties::from_second_moments(
+            area,
+            origin + first / (6.0 * area),
+            origin,
+            (xx / 12.0, yy / 12.0, xy / 24.0),
+        )
+    }
+}
+
+impl Inertial for DiskSegment {
+    fn mass_properties(&self) -> MassProperties {
+        let moment = self.moment();
+        let (a, b) = self.0.points;
+        let s = self.0.sagitta.abs();
+        if s < EPS {
+            return MassProperties {
+                centroid: moment.centroid,
+                ..Default::default()
+            };
+        }
+        let h = 0.5 * (b - a).length();
+        let radius = (h.powi(2) + s.powi(2)) / (2.0 * s);
+        let sign = self.0.sagitta.signum();
+        // Axis going from the chord to the arc and the axis along the chord
+        let u = -(b - a).perp() / (2.0 * h) * sign;
+        let v = u.perp();
+
+        // Second moments about the centroid along the axes
+        let (uu, vv) = if s > APPROX_CIRCLE * radius {
+            let r4 = radius.powi(4);
+            let half = (h / radius).asin();
+            let half = if s > radius { PI - half } else { half };
+            let (sine, cosine) = half.sin_cos();
+            // Sector minus the triangle with the apex at the circle center, about the center
+            let triangle = radius.powi(2) * sine * cosine;
+            let uu =
+                0.25 * r4 * (half + sine * cosine) - 0.5 * triangle * (radius * cosine).powi(2);
+            let vv = 0.25 * r4 * (half - sine * cosine) - triangle * (radius * sine).powi(2) / 6.0;
+            let area = radius.powi(2) * half - triangle;
+            let center = 0.5 * (a + b) + u * (s - radius);
+            let offset = (moment.centroid - center).dot(u);
+            (uu - area * offset * offset, vv)
+        } else {
+            // Approximate circle by parabola
+            ((16.0 / 175.0) * s.powi(3) * h, (4.0 / 15.0) * s * h.powi(3))
+        };
+        MassProperties {
+            area: moment.area,
+            centroid: moment.centroid,
+            ixx: sign * (uu * u.y * u.y + vv * v.y * v.y),
+            iyy: sign * (uu * u.x * u.x + vv * v.x * v.x),
+            ixy: sign * (uu * u.x * u.y + vv * v.x * v.y),
+        }
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Inertial for ArcPolygon<V> {
+    /// Properties of the polygon preserving its orientation, see [`ArcPolygon::signed_moment`].
+    fn mass_properties(&self) -> MassProperties {
+        self.edges()
+            .fold(self.frame().mass_properties(), |properties, arc| {
+                properties.merge(DiskSegment(arc).mass_properties())
+            })
+    }
+}
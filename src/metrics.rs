@@ -0,0 +1,91 @@
+use crate::{CopyIterator, EPS, Integrable, LineSegment, Polygon, impl_approx_eq};
+use core::{f32::consts::PI, iter};
+use glam::Vec2;
+
+/// Standard dimensionless shape descriptors.
+///
+/// All values are in `[0, 1]` range and are invariant to translation, rotation and scaling.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ShapeMetrics {
+    /// Isoperimetric quotient `4πA / P²`, which is `1` for a disk.
+    pub compactness: f32,
+    /// Ratio of the convex hull perimeter to the shape perimeter.
+    pub convexity: f32,
+    /// Ratio of the shape area to the convex hull area.
+    pub solidity: f32,
+    /// Eccentricity of the ellipse with the same second moments, which is `0` for a disk or a square.
+    pub eccentricity: f32,
+    /// Ratio of the shape area to the area of the minimal bounding rectangle.
+    pub rectangularity: f32,
+}
+
+impl_approx_eq!(
+    ShapeMetrics,
+    f32,
+    compactness,
+    convexity,
+    solidity,
+    eccentricity,
+    rectangularity
+);
+
+/// Compute shape metrics of the polygon.
+///
+/// Orientation of the polygon doesn't matter.
+/// Returns `None` if the polygon has zero area.
+pub fn metrics<V: CopyIterator<Item = Vec2> + ?Sized>(
+    polygon: &Polygon<V>,
+) -> Option<ShapeMetrics> {
+    let signed_area = polygon.area();
+    let area = signed_area.abs();
+    if area < EPS {
+        return None;
+    }
+    let perimeter = polygon.perimeter();
+
+    let (mut hull_area, mut hull_perimeter) = (0.0, 0.0);
+    let mut rect_area = f32::INFINITY;
+    let mut hull = polygon.convex_hull();
+    let first = hull.next()?;
+    let mut prev = first;
+    for next in hull.chain(iter::once(first)) {
+        let edge = LineSegment(prev, next);
+        prev = next;
+        hull_area += 0.5 * edge.0.perp_dot(edge.1);
+        hull_perimeter += edge.vec().length();
+        rect_area = rect_area.min(bounding_rect_area(polygon, edge));
+    }
+
+    let inertia = polygon.second_moment() / signed_area;
+    let mean = 0.5 * (inertia.x_axis.x + inertia.y_axis.y);
+    let dev =
+        (0.25 * (inertia.x_axis.x - inertia.y_axis.y).powi(2) + inertia.x_axis.y.powi(2)).sqrt();
+    let (major, minor) = (mean + dev, (mean - dev).max(0.0));
+
+    Some(ShapeMetrics {
+        compactness: 4.0 * PI * area / perimeter.powi(2),
+        convexity: hull_perimeter / perimeter,
+        solidity: area / hull_area,
+        eccentricity: (1.0 - minor / major).max(0.0).sqrt(),
+        rectangularity: area / rect_area,
+    })
+}
+
+/// Area of the polygon bounding rectangle aligned with the `edge`.
+fn bounding_rect_area<V: CopyIterator<Item = Vec2> + ?Sized>(
+    polygon: &Polygon<V>,
+    edge: LineSegment,
+) -> f32 {
+    let dir = match edge.vec().try_normalize() {
+        Some(dir) => dir,
+        None => return f32::INFINITY,
+    };
+    let (min, max) =
+        polygon
+            .vertices()
+            .fold((Vec2::INFINITY, Vec2::NEG_INFINITY), |(min, max), v| {
+                let p = Vec2::new(v.dot(dir), v.perp_dot(dir));
+                (min.min(p), max.max(p))
+            });
+    (max - min).element_product()
+}
@@ -0,0 +1,106 @@
+use crate::{ArcPolygon, ArcVertex, CopyIterator, OffsetOptions, Polygon, Region};
+use alloc::vec::Vec;
+use glam::Vec2;
+
+/// Replace the corners of the `contour` turning to the side given by `convex` by the arcs of the `radius`.
+///
+/// The arc is tangent to both edges of the corner. If the edges are too short,
+/// the arc radius is reduced so that the arc takes at most a half of each edge.
+fn round_corners(
+    contour: &Polygon<Vec<Vec2>>,
+    radius: f32,
+    convex: bool,
+) -> ArcPolygon<Vec<ArcVertex>> {
+    let points = &contour.vertices;
+    let n = points.len();
+    let mut vertices = Vec::with_capacity(2 * n);
+    for (i, &point) in points.iter().enumerate() {
+        let (prev, next) = (points[(i + n - 1) % n], points[(i + 1) % n]);
+        let (a, b) = (point - prev, next - point);
+        // Turn angle is positive for the left turns, which are the convex corners of a region
+        let turn = a.perp_dot(b).atan2(a.dot(b));
+        if radius <= 0.0 || turn.abs() < 1e-6 || (turn > 0.0) != convex {
+            vertices.push(ArcVertex {
+                point,
+                sagitta: 0.0,
+            });
+            continue;
+        }
+        let half = 0.5 * turn.abs();
+        let length = (radius * half.tan())
+            .min(0.5 * a.length())
+            .min(0.5 * b.length());
+        let radius = length / half.tan();
+        vertices.push(ArcVertex {
+            point: point - a.normalize() * length,
+            sagitta: turn.signum() * radius * (1.0 - half.cos()),
+        });
+        vertices.push(ArcVertex {
+            point: point + b.normalize() * length,
+            sagitta: 0.0,
+        });
+    }
+    ArcPolygon::new(vertices)
+}
+
+/// Round the corners of the region contours, see [`round_corners`].
+fn round_region(
+    region: Region<Vec<Polygon<Vec<Vec2>>>>,
+    radius: f32,
+    convex: bool,
+) -> Region<Vec<ArcPolygon<Vec<ArcVertex>>>> {
+    Region::new(
+        region
+            .components()
+            .map(|contour| round_corners(contour, radius, convex))
+            .collect(),
+    )
+}
+
+/// Morphological closing of the `region` by the disk of the `radius`, i.e. its dilation followed by the erosion.
+///
+/// Closing fills the gaps, holes and concave corners where the disk rolling outside of the region can't get in,
+/// so narrow gaps are bridged and concave corners are rounded by the arcs of the `radius`, while the rest of the boundary is kept.
+///
+/// Both offsets are done by the straight skeleton, see [`Region::offset`], and then the concave corners are replaced by the arcs.
+/// The result is exact while the corners of the intermediate offset don't affect its topology,
+/// otherwise where the dilated contours merge near their corners the gap is bridged flat, so the result is slightly larger.
+///
+/// Contours must not intersect, components must be counterclockwise and holes clockwise.
+pub fn close<C: ?Sized, V: CopyIterator<Item = Vec2> + ?Sized>(
+    region: &Region<C>,
+    radius: f32,
+) -> Region<Vec<ArcPolygon<Vec<ArcVertex>>>>
+where
+    for<'a> &'a C: IntoIterator<Item = &'a Polygon<V>>,
+{
+    let dilated = region.offset(radius.max(0.0), OffsetOptions::default());
+    let closed = dilated
+        .region
+        .offset(-radius.max(0.0), OffsetOptions::default());
+    round_region(closed.region, radius, false)
+}
+
+/// Morphological opening of the `region` by the disk of the `radius`, i.e. its erosion followed by the dilation.
+///
+/// Opening keeps the points which can be covered by the disk lying inside of the region,
+/// so the parts thinner than the disk are removed and convex corners are rounded by the arcs of the `radius`.
+///
+/// Both offsets are done by the straight skeleton, see [`Region::offset`], and then the convex corners are replaced by the arcs.
+/// The erosion by the straight skeleton differs from the exact one only at concave corners, which are restored by the dilation,
+/// so the result is exact while the corners of the dilation don't affect its topology.
+///
+/// Contours must not intersect, components must be counterclockwise and holes clockwise.
+pub fn open<C: ?Sized, V: CopyIterator<Item = Vec2> + ?Sized>(
+    region: &Region<C>,
+    radius: f32,
+) -> Region<Vec<ArcPolygon<Vec<ArcVertex>>>>
+where
+    for<'a> &'a C: IntoIterator<Item = &'a Polygon<V>>,
+{
+    let eroded = region.offset(-radius.max(0.0), OffsetOptions::default());
+    let opened = eroded
+        .region
+        .offset(radius.max(0.0), OffsetOptions::default());
+    round_region(opened.region, radius, true)
+}
@@ -0,0 +1,125 @@
+use crate::{Aabb, Closed, CopyIterator, EPS, GridIndex, Polygon, grid::square_size};
+use alloc::{vec, vec::Vec};
+use glam::Vec2;
+
+/// Containment hierarchy of non-crossing rings, e.g. the contours of a multi-ring SVG path or WKT polygon.
+///
+/// Each ring refers to the smallest ring containing it.
+/// Rings of even depth are outer boundaries and rings of odd depth are holes in their parents.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct NestingTree {
+    parents: Vec<Option<usize>>,
+    depths: Vec<usize>,
+}
+
+impl NestingTree {
+    /// Number of the rings.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Check that there are no rings.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Index of the smallest ring containing the ring `i`, `None` for the outermost rings.
+    pub fn parent(&self, i: usize) -> Option<usize> {
+        self.parents[i]
+    }
+
+    /// Number of the rings containing the ring `i`.
+    pub fn depth(&self, i: usize) -> usize {
+        self.depths[i]
+    }
+
+    /// Check that the ring `i` is a hole, i.e. it is inside of an odd number of rings.
+    pub fn is_hole(&self, i: usize) -> bool {
+        self.depths[i] % 2 == 1
+    }
+
+    /// Rings not contained in any other ring, in ascending order.
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.children_of(None)
+    }
+
+    /// Rings which parent is the ring `i`, in ascending order.
+    pub fn children(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        self.children_of(Some(i))
+    }
+
+    fn children_of(&self, parent: Option<usize>) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(move |&j| self.parents[j] == parent)
+    }
+
+    /// Reverse the `polygons` the tree was built of, so that the outer boundaries are counterclockwise
+    /// and the holes are clockwise, as [`Region`](crate::Region) expects.
+    pub fn orient<V: CopyIterator<Item = Vec2> + AsMut<[Vec2]>>(
+        &self,
+        polygons: &mut [Polygon<V>],
+    ) {
+        for (i, polygon) in polygons.iter_mut().enumerate() {
+            if polygon.is_ccw() == self.is_hole(i) {
+                polygon.reverse();
+            }
+        }
+    }
+}
+
+/// Find which of the `polygons` are inside of which.
+///
+/// Polygons must be simple and must not cross each other, but they may touch at vertices,
+/// and their orientations don't matter.
+///
+/// Polygons are indexed by a [`GridIndex`] over their bounding boxes,
+/// so each polygon is checked only against the larger polygons which boxes contain its box.
+/// A polygon is inside of the other one if its vertex not lying on the other polygon boundary is inside of it.
+pub fn nesting_tree<V: CopyIterator<Item = Vec2>>(polygons: &[Polygon<V>]) -> NestingTree {
+    let n = polygons.len();
+    let boxes: Vec<Aabb> = polygons
+        .iter()
+        .map(|polygon| Aabb::from_points(polygon.vertices()))
+        .collect();
+    let areas: Vec<f32> = polygons
+        .iter()
+        .map(|polygon| polygon.signed_area().abs())
+        .collect();
+    let contains = |j: usize, i: usize| {
+        let other = &polygons[j];
+        polygons[i]
+            .vertices()
+            .find(|&p| other.closest_point(p).is_none_or(|q| q.distance(p) >= EPS))
+            .is_some_and(|p| other.winding_number_2(p) != 0)
+    };
+
+    let bounds = boxes.iter().fold(Aabb::EMPTY, |a, b| a.union(*b));
+    let size = square_size(n);
+    let (offsets_len, entries_len) = GridIndex::storage_len(bounds, size, &boxes);
+    let (mut offsets, mut entries) = (vec![0; offsets_len], vec![0; entries_len]);
+    let mut parents = vec![None; n];
+    // Storage is sized as required, so the index is always built
+    if let Some(index) = GridIndex::new(bounds, size, &boxes, &mut offsets, &mut entries) {
+        for (i, aabb) in boxes.iter().enumerate() {
+            parents[i] = index
+                .query_region(*aabb)
+                .filter(|&j| {
+                    j != i
+                        && areas[j] > areas[i]
+                        && boxes[j].union(*aabb) == boxes[j]
+                        && contains(j, i)
+                })
+                .min_by(|&a, &b| areas[a].total_cmp(&areas[b]));
+        }
+    }
+
+    // Parents are larger, so they get their depths first
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| areas[b].total_cmp(&areas[a]));
+    let mut depths = vec![0; n];
+    for i in order {
+        if let Some(parent) = parents[i] {
+            depths[i] = depths[parent] + 1;
+        }
+    }
+    NestingTree { parents, depths }
+}
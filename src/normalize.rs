@@ -0,0 +1,57 @@
+use crate::{CopyIterator, EPS, LineSegment, Moment, Polygon};
+use core::f32::consts::PI;
+use glam::{Affine2, Vec2};
+
+/// Map the polygon to the canonical frame.
+///
+/// In the canonical frame the polygon centroid is at the origin,
+/// its RMS radius (square root of the polar second moment divided by area) is `1`,
+/// and its principal axis of the largest spread is aligned with the X axis.
+/// The direction of the axis is chosen so that the third moment along it is non-negative.
+///
+/// Returns the normalized polygon and the transform from the original frame to the canonical one.
+/// Returns `None` if the polygon has zero area.
+pub fn normalize<
+    V: CopyIterator<Item = Vec2> + ?Sized,
+    W: CopyIterator<Item = Vec2> + FromIterator<Vec2>,
+>(
+    polygon: &Polygon<V>,
+) -> Option<(Polygon<W>, Affine2)> {
+    let Moment { area, centroid } = polygon.signed_moment();
+    if area.abs() < EPS {
+        return None;
+    }
+    let inertia = polygon.second_moment() / area;
+    let rms_radius = (inertia.x_axis.x + inertia.y_axis.y).sqrt();
+    if rms_radius < EPS {
+        return None;
+    }
+    let mut angle = 0.5 * (2.0 * inertia.x_axis.y).atan2(inertia.x_axis.x - inertia.y_axis.y);
+
+    let frame = |angle: f32| {
+        Affine2::from_scale(Vec2::splat(1.0 / rms_radius))
+            * Affine2::from_angle(-angle)
+            * Affine2::from_translation(-centroid)
+    };
+    // Flip by rotation rather than reflection to preserve orientation
+    let rotated = frame(angle);
+    let third: f32 = polygon
+        .map_vertices(move |v| rotated.transform_point2(v))
+        .edges()
+        .map(|LineSegment(a, b)| {
+            a.perp_dot(b) * (a.x.powi(3) + a.x.powi(2) * b.x + a.x * b.x.powi(2) + b.x.powi(3))
+        })
+        .sum();
+    if third * area.signum() < 0.0 {
+        angle += PI;
+    }
+
+    let transform = frame(angle);
+    Some((
+        polygon
+            .vertices()
+            .map(|v| transform.transform_point2(v))
+            .collect(),
+        transform,
+    ))
+}
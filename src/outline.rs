@@ -0,0 +1,204 @@
+use crate::{Arc, ArcVertex, Circle, CopyIterator, LineSegment, Path};
+use core::{f32::consts::PI, iter};
+use either::Either;
+use glam::Vec2;
+
+/// Classification of an outline vertex.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VertexKind {
+    /// Sharp turn of the outline, or an end of an open outline.
+    Corner,
+    /// Vertex lying on a smooth part of the outline.
+    Smooth,
+}
+
+/// Turning angle of the outline at the vertex `i` estimated over `window` neighbours on each side.
+///
+/// Returns `None` if there's not enough neighbours.
+fn turning_angle(points: &[Vec2], closed: bool, window: usize, i: usize) -> Option<f32> {
+    let n = points.len();
+    let (prev, next) = if closed {
+        let w = window.min((n - 1) / 2);
+        if w == 0 {
+            return None;
+        }
+        (points[(i + n - w) % n], points[(i + w) % n])
+    } else {
+        let w = window.min(i).min(n - 1 - i);
+        if w == 0 {
+            return None;
+        }
+        (points[i - w], points[i + w])
+    };
+    let (u, v) = (points[i] - prev, next - points[i]);
+    Some(u.perp_dot(v).atan2(u.dot(v)))
+}
+
+/// Detect corners of the outline given by ordered `points`.
+///
+/// The turning angle at each vertex is measured between chords going to the vertices
+/// `window` steps away on each side, so the noise of the outline is smoothed out.
+/// The vertex is a corner if the absolute turning angle exceeds `angle_threshold` (in radians)
+/// and is the largest one within the `window` around the vertex.
+///
+/// If `closed` is `false` then the outline is an open polyline and its ends are always corners.
+///
+/// Yields every vertex index with its classification.
+pub fn detect_corners(
+    points: &[Vec2],
+    closed: bool,
+    angle_threshold: f32,
+    window: usize,
+) -> impl Iterator<Item = (usize, VertexKind)> + '_ {
+    let n = points.len();
+    let window = window.max(1);
+    let turn = move |i: usize| turning_angle(points, closed, window, i).map(f32::abs);
+    (0..n).map(move |i| {
+        let kind = match turn(i) {
+            None => {
+                if closed {
+                    VertexKind::Smooth
+                } else {
+                    VertexKind::Corner
+                }
+            }
+            Some(angle) if angle > angle_threshold => {
+                // Non-maximum suppression, the first of equal maximums wins
+                let is_max = (1..=window).all(|k| {
+                    let (prev, next) = if closed {
+                        (Some((i + n - k % n) % n), Some((i + k) % n))
+                    } else {
+                        (i.checked_sub(k), Some(i + k).filter(|&j| j < n))
+                    };
+                    let prev = prev.filter(|&j| j != i).and_then(turn);
+                    let next = next.filter(|&j| j != i).and_then(turn);
+                    prev.is_none_or(|a| a < angle) && next.is_none_or(|a| a <= angle)
+                });
+                if is_max {
+                    VertexKind::Corner
+                } else {
+                    VertexKind::Smooth
+                }
+            }
+            Some(_) => VertexKind::Smooth,
+        };
+        (i, kind)
+    })
+}
+
+/// Minimal turning angle of a corner used by [`vectorize`].
+const CORNER_ANGLE: f32 = PI / 4.0;
+/// Number of neighbours used to estimate turning angle by [`vectorize`].
+const CORNER_WINDOW: usize = 2;
+
+/// Check that all `points` are within `tolerance` from the segment connecting the first and the last one.
+fn fit_line(points: &[Vec2], tolerance: f32) -> bool {
+    let segment = LineSegment(points[0], points[points.len() - 1]);
+    points
+        .iter()
+        .all(|p| (segment.closest_point(*p) - *p).length() <= tolerance)
+}
+
+/// Find the arc going from the first point to the last one and passing through all `points` within `tolerance`.
+///
+/// Returns sagitta of the arc.
+fn fit_arc(points: &[Vec2], tolerance: f32) -> Option<f32> {
+    let (a, b) = (points[0], points[points.len() - 1]);
+    let r = b - a;
+    if r.length() < tolerance {
+        return None;
+    }
+    // The farthest point from the chord is close to the arc midpoint
+    let m = *points[1..points.len() - 1].iter().max_by(|p, q| {
+        r.perp_dot(**p - a)
+            .abs()
+            .total_cmp(&r.perp_dot(**q - a).abs())
+    })?;
+
+    // Circumcircle of `a`, `m` and `b`
+    let Either::Left(Circle { center, radius }) = Circle::from_points(a, m, b) else {
+        return None;
+    };
+
+    let arc = Arc::from_circle_points(center, radius, a, b, r.perp_dot(m - a) < 0.0);
+    let (mid, normal) = (arc.chord().center(), arc.normal());
+    points[1..points.len() - 1]
+        .iter()
+        .all(|p| {
+            ((*p - center).length() - radius).abs() <= tolerance
+                && (*p - mid).dot(normal) >= -tolerance
+        })
+        .then_some(arc.sagitta)
+}
+
+/// Fit points with a line or an arc, preferring the line.
+///
+/// Returns sagitta of the fitted edge.
+fn fit_edge(points: &[Vec2], tolerance: f32) -> Option<f32> {
+    if fit_line(points, tolerance) {
+        Some(0.0)
+    } else {
+        fit_arc(points, tolerance)
+    }
+}
+
+/// Convert an ordered sequence of outline `points` into a compact path of lines and arcs.
+///
+/// The pipeline:
+/// 1. Corners of the outline are detected (see [`detect_corners`]) and always become path vertices.
+/// 2. Each piece between adjacent corners is greedily covered by the longest possible edges
+///    (straight segments first, then arcs) that pass all intermediate points within `tolerance`.
+///
+/// The outline is treated as open. To vectorize a closed outline repeat its first point at the end.
+pub fn vectorize<W: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>>(
+    points: &[Vec2],
+    tolerance: f32,
+) -> Path<W> {
+    let n = points.len();
+    let mut corners = detect_corners(points, false, CORNER_ANGLE, CORNER_WINDOW)
+        .filter(|(_, kind)| *kind == VertexKind::Corner)
+        .map(|(i, _)| i)
+        .peekable();
+
+    let mut i = 0;
+    let edges = iter::from_fn(move || {
+        if i + 1 >= n {
+            return None;
+        }
+        while corners.next_if(|&c| c <= i).is_some() {}
+        let limit = corners.peek().copied().unwrap_or(n - 1);
+
+        // Galloping search of the farthest fitting end
+        let (mut j, mut sagitta) = (i + 1, 0.0);
+        let mut step = 1;
+        while j < limit {
+            let k = (j + step).min(limit);
+            if let Some(s) = fit_edge(&points[i..=k], tolerance) {
+                (j, sagitta) = (k, s);
+                step *= 2;
+            } else {
+                let mut bad = k;
+                while bad - j > 1 {
+                    let mid = (j + bad) / 2;
+                    match fit_edge(&points[i..=mid], tolerance) {
+                        Some(s) => (j, sagitta) = (mid, s),
+                        None => bad = mid,
+                    }
+                }
+                break;
+            }
+        }
+
+        let vertex = ArcVertex {
+            point: points[i],
+            sagitta,
+        };
+        i = j;
+        Some(vertex)
+    });
+
+    Path::from_iter(edges.chain(points.last().map(|&point| ArcVertex {
+        point,
+        sagitta: 0.0,
+    })))
+}
@@ -0,0 +1,222 @@
+use crate::{Closed, CopyIterator, Disk, Distance, HalfPlane, Polygon};
+use glam::Vec2;
+
+/// Cheap test whether two shapes have common points.
+///
+/// Unlike [`Intersect`](crate::Intersect) it doesn't construct the intersection itself.
+/// Touching shapes are considered overlapping.
+pub trait Overlaps<T: ?Sized> {
+    /// Check that the shapes overlap.
+    fn overlaps(&self, other: &T) -> bool;
+}
+
+/// Evidence of the result of an overlap test.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OverlapCertificate {
+    /// Point common to both shapes.
+    Witness(Vec2),
+    /// Half-plane containing the first shape, while the second one is strictly outside of it.
+    Separated(HalfPlane),
+}
+
+impl OverlapCertificate {
+    /// Check that the certificate proves the overlap.
+    pub fn is_overlap(&self) -> bool {
+        matches!(self, Self::Witness(_))
+    }
+
+    /// Certificate of the same result with the shapes swapped.
+    pub fn swap(self) -> Self {
+        match self {
+            Self::Witness(point) => Self::Witness(point),
+            // The second shape is outside, so it is in the closure of the complement
+            Self::Separated(plane) => Self::Separated(plane.complement()),
+        }
+    }
+}
+
+/// Overlap test which provides the evidence of its result.
+///
+/// It uses the same separating axes as [`Overlaps`], so the certificate proves overlap exactly when it reports it.
+pub trait CertifyOverlap<T: ?Sized>: Overlaps<T> {
+    /// Find a common point of the shapes or a line separating them.
+    ///
+    /// Returns `None` if either shape is empty.
+    fn overlap_certificate(&self, other: &T) -> Option<OverlapCertificate>;
+}
+
+/// Projection of the polygon vertices onto the `axis`.
+fn project<V: CopyIterator<Item = Vec2> + ?Sized>(polygon: &Polygon<V>, axis: Vec2) -> (f32, f32) {
+    polygon
+        .vertices()
+        .map(|v| v.dot(axis))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), x| {
+            (min.min(x), max.max(x))
+        })
+}
+
+/// Half-plane containing the projection `a` and excluding the projection `b` onto the normalized `axis`,
+/// if they don't overlap. The edge goes through the middle of the gap.
+fn separate(
+    (a_min, a_max): (f32, f32),
+    (b_min, b_max): (f32, f32),
+    axis: Vec2,
+) -> Option<HalfPlane> {
+    if a_max < b_min {
+        Some(HalfPlane::from_normal(axis * (0.5 * (a_max + b_min)), axis))
+    } else if b_max < a_min {
+        Some(HalfPlane::from_normal(
+            axis * (0.5 * (b_max + a_min)),
+            -axis,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Separating axis test for convex polygons, the half-plane contains `a`.
+fn separate_polygons<U, V>(a: &Polygon<U>, b: &Polygon<V>) -> Option<HalfPlane>
+where
+    U: CopyIterator<Item = Vec2> + ?Sized,
+    V: CopyIterator<Item = Vec2> + ?Sized,
+{
+    a.edges()
+        .chain(b.edges())
+        .filter_map(|edge| edge.vec().perp().try_normalize())
+        .find_map(|axis| separate(project(a, axis), project(b, axis), axis))
+}
+
+/// Separating axis test for convex polygon and disk, the half-plane contains the polygon.
+///
+/// The polygon must not be empty.
+fn separate_disk<V: CopyIterator<Item = Vec2> + ?Sized>(
+    polygon: &Polygon<V>,
+    disk: &Disk,
+) -> Option<HalfPlane> {
+    let closest = polygon.vertices().min_by(|a, b| {
+        (*a - disk.center)
+            .length_squared()
+            .total_cmp(&(*b - disk.center).length_squared())
+    })?;
+    [disk.center - closest]
+        .into_iter()
+        .chain(polygon.edges().map(|edge| edge.vec().perp()))
+        .filter_map(|axis| axis.try_normalize())
+        .find_map(|axis| {
+            let center = disk.center.dot(axis);
+            separate(
+                project(polygon, axis),
+                (center - disk.radius, center + disk.radius),
+                axis,
+            )
+        })
+}
+
+/// Separating axis test for convex polygons.
+///
+/// Both polygons must be convex, their orientation may be arbitrary.
+impl<U, V> Overlaps<Polygon<U>> for Polygon<V>
+where
+    U: CopyIterator<Item = Vec2> + ?Sized,
+    V: CopyIterator<Item = Vec2> + ?Sized,
+{
+    fn overlaps(&self, other: &Polygon<U>) -> bool {
+        if self.is_empty() || other.is_empty() {
+            return false;
+        }
+        separate_polygons(self, other).is_none()
+    }
+}
+
+/// Both polygons must be convex, their orientation may be arbitrary.
+impl<U, V> CertifyOverlap<Polygon<U>> for Polygon<V>
+where
+    U: CopyIterator<Item = Vec2> + ?Sized,
+    V: CopyIterator<Item = Vec2> + ?Sized,
+{
+    /// The witness is a vertex of one polygon inside of the other one,
+    /// or a common point of their boundaries if there is no such vertex.
+    fn overlap_certificate(&self, other: &Polygon<U>) -> Option<OverlapCertificate> {
+        let (a, b) = (self.vertices().next()?, other.vertices().next()?);
+        if let Some(plane) = separate_polygons(self, other) {
+            return Some(OverlapCertificate::Separated(plane));
+        }
+        let witness = if other.winding_number_2(a) != 0 {
+            a
+        } else if self.winding_number_2(b) != 0 {
+            b
+        } else {
+            // Boundaries of the overlapping polygons cross or touch each other
+            let (p, q) = self.closest_points(other)?;
+            p.midpoint(q)
+        };
+        Some(OverlapCertificate::Witness(witness))
+    }
+}
+
+/// Separating axis test for convex polygon and disk.
+///
+/// The polygon must be convex, its orientation may be arbitrary.
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Overlaps<Disk> for Polygon<V> {
+    fn overlaps(&self, disk: &Disk) -> bool {
+        !self.is_empty() && separate_disk(self, disk).is_none()
+    }
+}
+
+/// The polygon must be convex, its orientation may be arbitrary.
+impl<V: CopyIterator<Item = Vec2> + ?Sized> CertifyOverlap<Disk> for Polygon<V> {
+    /// The witness is the disk center if it is inside of the polygon,
+    /// and the point of the polygon boundary closest to it otherwise.
+    fn overlap_certificate(&self, disk: &Disk) -> Option<OverlapCertificate> {
+        if let Some(plane) = separate_disk(self, disk) {
+            return Some(OverlapCertificate::Separated(plane));
+        }
+        let witness = if self.winding_number_2(disk.center) != 0 {
+            disk.center
+        } else {
+            self.closest_point(disk.center)?
+        };
+        Some(OverlapCertificate::Witness(witness))
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Overlaps<Polygon<V>> for Disk {
+    fn overlaps(&self, polygon: &Polygon<V>) -> bool {
+        polygon.overlaps(self)
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> CertifyOverlap<Polygon<V>> for Disk {
+    fn overlap_certificate(&self, polygon: &Polygon<V>) -> Option<OverlapCertificate> {
+        polygon
+            .overlap_certificate(self)
+            .map(OverlapCertificate::swap)
+    }
+}
+
+impl Overlaps<Disk> for Disk {
+    fn overlaps(&self, other: &Disk) -> bool {
+        (self.center - other.center).length_squared() <= (self.radius + other.radius).powi(2)
+    }
+}
+
+impl CertifyOverlap<Disk> for Disk {
+    /// The witness is the middle of the common part of the line going through the centers.
+    fn overlap_certificate(&self, other: &Disk) -> Option<OverlapCertificate> {
+        let offset = other.center - self.center;
+        let distance = offset.length();
+        let axis = offset.try_normalize().unwrap_or(Vec2::X);
+        if !self.overlaps(other) {
+            let gap = 0.5 * (self.radius + distance - other.radius);
+            return Some(OverlapCertificate::Separated(HalfPlane::from_normal(
+                self.center + axis * gap,
+                axis,
+            )));
+        }
+        let start = (-self.radius).max(distance - other.radius);
+        let end = self.radius.min(distance + other.radius);
+        Some(OverlapCertificate::Witness(
+            self.center + axis * (0.5 * (start + end)),
+        ))
+    }
+}
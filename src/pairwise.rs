@@ -0,0 +1,89 @@
+use crate::{Aabb, Bounded, IntersectTo, Overlaps};
+use alloc::{vec, vec::Vec};
+use core::iter;
+
+/// Pairs of the `shapes` which bounding boxes overlap.
+///
+/// Yields indices `(i, j)`, where `i < j`, in unspecified order.
+/// Boxes are swept along the X axis, so only the boxes that overlap along it are compared.
+pub(crate) fn candidate_pairs<S: Bounded>(shapes: &[S]) -> impl Iterator<Item = (usize, usize)> {
+    let mut boxes: Vec<(Aabb, usize)> = shapes
+        .iter()
+        .map(|shape| shape.aabb())
+        .enumerate()
+        .filter(|(_, aabb)| !aabb.is_empty())
+        .map(|(i, aabb)| (aabb, i))
+        .collect();
+    boxes.sort_by(|(a, _), (b, _)| a.min.x.total_cmp(&b.min.x));
+
+    // Current box and the candidate that is checked against it
+    let (mut k, mut l) = (0, 1);
+    iter::from_fn(move || {
+        while k < boxes.len() {
+            let (aabb, i) = boxes[k];
+            // Boxes after `l` start even further to the right, so none of them can overlap with the current one
+            while let Some(&(other, j)) =
+                boxes.get(l).filter(|(other, _)| other.min.x <= aabb.max.x)
+            {
+                l += 1;
+                if aabb.intersects(&other) {
+                    return Some((i.min(j), i.max(j)));
+                }
+            }
+            k += 1;
+            l = k + 1;
+        }
+        None
+    })
+}
+
+/// Intersect all pairs of the `shapes`.
+///
+/// Yields indices of the intersecting shapes `(i, j)`, where `i < j`, and their intersection.
+///
+/// Pairs are pruned by sweeping bounding boxes along the X axis,
+/// so only the shapes with overlapping boxes are passed to the exact intersection.
+/// The order of yielded pairs is unspecified.
+pub fn pairwise_intersections<'a, S: Bounded + IntersectTo<S, U>, U: 'a>(
+    shapes: &'a [S],
+) -> impl Iterator<Item = (usize, usize, U)> + 'a {
+    candidate_pairs(shapes).filter_map(|(i, j)| Some((i, j, shapes[i].intersect_to(&shapes[j])?)))
+}
+
+/// Group the `shapes` into connected components, where shapes are connected if they overlap or touch.
+///
+/// Overlapping pairs are found the same way as in [`pairwise_intersections`],
+/// and then checked by [`Overlaps`] and merged by union-find.
+/// Each component is a sorted list of shape indices, components are sorted by their first index.
+/// Shapes with empty bounding boxes form their own components.
+pub fn components<S: Bounded + Overlaps<S>>(shapes: &[S]) -> Vec<Vec<usize>> {
+    let mut parent: Vec<usize> = (0..shapes.len()).collect();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            // Path halving
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    for (i, j) in candidate_pairs(shapes) {
+        if shapes[i].overlaps(&shapes[j]) {
+            let (a, b) = (root(&mut parent, i), root(&mut parent, j));
+            // Smaller index becomes the root, so it is the first one in its component
+            parent[a.max(b)] = a.min(b);
+        }
+    }
+
+    // Roots are visited before the other members of their components
+    let mut index = vec![usize::MAX; shapes.len()];
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for i in 0..shapes.len() {
+        let r = root(&mut parent, i);
+        if r == i {
+            index[i] = groups.len();
+            groups.push(Vec::new());
+        }
+        groups[index[r]].push(i);
+    }
+    groups
+}
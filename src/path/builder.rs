@@ -0,0 +1,158 @@
+use crate::{Arc, ArcVertex, EPS, Edge, GenericPath, GenericPolygon, Vertex};
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+use glam::Vec2;
+
+/// Builds a path or a polygon edge by edge from the points it goes through.
+///
+/// Works with both [`Vec2`] vertices giving [`Polyline`](crate::Polyline) and [`Polygon`](crate::Polygon),
+/// and [`ArcVertex`] vertices giving [`Path`](crate::Path) and [`ArcPolygon`](crate::ArcPolygon).
+/// In the latter case arcs are added by their points, and the sagittas are computed by the builder.
+///
+/// ```rust
+/// use geom2::{ArcVertex, PathBuilder};
+/// use glam::Vec2;
+///
+/// // Rectangle with the rounded top side
+/// let polygon = PathBuilder::<ArcVertex>::new()
+///     .move_to(Vec2::new(-1.0, 0.0))
+///     .line_to(Vec2::new(1.0, 0.0))
+///     .line_to(Vec2::new(1.0, 1.0))
+///     .arc_to(Vec2::new(0.0, 2.0), Vec2::new(-1.0, 1.0))
+///     .close();
+/// assert_eq!(polygon.len(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PathBuilder<T: Vertex> {
+    vertices: Vec<T>,
+}
+
+impl<T: Vertex> Default for PathBuilder<T> {
+    fn default() -> Self {
+        Self {
+            vertices: Vec::new(),
+        }
+    }
+}
+
+impl<T: Vertex + From<Vec2>> PathBuilder<T> {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check that no points were added.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// Last point of the path.
+    pub fn current(&self) -> Option<Vec2> {
+        self.vertices.last().map(|v| v.pos())
+    }
+
+    /// Start the path at the `point`, the points added before are dropped.
+    pub fn move_to(mut self, point: Vec2) -> Self {
+        self.vertices.clear();
+        self.vertices.push(point.into());
+        self
+    }
+
+    /// Add a straight edge to the `point`.
+    ///
+    /// If the path is not started, then it starts at the `point`.
+    pub fn line_to(mut self, point: Vec2) -> Self {
+        self.vertices.push(point.into());
+        self
+    }
+
+    /// Finish the open path.
+    pub fn finish(self) -> GenericPath<Vec<T>, T> {
+        GenericPath::new(self.vertices)
+    }
+
+    /// Connect the last point to the first one by a straight edge and finish the polygon.
+    ///
+    /// If the last point coincides with the first one, e.g. when the last edge is an arc ending at the start,
+    /// then it is merged with the first one.
+    pub fn close(mut self) -> GenericPolygon<Vec<T>, T> {
+        if let [first, .., last] = self.vertices[..]
+            && first.pos().distance(last.pos()) < EPS
+        {
+            self.vertices.pop();
+        }
+        GenericPolygon::new(self.vertices)
+    }
+}
+
+impl PathBuilder<ArcVertex> {
+    /// Add an arc edge.
+    fn push_arc(mut self, arc: Arc) -> Self {
+        match self.vertices.last_mut() {
+            Some(last) => last.sagitta = arc.sagitta,
+            None => self.vertices.push(arc.points.0.into()),
+        }
+        self.vertices.push(arc.points.1.into());
+        self
+    }
+
+    /// Add an arc edge going to the `point` through the `through` point.
+    ///
+    /// If the points are collinear, then the edge is straight.
+    /// If the path is not started, then it is equivalent to [`PathBuilder::move_to`] with the `point`.
+    pub fn arc_to(self, through: Vec2, point: Vec2) -> Self {
+        match self.current() {
+            Some(current) => self.push_arc(Arc::from_three_points(current, through, point)),
+            None => self.move_to(point),
+        }
+    }
+
+    /// Add an arc edge going to the `point` around the `center`.
+    ///
+    /// The `point` is projected onto the circle going through the last point.
+    /// If the path is not started, then it is equivalent to [`PathBuilder::move_to`] with the `point`.
+    pub fn arc_around(self, center: Vec2, point: Vec2, ccw: bool) -> Self {
+        match self.current() {
+            Some(current) => {
+                let radius = current.distance(center);
+                let point = center + (point - center).normalize_or_zero() * radius;
+                self.push_arc(Arc::from_circle_points(center, radius, current, point, ccw))
+            }
+            None => self.move_to(point),
+        }
+    }
+
+    /// Add an arc edge going to the `point` and smoothly continuing the last edge.
+    ///
+    /// If there's no last edge, then the edge is straight.
+    /// If the `point` lies right behind the last edge, then no tangent arc reaches it,
+    /// and the path turns back by a half circle made of two arcs.
+    pub fn tangent_arc_to(self, point: Vec2) -> Self {
+        let (current, direction) = match self.vertices[..] {
+            [.., a, b] => (b.point, end_tangent(Arc::from_vertices(&a, &b))),
+            _ => return self.line_to(point),
+        };
+        let chord = point - current;
+        let h = 0.5 * chord.length();
+        // Tangent to the arc turns by the half of its sweep along the chord
+        let angle = direction.angle_to(chord);
+        if PI - angle.abs() < EPS {
+            // Sagitta is infinite, turn back by the half circle on the side the point leans to
+            let center = current + 0.5 * chord;
+            let ccw = angle > 0.0;
+            let middle = center + 0.5 * if ccw { -chord.perp() } else { chord.perp() };
+            return self
+                .arc_around(center, middle, ccw)
+                .arc_around(center, point, ccw);
+        }
+        self.push_arc(Arc {
+            points: (current, point),
+            sagitta: h * (0.5 * angle).tan(),
+        })
+    }
+}
+
+/// Direction of the arc at its second end.
+fn end_tangent(arc: Arc) -> Vec2 {
+    Vec2::from_angle(0.5 * arc.sweep()).rotate(arc.chord().vec())
+}
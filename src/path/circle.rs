@@ -0,0 +1,32 @@
+use crate::{ArcVertex, CopyIterator, GenericPath, Polyline};
+use glam::Vec2;
+
+/// An open path with circular arc edges.
+///
+/// Each vertex stores the sagitta of the arc going from it to the next vertex,
+/// so the sagitta of the last vertex is ignored.
+pub type Path<V> = GenericPath<V, ArcVertex>;
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Path<V> {
+    /// Total length of the path.
+    pub fn length(&self) -> f32 {
+        self.edges().map(|arc| arc.length()).sum()
+    }
+
+    /// Approximate the path by the polyline deviating from the arcs by no more than the `tolerance`.
+    ///
+    /// See [`ArcPolygon::flatten`](crate::ArcPolygon::flatten) for details.
+    pub fn flatten<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        tolerance: f32,
+    ) -> Polyline<W> {
+        let last = self.vertices().last().map(|v| v.point);
+        self.edges()
+            .flat_map(|arc| {
+                let count = arc.segment_count(tolerance);
+                arc.flatten(tolerance).take(count)
+            })
+            .chain(last)
+            .collect()
+    }
+}
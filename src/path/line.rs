@@ -0,0 +1,156 @@
+use crate::{CopyIterator, EPS, GenericPath, Intersect, Line, LineSegment};
+use glam::Vec2;
+
+#[cfg(feature = "alloc")]
+use crate::{Closed, Crossings};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// An open path with straight line segment edges, e.g. a road or a trajectory.
+///
+/// Unlike [`Polygon`](crate::Polygon) the last vertex is not connected to the first one.
+pub type Polyline<V> = GenericPath<V, Vec2>;
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polyline<V> {
+    /// Total length of the polyline edges.
+    pub fn length(&self) -> f32 {
+        self.edges().map(|edge| edge.vec().length()).sum()
+    }
+
+    /// Point at the `distance` along the polyline from its first vertex.
+    ///
+    /// The distance is clamped to the polyline length.
+    /// Returns `None` if the polyline is empty.
+    pub fn point_at_length(&self, distance: f32) -> Option<Vec2> {
+        let mut rest = distance.max(0.0);
+        for edge in self.edges() {
+            let length = edge.vec().length();
+            if rest <= length {
+                return Some(if length > 0.0 {
+                    edge.0.lerp(edge.1, rest / length)
+                } else {
+                    edge.0
+                });
+            }
+            rest -= length;
+        }
+        self.vertices().last()
+    }
+
+    /// Get the point of the polyline closest to the given `point`.
+    ///
+    /// Returns `None` if the polyline is empty.
+    pub fn closest_point(&self, point: Vec2) -> Option<Vec2> {
+        let first = self.vertices().next()?;
+        Some(
+            self.edges()
+                .map(|edge| edge.closest_point(point))
+                .fold(first, |a, b| {
+                    if (b - point).length_squared() < (a - point).length_squared() {
+                        b
+                    } else {
+                        a
+                    }
+                }),
+        )
+    }
+
+    /// Points where the polyline crosses the `line`, in the order along the polyline.
+    ///
+    /// The vertex lying on the line is yielded once.
+    /// An edge lying on the line gives its center.
+    pub fn intersect_line(&self, line: &Line) -> impl Iterator<Item = Vec2> + '_ {
+        let line = *line;
+        self.intersections(move |edge| edge.intersect(&line))
+    }
+
+    /// Points where the polyline crosses the `segment`, in the order along the polyline.
+    ///
+    /// See [`Polyline::intersect_line`] for details.
+    pub fn intersect_segment(&self, segment: &LineSegment) -> impl Iterator<Item = Vec2> + '_ {
+        let segment = *segment;
+        self.intersections(move |edge| edge.intersect(&segment))
+    }
+
+    fn intersections<F: Fn(&LineSegment) -> Option<Vec2> + 'static>(
+        &self,
+        intersect: F,
+    ) -> impl Iterator<Item = Vec2> + '_ {
+        // Crossing at the start of an edge is already yielded as the end of the previous one
+        self.edges().enumerate().filter_map(move |(i, edge)| {
+            intersect(&edge).filter(|p| i == 0 || p.distance(edge.0) >= EPS)
+        })
+    }
+
+    /// Points evenly distributed along the polyline, so that the distance between them along the polyline doesn't exceed the `spacing`.
+    ///
+    /// The first and the last points are the polyline ends, and the original vertices are not kept.
+    /// Returns the first vertex only if the polyline is shorter than [`EPS`] or the `spacing` is not positive.
+    pub fn resample(&self, spacing: f32) -> impl Iterator<Item = Vec2> + '_ {
+        let length = self.length();
+        let count = if spacing > 0.0 && length >= EPS {
+            (length / spacing).ceil() as usize
+        } else {
+            0
+        };
+        let step = if count > 0 {
+            length / count as f32
+        } else {
+            0.0
+        };
+        let mut edges = self.edges().peekable();
+        // Length of the edges already passed
+        let mut passed = 0.0;
+        let first = self.vertices().next();
+        first.into_iter().chain((1..=count).filter_map(move |i| {
+            if i == count {
+                return self.vertices().last();
+            }
+            let distance = i as f32 * step;
+            while let Some(edge) = edges.peek() {
+                let length = edge.vec().length();
+                if length > 0.0 && passed + length >= distance {
+                    return Some(edge.0.lerp(edge.1, (distance - passed) / length));
+                }
+                passed += length;
+                edges.next();
+            }
+            None
+        }))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polyline<V> {
+    /// Parts of the polyline lying inside of the `shape`, in the order along the polyline.
+    ///
+    /// Edges are cut at the points where they cross the shape boundary.
+    pub fn clip<S: Crossings + Closed + ?Sized>(&self, shape: &S) -> Vec<Polyline<Vec<Vec2>>> {
+        let mut parts = Vec::new();
+        let mut part: Vec<Vec2> = Vec::new();
+        for edge in self.edges() {
+            let mut params: Vec<f32> = shape.crossings(&edge).collect();
+            params.sort_by(f32::total_cmp);
+            let mut start = 0.0;
+            for end in params.into_iter().chain([1.0]) {
+                if end - start < EPS && end < 1.0 {
+                    continue;
+                }
+                let (a, b) = (edge.0.lerp(edge.1, start), edge.0.lerp(edge.1, end));
+                if shape.contains(a.lerp(b, 0.5)) {
+                    if part.is_empty() {
+                        part.push(a);
+                    }
+                    part.push(b);
+                } else if !part.is_empty() {
+                    parts.push(Polyline::new(core::mem::take(&mut part)));
+                }
+                start = end;
+            }
+        }
+        if !part.is_empty() {
+            parts.push(Polyline::new(part));
+        }
+        parts
+    }
+}
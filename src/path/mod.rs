@@ -0,0 +1,101 @@
+#[cfg(feature = "alloc")]
+pub mod builder;
+pub mod circle;
+pub mod line;
+
+use crate::{CopyIterator, Edge, Vertex};
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+/// An open path defined by a sequence of vertices.
+///
+/// Unlike [`GenericPolygon`](crate::GenericPolygon) the path is not closed,
+/// so there's no edge connecting the last vertex to the first one.
+///
+/// ```text
+///     v1 +-----+ v2
+///       /       \
+///      /         \
+///  v0 +           + v3
+/// ```
+///
+/// Vertices are connected in order: v0 -> v1 -> v2 -> v3.
+#[derive(Clone, Copy)]
+pub struct GenericPath<V: CopyIterator<Item = T> + ?Sized, T: Vertex> {
+    _ghost: PhantomData<T>,
+    /// The vertices of the path.
+    pub vertices: V,
+}
+
+impl<T: Vertex, V: CopyIterator<Item = T>> GenericPath<V, T> {
+    /// Create a new path from a sequence of vertices.
+    pub fn new(vertices: V) -> Self {
+        Self {
+            vertices,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl<T: Vertex, V: CopyIterator<Item = T> + FromIterator<T>> FromIterator<T> for GenericPath<V, T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(V::from_iter(iter))
+    }
+}
+
+impl<T: Vertex + PartialEq, U: CopyIterator<Item = T> + ?Sized, V: CopyIterator<Item = T> + ?Sized>
+    PartialEq<GenericPath<U, T>> for GenericPath<V, T>
+{
+    fn eq(&self, other: &GenericPath<U, T>) -> bool {
+        self.vertices().eq(other.vertices())
+    }
+}
+
+impl<T: Vertex, V: CopyIterator<Item = T> + ?Sized> GenericPath<V, T> {
+    /// Get an iterator over the vertices of the path.
+    pub fn vertices(&self) -> V::CopyIter<'_> {
+        self.vertices.iter_copied()
+    }
+
+    /// Check if the path has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.vertices().next().is_none()
+    }
+
+    /// Get an iterator over the edges of the path.
+    ///
+    /// The edges are formed by connecting consecutive vertices.
+    pub fn edges(&self) -> impl Iterator<Item = T::Edge> {
+        self.vertices()
+            .zip(self.vertices().skip(1))
+            .map(|(a, b)| T::Edge::from_vertices(&a, &b))
+    }
+
+    /// Get a lazy view of the path with the `f` applied to each vertex.
+    ///
+    /// Vertices are not stored, the `f` is called every time the path is iterated.
+    pub fn map_vertices<'a, U: Vertex + 'a, F: Fn(T) -> U + 'a>(
+        &'a self,
+        f: F,
+    ) -> GenericPath<impl CopyIterator<Item = U> + 'a, U> {
+        GenericPath::new(self.vertices.map(f))
+    }
+}
+
+impl<T: Vertex, V: CopyIterator<Item = T> + ?Sized> GenericPath<V, T>
+where
+    for<'a> V::CopyIter<'a>: ExactSizeIterator,
+{
+    /// Get the number of vertices in the path.
+    pub fn len(&self) -> usize {
+        self.vertices().len()
+    }
+}
+
+impl<T: Vertex, V: CopyIterator<Item = T> + Debug + ?Sized> Debug for GenericPath<V, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Path {{ vertices: {:?} }}", &self.vertices)
+    }
+}
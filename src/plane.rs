@@ -1,4 +1,7 @@
-use crate::{Closed, Line, impl_approx_eq};
+use crate::{
+    Aabb, Closed, CopyIterator, EPS, Intersect, IntersectTo, Line, LineSegment, Polygon,
+    impl_approx_eq,
+};
 use glam::Vec2;
 
 /// A half-plane defined by a boundary line.
@@ -63,6 +66,126 @@ impl HalfPlane {
         let p = self.boundary_point();
         Line(p - 0.5 * self.normal.perp(), p + 0.5 * self.normal.perp())
     }
+
+    /// Half-plane with the edge shifted outwards by `d`.
+    ///
+    /// Positive `d` dilates the half-plane, negative one erodes it.
+    pub fn offset(&self, d: f32) -> Self {
+        Self {
+            normal: self.normal,
+            offset: self.offset + d,
+        }
+    }
+
+    /// Half-plane on the other side of the same edge.
+    pub fn complement(&self) -> Self {
+        Self {
+            normal: -self.normal,
+            offset: -self.offset,
+        }
+    }
+
+    /// Point where the edges of two half-planes cross, `None` if they are parallel.
+    pub fn edge_crossing(&self, other: &Self) -> Option<Vec2> {
+        let det = self.normal.perp_dot(other.normal);
+        if det.abs() < EPS {
+            return None;
+        }
+        Some((other.offset * self.normal.perp() - self.offset * other.normal.perp()) / det)
+    }
+
+    /// Clip the `shape` by the half-plane with the soft margin of `d` width at each side of the edge.
+    ///
+    /// Returns the pair of the core, the part of the `shape` lying deeper than `d` inside the half-plane,
+    /// and the margin, the part lying within the band between the half-plane offset by `-d` and by `d`.
+    /// Their union is the `shape` clipped by `self.offset(d)`.
+    ///
+    /// The `shape` itself is clipped only once by the dilated half-plane, and then the result is split by the eroded one.
+    pub fn clip_with_margin<S: IntersectTo<HalfPlane, P> + ?Sized, P: IntersectTo<HalfPlane, P>>(
+        &self,
+        shape: &S,
+        d: f32,
+    ) -> (Option<P>, Option<P>)
+    where
+        HalfPlane: IntersectTo<S, P> + IntersectTo<P, P>,
+    {
+        let d = d.abs();
+        let inner = self.offset(-d);
+        match shape.intersect_to(&self.offset(d)) {
+            Some(dilated) => (
+                dilated.intersect_to(&inner),
+                dilated.intersect_to(&inner.complement()),
+            ),
+            None => (None, None),
+        }
+    }
+}
+
+/// Intersection of a set of half-planes.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ConvexRegion<P> {
+    /// Half-planes have no common points.
+    Empty,
+    /// Convex polygon bounded by the half-planes (and by the bounds, if given).
+    Polygon(P),
+    /// Half-planes have common points, but no bounds are given and the intersection is unbounded.
+    Unbounded,
+}
+
+/// Intersect the `planes` into a convex counterclockwise polygon.
+///
+/// If `bounds` are given then the result is clipped by them and is never [`ConvexRegion::Unbounded`].
+/// Intersection of no half-planes is the whole plane (or the `bounds`).
+///
+/// Takes `O(n * k)` time, where `n` is the number of half-planes and `k` is the number of polygon vertices.
+/// Without `bounds` additional `O(n^2)` time is taken to check that the result is bounded.
+pub fn intersect_half_planes<I, W>(planes: I, bounds: Option<Aabb>) -> ConvexRegion<Polygon<W>>
+where
+    I: IntoIterator<Item = HalfPlane>,
+    I::IntoIter: Clone,
+    W: CopyIterator<Item = Vec2> + FromIterator<Vec2>,
+{
+    let planes = planes.into_iter();
+    let given = bounds.is_some();
+    let bounds = bounds.unwrap_or_else(|| {
+        // Each vertex of the intersection is the crossing of two edges,
+        // and if there are no vertices then all the edges are parallel and the intersection
+        // contains a segment between their boundary points,
+        // so the box around these points always has common points with a non-empty intersection
+        // (origin is added to have a non-empty box when there are no planes)
+        let points = planes.clone().flat_map(|a| {
+            planes
+                .clone()
+                .filter_map(move |b| a.edge_crossing(&b))
+                .chain([a.boundary_point()])
+        });
+        let aabb = Aabb::from_points(points.chain([Vec2::ZERO]));
+        aabb.inflate(aabb.size().max_element().max(1.0))
+    });
+    if bounds.is_empty() {
+        return ConvexRegion::Empty;
+    }
+
+    let mut polygon: Polygon<W> = Polygon::from_iter(bounds.corners());
+    for plane in planes.clone() {
+        polygon = match polygon.intersect_to(&plane) {
+            Some(polygon) => polygon,
+            None => return ConvexRegion::Empty,
+        };
+    }
+
+    // Intersection is unbounded if there is a direction along which it extends infinitely,
+    // such direction can be chosen to be parallel to one of the edges
+    let unbounded = planes.clone().next().is_none()
+        || planes
+            .clone()
+            .flat_map(|plane| [plane.normal.perp(), -plane.normal.perp()])
+            .any(|dir| planes.clone().all(|plane| plane.normal.dot(dir) <= EPS));
+    if !given && unbounded {
+        ConvexRegion::Unbounded
+    } else {
+        ConvexRegion::Polygon(polygon)
+    }
 }
 
 impl Closed for HalfPlane {
@@ -72,3 +195,40 @@ impl Closed for HalfPlane {
 }
 
 impl_approx_eq!(HalfPlane, f32, normal, offset);
+
+/// Range of the `segment` parameters lying inside of the half-plane, given the current range `(t0, t1)`.
+///
+/// It is a single step of Liang–Barsky (Cyrus–Beck) clipping, the range is empty if `t0 > t1`.
+pub(crate) fn clip_params(
+    segment: &LineSegment,
+    plane: &HalfPlane,
+    (t0, t1): (f32, f32),
+) -> (f32, f32) {
+    let (d0, d1) = (plane.distance(segment.0), plane.distance(segment.1));
+    if (d0 - d1).abs() < EPS {
+        // Segment is parallel to the edge
+        return if d0 <= 0.0 { (t0, t1) } else { (1.0, 0.0) };
+    }
+    let t = d0 / (d0 - d1);
+    if d0 < d1 {
+        (t0, t1.min(t))
+    } else {
+        (t0.max(t), t1)
+    }
+}
+
+impl Intersect<HalfPlane> for LineSegment {
+    /// Part of the segment lying inside of the half-plane, in the same direction as the segment.
+    type Output = LineSegment;
+    fn intersect(&self, plane: &HalfPlane) -> Option<LineSegment> {
+        let (t0, t1) = clip_params(self, plane, (0.0, 1.0));
+        (t0 <= t1).then(|| LineSegment(self.0.lerp(self.1, t0), self.0.lerp(self.1, t1)))
+    }
+}
+
+impl Intersect<LineSegment> for HalfPlane {
+    type Output = LineSegment;
+    fn intersect(&self, segment: &LineSegment) -> Option<LineSegment> {
+        segment.intersect(self)
+    }
+}
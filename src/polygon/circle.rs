@@ -1,9 +1,15 @@
 use crate::{
-    ArcVertex, Circle, Closed, CopyIterator, Disk, DiskSegment, EPS, FramedPolygon, GenericPolygon,
-    Integrable, Intersect, IntersectTo, Line, LineSegment, Meta, MetaPolygon, Moment, Polygon,
-    Unmeta,
+    Arc, ArcTarget, ArcVertex, Circle, Closed, CopyIterator, Decompose, Disk, DiskSegment,
+    DiskTarget, EPS, FramedPolygon, GenericPolygon, HalfPlane, Integrable, Intersect, IntersectTo,
+    Line, LineSegment, Meta, MetaPolygon, Moment, Polygon, Unmeta, telemetry,
+};
+use core::{
+    array::from_fn,
+    error::Error,
+    f32::consts::PI,
+    fmt::{self, Display, Formatter},
+    iter,
 };
-use core::{array::from_fn, f32::consts::PI};
 use genawaiter::{stack::let_gen, yield_};
 use glam::Vec2;
 
@@ -53,6 +59,138 @@ impl<const N: usize> ArcPolygon<[ArcVertex; N]> {
     }
 }
 
+/// Error of constructing arc polygon from bulge-annotated polyline.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BulgeError {
+    /// Polyline has less than two distinct vertices.
+    TooFewVertices,
+    /// Polyline is marked as open and its last vertex doesn't coincide with the first one.
+    NotClosed,
+    /// Vertex coordinates or bulge is infinite or NaN.
+    NonFinite,
+}
+
+impl Display for BulgeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewVertices => write!(f, "polyline has less than two vertices"),
+            Self::NotClosed => write!(f, "polyline is not closed"),
+            Self::NonFinite => write!(f, "polyline contains non-finite value"),
+        }
+    }
+}
+
+impl Error for BulgeError {}
+
+impl<V: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>> ArcPolygon<V> {
+    /// Construct arc polygon from vertices with bulges, as in DXF polylines.
+    ///
+    /// Bulge of the vertex defines the edge going from it to the next vertex, see [`Arc::bulge`].
+    ///
+    /// If the polyline is not marked as `closed` then its last vertex must coincide with the first one.
+    /// Such duplicated vertex is removed regardless of `closed` flag.
+    /// Consecutive coincident vertices are merged as well, keeping the bulge of the last one.
+    pub fn from_bulges<I: IntoIterator<Item = (Vec2, f32)>>(
+        vertices: I,
+        closed: bool,
+    ) -> Result<Self, BulgeError> {
+        let mut iter = vertices.into_iter().peekable();
+        let first = match iter.peek() {
+            Some(&(point, _)) => point,
+            None => return Err(BulgeError::TooFewVertices),
+        };
+        let mut count = 0;
+        let polygon = iter::from_fn(|| {
+            loop {
+                let (point, bulge) = iter.next()?;
+                if !point.is_finite() || !bulge.is_finite() {
+                    return Some(Err(BulgeError::NonFinite));
+                }
+                let next = match iter.peek() {
+                    // Edge to the coincident vertex has no length, so its bulge is meaningless
+                    Some(&(next, _)) if (next - point).abs().max_element() < EPS => continue,
+                    Some(&(next, _)) => next,
+                    None => {
+                        if (point - first).abs().max_element() < EPS {
+                            return None;
+                        } else if !closed {
+                            return Some(Err(BulgeError::NotClosed));
+                        }
+                        first
+                    }
+                };
+                count += 1;
+                return Some(Ok(ArcVertex {
+                    point,
+                    sagitta: 0.5 * bulge * (next - point).length(),
+                }));
+            }
+        })
+        .collect::<Result<Self, _>>()?;
+        if count < 2 {
+            return Err(BulgeError::TooFewVertices);
+        }
+        Ok(polygon)
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized> ArcPolygon<V> {
+    /// Area of the polygon, positive if it is counterclockwise and negative if clockwise.
+    ///
+    /// Unlike [`FramedPolygon::orientation`] it takes arc segments into account.
+    pub fn signed_area(&self) -> f32 {
+        self.pieces().map(|piece| piece.area()).sum()
+    }
+
+    /// Check that the polygon is counterclockwise, i.e. has positive area.
+    pub fn is_ccw(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    /// Moment of the polygon preserving its orientation.
+    ///
+    /// The area is negative for clockwise polygons while the centroid stays the same,
+    /// so that merging such moment with the enclosing one subtracts a hole from it.
+    pub fn signed_moment(&self) -> Moment {
+        self.piecewise_moment()
+    }
+
+    /// Approximate the polygon by the polygon with straight edges deviating from the arcs by no more than the `tolerance`.
+    ///
+    /// Each arc is replaced by the polyline given by [`Arc::flatten`], the original vertices are kept.
+    /// The `tolerance` must be positive.
+    pub fn flatten<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        tolerance: f32,
+    ) -> Polygon<W> {
+        // The last point of each arc is the first point of the next one
+        self.edges()
+            .flat_map(|arc| {
+                let count = arc.segment_count(tolerance);
+                arc.flatten(tolerance).take(count)
+            })
+            .collect()
+    }
+
+    /// Reverse the direction of the polygon boundary, making its orientation opposite.
+    ///
+    /// Sagittas are negated and moved to the vertices which become the arcs starts.
+    pub fn reverse(&mut self)
+    where
+        V: AsMut<[ArcVertex]>,
+    {
+        let vertices = self.vertices.as_mut();
+        if let Some(last) = vertices.last().map(|v| v.sagitta) {
+            // Sagitta of the edge `(a, b)` goes to the vertex `b`
+            let mut sagitta = last;
+            for v in vertices.iter_mut() {
+                sagitta = core::mem::replace(&mut v.sagitta, -sagitta);
+            }
+        }
+        vertices.reverse();
+    }
+}
+
 impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Closed for ArcPolygon<V> {
     fn winding_number_2(&self, point: Vec2) -> i32 {
         let mut winding_number = self.frame().winding_number_2(point);
@@ -66,215 +204,479 @@ impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Closed for ArcPolygon<V> {
 }
 
 impl<V: CopyIterator<Item = ArcVertex> + ?Sized> Integrable for ArcPolygon<V> {
+    /// Same as [`ArcPolygon::signed_moment`].
     fn moment(&self) -> Moment {
-        let mut moment = self.frame().moment();
-
-        for arc in self.edges() {
-            moment = moment.merge(DiskSegment(arc).moment());
-        }
-
-        moment
+        self.signed_moment()
     }
 }
 
-impl<
-    V: CopyIterator<Item = Vec2> + ?Sized,
-    W: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>,
-> IntersectTo<Disk, ArcPolygon<W>> for Polygon<V>
-{
-    fn intersect_to(&self, disk: &Disk) -> Option<ArcPolygon<W>> {
-        let unmeta: MetaArcPolygon<Unmeta<W>, ()> =
-            Meta::new(Polygon::new(self.vertices.to_ref()), ())
-                .intersect_to(&Meta::new(*disk, ()))?;
-        Some(ArcPolygon::new(unmeta.vertices.0))
+impl<V: CopyIterator<Item = Vec2> + ?Sized, T: DiskTarget> IntersectTo<Disk, T> for Polygon<V> {
+    fn intersect_to(&self, disk: &Disk) -> Option<T> {
+        match clip_polygon(
+            &self.map_vertices(|x| Meta::new(x, ())),
+            &Meta::new(*disk, ()),
+        ) {
+            Some(Unmeta(target)) => Some(target),
+            None => self.contains(disk.center).then(|| T::from_disk(*disk)),
+        }
     }
 }
 
-impl<
-    V: CopyIterator<Item = Vec2> + ?Sized,
-    W: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>,
-> IntersectTo<Polygon<V>, ArcPolygon<W>> for Disk
-{
-    fn intersect_to(&self, other: &Polygon<V>) -> Option<ArcPolygon<W>> {
+impl<V: CopyIterator<Item = Vec2> + ?Sized, T: DiskTarget> IntersectTo<Polygon<V>, T> for Disk {
+    fn intersect_to(&self, other: &Polygon<V>) -> Option<T> {
         other.intersect_to(self)
     }
 }
 
-impl<
+/// Clip the polygon by the disk.
+///
+/// Returns `None` if the polygon boundary doesn't cross the disk.
+fn clip_polygon<M, V, T>(polygon: &MetaPolygon<V, M>, disk: &Meta<Disk, M>) -> Option<T>
+where
     M: Copy,
     V: CopyIterator<Item = Meta<Vec2, M>> + ?Sized,
-    W: CopyIterator<Item = Meta<ArcVertex, M>> + FromIterator<Meta<ArcVertex, M>>,
-> IntersectTo<Meta<Disk, M>, MetaArcPolygon<W, M>> for MetaPolygon<V, M>
+    T: ArcTarget<Meta<ArcVertex, M>>,
 {
-    fn intersect_to(&self, disk: &Meta<Disk, M>) -> Option<MetaArcPolygon<W, M>> {
-        // Clip vertices
-        let_gen!(gen_, {
-            let mut iter = self.vertices();
-            let mut first = None;
-            let mut last = None;
-            let mut prev = match iter.next() {
-                Some(x) => x,
-                None => return,
-            };
-            let mut prev_inside = disk.contains(*prev);
-            for curr in iter.chain([prev]) {
-                let inside = disk.contains(*curr);
-                match (prev_inside, inside) {
-                    (true, true) => {
+    // Clip vertices
+    let_gen!(gen_, {
+        let mut iter = polygon.vertices();
+        let mut first = None;
+        let mut last = None;
+        let mut prev = match iter.next() {
+            Some(x) => x,
+            None => return,
+        };
+        let mut prev_inside = disk.contains(*prev);
+        for curr in iter.chain([prev]) {
+            let inside = disk.contains(*curr);
+            match (prev_inside, inside) {
+                (true, true) => {
+                    yield_!(Meta::new(
+                        ArcVertex {
+                            point: *prev,
+                            sagitta: 0.0,
+                        },
+                        prev.meta
+                    ));
+                }
+                (true, false) => {
+                    last = Some(Meta::new(
+                        disk.inner
+                            .edge()
+                            .intersect(&Line(*prev, *curr))
+                            .unwrap_or([*prev, *curr])[1],
+                        prev.meta,
+                    ));
+                    yield_!(Meta::new(
+                        ArcVertex {
+                            point: *prev,
+                            sagitta: 0.0,
+                        },
+                        prev.meta
+                    ));
+                }
+                (false, true) => {
+                    let clip = Meta::new(
+                        disk.inner
+                            .edge()
+                            .intersect(&Line(*prev, *curr))
+                            .unwrap_or([*prev, *curr])[0],
+                        prev.meta,
+                    );
+
+                    if let Some(last) = last {
                         yield_!(Meta::new(
                             ArcVertex {
-                                point: *prev,
-                                sagitta: 0.0,
+                                point: *last,
+                                sagitta: disk.radius
+                                    - Line(*last, *clip).signed_distance(disk.center),
                             },
-                            prev.meta
-                        ));
-                    }
-                    (true, false) => {
-                        last = Some(Meta::new(
-                            disk.inner
-                                .edge()
-                                .intersect(&Line(*prev, *curr))
-                                .unwrap_or([*prev, *curr])[1],
-                            prev.meta,
-                        ));
-                        yield_!(Meta::new(
-                            ArcVertex {
-                                point: *prev,
-                                sagitta: 0.0,
-                            },
-                            prev.meta
-                        ));
+                            disk.meta
+                        ))
+                    } else {
+                        if first.is_none() {
+                            first = Some(clip);
+                        }
                     }
-                    (false, true) => {
-                        let clip = Meta::new(
-                            disk.inner
-                                .edge()
-                                .intersect(&Line(*prev, *curr))
-                                .unwrap_or([*prev, *curr])[0],
-                            prev.meta,
-                        );
 
+                    yield_!(Meta::new(
+                        ArcVertex {
+                            point: *clip,
+                            sagitta: 0.0,
+                        },
+                        clip.meta
+                    ));
+                }
+                (false, false) => match disk.inner.edge().intersect(&LineSegment(*prev, *curr)) {
+                    Some([Some(a), Some(b)]) => {
                         if let Some(last) = last {
                             yield_!(Meta::new(
                                 ArcVertex {
                                     point: *last,
                                     sagitta: disk.radius
-                                        - Line(*last, *clip).signed_distance(disk.center),
+                                        - Line(*last, a).signed_distance(disk.center),
                                 },
                                 disk.meta
-                            ))
+                            ));
                         } else {
                             if first.is_none() {
-                                first = Some(clip);
+                                first = Some(Meta::new(a, prev.meta));
                             }
                         }
-
                         yield_!(Meta::new(
                             ArcVertex {
-                                point: *clip,
+                                point: a,
                                 sagitta: 0.0,
                             },
-                            clip.meta
+                            prev.meta
                         ));
+                        last = Some(Meta::new(b, prev.meta));
                     }
-                    (false, false) => match disk.inner.edge().intersect(&LineSegment(*prev, *curr))
-                    {
-                        Some([Some(a), Some(b)]) => {
-                            if let Some(last) = last {
-                                yield_!(Meta::new(
-                                    ArcVertex {
-                                        point: *last,
-                                        sagitta: disk.radius
-                                            - Line(*last, a).signed_distance(disk.center),
-                                    },
-                                    disk.meta
-                                ));
-                            } else {
-                                if first.is_none() {
-                                    first = Some(Meta::new(a, prev.meta));
-                                }
-                            }
+                    _ => {}
+                },
+            };
+            prev_inside = inside;
+            prev = curr;
+        }
+        if let (Some(a), Some(b)) = (first, last) {
+            yield_!(Meta::new(
+                ArcVertex {
+                    point: *b,
+                    sagitta: disk.radius - Line(*b, *a).signed_distance(disk.center),
+                },
+                disk.meta
+            ));
+        }
+    });
+    let mut iter = gen_.into_iter();
+
+    if let Some(mut prev) = iter.next() {
+        // Deduplicate vertices
+        let iter = iter.chain([prev]).filter_map(|curr| {
+            let ret = if (prev.point - curr.point).abs().max_element() > EPS {
+                Some(prev)
+            } else {
+                telemetry::degenerate();
+                None
+            };
+            prev = curr;
+            ret
+        });
+        let mut count = 0;
+        let target = T::from_arc_vertices(iter.inspect(|_| count += 1));
+        telemetry::clip_output(count);
+        Some(target)
+    } else {
+        None
+    }
+}
+
+/// Whole disk as a polygon with metadata.
+fn disk_polygon<M: Copy, T: ArcTarget<Meta<ArcVertex, M>>>(disk: &Meta<Disk, M>) -> T {
+    T::from_arc_vertices(
+        disk.polygon::<2>()
+            .vertices()
+            .map(|x| Meta::new(x, disk.meta)),
+    )
+}
+
+impl<M: Copy, V: CopyIterator<Item = Meta<Vec2, M>> + ?Sized, T: ArcTarget<Meta<ArcVertex, M>>>
+    IntersectTo<Meta<Disk, M>, T> for MetaPolygon<V, M>
+{
+    fn intersect_to(&self, disk: &Meta<Disk, M>) -> Option<T> {
+        clip_polygon(self, disk).or_else(|| {
+            self.frame()
+                .contains(disk.center)
+                .then(|| disk_polygon(disk))
+        })
+    }
+}
+
+impl<M: Copy, V: CopyIterator<Item = Meta<Vec2, M>> + ?Sized, T: ArcTarget<Meta<ArcVertex, M>>>
+    IntersectTo<MetaPolygon<V, M>, T> for Meta<Disk, M>
+{
+    fn intersect_to(&self, other: &MetaPolygon<V, M>) -> Option<T> {
+        other.intersect_to(self)
+    }
+}
+
+impl<M: Copy, V: CopyIterator<Item = Vec2> + ?Sized, T: ArcTarget<Meta<ArcVertex, M>>>
+    IntersectTo<Meta<Disk, M>, T> for Meta<Polygon<V>, M>
+{
+    fn intersect_to(&self, disk: &Meta<Disk, M>) -> Option<T> {
+        self.map_vertices(|x| Meta::new(x, self.meta))
+            .intersect_to(disk)
+    }
+}
+
+impl<M: Copy, V: CopyIterator<Item = Vec2> + ?Sized, T: ArcTarget<Meta<ArcVertex, M>>>
+    IntersectTo<Meta<Polygon<V>, M>, T> for Meta<Disk, M>
+{
+    fn intersect_to(&self, other: &Meta<Polygon<V>, M>) -> Option<T> {
+        other.intersect_to(self)
+    }
+}
+
+/// Convex region that arc polygons can be clipped by.
+trait ClipRegion {
+    /// Check that the `point` is inside the region.
+    fn is_inside(&self, point: Vec2) -> bool;
+    /// Points where the region boundary crosses the `arc`.
+    fn crossings(&self, arc: &Arc) -> [Option<Vec2>; 2];
+    /// Sagitta of the region boundary piece going counterclockwise from `a` to `b`.
+    fn boundary_sagitta(&self, a: Vec2, b: Vec2) -> f32;
+}
+
+impl ClipRegion for HalfPlane {
+    fn is_inside(&self, point: Vec2) -> bool {
+        self.distance(point) < 0.0
+    }
+    fn crossings(&self, arc: &Arc) -> [Option<Vec2>; 2] {
+        match arc.circle() {
+            Some(circle) => circle
+                .intersect(&self.edge())
+                .map_or([None; 2], |ps| ps.map(Some)),
+            None => {
+                let (a, b) = arc.points;
+                let (da, db) = (self.distance(a), self.distance(b));
+                if (da < 0.0) != (db < 0.0) && (da - db).abs() > EPS {
+                    [Some(a.lerp(b, da / (da - db))), None]
+                } else {
+                    [None; 2]
+                }
+            }
+        }
+    }
+    fn boundary_sagitta(&self, _: Vec2, _: Vec2) -> f32 {
+        0.0
+    }
+}
+
+impl ClipRegion for Disk {
+    fn is_inside(&self, point: Vec2) -> bool {
+        self.contains(point)
+    }
+    fn crossings(&self, arc: &Arc) -> [Option<Vec2>; 2] {
+        match arc.circle() {
+            Some(circle) => circle
+                .intersect(&self.edge())
+                .map_or([None; 2], |ps| ps.map(Some)),
+            None => self.edge().intersect(&arc.chord()).unwrap_or([None; 2]),
+        }
+    }
+    fn boundary_sagitta(&self, a: Vec2, b: Vec2) -> f32 {
+        Arc::from_circle_points(self.center, self.radius, a, b, true).sagitta
+    }
+}
+
+/// Part of the polygon edge lying inside of the clip region.
+#[derive(Clone, Copy)]
+struct ClipPiece<M: Copy> {
+    arc: Arc,
+    meta: M,
+}
+
+/// Split the `edge` by the boundary of the `region` and take pieces lying inside of it.
+fn clip_edge<M: Copy, R: ClipRegion>(
+    Meta { inner: edge, meta }: Meta<Arc, M>,
+    region: &R,
+) -> [Option<ClipPiece<M>>; 3] {
+    let mut params = [0.0, 1.0, 1.0, 1.0];
+    let mut n = 1;
+    for point in region.crossings(&edge).into_iter().flatten() {
+        let t = edge.param_of(point);
+        if t > 0.0 && t < 1.0 {
+            params[n] = t;
+            n += 1;
+        }
+    }
+    params[1..n].sort_by(f32::total_cmp);
+
+    let mut pieces = [None; 3];
+    for (i, piece) in pieces.iter_mut().enumerate().take(n) {
+        let (t0, t1) = (params[i], params[i + 1]);
+        if !region.is_inside(edge.point_at(0.5 * (t0 + t1))) {
+            continue;
+        }
+        let arc = if n == 1 {
+            edge
+        } else {
+            let (a, b) = (
+                if i == 0 {
+                    edge.points.0
+                } else {
+                    edge.point_at(t0)
+                },
+                if i + 1 == n {
+                    edge.points.1
+                } else {
+                    edge.point_at(t1)
+                },
+            );
+            edge.sub_arc(a, b)
+        };
+        *piece = Some(ClipPiece { arc, meta });
+    }
+    pieces
+}
+
+/// Clip the polygon by convex region.
+///
+/// Returns `None` if there's no polygon edges inside the region.
+fn clip<M, V, T, R>(polygon: &MetaArcPolygon<V, M>, region: &Meta<R, M>) -> Option<T>
+where
+    M: Copy,
+    V: CopyIterator<Item = Meta<ArcVertex, M>> + ?Sized,
+    T: ArcTarget<Meta<ArcVertex, M>>,
+    R: ClipRegion,
+{
+    let_gen!(gen_, {
+        let mut first: Option<ClipPiece<M>> = None;
+        let mut prev: Option<ClipPiece<M>> = None;
+        for edge in polygon.edges() {
+            for piece in IntoIterator::into_iter(clip_edge(edge, &region.inner)).flatten() {
+                match prev {
+                    Some(prev) => {
+                        let (a, b) = (prev.arc.points.1, piece.arc.points.0);
+                        yield_!(Meta::new(
+                            ArcVertex {
+                                point: prev.arc.points.0,
+                                sagitta: prev.arc.sagitta,
+                            },
+                            prev.meta
+                        ));
+                        if (a - b).abs().max_element() > EPS {
+                            // Connect pieces along the region boundary
                             yield_!(Meta::new(
                                 ArcVertex {
                                     point: a,
-                                    sagitta: 0.0,
+                                    sagitta: region.boundary_sagitta(a, b),
                                 },
-                                prev.meta
+                                region.meta
                             ));
-                            last = Some(Meta::new(b, prev.meta));
                         }
-                        _ => {}
-                    },
-                };
-                prev_inside = inside;
-                prev = curr;
+                    }
+                    None => first = Some(piece),
+                }
+                prev = Some(piece);
             }
-            if let (Some(a), Some(b)) = (first, last) {
+        }
+        if let (Some(prev), Some(first)) = (prev, first) {
+            let (a, b) = (prev.arc.points.1, first.arc.points.0);
+            yield_!(Meta::new(
+                ArcVertex {
+                    point: prev.arc.points.0,
+                    sagitta: prev.arc.sagitta,
+                },
+                prev.meta
+            ));
+            if (a - b).abs().max_element() > EPS {
                 yield_!(Meta::new(
                     ArcVertex {
-                        point: *b,
-                        sagitta: disk.radius - Line(*b, *a).signed_distance(disk.center),
+                        point: a,
+                        sagitta: region.boundary_sagitta(a, b),
                     },
-                    disk.meta
+                    region.meta
                 ));
             }
+        }
+    });
+    let mut iter = gen_.into_iter();
+
+    if let Some(mut prev) = iter.next() {
+        // Deduplicate vertices
+        let iter = iter.chain([prev]).filter_map(|curr| {
+            let ret = if (prev.point - curr.point).abs().max_element() > EPS {
+                Some(prev)
+            } else {
+                telemetry::degenerate();
+                None
+            };
+            prev = curr;
+            ret
         });
-        let mut iter = gen_.into_iter();
+        let mut count = 0;
+        let target = T::from_arc_vertices(iter.inspect(|_| count += 1));
+        telemetry::clip_output(count);
+        Some(target)
+    } else {
+        None
+    }
+}
 
-        if let Some(mut prev) = iter.next() {
-            // Deduplicate vertices
-            let iter = iter.chain([prev]).filter_map(|curr| {
-                let ret = if (prev.point - curr.point).abs().max_element() > EPS {
-                    Some(prev)
-                } else {
-                    None
-                };
-                prev = curr;
-                ret
-            });
-            Some(MetaArcPolygon::<W, M>::from_iter(iter))
-        } else if self.frame().contains(disk.center) {
-            Some(MetaArcPolygon::<W, M>::from_iter(
-                disk.polygon::<2>()
-                    .vertices()
-                    .map(|x| Meta::new(x, disk.meta)),
-            ))
-        } else {
-            None
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized, T: ArcTarget> IntersectTo<HalfPlane, T>
+    for ArcPolygon<V>
+{
+    fn intersect_to(&self, plane: &HalfPlane) -> Option<T> {
+        let Unmeta(target) = clip(
+            &GenericPolygon::new(Unmeta(self.vertices.to_ref())),
+            &Meta::new(*plane, ()),
+        )?;
+        Some(target)
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized, T: ArcTarget> IntersectTo<ArcPolygon<V>, T>
+    for HalfPlane
+{
+    fn intersect_to(&self, other: &ArcPolygon<V>) -> Option<T> {
+        other.intersect_to(self)
+    }
+}
+
+impl<M: Copy, V: CopyIterator<Item = Meta<ArcVertex, M>> + ?Sized, T: ArcTarget<Meta<ArcVertex, M>>>
+    IntersectTo<Meta<HalfPlane, M>, T> for MetaArcPolygon<V, M>
+{
+    fn intersect_to(&self, plane: &Meta<HalfPlane, M>) -> Option<T> {
+        clip(self, plane)
+    }
+}
+
+impl<M: Copy, V: CopyIterator<Item = Meta<ArcVertex, M>> + ?Sized, T: ArcTarget<Meta<ArcVertex, M>>>
+    IntersectTo<MetaArcPolygon<V, M>, T> for Meta<HalfPlane, M>
+{
+    fn intersect_to(&self, other: &MetaArcPolygon<V, M>) -> Option<T> {
+        other.intersect_to(self)
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized, T: DiskTarget> IntersectTo<Disk, T>
+    for ArcPolygon<V>
+{
+    fn intersect_to(&self, disk: &Disk) -> Option<T> {
+        match clip(
+            &GenericPolygon::new(Unmeta(self.vertices.to_ref())),
+            &Meta::new(*disk, ()),
+        ) {
+            Some(Unmeta(target)) => Some(target),
+            None => self.contains(disk.center).then(|| T::from_disk(*disk)),
         }
     }
 }
 
-impl<
-    M: Copy,
-    V: CopyIterator<Item = Meta<Vec2, M>> + ?Sized,
-    W: CopyIterator<Item = Meta<ArcVertex, M>> + FromIterator<Meta<ArcVertex, M>>,
-> IntersectTo<MetaPolygon<V, M>, MetaArcPolygon<W, M>> for Meta<Disk, M>
+impl<V: CopyIterator<Item = ArcVertex> + ?Sized, T: DiskTarget> IntersectTo<ArcPolygon<V>, T>
+    for Disk
 {
-    fn intersect_to(&self, other: &MetaPolygon<V, M>) -> Option<MetaArcPolygon<W, M>> {
+    fn intersect_to(&self, other: &ArcPolygon<V>) -> Option<T> {
         other.intersect_to(self)
     }
 }
 
-impl<
-    M: Copy,
-    V: CopyIterator<Item = Vec2> + ?Sized,
-    W: CopyIterator<Item = Meta<ArcVertex, M>> + FromIterator<Meta<ArcVertex, M>>,
-> IntersectTo<Meta<Disk, M>, MetaArcPolygon<W, M>> for Meta<Polygon<V>, M>
+impl<M: Copy, V: CopyIterator<Item = Meta<ArcVertex, M>> + ?Sized, T: ArcTarget<Meta<ArcVertex, M>>>
+    IntersectTo<Meta<Disk, M>, T> for MetaArcPolygon<V, M>
 {
-    fn intersect_to(&self, disk: &Meta<Disk, M>) -> Option<MetaArcPolygon<W, M>> {
-        self.map_vertices(|x| Meta::new(x, self.meta))
-            .intersect_to(disk)
+    fn intersect_to(&self, disk: &Meta<Disk, M>) -> Option<T> {
+        clip(self, disk).or_else(|| {
+            let inner = GenericPolygon::new(self.vertices.map(|v| v.inner));
+            inner.contains(disk.center).then(|| disk_polygon(disk))
+        })
     }
 }
 
-impl<
-    M: Copy,
-    V: CopyIterator<Item = Vec2> + ?Sized,
-    W: CopyIterator<Item = Meta<ArcVertex, M>> + FromIterator<Meta<ArcVertex, M>>,
-> IntersectTo<Meta<Polygon<V>, M>, MetaArcPolygon<W, M>> for Meta<Disk, M>
+impl<M: Copy, V: CopyIterator<Item = Meta<ArcVertex, M>> + ?Sized, T: ArcTarget<Meta<ArcVertex, M>>>
+    IntersectTo<MetaArcPolygon<V, M>, T> for Meta<Disk, M>
 {
-    fn intersect_to(&self, other: &Meta<Polygon<V>, M>) -> Option<MetaArcPolygon<W, M>> {
+    fn intersect_to(&self, other: &MetaArcPolygon<V, M>) -> Option<T> {
         other.intersect_to(self)
     }
 }
@@ -0,0 +1,100 @@
+use crate::{CopyIterator, Edge, GenericPolygon, Polygon, Vertex};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use glam::{IVec2, Vec2};
+
+/// Line segment with integer endpoints.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct IntegerSegment(pub IVec2, pub IVec2);
+
+impl Edge for IntegerSegment {
+    type Vertex = IVec2;
+    fn from_vertices(a: &Self::Vertex, b: &Self::Vertex) -> Self {
+        IntegerSegment(*a, *b)
+    }
+}
+impl Vertex for IVec2 {
+    type Edge = IntegerSegment;
+    fn pos(&self) -> Vec2 {
+        self.as_vec2()
+    }
+}
+
+/// A polygon with integer vertex coordinates.
+///
+/// Coordinates are measured in grid cells, see [`Polygon::quantize`].
+pub type IntegerPolygon<V> = GenericPolygon<V, IVec2>;
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Snap polygon vertices to the grid with the `cell` size.
+    ///
+    /// Coordinates are rounded to the nearest grid node (half-way cases are rounded away from zero).
+    /// Then degenerate vertices are collapsed until none of them is left:
+    /// the ones that got to the same node as the previous one, so there are no zero-length edges,
+    /// and the ones with collinear incoming and outgoing edges, including the tips of zero-area spikes.
+    /// Collinearity is checked exactly in integer arithmetic.
+    ///
+    /// Returns `None` if less than three vertices left.
+    #[cfg(feature = "alloc")]
+    pub fn quantize<W: CopyIterator<Item = IVec2> + FromIterator<IVec2>>(
+        &self,
+        cell: f32,
+    ) -> Option<IntegerPolygon<W>> {
+        let collinear =
+            |a: IVec2, b: IVec2, c: IVec2| (b - a).as_i64vec2().perp_dot((c - b).as_i64vec2()) == 0;
+        // Removing a vertex may make the previous one degenerate, so they are kept on the stack
+        let mut kept: Vec<IVec2> = Vec::new();
+        for v in self
+            .vertices()
+            .skip(1)
+            .chain(self.vertices().take(1))
+            .map(|v| (v / cell).round().as_ivec2())
+        {
+            loop {
+                match kept[..] {
+                    [.., b] if b == v => break,
+                    [.., a, b] if collinear(a, b, v) => {
+                        kept.pop();
+                    }
+                    _ => {
+                        kept.push(v);
+                        break;
+                    }
+                }
+            }
+        }
+        // Vertices around the start of the contour are checked with the ones at its end
+        let mut start = 0;
+        while kept.len() - start >= 3 {
+            let n = kept.len();
+            if kept[n - 1] == kept[start] || collinear(kept[n - 2], kept[n - 1], kept[start]) {
+                kept.pop();
+            } else if collinear(kept[n - 1], kept[start], kept[start + 1]) {
+                start += 1;
+            } else {
+                break;
+            }
+        }
+        if kept.len() - start < 3 {
+            return None;
+        }
+        Some(kept.drain(start..).collect())
+    }
+}
+
+impl<V: CopyIterator<Item = IVec2> + ?Sized> IntegerPolygon<V> {
+    /// Convert grid coordinates back to the real ones.
+    pub fn dequantize<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        cell: f32,
+    ) -> Polygon<W> {
+        self.vertices().map(|v| v.as_vec2() * cell).collect()
+    }
+
+    /// Exact doubled signed area of the polygon, positive if it is counterclockwise.
+    pub fn double_area(&self) -> i64 {
+        self.edges()
+            .map(|IntegerSegment(a, b)| a.as_i64vec2().perp_dot(b.as_i64vec2()))
+            .sum()
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{
-    Closed, CopyIterator, EPS, FramedPolygon, GenericPolygon, HalfPlane, Integrable, IntersectTo,
-    Line, LineSegment, Meta, Moment, Unmeta,
+    ArcPolygon, ArcVertex, Circle, Closed, CopyIterator, EPS, FramedPolygon, GenericPolygon,
+    HalfPlane, Integrable, IntersectTo, Line, LineSegment, Meta, Moment, Unmeta,
+    plane::clip_params, telemetry,
 };
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{f32::consts::PI, iter};
 use genawaiter::{stack::let_gen, yield_};
-use glam::Vec2;
+use glam::{Mat2, Vec2};
 
 /// A polygon with straight line segment edges.
 ///
@@ -28,6 +32,145 @@ impl<M: Copy, V: CopyIterator<Item = Meta<Vec2, M>> + ?Sized> FramedPolygon for
     }
 }
 
+impl<V: CopyIterator<Item = Vec2> + FromIterator<Vec2>> Polygon<V> {
+    /// Regular counterclockwise polygon with `n` vertices lying on the circle of `radius` around the `center`.
+    ///
+    /// The first vertex is at the `rotation` angle counterclockwise from the X axis.
+    pub fn regular(center: Vec2, radius: f32, n: usize, rotation: f32) -> Self {
+        (0..n)
+            .map(|i| center + radius * Vec2::from_angle(rotation + 2.0 * PI * i as f32 / n as f32))
+            .collect()
+    }
+
+    /// Regular polygon with `n` vertices inscribed into the `circle`, the first vertex lies on the X axis.
+    pub fn from_circle(circle: Circle, n: usize) -> Self {
+        Self::regular(circle.center, circle.radius, n, 0.0)
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Round the corners of the polygon by tangent arcs of the `radius`.
+    ///
+    /// Each corner is replaced by the arc touching both of its edges, convex corners are cut and reflex corners are filled.
+    /// The arc can take no more than a half of each edge, so at short edges the radius is reduced to fit.
+    /// Corners with collinear edges are kept as is.
+    pub fn fillet<W: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>>(
+        &self,
+        radius: f32,
+    ) -> ArcPolygon<W> {
+        // Distance from the corner to the arc ends, and the arc sagitta
+        let corner = |a: Vec2, b: Vec2, c: Vec2| {
+            let (u, v) = (b - a, c - b);
+            let turn = u.angle_to(v);
+            if !turn.is_finite() || u.length() < EPS || v.length() < EPS {
+                return (0.0, 0.0);
+            }
+            let half = 0.5 * turn.abs();
+            let distance = (radius * half.tan())
+                .min(0.5 * u.length())
+                .min(0.5 * v.length());
+            // Sagitta of the arc of `turn` sweep with the radius `distance / tan(half)`
+            let sagitta = turn.signum() * distance * half.cos() * (0.5 * half).tan();
+            (distance, sagitta)
+        };
+        self.vertices_window()
+            .flat_map(|[a, b, c, d]| {
+                let (distance, sagitta) = corner(a, b, c);
+                let (next, _) = corner(b, c, d);
+                let start = ArcVertex {
+                    point: b + (a - b).normalize_or_zero() * distance,
+                    sagitta,
+                };
+                // Straight part of the edge is skipped if arcs take the whole edge
+                let end = (distance > 0.0 && distance + next < (c - b).length() - EPS).then(|| {
+                    ArcVertex {
+                        point: b + (c - b).normalize_or_zero() * distance,
+                        sagitta: 0.0,
+                    }
+                });
+                iter::once(start).chain(end)
+            })
+            .collect()
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Smooth the polygon by cutting its corners `iterations` times using Chaikin's algorithm.
+    ///
+    /// Each iteration replaces every edge by two points at its quarters, so the number of vertices doubles.
+    /// The result converges to the quadratic B-spline of the original vertices and stays inside of their convex hull.
+    #[cfg(feature = "alloc")]
+    pub fn smooth_chaikin<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        iterations: usize,
+    ) -> Polygon<W> {
+        let mut points: Vec<Vec2> = self.vertices().collect();
+        for _ in 0..iterations {
+            points = Polygon::new(points.to_ref())
+                .edges()
+                .flat_map(|LineSegment(a, b)| [a.lerp(b, 0.25), a.lerp(b, 0.75)])
+                .collect();
+        }
+        points.into_iter().collect()
+    }
+
+    /// Resample the polygon along the centripetal Catmull–Rom spline going through its vertices.
+    ///
+    /// Each edge is replaced by `segments` pieces of the spline, and the original vertices are kept.
+    /// Centripetal parametrization doesn't produce loops and cusps at short edges, unlike the uniform one.
+    pub fn catmull_rom<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        segments: usize,
+    ) -> Polygon<W> {
+        let segments = segments.max(1);
+        // Windows are rotated, so that the first piece starts at the first vertex
+        let n = self.vertices().count();
+        let windows = self.vertices_window().skip(n.saturating_sub(1));
+        windows
+            .chain(self.vertices_window().take(n.saturating_sub(1)))
+            .flat_map(|[p0, p1, p2, p3]| {
+                // Knot intervals are square roots of the edge lengths, coincident points are given unit interval
+                let interval = |a: Vec2, b: Vec2| match a.distance(b).sqrt() {
+                    d if d < EPS => 1.0,
+                    d => d,
+                };
+                let (d0, d1, d2) = (interval(p0, p1), interval(p1, p2), interval(p2, p3));
+                // Tangents at the edge ends scaled to the edge parameter
+                let m1 = d1 * ((p1 - p0) / d0 - (p2 - p0) / (d0 + d1) + (p2 - p1) / d1);
+                let m2 = d1 * ((p2 - p1) / d1 - (p3 - p1) / (d1 + d2) + (p3 - p2) / d2);
+                (0..segments).map(move |i| {
+                    let t = i as f32 / segments as f32;
+                    let (t2, t3) = (t * t, t * t * t);
+                    (2.0 * t3 - 3.0 * t2 + 1.0) * p1
+                        + (t3 - 2.0 * t2 + t) * m1
+                        + (3.0 * t2 - 2.0 * t3) * p2
+                        + (t3 - t2) * m2
+                })
+            })
+            .collect()
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Cut the polygon by the `line` into the parts lying on its left and right sides.
+    ///
+    /// Each part is the polygon clipped by the half-plane on the corresponding side, see [`HalfPlane::from_edge`],
+    /// and is `None` if nothing of the polygon lies there.
+    /// When the line crosses a concave polygon many times, disjoint pieces on the same side
+    /// are joined by zero-width bridges along the line into a single contour, like in [`slice`](crate::slice).
+    /// The `line` points must differ.
+    pub fn split<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        line: &Line,
+    ) -> (Option<Polygon<W>>, Option<Polygon<W>>) {
+        let left = HalfPlane::from_edge(*line);
+        (
+            self.intersect_to(&left),
+            self.intersect_to(&left.complement()),
+        )
+    }
+}
+
 impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
     /// Check if the polygon is convex.
     ///
@@ -56,33 +199,117 @@ impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
     }
 }
 
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Get the point of the polygon boundary closest to the given `point`.
+    ///
+    /// Returns `None` if the polygon is empty.
+    pub fn closest_point(&self, point: Vec2) -> Option<Vec2> {
+        self.edges()
+            .map(|edge| edge.closest_point(point))
+            .min_by(|a, b| {
+                (*a - point)
+                    .length_squared()
+                    .total_cmp(&(*b - point).length_squared())
+            })
+    }
+
+    /// Area of the polygon, positive if it is counterclockwise and negative if clockwise.
+    pub fn signed_area(&self) -> f32 {
+        0.5 * self
+            .edges()
+            .map(|LineSegment(a, b)| a.perp_dot(b))
+            .sum::<f32>()
+    }
+
+    /// Check that the polygon is counterclockwise, i.e. has positive area.
+    pub fn is_ccw(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    /// Reverse the order of vertices, making the polygon orientation opposite.
+    pub fn reverse(&mut self)
+    where
+        V: AsMut<[Vec2]>,
+    {
+        self.vertices.as_mut().reverse();
+    }
+
+    /// Total length of the polygon edges.
+    pub fn perimeter(&self) -> f32 {
+        self.edges().map(|edge| edge.vec().length()).sum()
+    }
+
+    /// Second moment of area (inertia tensor) about the centroid.
+    ///
+    /// Returns matrix `[[Ixx, Ixy], [Ixy, Iyy]]` where `Ixx = ∫(x - cx)² dA` and so on.
+    /// The moment is negative if the polygon is clockwise.
+    pub fn second_moment(&self) -> Mat2 {
+        let (mut area, mut first) = (0.0, Vec2::ZERO);
+        let (mut xx, mut yy, mut xy) = (0.0, 0.0, 0.0);
+        for LineSegment(a, b) in self.edges() {
+            let cross = a.perp_dot(b);
+            area += cross / 2.0;
+            first += (a + b) * cross / 6.0;
+            xx += cross * (a.x * a.x + a.x * b.x + b.x * b.x) / 12.0;
+            yy += cross * (a.y * a.y + a.y * b.y + b.y * b.y) / 12.0;
+            xy += cross * (2.0 * a.x * a.y + a.x * b.y + b.x * a.y + 2.0 * b.x * b.y) / 24.0;
+        }
+        let origin = Mat2::from_cols(Vec2::new(xx, xy), Vec2::new(xy, yy));
+        if area.abs() < EPS {
+            return origin;
+        }
+        // Parallel axis theorem
+        origin - Mat2::from_cols(first * first.x, first * first.y) / area
+    }
+}
+
 impl<V: CopyIterator<Item = Vec2> + ?Sized> Closed for Polygon<V> {
     fn winding_number_2(&self, point: Vec2) -> i32 {
-        let mut winding_number = 0;
-
-        for LineSegment(v0, v1) in self.edges() {
-            // Test if edge crosses the horizontal line at point.y
-            if v0.y <= point.y {
-                if v1.y > point.y {
-                    // Upward crossing - check if point is left of edge
-                    if (v1 - v0).perp_dot(point - v0) > 0.0 {
-                        winding_number += 1;
-                    }
-                }
-            } else if v1.y <= point.y {
-                // Downward crossing - check if point is right of edge
-                if (v1 - v0).perp_dot(point - v0) < 0.0 {
-                    winding_number -= 1;
+        #[cfg(feature = "simd")]
+        {
+            super::simd::winding_number_2(self.edges(), point)
+        }
+        #[cfg(not(feature = "simd"))]
+        {
+            scalar_winding_number_2(self.edges(), point)
+        }
+    }
+}
+
+/// Winding number of the closed sequence of `edges` around the `point`.
+pub(crate) fn scalar_winding_number_2<I: Iterator<Item = LineSegment>>(
+    edges: I,
+    point: Vec2,
+) -> i32 {
+    let mut winding_number = 0;
+
+    for LineSegment(v0, v1) in edges {
+        // Test if edge crosses the horizontal line at point.y
+        if v0.y <= point.y {
+            if v1.y > point.y {
+                // Upward crossing - check if point is left of edge
+                if (v1 - v0).perp_dot(point - v0) > 0.0 {
+                    winding_number += 1;
                 }
             }
+        } else if v1.y <= point.y {
+            // Downward crossing - check if point is right of edge.
+            // Computed from the lower end so that the reversed edge gives exactly the opposite result.
+            if (v0 - v1).perp_dot(point - v1) > 0.0 {
+                winding_number -= 1;
+            }
         }
-
-        winding_number
     }
+
+    winding_number
 }
 
-impl<V: CopyIterator<Item = Vec2> + ?Sized> Integrable for Polygon<V> {
-    fn moment(&self) -> Moment {
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Moment of the polygon preserving its orientation.
+    ///
+    /// The area is negative for clockwise polygons while the centroid stays the same,
+    /// so that merging such moment with the enclosing one subtracts a hole from it.
+    pub fn signed_moment(&self) -> Moment {
         // Shoelace formula
         let mut area = 0.0;
         let mut centroid = Vec2::ZERO;
@@ -92,7 +319,7 @@ impl<V: CopyIterator<Item = Vec2> + ?Sized> Integrable for Polygon<V> {
             centroid += (a + b) * cross;
         }
         area *= 0.5;
-        if area < EPS {
+        if area.abs() < EPS {
             centroid = Vec2::ZERO;
         } else {
             centroid /= 6.0 * area;
@@ -101,6 +328,13 @@ impl<V: CopyIterator<Item = Vec2> + ?Sized> Integrable for Polygon<V> {
     }
 }
 
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Integrable for Polygon<V> {
+    /// Same as [`Polygon::signed_moment`].
+    fn moment(&self) -> Moment {
+        self.signed_moment()
+    }
+}
+
 impl<V: CopyIterator<Item = Vec2> + ?Sized, W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>
     IntersectTo<HalfPlane, Polygon<W>> for Polygon<V>
 {
@@ -120,6 +354,81 @@ impl<V: CopyIterator<Item = Vec2> + ?Sized, W: CopyIterator<Item = Vec2> + FromI
     }
 }
 
+/// Points where the line crosses the polygon boundary, ordered along the line.
+///
+/// Vertices are classified by the side of the line, the ones lying on it are counted as lying on the right side,
+/// so the vertex where the boundary passes through the line is yielded once,
+/// and the number of points is always even.
+/// For a simple polygon each consecutive pair of points is the chord lying inside of it,
+/// which is degenerate where the boundary touches the line from the left.
+/// Returns `None` if the line doesn't cross the polygon.
+#[cfg(feature = "alloc")]
+impl<V: CopyIterator<Item = Vec2> + ?Sized, W: FromIterator<Vec2>> IntersectTo<Polygon<V>, W>
+    for Line
+{
+    fn intersect_to(&self, polygon: &Polygon<V>) -> Option<W> {
+        let direction = self.1 - self.0;
+        let side = |p: Vec2| direction.perp_dot(p - self.0);
+        let mut points: Vec<(f32, Vec2)> = polygon
+            .edges()
+            .filter_map(|LineSegment(a, b)| {
+                let (da, db) = (side(a), side(b));
+                if (da > 0.0) == (db > 0.0) {
+                    return None;
+                }
+                let p = a.lerp(b, da / (da - db));
+                Some(((p - self.0).dot(direction), p))
+            })
+            .collect();
+        if points.is_empty() {
+            return None;
+        }
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Some(points.into_iter().map(|(_, p)| p).collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: CopyIterator<Item = Vec2> + ?Sized, W: FromIterator<Vec2>> IntersectTo<Line, W>
+    for Polygon<V>
+{
+    fn intersect_to(&self, line: &Line) -> Option<W> {
+        line.intersect_to(self)
+    }
+}
+
+/// Part of the segment lying inside of the convex counterclockwise polygon, in the same direction as the segment.
+///
+/// The segment is clipped by the half-planes of all edges in turn, so the polygon must be convex.
+impl<V: CopyIterator<Item = Vec2> + ?Sized> IntersectTo<Polygon<V>, LineSegment> for LineSegment {
+    fn intersect_to(&self, polygon: &Polygon<V>) -> Option<LineSegment> {
+        if polygon.is_empty() {
+            return None;
+        }
+        let mut range = (0.0, 1.0);
+        for edge in polygon.edges() {
+            if edge.is_degenerate() {
+                continue;
+            }
+            range = clip_params(self, &HalfPlane::from_edge(edge.line()), range);
+            if range.0 > range.1 {
+                return None;
+            }
+        }
+        let (t0, t1) = range;
+        Some(LineSegment(
+            self.0.lerp(self.1, t0),
+            self.0.lerp(self.1, t1),
+        ))
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> IntersectTo<LineSegment, LineSegment> for Polygon<V> {
+    fn intersect_to(&self, segment: &LineSegment) -> Option<LineSegment> {
+        segment.intersect_to(self)
+    }
+}
+
 impl<
     M: Copy,
     V: CopyIterator<Item = Meta<Vec2, M>> + ?Sized,
@@ -149,6 +458,7 @@ impl<
                         let sum_dist = dist - prev_dist;
                         yield_!(Meta::new(
                             if sum_dist < EPS {
+                                telemetry::eps_rescue();
                                 0.5 * (*prev + *curr)
                             } else {
                                 (*prev * dist - *curr * prev_dist) / sum_dist
@@ -163,6 +473,7 @@ impl<
                         let sum_dist = prev_dist - dist;
                         yield_!(Meta::new(
                             if sum_dist < EPS {
+                                telemetry::eps_rescue();
                                 0.5 * (*prev + *curr)
                             } else {
                                 (*curr * prev_dist - *prev * dist) / sum_dist
@@ -186,12 +497,16 @@ impl<
                 let ret = if (*prev - *curr).abs().max_element() > EPS {
                     Some(curr)
                 } else {
+                    telemetry::degenerate();
                     None
                 };
                 prev = curr;
                 ret
             });
-            Some(MetaPolygon::<W, M>::from_iter(iter))
+            let mut count = 0;
+            let polygon = MetaPolygon::<W, M>::from_iter(iter.inspect(|_| count += 1));
+            telemetry::clip_output(count);
+            Some(polygon)
         } else {
             None
         }
@@ -1,5 +1,8 @@
 pub mod circle;
+pub mod integer;
 pub mod line;
+#[cfg(feature = "simd")]
+mod simd;
 
 use crate::{CopyIterator, EPS, Edge, Integrable, Polygon, Vertex};
 use core::{
@@ -90,6 +93,9 @@ impl<T: Vertex, V: CopyIterator<Item = T> + ?Sized> GenericPolygon<V, T> {
             .map(|[a, b]| T::Edge::from_vertices(&a, &b))
     }
 
+    /// Get a lazy view of the polygon with the `f` applied to each vertex.
+    ///
+    /// Vertices are not stored, the `f` is called every time the polygon is iterated.
     pub fn map_vertices<'a, U: Vertex + 'a, F: Fn(T) -> U + 'a>(
         &'a self,
         f: F,
@@ -0,0 +1,47 @@
+use super::line::scalar_winding_number_2;
+use crate::LineSegment;
+use glam::{Vec2, Vec4};
+
+/// Number of edges processed per iteration.
+const LANES: usize = 4;
+
+/// Vectorized version of [`scalar_winding_number_2`].
+///
+/// Edges are gathered into lanes of [`Vec4`] coordinates, the remaining ones are processed one by one.
+/// Comparisons and cross products are the same as in the scalar version, so results are identical.
+pub fn winding_number_2<I: Iterator<Item = LineSegment>>(mut edges: I, point: Vec2) -> i32 {
+    let (px, py) = (Vec4::splat(point.x), Vec4::splat(point.y));
+    let mut winding_number = 0;
+    loop {
+        let mut lanes = [[0.0; LANES]; 4];
+        let mut n = 0;
+        for LineSegment(v0, v1) in edges.by_ref().take(LANES) {
+            for (lane, value) in lanes.iter_mut().zip([v0.x, v0.y, v1.x, v1.y]) {
+                lane[n] = value;
+            }
+            n += 1;
+        }
+        if n < LANES {
+            return winding_number
+                + scalar_winding_number_2(
+                    (0..n).map(|i| {
+                        LineSegment(
+                            Vec2::new(lanes[0][i], lanes[1][i]),
+                            Vec2::new(lanes[2][i], lanes[3][i]),
+                        )
+                    }),
+                    point,
+                );
+        }
+
+        let [x0, y0, x1, y1] = lanes.map(Vec4::from_array);
+        let upward = y0.cmple(py) & y1.cmpgt(py);
+        let downward = y0.cmpgt(py) & y1.cmple(py);
+        // Same as `(v1 - v0).perp_dot(point - v0)` and `(v0 - v1).perp_dot(point - v1)`
+        let up_cross = (x1 - x0) * (py - y0) - (y1 - y0) * (px - x0);
+        let down_cross = (x0 - x1) * (py - y1) - (y0 - y1) * (px - x1);
+        let zero = Vec4::ZERO;
+        winding_number += (upward & up_cross.cmpgt(zero)).bitmask().count_ones() as i32;
+        winding_number -= (downward & down_cross.cmpgt(zero)).bitmask().count_ones() as i32;
+    }
+}
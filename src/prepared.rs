@@ -0,0 +1,98 @@
+use crate::{Aabb, Boundary, BoundaryEdge, Bounded, EPS, GridIndex, grid::square_size};
+use alloc::{vec, vec::Vec};
+use glam::{UVec2, Vec2};
+
+/// Shape boundary with its edges indexed for fast proximity queries.
+///
+/// The bounding boxes of the edges are cached and indexed by a [`GridIndex`] of about one cell per edge,
+/// so a query checks only the edges near the point instead of the whole boundary.
+/// This makes hit-testing and snapping to the boundaries of big polygons and arc polygons cheap,
+/// when the same shape is queried many times.
+///
+/// Created by [`PreparedBoundary::new`], the shape itself is not referred to.
+#[derive(Clone, Debug)]
+pub struct PreparedBoundary {
+    edges: Vec<BoundaryEdge>,
+    boxes: Vec<Aabb>,
+    bounds: Aabb,
+    size: UVec2,
+    offsets: Vec<usize>,
+    entries: Vec<usize>,
+}
+
+impl PreparedBoundary {
+    /// Index the boundary of the `shape`.
+    ///
+    /// Takes `O(n + m)` time, where `m` is the total number of the grid cells overlapped by the edges boxes.
+    pub fn new<S: Boundary + ?Sized>(shape: &S) -> Self {
+        let edges: Vec<BoundaryEdge> = shape.boundary().collect();
+        let boxes: Vec<Aabb> = edges.iter().map(|edge| edge.aabb()).collect();
+        let bounds = boxes.iter().fold(Aabb::EMPTY, |a, b| a.union(*b));
+        let size = square_size(edges.len());
+        let (offsets_len, entries_len) = GridIndex::storage_len(bounds, size, &boxes);
+        let (mut offsets, mut entries) = (vec![0; offsets_len], vec![0; entries_len]);
+        // Storage is sized as required, so the index is always built
+        let _ = GridIndex::new(bounds, size, &boxes, &mut offsets, &mut entries);
+        Self {
+            edges,
+            boxes,
+            bounds,
+            size,
+            offsets,
+            entries,
+        }
+    }
+
+    /// Edges of the boundary in the order given by [`Boundary::boundary`].
+    pub fn edges(&self) -> &[BoundaryEdge] {
+        &self.edges
+    }
+
+    /// Index of the edges bounding boxes, box indices are the edge indices.
+    pub fn index(&self) -> GridIndex<'_> {
+        GridIndex::from_parts(
+            self.bounds,
+            self.size,
+            &self.boxes,
+            &self.offsets,
+            &self.entries,
+        )
+    }
+
+    /// Indices of the edges which boxes are within the `distance` from the `point`, in unspecified order.
+    fn candidates(&self, point: Vec2, distance: f32) -> impl Iterator<Item = usize> + '_ {
+        let region = Aabb::new(point - distance, point + distance);
+        self.index().query_region(region)
+    }
+
+    /// Index of the edge closest to the `point` and its closest point, if it is within the `distance`.
+    ///
+    /// Useful for snapping, the `distance` is the snapping radius.
+    pub fn closest_within(&self, point: Vec2, distance: f32) -> Option<(usize, Vec2)> {
+        self.candidates(point, distance)
+            .map(|i| (i, self.edges[i].closest_point(point)))
+            .filter(|(_, closest)| closest.distance(point) <= distance)
+            .min_by(|(i, a), (j, b)| {
+                a.distance(point)
+                    .total_cmp(&b.distance(point))
+                    .then(i.cmp(j))
+            })
+    }
+
+    /// Check that the `point` is within the `distance` from the boundary.
+    pub fn is_within(&self, point: Vec2, distance: f32) -> bool {
+        self.candidates(point, distance)
+            .any(|i| self.edges[i].closest_point(point).distance(point) <= distance)
+    }
+
+    /// Check that the `point` is within EPS-neighbourhood of the boundary.
+    pub fn is_near(&self, point: Vec2) -> bool {
+        self.is_within(point, EPS)
+    }
+}
+
+impl Bounded for PreparedBoundary {
+    fn aabb(&self) -> Aabb {
+        self.bounds
+    }
+}
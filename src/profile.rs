@@ -0,0 +1,228 @@
+use crate::{CopyIterator, EPS, Line, Polygon};
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+use glam::Vec2;
+
+/// Area of the polygon part lying inside of the half-plane as a function of the half-plane offset.
+///
+/// Width of the polygon cross-section is piecewise linear in the offset with breaks at the vertices,
+/// so the area is piecewise quadratic and is evaluated exactly in `O(log n)` time.
+#[derive(Clone, PartialEq, Debug)]
+pub struct AreaProfile {
+    /// Vertex projections onto the normal in ascending order, each one starts a piece.
+    offsets: Vec<f32>,
+    /// Area, cross-section width and its derivative at the start of each piece.
+    pieces: Vec<(f32, f32, f32)>,
+}
+
+/// Profile of the area of the `polygon` clipped by the half-plane with the `normal` pointing outside.
+///
+/// The `normal` is normalized, so the offsets are distances along it like [`HalfPlane::offset`](crate::HalfPlane::offset).
+/// Profile is computed once from the sorted vertex projections in `O(n log n)` time.
+/// The polygon must be counterclockwise, otherwise the areas are negative.
+pub fn area_profile<V: CopyIterator<Item = Vec2> + ?Sized>(
+    polygon: &Polygon<V>,
+    normal: Vec2,
+) -> AreaProfile {
+    let normal = normal.normalize_or_zero();
+    // Coordinate along the cross-section, so that it goes to the right when the normal points up
+    let tangent = -normal.perp();
+    // Each edge changes the width and its slope when the offset passes its ends
+    let mut events: Vec<(f32, f32, f32)> = Vec::new();
+    for edge in polygon.edges() {
+        let (a, b) = (edge.0, edge.1);
+        let (ya, yb) = (a.dot(normal), b.dot(normal));
+        if (yb - ya).abs() < EPS {
+            continue;
+        }
+        // Edges going up are on the right side of the counterclockwise polygon and add to the width
+        let sign = (yb - ya).signum();
+        let ((y0, x0), (y1, x1)) = if ya < yb {
+            ((ya, a.dot(tangent)), (yb, b.dot(tangent)))
+        } else {
+            ((yb, b.dot(tangent)), (ya, a.dot(tangent)))
+        };
+        let slope = sign * (x1 - x0) / (y1 - y0);
+        events.push((y0, sign * x0, slope));
+        events.push((y1, -sign * x1, -slope));
+    }
+    events.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut offsets = Vec::new();
+    let mut pieces: Vec<(f32, f32, f32)> = Vec::new();
+    for (y, jump, change) in events {
+        let (area, width, slope) = match (offsets.last(), pieces.last()) {
+            (Some(&y0), Some(&(area, width, slope))) => {
+                let d = y - y0;
+                (
+                    area + width * d + 0.5 * slope * d * d,
+                    width + slope * d,
+                    slope,
+                )
+            }
+            _ => (0.0, 0.0, 0.0),
+        };
+        if offsets.last() == Some(&y) {
+            let piece = pieces.last_mut().unwrap();
+            piece.1 += jump;
+            piece.2 += change;
+        } else {
+            offsets.push(y);
+            pieces.push((area, width + jump, slope + change));
+        }
+    }
+    // The polygon is fully inside after the last vertex
+    if let Some(piece) = pieces.last_mut() {
+        *piece = (piece.0, 0.0, 0.0);
+    }
+    AreaProfile { offsets, pieces }
+}
+
+impl AreaProfile {
+    /// Range of offsets where the half-plane boundary crosses the polygon.
+    ///
+    /// Returns `None` if the polygon is empty.
+    pub fn range(&self) -> Option<(f32, f32)> {
+        Some((*self.offsets.first()?, *self.offsets.last()?))
+    }
+
+    /// Total area of the polygon.
+    pub fn total(&self) -> f32 {
+        self.pieces.last().map_or(0.0, |piece| piece.0)
+    }
+
+    /// Piece containing the `offset` and the distance from its start.
+    fn piece(&self, offset: f32) -> Option<((f32, f32, f32), f32)> {
+        let i = self.offsets.partition_point(|&y| y <= offset);
+        if i == 0 {
+            None
+        } else {
+            Some((self.pieces[i - 1], offset - self.offsets[i - 1]))
+        }
+    }
+
+    /// Area of the polygon part inside of the half-plane with the `offset`.
+    pub fn area(&self, offset: f32) -> f32 {
+        match self.piece(offset) {
+            Some(((area, width, slope), d)) => area + width * d + 0.5 * slope * d * d,
+            None => 0.0,
+        }
+    }
+
+    /// Length of the polygon cross-section by the half-plane boundary with the `offset`, i.e. the derivative of the area.
+    pub fn width(&self, offset: f32) -> f32 {
+        match self.piece(offset) {
+            Some(((_, width, slope), d)) => width + slope * d,
+            None => 0.0,
+        }
+    }
+
+    /// The least offset where the area inside of the half-plane reaches the `area`.
+    ///
+    /// The area is clamped to the polygon one, e.g. `profile.offset_of(0.5 * profile.total())` cuts the polygon in halves.
+    /// Returns `None` if the polygon is empty.
+    pub fn offset_of(&self, area: f32) -> Option<f32> {
+        if self.pieces.is_empty() {
+            return None;
+        }
+        let i = self
+            .pieces
+            .partition_point(|piece| piece.0 < area)
+            .clamp(1, self.pieces.len());
+        let (y0, (a0, width, slope)) = (self.offsets[i - 1], self.pieces[i - 1]);
+        let a = area - a0;
+        if a <= 0.0 {
+            return Some(y0);
+        }
+        // Root of `0.5 * slope * d^2 + width * d - a`, written in the form stable for small slope
+        let d = 2.0 * a / (width + (width * width + 2.0 * slope * a).max(0.0).sqrt());
+        let end = self.offsets.get(i).copied().unwrap_or(y0);
+        Some((y0 + d).min(end))
+    }
+}
+
+/// Line of the `direction` cutting the `polygon` into two parts of equal area.
+///
+/// The line goes along the `direction` and the parts lying on its left and right sides have the same area.
+/// Returns `None` if the `direction` is zero or the polygon is empty.
+/// The polygon must be counterclockwise.
+pub fn bisect<V: CopyIterator<Item = Vec2> + ?Sized>(
+    polygon: &Polygon<V>,
+    direction: Vec2,
+) -> Option<Line> {
+    equal_area_partition(polygon, 2, direction).next()
+}
+
+/// Lines of the `direction` cutting the `polygon` into `count` parts of equal area.
+///
+/// Lines go along the `direction` and are yielded from the left to the right,
+/// so the area on the left of the `i`-th line is `(i + 1) / count` of the polygon area.
+/// Yields nothing if the `direction` is zero or the polygon is empty, otherwise yields `count - 1` lines.
+/// The profile is computed once, so it takes `O(n log n + count log n)` time.
+/// The polygon must be counterclockwise.
+pub fn equal_area_partition<V: CopyIterator<Item = Vec2> + ?Sized>(
+    polygon: &Polygon<V>,
+    count: usize,
+    direction: Vec2,
+) -> impl Iterator<Item = Line> + use<V> {
+    let direction = direction.normalize_or_zero();
+    // Left side of the line is inside of the half-plane
+    let normal = -direction.perp();
+    let profile = area_profile(polygon, normal);
+    let total = profile.total();
+    let cuts = if direction == Vec2::ZERO {
+        0
+    } else {
+        count.saturating_sub(1)
+    };
+    (1..=cuts).map_while(move |i| {
+        let offset = profile.offset_of(total * i as f32 / count as f32)?;
+        let point = offset * normal;
+        Some(Line(point, point + direction))
+    })
+}
+
+/// Line cutting both polygons `a` and `b` into two parts of equal area at once.
+///
+/// Such a line always exists by the ham sandwich theorem.
+/// The line bisecting `a` is found for each direction, and the direction is searched by bisection
+/// over a half turn, since turning the line by a half turn swaps the sides of `b`.
+/// Each step builds the area profiles of both polygons, so it takes `O(n log n)` time for a fixed number of steps.
+/// Returns `None` if any of the polygons is empty.
+/// Polygons must be counterclockwise.
+pub fn bisect_two<U, V>(a: &Polygon<U>, b: &Polygon<V>) -> Option<Line>
+where
+    U: CopyIterator<Item = Vec2> + ?Sized,
+    V: CopyIterator<Item = Vec2> + ?Sized,
+{
+    // Area of `b` on the left of the bisector of `a` minus the half of `b`
+    let imbalance = |angle: f32| -> Option<(Line, f32)> {
+        let direction = Vec2::from_angle(angle);
+        let line = bisect(a, direction)?;
+        let normal = -direction.perp();
+        let profile = area_profile(b, normal);
+        Some((
+            line,
+            profile.area(line.0.dot(normal)) - 0.5 * profile.total(),
+        ))
+    };
+    let (mut lower, mut upper) = (0.0, PI);
+    let (mut line, start) = imbalance(lower)?;
+    if start == 0.0 {
+        return Some(line);
+    }
+    // Each step halves the interval, so the angle reaches the float precision
+    for _ in 0..32 {
+        let middle = 0.5 * (lower + upper);
+        let (l, value) = imbalance(middle)?;
+        line = l;
+        if value == 0.0 {
+            break;
+        } else if (value > 0.0) == (start > 0.0) {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+    Some(line)
+}
@@ -0,0 +1,76 @@
+use crate::{Closed, Integrable, Moment};
+use glam::Vec2;
+
+/// Shape consisting of multiple contours.
+///
+/// Contours are stored in any container `C` which reference can be iterated over, e.g. `[P; N]` or `Vec<P>`.
+///
+/// Contours are expected not to intersect each other.
+/// Disjoint components are counterclockwise, while holes can be represented by
+/// clockwise contours placed inside of them.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct Region<C: ?Sized> {
+    /// Contours of the region.
+    pub contours: C,
+}
+
+impl<C> Region<C> {
+    /// Create a new region from the contours.
+    pub fn new(contours: C) -> Self {
+        Self { contours }
+    }
+}
+
+impl<C: ?Sized> Region<C> {
+    /// Iterate over the region contours.
+    pub fn components<'a>(&'a self) -> <&'a C as IntoIterator>::IntoIter
+    where
+        &'a C: IntoIterator,
+    {
+        self.contours.into_iter()
+    }
+
+    /// Number of contours in the region.
+    pub fn len<'a>(&'a self) -> usize
+    where
+        &'a C: IntoIterator,
+    {
+        self.components().count()
+    }
+
+    /// Check that the region has no contours.
+    pub fn is_empty<'a>(&'a self) -> bool
+    where
+        &'a C: IntoIterator,
+    {
+        self.components().next().is_none()
+    }
+}
+
+impl<P, C: FromIterator<P>> FromIterator<P> for Region<C> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        Self::new(C::from_iter(iter))
+    }
+}
+
+impl<C: ?Sized> Closed for Region<C>
+where
+    for<'a> &'a C: IntoIterator<Item: Closed>,
+{
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        self.components()
+            .map(|contour| contour.winding_number_2(point))
+            .sum()
+    }
+}
+
+impl<C: ?Sized> Integrable for Region<C>
+where
+    for<'a> &'a C: IntoIterator<Item: Integrable>,
+{
+    fn moment(&self) -> Moment {
+        self.components()
+            .map(|contour| contour.moment())
+            .fold(Moment::default(), Moment::merge)
+    }
+}
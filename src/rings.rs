@@ -0,0 +1,95 @@
+use crate::{
+    Aabb, Closed, CopyIterator, FillRule, LineSegment, Polygon, Region, accrete::link,
+    nesting_tree, segment_intersections,
+};
+use alloc::{vec, vec::Vec};
+use glam::Vec2;
+
+/// Relative distance at which edge sides are probed, and intersections are snapped to the edge ends.
+const PROBE: f32 = 1e-5;
+
+/// Convert arbitrary rings, e.g. the subpaths of an imported SVG path, into disjoint regions with holes.
+///
+/// Rings may be of any orientation, they may overlap, cross themselves and each other, and be nested.
+/// Points inside of the result are those which winding number around all the `rings` is filled by the `rule`,
+/// where a counterclockwise ring winds the points inside of it once and a clockwise one minus once.
+///
+/// Each region has the counterclockwise outer contour first, followed by the clockwise contours of its holes.
+/// Islands inside of the holes are separate regions. Regions and holes are in unspecified order.
+///
+/// Ring edges are split at the points where they cross, and the parts separating filled points from unfilled ones are kept.
+/// The parts are joined into contours which are grouped into regions by [`nesting_tree`].
+/// Takes `O((n + k) log n + m n)` time, where `n` is the number of the ring vertices,
+/// `k` is the number of crossings and `m` is the number of the edge parts.
+pub fn resolve_rings<V: CopyIterator<Item = Vec2>>(
+    rings: &[Polygon<V>],
+    rule: FillRule,
+) -> Vec<Region<Vec<Polygon<Vec<Vec2>>>>> {
+    let rings: Vec<&Polygon<V>> = rings
+        .iter()
+        .filter(|ring| ring.vertices().nth(2).is_some())
+        .collect();
+    let edges: Vec<LineSegment> = rings.iter().flat_map(|ring| ring.edges()).collect();
+    let bounds = Aabb::from_points(edges.iter().map(|edge| edge.0));
+    let probe = PROBE * bounds.size().max_element();
+    let filled = |point: Vec2| {
+        // Winding number of the polygon is not doubled
+        let winding: i32 = rings.iter().map(|ring| ring.winding_number_2(point)).sum();
+        rule.fills(winding)
+    };
+
+    let mut splits: Vec<Vec<Vec2>> = vec![Vec::new(); edges.len()];
+    for (i, j, point) in segment_intersections(edges.iter().copied()) {
+        // Point close to an edge end is replaced by it, so both edges are split at exactly the same point
+        let point = [edges[i].0, edges[i].1, edges[j].0, edges[j].1]
+            .into_iter()
+            .find(|end| end.distance(point) < probe)
+            .unwrap_or(point);
+        splits[i].push(point);
+        splits[j].push(point);
+    }
+
+    let mut kept = Vec::new();
+    for (i, edge) in edges.iter().enumerate() {
+        let mut points = core::mem::take(&mut splits[i]);
+        points.push(edge.0);
+        points.push(edge.1);
+        let direction = edge.1 - edge.0;
+        points.sort_by(|a, b| {
+            (*a - edge.0)
+                .dot(direction)
+                .total_cmp(&(*b - edge.0).dot(direction))
+        });
+        points.dedup();
+        for pair in points.windows(2) {
+            let part = LineSegment(pair[0], pair[1]);
+            let (middle, normal) = (part.center(), direction.perp().normalize_or_zero());
+            // Parts are directed so that the filled side is on the left
+            match (
+                filled(middle + normal * probe),
+                filled(middle - normal * probe),
+            ) {
+                (true, false) => kept.push(part),
+                (false, true) => kept.push(LineSegment(part.1, part.0)),
+                _ => (),
+            }
+        }
+    }
+    // Coinciding edges of different rings give the same part several times
+    let key = |p: Vec2| (p.x.to_bits(), p.y.to_bits());
+    kept.sort_by_key(|part| (key(part.0), key(part.1)));
+    kept.dedup();
+
+    let contours = link(kept);
+    let tree = nesting_tree(&contours);
+    (0..contours.len())
+        .filter(|&i| contours[i].is_ccw())
+        .map(|i| {
+            let holes = tree.children(i).filter(|&j| !contours[j].is_ccw());
+            [i].into_iter()
+                .chain(holes)
+                .map(|j| contours[j].clone())
+                .collect()
+        })
+        .collect()
+}
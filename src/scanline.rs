@@ -0,0 +1,76 @@
+use crate::{CopyIterator, LineSegment, Polygon};
+use alloc::vec::Vec;
+use glam::Vec2;
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Horizontal spans of the polygon interior as `(y, x_start, x_end)`, from the bottom to the top and from the left to the right.
+    ///
+    /// Scanlines go through the middles of the rows of height `spacing` aligned to zero, i.e. at `y = (k + 0.5) * spacing`,
+    /// so the spans of different polygons sampled with the same spacing fall onto the same rows of an occupancy grid.
+    /// Spans are the parts of the scanline where the winding number is positive, as [`Closed::contains`](crate::Closed::contains) gives.
+    ///
+    /// Edges are sorted by their bottom ends once, and each scanline crosses only the edges active at its height,
+    /// so it takes `O(n log n + r + c log c)` time, where `r` is the number of the rows and `c` is the number of the crossings.
+    pub fn scanlines(&self, spacing: f32) -> impl Iterator<Item = (f32, f32, f32)> + '_ {
+        let mut edges: Vec<LineSegment> =
+            self.edges().filter(|edge| edge.0.y != edge.1.y).collect();
+        edges.sort_by(|a, b| a.0.y.min(a.1.y).total_cmp(&b.0.y.min(b.1.y)));
+        let (bottom, top) =
+            edges
+                .iter()
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), edge| {
+                    (
+                        min.min(edge.0.y.min(edge.1.y)),
+                        max.max(edge.0.y.max(edge.1.y)),
+                    )
+                });
+        let rows = if spacing > 0.0 && bottom < top {
+            (bottom / spacing - 0.5).ceil() as i64..(top / spacing - 0.5).ceil() as i64
+        } else {
+            0..0
+        };
+
+        let mut active: Vec<LineSegment> = Vec::new();
+        let mut next = 0;
+        let mut crossings: Vec<(f32, i32)> = Vec::new();
+        rows.flat_map(move |k| {
+            let y = (k as f32 + 0.5) * spacing;
+            while next < edges.len() && edges[next].0.y.min(edges[next].1.y) <= y {
+                active.push(edges[next]);
+                next += 1;
+            }
+            active.retain(|edge| edge.0.y.max(edge.1.y) > y);
+
+            // Half-open edges, so that a vertex on the scanline is crossed once
+            crossings.clear();
+            crossings.extend(
+                active
+                    .iter()
+                    .filter(|edge| (edge.0.y <= y) != (edge.1.y <= y))
+                    .map(|LineSegment(a, b)| {
+                        let x = a.x + (b.x - a.x) * ((y - a.y) / (b.y - a.y));
+                        // Interior of a counterclockwise polygon is on the right of its downward edges
+                        (x, if b.y < a.y { 1 } else { -1 })
+                    }),
+            );
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut spans = Vec::new();
+            let (mut winding, mut start) = (0, 0.0);
+            for (x, sign) in crossings.iter().copied() {
+                let inside = winding > 0;
+                winding += sign;
+                if !inside && winding > 0 {
+                    start = x;
+                } else if inside && winding <= 0 && x > start {
+                    match spans.last_mut() {
+                        // Spans separated only by coinciding edges are joined
+                        Some((_, _, end)) if *end == start => *end = x,
+                        _ => spans.push((y, start, x)),
+                    }
+                }
+            }
+            spans
+        })
+    }
+}
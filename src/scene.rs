@@ -0,0 +1,184 @@
+use crate::{Aabb, BitGrid, Bounded, Closed, to_mask};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use glam::{UVec2, Vec2};
+
+/// Rule deciding which points are inside of a layer given the number of its shapes around the point.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum FillRule {
+    /// Point is inside if the shapes winding around it don't cancel each other out.
+    #[default]
+    NonZero,
+    /// Point is inside if it is wound by an odd number of shapes.
+    EvenOdd,
+}
+
+impl FillRule {
+    /// Check that the point wound `winding` times is inside.
+    pub(crate) fn fills(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// How a layer is combined with the layers below it.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum LayerRole {
+    /// Layer is added to the scene.
+    #[default]
+    Additive,
+    /// Layer is cut out of the scene.
+    Subtractive,
+}
+
+/// Shape that can be placed into a scene.
+trait SceneShape: Closed + Bounded {}
+
+impl<S: Closed + Bounded> SceneShape for S {}
+
+struct Layer<'a> {
+    name: String,
+    rule: FillRule,
+    role: LayerRole,
+    shapes: Vec<Box<dyn SceneShape + 'a>>,
+}
+
+impl Layer<'_> {
+    fn contains(&self, point: Vec2) -> bool {
+        // Each shape is counted once, so that shapes with different winding number scales can be mixed
+        let winding: i32 = self
+            .shapes
+            .iter()
+            .map(|shape| shape.winding_number_2(point).signum())
+            .sum();
+        self.rule.fills(winding)
+    }
+}
+
+/// Collects shapes into named layers of a [`Scene`].
+///
+/// Layers are composed in the order they were first mentioned.
+#[derive(Default)]
+pub struct SceneBuilder<'a> {
+    layers: Vec<Layer<'a>>,
+}
+
+impl<'a> SceneBuilder<'a> {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn layer_mut(&mut self, name: &str) -> &mut Layer<'a> {
+        let index = match self.layers.iter().position(|layer| layer.name == name) {
+            Some(i) => i,
+            None => {
+                self.layers.push(Layer {
+                    name: name.into(),
+                    rule: FillRule::default(),
+                    role: LayerRole::default(),
+                    shapes: Vec::new(),
+                });
+                self.layers.len() - 1
+            }
+        };
+        &mut self.layers[index]
+    }
+
+    /// Set the fill rule and the role of the layer, creating it if it doesn't exist.
+    ///
+    /// Layers created by [`SceneBuilder::push`] are [`FillRule::NonZero`] and [`LayerRole::Additive`].
+    pub fn layer(&mut self, name: &str, rule: FillRule, role: LayerRole) -> &mut Self {
+        let layer = self.layer_mut(name);
+        layer.rule = rule;
+        layer.role = role;
+        self
+    }
+
+    /// Put the shape onto the layer, creating it if it doesn't exist.
+    pub fn push<S: Closed + Bounded + 'a>(&mut self, name: &str, shape: S) -> &mut Self {
+        self.layer_mut(name).shapes.push(Box::new(shape));
+        self
+    }
+
+    /// Compose the layers into the scene.
+    pub fn build(self) -> Scene<'a> {
+        let bounds = self
+            .layers
+            .iter()
+            .filter(|layer| layer.role == LayerRole::Additive)
+            .flat_map(|layer| &layer.shapes)
+            .fold(Aabb::EMPTY, |a, shape| a.union(shape.aabb()));
+        Scene {
+            layers: self.layers,
+            bounds,
+        }
+    }
+}
+
+/// Composite shape made of layers of shapes.
+///
+/// Within a layer the shapes are combined by the layer [`FillRule`] applied to the sum of their winding signs,
+/// so clockwise shapes cancel counterclockwise ones.
+/// Then the layers are applied one after another starting from the empty scene:
+/// [`LayerRole::Additive`] layers are united with the scene and [`LayerRole::Subtractive`] ones are cut out of it.
+///
+/// The scene is a [`Closed`] shape, so it can be passed anywhere a shape is expected.
+pub struct Scene<'a> {
+    layers: Vec<Layer<'a>>,
+    bounds: Aabb,
+}
+
+impl Scene<'_> {
+    /// Names of the layers in the order of composition.
+    pub fn layers(&self) -> impl Iterator<Item = &str> {
+        self.layers.iter().map(|layer| layer.name.as_str())
+    }
+
+    /// Check that the `point` is inside of the layer on its own.
+    ///
+    /// Returns `None` if there is no such layer.
+    pub fn layer_contains(&self, name: &str, point: Vec2) -> Option<bool> {
+        self.layers
+            .iter()
+            .find(|layer| layer.name == name)
+            .map(|layer| layer.contains(point))
+    }
+
+    /// Rasterize the scene into the grid of `size` cells covering its bounding box.
+    pub fn rasterize(&self, size: UVec2) -> BitGrid {
+        to_mask(self, self.bounds, size)
+    }
+
+    /// Area of the scene estimated by rasterizing it into the grid of `size` cells.
+    ///
+    /// The error is of the order of the boundary length multiplied by the cell size.
+    pub fn area(&self, size: UVec2) -> f32 {
+        if self.bounds.is_empty() {
+            0.0
+        } else {
+            self.rasterize(size).area()
+        }
+    }
+}
+
+impl Closed for Scene<'_> {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        let inside = self
+            .layers
+            .iter()
+            .fold(false, |inside, layer| match layer.role {
+                LayerRole::Additive => inside || layer.contains(point),
+                LayerRole::Subtractive => inside && !layer.contains(point),
+            });
+        if inside { 2 } else { 0 }
+    }
+}
+
+impl Bounded for Scene<'_> {
+    /// Bounding box of the additive layers.
+    fn aabb(&self) -> Aabb {
+        self.bounds
+    }
+}
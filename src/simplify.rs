@@ -0,0 +1,248 @@
+use crate::{CopyIterator, LineSegment, Polygon};
+#[cfg(feature = "alloc")]
+use crate::{CopyRef, EPS, Integrable, Triangle};
+#[cfg(feature = "alloc")]
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+#[cfg(not(feature = "alloc"))]
+use core::iter;
+use glam::Vec2;
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Remove vertices that deviate from the polygon outline less than `tolerance`.
+    ///
+    /// Uses Douglas–Peucker algorithm.
+    /// The outline is split into two chains at the first vertex and the vertex farthest from it,
+    /// so both of them are always kept.
+    ///
+    /// With `alloc` feature the vertices are collected and the chains are split using an explicit stack,
+    /// which takes `O(n log n)` time on average and `O(n^2)` in the worst case.
+    /// Otherwise it doesn't allocate, chains are processed without a stack at the cost of repeated scans:
+    /// each kept vertex takes `O(n d)` time, where `d` is the depth of the recursion,
+    /// so the whole simplification takes `O(n^3)` time in the worst case.
+    pub fn simplify<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        tolerance: f32,
+    ) -> Polygon<W> {
+        let n = self.vertices().count();
+        let split = match self.vertices().next() {
+            Some(first) => self
+                .vertices()
+                .map(|v| v.distance_squared(first))
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map_or(0, |(i, _)| i),
+            None => 0,
+        };
+        if n <= 3 || split == 0 {
+            return self.vertices().collect();
+        }
+        #[cfg(feature = "alloc")]
+        {
+            let vertices: Vec<Vec2> = self.vertices().collect();
+            let mut kept = vec![false; n];
+            kept[0] = true;
+            kept[split] = true;
+            douglas_peucker(&vertices, 0, split, tolerance, &mut kept);
+            douglas_peucker(&vertices, split, n, tolerance, &mut kept);
+            // Same order as without `alloc`, starting after the first vertex
+            (1..=n)
+                .map(|i| i % n)
+                .filter(|&i| kept[i])
+                .map(|i| vertices[i])
+                .collect()
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            let vertex = |i: usize| self.vertices().nth(i % n).unwrap();
+            self.simplify_chain(0, split, tolerance)
+                .chain(self.simplify_chain(split, n, tolerance))
+                .map(vertex)
+                .collect()
+        }
+    }
+
+    /// Reduce the polygon to at most `max_vertices` vertices keeping its area and centroid.
+    ///
+    /// Vertices are removed by Visvalingam–Whyatt algorithm, the one spanning the triangle of the smallest area
+    /// with its neighbours goes first.
+    /// Then the remaining vertices are slightly adjusted: scaled about the centroid to restore the area
+    /// and shifted to restore the centroid, so they match the original ones up to rounding errors.
+    /// Polygons of zero area are only reduced.
+    ///
+    /// At least 3 vertices are kept. Takes `O(n log n)` time.
+    #[cfg(feature = "alloc")]
+    pub fn simplify_preserving_moments<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+        &self,
+        max_vertices: usize,
+    ) -> Polygon<W> {
+        let vertices: Vec<Vec2> = self.vertices().collect();
+        let n = vertices.len();
+        let target = max_vertices.max(3);
+        if n <= target {
+            return self.vertices().collect();
+        }
+
+        // Vertices are removed from the doubly linked list
+        let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+        let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+        let mut removed = vec![false; n];
+        let candidate = |index: usize, prev: usize, next: usize| Candidate {
+            area: Triangle(vertices[prev], vertices[index], vertices[next])
+                .signed_area()
+                .abs(),
+            index,
+            prev,
+            next,
+        };
+        let mut heap: BinaryHeap<Candidate> =
+            (0..n).map(|i| candidate(i, prev[i], next[i])).collect();
+        let mut count = n;
+        while count > target {
+            // Each remaining vertex has an actual candidate, so the heap can't be exhausted
+            let Candidate {
+                index,
+                prev: p,
+                next: q,
+                ..
+            } = heap.pop().unwrap();
+            // Skip outdated candidates which neighbours have been removed
+            if removed[index] || prev[index] != p || next[index] != q {
+                continue;
+            }
+            removed[index] = true;
+            next[p] = q;
+            prev[q] = p;
+            count -= 1;
+            heap.extend([candidate(p, prev[p], q), candidate(q, p, next[q])]);
+        }
+
+        let kept: Vec<Vec2> = (0..n)
+            .filter(|&i| !removed[i])
+            .map(|i| vertices[i])
+            .collect();
+        let (original, reduced) = (
+            self.moment(),
+            Polygon::new(CopyRef(kept.as_slice())).moment(),
+        );
+        if original.area.abs() < EPS || original.area * reduced.area <= 0.0 {
+            return Polygon::from_iter(kept);
+        }
+        let scale = (original.area / reduced.area).sqrt();
+        kept.into_iter()
+            .map(|v| original.centroid + (v - reduced.centroid) * scale)
+            .collect()
+    }
+
+    /// Indices of vertices that are kept in the chain between `start` and `end` (inclusive, `start` is not yielded).
+    ///
+    /// Recursive Douglas–Peucker splits the chain at its farthest vertex and simplifies both parts in turn.
+    /// Instead of keeping a stack of the pending parts, the next kept vertex is found by descending from the whole chain
+    /// into the part that follows the last kept vertex, which gives the same vertices in the same order.
+    #[cfg(not(feature = "alloc"))]
+    fn simplify_chain(
+        &self,
+        start: usize,
+        end: usize,
+        tolerance: f32,
+    ) -> impl Iterator<Item = usize> + '_ {
+        let n = self.vertices().count();
+        let vertex = move |i: usize| self.vertices().nth(i % n).unwrap();
+        // Vertex between `a` and `b` farthest from the segment connecting them
+        let farthest = move |a: usize, b: usize| {
+            let segment = LineSegment(vertex(a), vertex(b));
+            self.vertices()
+                .chain(self.vertices())
+                .enumerate()
+                .take(b)
+                .skip(a + 1)
+                .map(|(i, v)| (i, segment.closest_point(v).distance(v)))
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        };
+        let mut anchor = start;
+        iter::from_fn(move || {
+            if anchor >= end {
+                return None;
+            }
+            let (mut a, mut b) = (start, end);
+            loop {
+                match farthest(a, b) {
+                    Some((i, distance)) if distance > tolerance => {
+                        if anchor < i {
+                            b = i;
+                        } else {
+                            a = i;
+                        }
+                    }
+                    // The anchor is kept, so the part that fits starts at it
+                    _ => break,
+                }
+            }
+            anchor = b;
+            Some(b)
+        })
+    }
+}
+
+/// Mark the vertices kept by Douglas–Peucker algorithm in the chain between `start` and `end`.
+///
+/// Indices are taken modulo the number of the `vertices`, so the chain can wrap around the polygon.
+/// Pending parts of the chain are kept on the stack instead of the recursion.
+#[cfg(feature = "alloc")]
+fn douglas_peucker(vertices: &[Vec2], start: usize, end: usize, tolerance: f32, kept: &mut [bool]) {
+    let n = vertices.len();
+    let mut stack = vec![(start, end)];
+    while let Some((a, b)) = stack.pop() {
+        let segment = LineSegment(vertices[a % n], vertices[b % n]);
+        let farthest = (a + 1..b)
+            .map(|i| {
+                let v = vertices[i % n];
+                (i, segment.closest_point(v).distance(v))
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+        if let Some((i, distance)) = farthest
+            && distance > tolerance
+        {
+            kept[i % n] = true;
+            stack.extend([(a, i), (i, b)]);
+        }
+    }
+}
+
+/// Vertex that can be removed by [`Polygon::simplify_preserving_moments`].
+///
+/// Ordered so that the vertex with the smallest area is popped from the heap first.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    /// Area of the triangle formed with the neighbours.
+    area: f32,
+    index: usize,
+    prev: usize,
+    next: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+#[cfg(feature = "alloc")]
+impl Eq for Candidate {}
+#[cfg(feature = "alloc")]
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then(other.index.cmp(&self.index))
+    }
+}
@@ -0,0 +1,607 @@
+use crate::{Aabb, CopyIterator, Polygon, Region};
+use alloc::vec::Vec;
+use glam::Vec2;
+
+/// Node of a straight skeleton.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SkeletonNode {
+    /// Position of the node.
+    pub point: Vec2,
+    /// Time when the wavefront reaches the node, i.e. the distance from the node to the nearest boundary edge line.
+    pub time: f32,
+}
+
+/// Straight skeleton of a polygonal region.
+///
+/// The skeleton is traced by the wavefront: all edges of the region move inward with unit speed,
+/// and the vertices of the shrinking boundary run along the skeleton arcs.
+/// Boundary vertices are the first nodes of the skeleton and have zero time.
+///
+/// Besides the skeleton itself, the time of a node is the height of the roof built over the region with unit slope,
+/// so the arcs are the roof ridges and valleys projected onto the plane.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct StraightSkeleton {
+    /// Nodes of the skeleton.
+    pub nodes: Vec<SkeletonNode>,
+    /// Arcs of the skeleton as pairs of node indices, the first node is reached by the wavefront earlier.
+    pub arcs: Vec<(usize, usize)>,
+    /// Processing was stopped before the wavefront collapsed, so the skeleton lacks its innermost part.
+    ///
+    /// The number of the events is limited to guarantee termination in presence of rounding errors,
+    /// it is exceeded only for highly degenerate input.
+    pub incomplete: bool,
+}
+
+impl StraightSkeleton {
+    /// Node reached by the wavefront last.
+    ///
+    /// The node lies deep inside the region, and its time estimates the radius of the largest disk inscribed into the region.
+    /// For convex polygons the estimate is exact.
+    pub fn deepest(&self) -> Option<&SkeletonNode> {
+        self.nodes.iter().max_by(|a, b| a.time.total_cmp(&b.time))
+    }
+}
+
+/// Change of the offset contours topology.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OffsetEvent {
+    /// Contour pinched and split into two ones.
+    Split {
+        /// Offset distance where the event happened.
+        distance: f32,
+        /// Point where the contour was pinched.
+        point: Vec2,
+    },
+    /// Two contours touched each other and joined into a single one,
+    /// e.g. an inset component merged with a growing hole, or two outset islands merged together.
+    Merge {
+        /// Offset distance where the event happened.
+        distance: f32,
+        /// Point where the contours touched.
+        point: Vec2,
+    },
+    /// Contour shrank to nothing.
+    Vanish {
+        /// Offset distance where the event happened.
+        distance: f32,
+        /// Point where the contour vanished.
+        point: Vec2,
+    },
+}
+
+/// Parameters of [`Polygon::offset`] and [`Region::offset`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OffsetOptions {
+    /// Maximal ratio of the distance from an original vertex to the offset corner and the offset distance.
+    ///
+    /// Sharp corners are beveled so that the corner doesn't exceed the limit,
+    /// e.g. `1.0` cuts each corner at the offset distance, and `2.0` leaves unbeveled corners down to 60 degrees.
+    /// Infinite limit keeps all corners sharp, as the straight skeleton prescribes.
+    pub miter_limit: f32,
+}
+
+impl Default for OffsetOptions {
+    fn default() -> Self {
+        Self {
+            miter_limit: f32::INFINITY,
+        }
+    }
+}
+
+/// Result of the skeleton-based offsetting.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Offset {
+    /// Offset contours, components are counterclockwise and holes are clockwise.
+    pub region: Region<Vec<Polygon<Vec<Vec2>>>>,
+    /// Topology changes happened until the offset distance was reached, ordered by distance.
+    pub events: Vec<OffsetEvent>,
+    /// Processing was stopped before the offset distance was reached, see [`StraightSkeleton::incomplete`].
+    ///
+    /// The region is then the wavefront at the distance of the last processed event moved to the offset distance,
+    /// so it may be self-intersecting.
+    pub incomplete: bool,
+}
+
+/// Line of an original boundary edge.
+#[derive(Clone, Copy, Debug)]
+struct EdgeLine {
+    /// Unit direction of the edge.
+    dir: Vec2,
+    /// Inward unit normal.
+    normal: Vec2,
+    /// At time `t` the line consists of points `p` where `normal.dot(p) == offset + t`.
+    offset: f32,
+}
+
+/// Vertex of the wavefront.
+#[derive(Clone, Copy, Debug)]
+struct WaveVertex {
+    /// Position at the `start` time.
+    origin: Vec2,
+    start: f32,
+    velocity: Vec2,
+    /// Edge lines before and after the vertex.
+    edges: (usize, usize),
+    /// Neighbour vertices in the wavefront contour.
+    prev: usize,
+    next: usize,
+    /// Skeleton node where the vertex started.
+    node: usize,
+    alive: bool,
+}
+
+impl WaveVertex {
+    fn position(&self, time: f32) -> Vec2 {
+        self.origin + self.velocity * (time - self.start)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Event {
+    /// Edge after the vertex shrinks to zero length.
+    Edge(usize),
+    /// Reflex vertex (the first one) hits the edge after the other vertex.
+    Split(usize, usize),
+}
+
+/// Shrinking boundary of a polygonal region.
+///
+/// Events are found by checking all vertices and all pairs of reflex vertices and edges,
+/// so each event takes `O(n^2)` time.
+#[derive(Clone, Debug)]
+pub(crate) struct Wavefront {
+    lines: Vec<EdgeLine>,
+    vertices: Vec<WaveVertex>,
+    time: f32,
+    /// Tolerance scaled to the region size.
+    eps: f32,
+    /// Number of events left to process, guarantees termination in presence of rounding errors.
+    budget: usize,
+    /// Events were left unprocessed because the budget was exhausted.
+    incomplete: bool,
+    skeleton: StraightSkeleton,
+    events: Vec<OffsetEvent>,
+}
+
+impl Wavefront {
+    /// Wavefront starting at the contours given as sequences of points.
+    ///
+    /// Repeated points are skipped, contours with less than 3 distinct points are ignored.
+    pub(crate) fn new<I: IntoIterator<Item: IntoIterator<Item = Vec2>>>(contours: I) -> Self {
+        let contours: Vec<Vec<Vec2>> = contours
+            .into_iter()
+            .map(|contour| contour.into_iter().collect())
+            .collect();
+        let bounds = Aabb::from_points(contours.iter().flatten().copied());
+        let eps = if bounds.is_empty() {
+            0.0
+        } else {
+            1e-5 * bounds.size().max_element()
+        };
+        let mut this = Self {
+            lines: Vec::new(),
+            vertices: Vec::new(),
+            time: 0.0,
+            eps,
+            budget: 0,
+            incomplete: false,
+            skeleton: StraightSkeleton::default(),
+            events: Vec::new(),
+        };
+        for mut points in contours {
+            points.dedup_by(|a, b| a.distance(*b) <= eps);
+            while points.len() > 1 && points[0].distance(points[points.len() - 1]) <= eps {
+                points.pop();
+            }
+            let n = points.len();
+            if n < 3 {
+                continue;
+            }
+            let (line0, vertex0) = (this.lines.len(), this.vertices.len());
+            for i in 0..n {
+                let (a, b) = (points[i], points[(i + 1) % n]);
+                let dir = (b - a).normalize();
+                this.lines.push(EdgeLine {
+                    dir,
+                    normal: dir.perp(),
+                    offset: dir.perp().dot(a),
+                });
+            }
+            for (i, &point) in points.iter().enumerate() {
+                let edges = (line0 + (i + n - 1) % n, line0 + i);
+                let node = this.node(point, 0.0);
+                this.vertices.push(WaveVertex {
+                    origin: point,
+                    start: 0.0,
+                    velocity: this.velocity(edges),
+                    edges,
+                    prev: vertex0 + (i + n - 1) % n,
+                    next: vertex0 + (i + 1) % n,
+                    node,
+                    alive: true,
+                });
+            }
+        }
+        // Edge events remove a vertex and split events are made by distinct reflex vertices,
+        // so there are less than `2 n` events unless rounding errors make more of them
+        this.budget = 4 * this.vertices.len() + 16;
+        this
+    }
+
+    /// Velocity of the vertex between the edge lines, it keeps unit distance rate to both of them.
+    fn velocity(&self, (prev, next): (usize, usize)) -> Vec2 {
+        let (a, b) = (self.lines[prev].normal, self.lines[next].normal);
+        // Opposite edges give a spike that retreats infinitely fast, the speed is limited
+        (a + b) / (1.0 + a.dot(b)).max(1e-6)
+    }
+
+    fn is_reflex(&self, vertex: &WaveVertex) -> bool {
+        let (prev, next) = vertex.edges;
+        self.lines[prev].dir.perp_dot(self.lines[next].dir) < 0.0
+    }
+
+    /// Node at the point and time, nodes of simultaneous events at the same point are shared.
+    fn node(&mut self, point: Vec2, time: f32) -> usize {
+        let eps = self.eps;
+        let nodes = &mut self.skeleton.nodes;
+        match nodes
+            .iter()
+            .rposition(|node| (node.time - time).abs() <= eps && node.point.distance(point) <= eps)
+        {
+            Some(i) => i,
+            None => {
+                nodes.push(SkeletonNode { point, time });
+                nodes.len() - 1
+            }
+        }
+    }
+
+    fn arc(&mut self, a: usize, b: usize) {
+        if a != b {
+            self.skeleton.arcs.push((a, b));
+        }
+    }
+
+    fn alive(&self) -> impl Iterator<Item = (usize, &WaveVertex)> {
+        self.vertices.iter().enumerate().filter(|(_, v)| v.alive)
+    }
+
+    /// Earliest upcoming event.
+    fn next_event(&self) -> Option<(f32, Event)> {
+        let (now, eps) = (self.time, self.eps);
+        let mut best: Option<(f32, Event)> = None;
+        let mut consider = |time: f32, event: Event| {
+            // Edge events go first when simultaneous with split ones,
+            // so that a reflex vertex hitting the end of an edge is handled as a shrinking edge
+            let margin = match event {
+                Event::Edge(_) => 0.0,
+                Event::Split(..) => eps,
+            };
+            if best.is_none_or(|(t, _)| time < t - margin) {
+                best = Some((time, event));
+            }
+        };
+        for (i, a) in self.alive() {
+            let b = &self.vertices[a.next];
+            let line = &self.lines[a.edges.1];
+            let length = line.dir.dot(b.position(now) - a.position(now));
+            let rate = line.dir.dot(b.velocity - a.velocity);
+            if length <= eps {
+                consider(now, Event::Edge(i));
+            } else if rate < 0.0 {
+                consider(now + length / -rate, Event::Edge(i));
+            }
+        }
+        for (i, a) in self.alive().filter(|(_, v)| self.is_reflex(v)) {
+            for (j, c) in self.alive() {
+                if j == i || c.next == i {
+                    continue;
+                }
+                let line = &self.lines[c.edges.1];
+                let rate = line.normal.dot(a.velocity) - 1.0;
+                let distance = line.normal.dot(a.position(now)) - line.offset - now;
+                // Vertex behind the line is skipped unless it has just passed it,
+                // e.g. when a simultaneous edge event has been handled first
+                let time = now + distance / -rate;
+                if rate >= 0.0 || time < now - eps {
+                    continue;
+                }
+                let time = time.max(now);
+                let point = a.position(time);
+                let (start, end) = (c.position(time), self.vertices[c.next].position(time));
+                if line.dir.dot(point - start) >= -eps && line.dir.dot(end - point) >= -eps {
+                    consider(time, Event::Split(i, j));
+                }
+            }
+        }
+        best
+    }
+
+    /// Add a vertex between the edge lines and link it into the contour.
+    fn insert(
+        &mut self,
+        point: Vec2,
+        node: usize,
+        edges: (usize, usize),
+        prev: usize,
+        next: usize,
+    ) -> usize {
+        let index = self.vertices.len();
+        self.vertices.push(WaveVertex {
+            origin: point,
+            start: self.time,
+            velocity: self.velocity(edges),
+            edges,
+            prev,
+            next,
+            node,
+            alive: true,
+        });
+        self.vertices[prev].next = index;
+        self.vertices[next].prev = index;
+        index
+    }
+
+    /// Stop the vertex at the node.
+    fn kill(&mut self, vertex: usize, node: usize) {
+        self.vertices[vertex].alive = false;
+        self.arc(self.vertices[vertex].node, node);
+    }
+
+    /// Remove the contour of the vertex if it has degenerated into a point or a segment.
+    fn collapse(&mut self, vertex: usize) {
+        let next = self.vertices[vertex].next;
+        if self.vertices[next].next != vertex && next != vertex {
+            return;
+        }
+        // Contour split off and collapsed at once, e.g. between parallel edges meeting along a segment,
+        // is not a topology change
+        let (now, eps) = (self.time, self.eps);
+        let transient = [vertex, next]
+            .iter()
+            .all(|&v| (self.vertices[v].start - now).abs() <= eps);
+        let split = self.events.iter().rposition(|event| {
+            matches!(event, OffsetEvent::Split { distance, .. } if (distance - now).abs() <= eps)
+        });
+        let mut nodes = [0; 2];
+        for (k, v) in [vertex, next].into_iter().enumerate() {
+            nodes[k] = self.node(self.vertices[v].position(self.time), self.time);
+            self.kill(v, nodes[k]);
+        }
+        self.arc(nodes[0], nodes[1]);
+        match split {
+            Some(i) if transient => {
+                self.events.remove(i);
+            }
+            _ => {
+                let (a, b) = (self.skeleton.nodes[nodes[0]], self.skeleton.nodes[nodes[1]]);
+                self.events.push(OffsetEvent::Vanish {
+                    distance: now,
+                    point: 0.5 * (a.point + b.point),
+                });
+            }
+        }
+    }
+
+    /// Check that the vertices belong to the same contour.
+    fn same_contour(&self, a: usize, b: usize) -> bool {
+        let mut i = self.vertices[a].next;
+        while i != a {
+            if i == b {
+                return true;
+            }
+            i = self.vertices[i].next;
+        }
+        a == b
+    }
+
+    fn handle(&mut self, event: Event) {
+        let now = self.time;
+        match event {
+            Event::Edge(a) => {
+                let b = self.vertices[a].next;
+                let (va, vb) = (self.vertices[a], self.vertices[b]);
+                if va.prev == b {
+                    return self.collapse(a);
+                }
+                let point = 0.5 * (va.position(now) + vb.position(now));
+                let node = self.node(point, now);
+                self.kill(a, node);
+                self.kill(b, node);
+                let w = self.insert(point, node, (va.edges.0, vb.edges.1), va.prev, vb.next);
+                self.collapse(w);
+            }
+            Event::Split(v, c) => {
+                let (vv, vc) = (self.vertices[v], self.vertices[c]);
+                let point = vv.position(now);
+                let event = if self.same_contour(v, c) {
+                    OffsetEvent::Split {
+                        distance: now,
+                        point,
+                    }
+                } else {
+                    OffsetEvent::Merge {
+                        distance: now,
+                        point,
+                    }
+                };
+                self.events.push(event);
+                let node = self.node(point, now);
+                self.kill(v, node);
+                let first = self.insert(point, node, (vv.edges.0, vc.edges.1), vv.prev, vc.next);
+                let second = self.insert(point, node, (vc.edges.1, vv.edges.1), c, vv.next);
+                self.collapse(first);
+                if self.vertices[second].alive {
+                    self.collapse(second);
+                }
+            }
+        }
+    }
+
+    /// Process all events up to the `time` and move the wavefront there.
+    pub(crate) fn advance(&mut self, time: f32) {
+        while let Some((t, event)) = self.next_event().filter(|(t, _)| *t <= time) {
+            if self.budget == 0 {
+                self.incomplete = true;
+                break;
+            }
+            self.time = t;
+            self.handle(event);
+            self.budget -= 1;
+        }
+        if time.is_finite() {
+            self.time = self.time.max(time);
+        }
+    }
+
+    /// Push the vertex position to the `points`, beveling the corner if its miter exceeds the `miter_limit`.
+    fn corner(&self, vertex: &WaveVertex, miter_limit: f32, points: &mut Vec<Vec2>) {
+        let point = vertex.position(self.time);
+        let (elapsed, miter) = (self.time - vertex.start, vertex.velocity.length());
+        // Only the corners turning away from the wavefront interior get spiky
+        if elapsed <= 0.0 || miter <= miter_limit || !self.is_reflex(vertex) {
+            points.push(point);
+            return;
+        }
+        // Bevel line is orthogonal to the vertex path and lies at the limit distance from the path start
+        let (axis, excess) = (
+            vertex.velocity / miter,
+            (miter - miter_limit.max(0.0)) * elapsed,
+        );
+        let (prev, next) = (
+            self.vertices[vertex.prev].position(self.time),
+            self.vertices[vertex.next].position(self.time),
+        );
+        for (dir, neighbour) in [
+            (-self.lines[vertex.edges.0].dir, prev),
+            (self.lines[vertex.edges.1].dir, next),
+        ] {
+            let step = (excess / -axis.dot(dir)).min(point.distance(neighbour));
+            points.push(point + dir * step);
+        }
+    }
+
+    /// Current contours of the wavefront.
+    pub(crate) fn contours(&self, miter_limit: f32) -> Vec<Polygon<Vec<Vec2>>> {
+        let mut visited = alloc::vec![false; self.vertices.len()];
+        let mut contours = Vec::new();
+        for (first, _) in self.alive() {
+            if visited[first] {
+                continue;
+            }
+            let mut points = Vec::new();
+            let mut i = first;
+            while !visited[i] {
+                visited[i] = true;
+                self.corner(&self.vertices[i], miter_limit, &mut points);
+                i = self.vertices[i].next;
+            }
+            if points.len() >= 3 {
+                contours.push(Polygon::new(points));
+            }
+        }
+        contours
+    }
+
+    pub(crate) fn into_skeleton(mut self) -> StraightSkeleton {
+        self.advance(f32::INFINITY);
+        StraightSkeleton {
+            incomplete: self.incomplete,
+            ..self.skeleton
+        }
+    }
+}
+
+/// Offset the contours given as sequences of points.
+fn offset<I: IntoIterator<Item: IntoIterator<Item = Vec2>>>(
+    contours: I,
+    distance: f32,
+    options: OffsetOptions,
+) -> Offset {
+    // Outset is the inset of the complement, which is bounded by the reversed contours
+    let outset = distance > 0.0;
+    let mut wavefront = Wavefront::new(contours.into_iter().map(|contour| {
+        let mut points: Vec<Vec2> = contour.into_iter().collect();
+        if outset {
+            points.reverse();
+        }
+        points
+    }));
+    wavefront.advance(distance.abs());
+    let mut contours = wavefront.contours(options.miter_limit);
+    if outset {
+        for contour in &mut contours {
+            contour.vertices.reverse();
+        }
+    }
+    Offset {
+        region: Region::new(contours),
+        events: wavefront.events,
+        incomplete: wavefront.incomplete,
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Straight skeleton of the polygon.
+    ///
+    /// There are `O(n)` events and finding each of them takes `O(n^2)`, so it takes `O(n^3)` time.
+    /// The polygon must be counterclockwise and non-self-intersecting.
+    pub fn straight_skeleton(&self) -> StraightSkeleton {
+        Wavefront::new([self.vertices()]).into_skeleton()
+    }
+
+    /// Shrink the polygon by moving its edges inward by the `distance`.
+    ///
+    /// Edges that shrink to zero length are removed, and the polygon may split into several ones,
+    /// so the result is a region. It is empty if the `distance` exceeds the [`StraightSkeleton::deepest`] time.
+    ///
+    /// The polygon must be counterclockwise and non-self-intersecting.
+    pub fn inset(&self, distance: f32) -> Region<Vec<Polygon<Vec<Vec2>>>> {
+        self.offset(-distance, OffsetOptions::default()).region
+    }
+
+    /// Move the polygon edges outward by the `distance`, or inward if it is negative.
+    ///
+    /// Offsetting follows the straight skeleton of the polygon or of its complement for outward offsets,
+    /// so the edges stay parallel to the original ones and the corners are mitered up to the [`OffsetOptions::miter_limit`].
+    /// Contours splitting, merging and vanishing on the way are reported as [`OffsetEvent`]s.
+    /// It takes `O(n^3)` time in the worst case as [`Polygon::straight_skeleton`] does.
+    ///
+    /// The polygon must be counterclockwise and non-self-intersecting.
+    pub fn offset(&self, distance: f32, options: OffsetOptions) -> Offset {
+        offset([self.vertices()], distance, options)
+    }
+}
+
+impl<C: ?Sized, V: CopyIterator<Item = Vec2> + ?Sized> Region<C>
+where
+    for<'a> &'a C: IntoIterator<Item = &'a Polygon<V>>,
+{
+    /// Straight skeleton of the region.
+    ///
+    /// See [`Polygon::straight_skeleton`] for details.
+    /// Contours must not intersect, components must be counterclockwise and holes clockwise.
+    pub fn straight_skeleton(&self) -> StraightSkeleton {
+        Wavefront::new(self.components().map(|contour| contour.vertices())).into_skeleton()
+    }
+
+    /// Shrink the region by moving its edges inward by the `distance`.
+    ///
+    /// Holes grow, and the components may split or merge with the holes.
+    /// Contours must not intersect, components must be counterclockwise and holes clockwise.
+    pub fn inset(&self, distance: f32) -> Region<Vec<Polygon<Vec<Vec2>>>> {
+        self.offset(-distance, OffsetOptions::default()).region
+    }
+
+    /// Move the region edges outward by the `distance`, or inward if it is negative.
+    ///
+    /// See [`Polygon::offset`] for details.
+    /// Contours must not intersect, components must be counterclockwise and holes clockwise.
+    pub fn offset(&self, distance: f32, options: OffsetOptions) -> Offset {
+        offset(
+            self.components().map(|contour| contour.vertices()),
+            distance,
+            options,
+        )
+    }
+}
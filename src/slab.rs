@@ -0,0 +1,178 @@
+use crate::{Closed, CopyIterator, LineSegment, Polygon};
+use alloc::{vec, vec::Vec};
+use glam::Vec2;
+
+/// Edge crossing a slab, directed from left to right.
+#[derive(Clone, Copy, Debug)]
+struct Entry {
+    edge: LineSegment,
+    /// Winding number contribution of the edge when it is above the point,
+    /// `1` if the original edge goes from right to left and `-1` otherwise.
+    sign: i32,
+}
+
+impl Entry {
+    fn y_at(&self, x: f32) -> f32 {
+        let LineSegment(a, b) = self.edge;
+        a.y + (b.y - a.y) * ((x - a.x) / (b.x - a.x))
+    }
+}
+
+/// Polygon split into vertical slabs by the vertices X coordinates.
+///
+/// Edges crossing each slab don't cross each other inside of it, so they are stored sorted from the bottom to the top,
+/// and a vertical line meets them in this order.
+/// The slab of a point is found by bisection, and then its position among the slab edges is found by bisection too,
+/// so containment and vertical ray queries take `O(log n)` time.
+/// Slabs are stored in flat arrays, but an edge is stored in each slab it crosses,
+/// so the index takes `O(n^2)` memory in the worst case, and about `O(n sqrt(n))` for typical polygons.
+///
+/// Polygon must be simple. Created by [`Polygon::build_slab_index`].
+#[derive(Clone, Debug)]
+pub struct SlabIndex {
+    /// Sorted distinct X coordinates of the vertices, slab `i` spans from `xs[i]` to `xs[i + 1]`.
+    xs: Vec<f32>,
+    /// Slab `i` refers to `entries[offsets[i]..offsets[i + 1]]`.
+    offsets: Vec<usize>,
+    entries: Vec<Entry>,
+    /// Sum of the signs of the entry and the entries above it in the same slab.
+    windings: Vec<i32>,
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Split the polygon into vertical slabs for repeated vertical ray queries.
+    ///
+    /// Takes `O(n log n + m log n)` time, where `m` is the total number of the slabs edges.
+    pub fn build_slab_index(&self) -> SlabIndex {
+        let mut xs: Vec<f32> = self.vertices().map(|v| v.x).collect();
+        xs.sort_by(f32::total_cmp);
+        xs.dedup();
+        let count = xs.len().saturating_sub(1);
+
+        // Vertical edges don't cross any slab
+        let edges: Vec<Entry> = self
+            .edges()
+            .filter(|edge| edge.0.x != edge.1.x)
+            .map(|LineSegment(a, b)| {
+                if a.x < b.x {
+                    Entry {
+                        edge: LineSegment(a, b),
+                        sign: -1,
+                    }
+                } else {
+                    Entry {
+                        edge: LineSegment(b, a),
+                        sign: 1,
+                    }
+                }
+            })
+            .collect();
+        let slabs = |entry: &Entry| {
+            let LineSegment(a, b) = entry.edge;
+            xs.partition_point(|&x| x < a.x)..xs.partition_point(|&x| x < b.x)
+        };
+
+        // Count entries of each slab and turn the counts into the slab ends
+        let mut offsets = vec![0; count + 1];
+        for entry in &edges {
+            for i in slabs(entry) {
+                offsets[i] += 1;
+            }
+        }
+        let mut total = 0;
+        for offset in offsets.iter_mut() {
+            total += *offset;
+            *offset = total;
+        }
+        let mut entries = vec![
+            Entry {
+                edge: LineSegment(Vec2::ZERO, Vec2::ZERO),
+                sign: 0,
+            };
+            total
+        ];
+        // Fill the slabs from the ends, so that the offsets become the slab starts
+        for entry in &edges {
+            for i in slabs(entry) {
+                offsets[i] -= 1;
+                entries[offsets[i]] = *entry;
+            }
+        }
+
+        let mut windings = vec![0; total];
+        for i in 0..count {
+            let middle = 0.5 * (xs[i] + xs[i + 1]);
+            let range = offsets[i]..offsets[i + 1];
+            entries[range.clone()].sort_by(|a, b| a.y_at(middle).total_cmp(&b.y_at(middle)));
+            let mut winding = 0;
+            for k in range.rev() {
+                winding += entries[k].sign;
+                windings[k] = winding;
+            }
+        }
+
+        SlabIndex {
+            xs,
+            offsets,
+            entries,
+            windings,
+        }
+    }
+}
+
+impl SlabIndex {
+    /// Range of the entries of the slab containing the vertical line at `x`.
+    fn slab(&self, x: f32) -> Option<(usize, usize)> {
+        let i = self.xs.partition_point(|&v| v <= x);
+        if i == 0 || i >= self.xs.len() {
+            return None;
+        }
+        Some((self.offsets[i - 1], self.offsets[i]))
+    }
+
+    /// Index of the first entry of the slab above the `point`, the end of the slab if there is none.
+    fn first_above(&self, point: Vec2) -> Option<(usize, usize)> {
+        let (start, end) = self.slab(point.x)?;
+        let k = self.entries[start..end].partition_point(|entry| entry.y_at(point.x) < point.y);
+        Some((start + k, end))
+    }
+
+    /// Number of the slabs.
+    pub fn slab_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Y coordinates of the points where the vertical line at `x` crosses the polygon boundary, from the bottom to the top.
+    ///
+    /// Takes `O(log n + k)` time, where `k` is the number of the crossings.
+    /// Lines going through vertices are unspecified.
+    pub fn crossings(&self, x: f32) -> impl Iterator<Item = f32> + '_ {
+        let (start, end) = self.slab(x).unwrap_or((0, 0));
+        self.entries[start..end]
+            .iter()
+            .map(move |entry| entry.y_at(x))
+    }
+
+    /// The closest point of the boundary above the `point`, i.e. the first hit of the upward vertical ray.
+    pub fn above(&self, point: Vec2) -> Option<Vec2> {
+        let (k, end) = self.first_above(point)?;
+        (k < end).then(|| Vec2::new(point.x, self.entries[k].y_at(point.x)))
+    }
+
+    /// The closest point of the boundary below the `point`, i.e. the first hit of the downward vertical ray.
+    pub fn below(&self, point: Vec2) -> Option<Vec2> {
+        let (start, _) = self.slab(point.x)?;
+        let (k, _) = self.first_above(point)?;
+        (k > start).then(|| Vec2::new(point.x, self.entries[k - 1].y_at(point.x)))
+    }
+}
+
+impl Closed for SlabIndex {
+    /// Same winding number as the polygon gives, counted along the upward vertical ray.
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        match self.first_above(point) {
+            Some((k, end)) if k < end => self.windings[k],
+            _ => 0,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{CopyIterator, HalfPlane, IntersectTo, Line, Polygon, Region};
+use core::f32::consts::PI;
+use glam::Vec2;
+
+/// Cut the `polygon` into parallel strips of `spacing` width stacked along the `direction`.
+///
+/// Strip boundaries are perpendicular to the `direction` and placed at multiples of `spacing`
+/// measured from the origin, so the same grid of strips is used for different polygons.
+/// Strips are yielded in the `direction` order starting from the first one touching the polygon.
+///
+/// Each strip is a region consisting of the polygon part lying inside of it,
+/// it is empty if the polygon only touches the strip.
+/// Disjoint parts of a concave polygon within the strip are joined by zero-width bridges into a single contour,
+/// which doesn't affect moments and containment.
+pub fn slice<
+    'a,
+    V: CopyIterator<Item = Vec2> + ?Sized,
+    W: CopyIterator<Item = Vec2> + FromIterator<Vec2> + 'a,
+>(
+    polygon: &'a Polygon<V>,
+    direction: Vec2,
+    spacing: f32,
+) -> impl Iterator<Item = Region<Option<Polygon<W>>>> + 'a {
+    let normal = direction.normalize_or_zero();
+    let (min, max) = polygon
+        .vertices()
+        .map(|v| v.dot(normal))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), x| {
+            (min.min(x), max.max(x))
+        });
+    let range = if normal != Vec2::ZERO && spacing > 0.0 && min < max {
+        (min / spacing).floor() as i64..(max / spacing).ceil() as i64
+    } else {
+        0..0
+    };
+    range.map(move |k| {
+        let upper = HalfPlane {
+            normal,
+            offset: (k + 1) as f32 * spacing,
+        };
+        let lower = HalfPlane {
+            normal: -normal,
+            offset: -(k as f32 * spacing),
+        };
+        Region::new(
+            polygon
+                .intersect_to(&upper)
+                .and_then(|part: Polygon<W>| part.intersect_to(&lower)),
+        )
+    })
+}
+
+/// Cut the `shape` into `count` equal angular sectors around the `origin`.
+///
+/// Sector `k` spans the angles from `2 * PI * k / count` to `2 * PI * (k + 1) / count`
+/// measured counterclockwise from the X axis.
+/// Area of each yielded region gives the polar histogram of the shape around the `origin`.
+///
+/// Sectors are clipped by a pair of half-planes, so the `shape` can be any polygon that can be clipped by them,
+/// e.g. [`Polygon`] or [`ArcPolygon`](crate::ArcPolygon).
+///
+/// Nothing is yielded if `count` is less than 2, since sectors larger than a half-plane can't be clipped this way.
+pub fn angular_decompose<
+    'a,
+    S: IntersectTo<HalfPlane, P> + ?Sized,
+    P: IntersectTo<HalfPlane, P> + 'a,
+>(
+    shape: &'a S,
+    origin: Vec2,
+    count: usize,
+) -> impl Iterator<Item = Region<Option<P>>> + 'a
+where
+    HalfPlane: IntersectTo<S, P> + IntersectTo<P, P>,
+{
+    let count = if count >= 2 { count } else { 0 };
+    let ray = move |k: usize| origin + Vec2::from_angle(2.0 * PI * k as f32 / count as f32);
+    (0..count).map(move |k| {
+        // Sector lies to the left of its first ray and to the right of the second one
+        let first = HalfPlane::from_edge(Line(origin, ray(k)));
+        let second = HalfPlane::from_edge(Line(ray(k + 1), origin));
+        Region::new(
+            shape
+                .intersect_to(&first)
+                .and_then(|part: P| part.intersect_to(&second)),
+        )
+    })
+}
@@ -0,0 +1,251 @@
+use crate::{Aabb, Bounded, Closed, GridIndex, Overlaps, grid::square_size};
+use alloc::{sync::Arc, vec, vec::Vec};
+use glam::{UVec2, Vec2};
+
+/// Stable identifier of a shape in a [`GeometryStore`].
+///
+/// Identifiers are assigned in the order of insertion starting from zero.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ShapeId(usize);
+
+impl ShapeId {
+    /// Position of the shape in the store.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Collects shapes for a [`GeometryStore`].
+#[derive(Clone, Debug)]
+pub struct GeometryStoreBuilder<S> {
+    shapes: Vec<Arc<S>>,
+}
+
+impl<S> Default for GeometryStoreBuilder<S> {
+    fn default() -> Self {
+        Self { shapes: Vec::new() }
+    }
+}
+
+impl<S> GeometryStoreBuilder<S> {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of shapes added so far.
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    /// Check that no shapes were added.
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Add the shape and return its identifier.
+    pub fn insert(&mut self, shape: S) -> ShapeId {
+        self.insert_shared(Arc::new(shape))
+    }
+
+    /// Add the shared shape and return its identifier.
+    ///
+    /// If the same allocation was already added, then its identifier is returned instead.
+    /// Takes `O(n)` time.
+    pub fn insert_shared(&mut self, shape: Arc<S>) -> ShapeId {
+        match self.shapes.iter().position(|s| Arc::ptr_eq(s, &shape)) {
+            Some(i) => ShapeId(i),
+            None => self.push(shape),
+        }
+    }
+
+    /// Add the shape unless an equal one was already added, and return the identifier of the stored shape.
+    ///
+    /// Shapes with different bounding boxes are skipped without comparison.
+    /// Takes `O(n)` time.
+    pub fn intern(&mut self, shape: S) -> ShapeId
+    where
+        S: Bounded + PartialEq,
+    {
+        let aabb = shape.aabb();
+        match self
+            .shapes
+            .iter()
+            .position(|s| s.aabb() == aabb && **s == shape)
+        {
+            Some(i) => ShapeId(i),
+            None => self.push(Arc::new(shape)),
+        }
+    }
+
+    fn push(&mut self, shape: Arc<S>) -> ShapeId {
+        self.shapes.push(shape);
+        ShapeId(self.shapes.len() - 1)
+    }
+
+    /// Build the store indexing all the added shapes.
+    pub fn build(self) -> GeometryStore<S>
+    where
+        S: Bounded,
+    {
+        GeometryStore::new(self.shapes)
+    }
+}
+
+#[derive(Debug)]
+struct Storage<S> {
+    shapes: Vec<Arc<S>>,
+    boxes: Vec<Aabb>,
+    bounds: Aabb,
+    size: UVec2,
+    offsets: Vec<usize>,
+    entries: Vec<usize>,
+}
+
+/// Immutable collection of shapes with a spatial index.
+///
+/// Shapes are referred to by [`ShapeId`]s and stored behind [`Arc`]s, so they can be shared with the rest of the application.
+/// The bounding boxes of the shapes are indexed by a [`GridIndex`] of about one cell per shape,
+/// so the queries check only the shapes near the query point or box.
+///
+/// The store is never modified after it is built, and cloning it only increments the reference counter.
+/// It is [`Send`] and [`Sync`] if the shapes are, so the clones can be queried from multiple threads concurrently.
+#[derive(Debug)]
+pub struct GeometryStore<S> {
+    storage: Arc<Storage<S>>,
+}
+
+impl<S> Clone for GeometryStore<S> {
+    fn clone(&self) -> Self {
+        Self {
+            storage: self.storage.clone(),
+        }
+    }
+}
+
+impl<S: Bounded> GeometryStore<S> {
+    /// Build the store of the `shapes`, their identifiers are their positions in the sequence.
+    pub fn new<I: IntoIterator<Item = Arc<S>>>(shapes: I) -> Self {
+        let shapes: Vec<Arc<S>> = shapes.into_iter().collect();
+        let boxes: Vec<Aabb> = shapes.iter().map(|shape| shape.aabb()).collect();
+        let bounds = boxes.iter().fold(Aabb::EMPTY, |a, b| a.union(*b));
+        let size = square_size(shapes.len());
+        let (offsets_len, entries_len) = GridIndex::storage_len(bounds, size, &boxes);
+        let (mut offsets, mut entries) = (vec![0; offsets_len], vec![0; entries_len]);
+        // Storage is sized as required, so the index is always built
+        let _ = GridIndex::new(bounds, size, &boxes, &mut offsets, &mut entries);
+        Self {
+            storage: Arc::new(Storage {
+                shapes,
+                boxes,
+                bounds,
+                size,
+                offsets,
+                entries,
+            }),
+        }
+    }
+}
+
+impl<S: Bounded> FromIterator<S> for GeometryStore<S> {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::new(iter.into_iter().map(Arc::new))
+    }
+}
+
+impl<S> GeometryStore<S> {
+    /// Number of shapes in the store.
+    pub fn len(&self) -> usize {
+        self.storage.shapes.len()
+    }
+
+    /// Check that the store has no shapes.
+    pub fn is_empty(&self) -> bool {
+        self.storage.shapes.is_empty()
+    }
+
+    /// Bounding box of all shapes.
+    pub fn bounds(&self) -> Aabb {
+        self.storage.bounds
+    }
+
+    /// Shape with the identifier.
+    pub fn get(&self, id: ShapeId) -> Option<&S> {
+        self.storage.shapes.get(id.0).map(|shape| &**shape)
+    }
+
+    /// Shared reference to the shape with the identifier.
+    pub fn shared(&self, id: ShapeId) -> Option<Arc<S>> {
+        self.storage.shapes.get(id.0).cloned()
+    }
+
+    /// Bounding box of the shape with the identifier.
+    pub fn aabb(&self, id: ShapeId) -> Option<Aabb> {
+        self.storage.boxes.get(id.0).copied()
+    }
+
+    /// Iterate over the shapes with their identifiers.
+    pub fn iter(&self) -> impl Iterator<Item = (ShapeId, &S)> {
+        self.storage
+            .shapes
+            .iter()
+            .enumerate()
+            .map(|(i, shape)| (ShapeId(i), &**shape))
+    }
+
+    /// Index of the shapes bounding boxes, box indices are the shapes identifier indices.
+    pub fn index(&self) -> GridIndex<'_> {
+        let storage = &*self.storage;
+        GridIndex::from_parts(
+            storage.bounds,
+            storage.size,
+            &storage.boxes,
+            &storage.offsets,
+            &storage.entries,
+        )
+    }
+
+    /// Shapes which bounding boxes intersect the `region`, each one is reported once.
+    pub fn query_region(&self, region: Aabb) -> impl Iterator<Item = ShapeId> + '_ {
+        self.index().query_region(region).map(ShapeId)
+    }
+
+    /// Shapes containing the `point`, in ascending order of identifiers.
+    pub fn containing(&self, point: Vec2) -> impl Iterator<Item = ShapeId> + '_
+    where
+        S: Closed,
+    {
+        self.index()
+            .query_point(point)
+            .filter(move |&i| self.storage.shapes[i].contains(point))
+            .map(ShapeId)
+    }
+
+    /// Shapes overlapping the `other` one.
+    pub fn overlapping<'a, T: Bounded + ?Sized>(
+        &'a self,
+        other: &'a T,
+    ) -> impl Iterator<Item = ShapeId> + 'a
+    where
+        S: Overlaps<T>,
+    {
+        self.query_region(other.aabb())
+            .filter(move |&id| self.storage.shapes[id.0].overlaps(other))
+    }
+
+    /// Find which shapes contain which of the `points`.
+    ///
+    /// Yields pairs of the shape identifier and the point index, ordered by the point index and then by the shape.
+    pub fn containing_points<'a>(
+        &'a self,
+        points: &'a [Vec2],
+    ) -> impl Iterator<Item = (ShapeId, usize)> + 'a
+    where
+        S: Closed,
+    {
+        points
+            .iter()
+            .enumerate()
+            .flat_map(move |(j, &point)| self.containing(point).map(move |id| (id, j)))
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{Arc, ArcPolygon, ArcVertex, Closed, CopyIterator, Disk, EPS, Polygon, Region};
+use alloc::{vec, vec::Vec};
+use core::f32::consts::PI;
+use glam::Vec2;
+
+/// Point where polygon boundary crosses the circle.
+#[derive(Clone, Copy, Debug)]
+struct Crossing {
+    /// Index of the polygon edge.
+    edge: usize,
+    /// Position on the edge.
+    t: f32,
+    point: Vec2,
+    /// Boundary goes into the disk.
+    entry: bool,
+}
+
+/// Append the vertex replacing the last one if they coincide.
+fn push(contour: &mut Vec<ArcVertex>, vertex: ArcVertex) {
+    match contour.last_mut() {
+        Some(last) if (last.point - vertex.point).abs().max_element() < EPS => *last = vertex,
+        _ => contour.push(vertex),
+    }
+}
+
+impl<V: CopyIterator<Item = Vec2> + ?Sized> Polygon<V> {
+    /// Cut the `disk` out of the counterclockwise polygon.
+    ///
+    /// Circular parts of the result boundary are represented exactly by arcs.
+    /// If the disk lies strictly inside the polygon it becomes a clockwise hole contour,
+    /// otherwise each contour of the result is a part of the polygon with notches.
+    /// The disk may split the polygon into several parts.
+    ///
+    /// Polygon vertices lying exactly on the circle are treated as being outside of the disk.
+    pub fn subtract_disk<
+        W: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>,
+        R: FromIterator<ArcPolygon<W>>,
+    >(
+        &self,
+        disk: &Disk,
+    ) -> Region<R> {
+        let vertices: Vec<Vec2> = self.vertices().collect();
+        let n = vertices.len();
+        let (center, radius) = (disk.center, disk.radius);
+        let inside = |p: Vec2| p.distance_squared(center) < radius * radius;
+
+        let mut crossings = Vec::new();
+        for (edge, (&a, &b)) in vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .enumerate()
+        {
+            // Roots of `|a + (b - a) * t - center| = radius`
+            let (d, f) = (b - a, a - center);
+            let (qa, qb, qc) = (
+                d.length_squared(),
+                2.0 * f.dot(d),
+                f.length_squared() - radius * radius,
+            );
+            let discr = qb * qb - 4.0 * qa * qc;
+            if qa == 0.0 || discr <= 0.0 {
+                continue;
+            }
+            let sqrt = discr.sqrt();
+            let roots = [(-qb - sqrt) / (2.0 * qa), (-qb + sqrt) / (2.0 * qa)];
+            let mut add = |t: f32, entry: bool| {
+                let t = t.clamp(0.0, 1.0);
+                crossings.push(Crossing {
+                    edge,
+                    t,
+                    point: a + d * t,
+                    entry,
+                })
+            };
+            match (inside(a), inside(b)) {
+                (false, true) => add(roots[0], true),
+                (true, false) => add(roots[1], false),
+                (false, false) => {
+                    if roots[0] > 0.0 && roots[1] < 1.0 {
+                        add(roots[0], true);
+                        add(roots[1], false);
+                    }
+                }
+                (true, true) => (),
+            }
+        }
+
+        if crossings.is_empty() {
+            let outline = || {
+                ArcPolygon::new(
+                    vertices
+                        .iter()
+                        .map(|&point| ArcVertex {
+                            point,
+                            sagitta: 0.0,
+                        })
+                        .collect(),
+                )
+            };
+            return if vertices.first().is_some_and(|&v| inside(v)) {
+                Region::new(R::from_iter([]))
+            } else if self.contains(center) {
+                let mut hole = disk.polygon::<3>();
+                hole.reverse();
+                Region::new(R::from_iter([
+                    outline(),
+                    ArcPolygon::new(hole.vertices().collect()),
+                ]))
+            } else {
+                Region::new(R::from_iter([outline()]))
+            };
+        }
+
+        // Crossings are already ordered along the boundary, so entries and exits alternate
+        let m = crossings.len();
+        let angle = |p: Vec2| (p - center).to_angle();
+        // Exit point that is reached first when going clockwise along the circle from the `entry`
+        let next_exit = |entry: Vec2| {
+            (0..m)
+                .filter(|&k| !crossings[k].entry)
+                .min_by(|&i, &j| {
+                    let sweep =
+                        |k: usize| (angle(entry) - angle(crossings[k].point)).rem_euclid(2.0 * PI);
+                    sweep(i).total_cmp(&sweep(j))
+                })
+                .unwrap()
+        };
+
+        let mut visited = vec![false; m];
+        let contours = (0..m).filter_map(|start| {
+            if crossings[start].entry || visited[start] {
+                return None;
+            }
+            let mut contour = Vec::new();
+            let mut exit = start;
+            loop {
+                visited[exit] = true;
+                let Crossing { edge, t, point, .. } = crossings[exit];
+                push(
+                    &mut contour,
+                    ArcVertex {
+                        point,
+                        sagitta: 0.0,
+                    },
+                );
+                // Walk along the polygon boundary outside of the disk
+                let entry = crossings[(exit + 1) % m];
+                let count = if entry.edge == edge && entry.t > t {
+                    0
+                } else {
+                    (entry.edge + n - edge - 1) % n + 1
+                };
+                for k in 1..=count {
+                    push(
+                        &mut contour,
+                        ArcVertex {
+                            point: vertices[(edge + k) % n],
+                            sagitta: 0.0,
+                        },
+                    );
+                }
+                // Go back along the circle
+                exit = next_exit(entry.point);
+                let arc = Arc::from_circle_points(
+                    center,
+                    radius,
+                    entry.point,
+                    crossings[exit].point,
+                    false,
+                );
+                push(
+                    &mut contour,
+                    ArcVertex {
+                        point: entry.point,
+                        sagitta: arc.sagitta,
+                    },
+                );
+                if exit == start {
+                    break;
+                }
+            }
+            Some(ArcPolygon::new(contour.into_iter().collect()))
+        });
+        Region::new(contours.collect())
+    }
+}
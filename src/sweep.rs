@@ -0,0 +1,418 @@
+use crate::LineSegment;
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::cmp::Ordering;
+use glam::{DVec2, Vec2};
+
+/// Relative tolerance used to merge coincident event points.
+///
+/// Computations are done in double precision, so it is much smaller than the precision of the input.
+const TOLERANCE: f64 = 1e-12;
+
+/// Find all intersections between the `segments` using Bentley–Ottmann sweep line algorithm.
+///
+/// Yields indices of the intersecting segments `(i, j)`, where `i < j`, and their common point.
+/// Segments are also reported when they touch each other by their ends,
+/// and overlapping collinear segments are reported at the ends of their overlap.
+/// When several segments meet at the same point, all their pairs are reported.
+///
+/// Events are ordered by a binary heap and the sweep line status is a treap searched by the segment heights,
+/// so the algorithm takes `O((n + k) log n)` expected time, where `k` is the number of intersections.
+/// Only the segments which become adjacent in the status are tested for intersection.
+/// Intersections are computed eagerly in double precision before the first item is yielded.
+pub fn segment_intersections<I: IntoIterator<Item = LineSegment>>(
+    segments: I,
+) -> impl Iterator<Item = (usize, usize, Vec2)> {
+    let mut sweep = Sweep::new(segments);
+    let mut output = Vec::new();
+    while let Some(point) = sweep.next_point() {
+        sweep.handle(point, &mut output);
+    }
+    output.into_iter()
+}
+
+/// Point where the sweep line must stop.
+///
+/// Ordered so that the leftmost point (the lowest one among equal) is popped from the heap first.
+#[derive(Clone, Copy, Debug)]
+struct Event {
+    point: DVec2,
+    /// Segment starting at this point.
+    start: Option<usize>,
+    /// Pair of segments crossing at this point.
+    cross: Option<(usize, usize)>,
+}
+
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Event {}
+impl PartialOrd for Event {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Event {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(other.point, self.point)
+    }
+}
+
+fn compare(a: DVec2, b: DVec2) -> Ordering {
+    a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+}
+
+fn is_same(a: DVec2, b: DVec2) -> bool {
+    (a - b).abs().max_element() <= TOLERANCE * (1.0 + a.abs().max_element())
+}
+
+/// Segment directed from left to right (from bottom to top if vertical).
+#[derive(Clone, Copy, Debug)]
+struct Segment(DVec2, DVec2);
+
+impl Segment {
+    fn new(LineSegment(a, b): LineSegment) -> Self {
+        let (a, b) = (a.as_dvec2(), b.as_dvec2());
+        match compare(a, b) {
+            Ordering::Greater => Self(b, a),
+            _ => Self(a, b),
+        }
+    }
+
+    fn vec(&self) -> DVec2 {
+        self.1 - self.0
+    }
+
+    fn contains(&self, point: DVec2) -> bool {
+        let r = self.vec();
+        let t = ((point - self.0).dot(r) / r.length_squared()).clamp(0.0, 1.0);
+        // Degenerate segment gives NaN
+        let closest = if t.is_finite() {
+            self.0 + r * t
+        } else {
+            self.0
+        };
+        is_same(closest, point)
+    }
+
+    /// Height of the segment at the sweep line passing through the `point`.
+    fn height(&self, point: DVec2) -> f64 {
+        let Self(a, b) = *self;
+        if b.x - a.x <= TOLERANCE * (1.0 + a.x.abs()) {
+            point.y.clamp(a.y, b.y)
+        } else {
+            a.y + (b.y - a.y) * ((point.x - a.x) / (b.x - a.x))
+        }
+    }
+
+    /// Common point of the segments.
+    ///
+    /// Collinear overlapping segments give the start of the overlap.
+    fn intersect(&self, other: &Self) -> Option<DVec2> {
+        let (r, s) = (self.vec(), other.vec());
+        let pq = other.0 - self.0;
+        let den = r.perp_dot(s);
+        let scale = r.length() * s.length();
+        if den.abs() > TOLERANCE * scale {
+            let u = pq.perp_dot(s) / den;
+            let v = pq.perp_dot(r) / den;
+            let range = -TOLERANCE..=1.0 + TOLERANCE;
+            if range.contains(&u) && range.contains(&v) {
+                Some(self.0 + r * u.clamp(0.0, 1.0))
+            } else {
+                None
+            }
+        } else {
+            let start = match compare(self.0, other.0) {
+                Ordering::Less => other.0,
+                _ => self.0,
+            };
+            if self.contains(start) && other.contains(start) {
+                Some(start)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Event point with all the events merged into it.
+struct Point {
+    pos: DVec2,
+    starts: Vec<usize>,
+    crosses: Vec<usize>,
+}
+
+/// Node of the status treap, the node index is the index of its segment.
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    left: Option<usize>,
+    right: Option<usize>,
+    priority: u64,
+}
+
+/// Segments crossing the sweep line ordered from bottom to top.
+///
+/// It is a treap with the node priorities given by a hash of the segment index,
+/// so it is balanced with high probability and all the operations take `O(log n)` expected time.
+struct Status {
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+impl Status {
+    fn new(count: usize) -> Self {
+        let nodes = (0..count)
+            .map(|i| {
+                // SplitMix64 finalizer
+                let mut x = (i as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+                x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+                x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+                Node {
+                    left: None,
+                    right: None,
+                    priority: x ^ (x >> 31),
+                }
+            })
+            .collect();
+        Self { nodes, root: None }
+    }
+
+    /// Split the `tree` into the prefix where the `pred` holds and the rest.
+    fn split(
+        &mut self,
+        tree: Option<usize>,
+        pred: &impl Fn(usize) -> bool,
+    ) -> (Option<usize>, Option<usize>) {
+        let Some(i) = tree else {
+            return (None, None);
+        };
+        if pred(i) {
+            let (left, right) = self.split(self.nodes[i].right, pred);
+            self.nodes[i].right = left;
+            (Some(i), right)
+        } else {
+            let (left, right) = self.split(self.nodes[i].left, pred);
+            self.nodes[i].left = right;
+            (left, Some(i))
+        }
+    }
+
+    /// Concatenate the trees.
+    fn merge(&mut self, a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        let (Some(i), Some(j)) = (a, b) else {
+            return a.or(b);
+        };
+        if self.nodes[i].priority > self.nodes[j].priority {
+            self.nodes[i].right = self.merge(self.nodes[i].right, b);
+            a
+        } else {
+            self.nodes[j].left = self.merge(a, self.nodes[j].left);
+            b
+        }
+    }
+
+    /// Tree consisting of the single segment.
+    fn leaf(&mut self, i: usize) -> Option<usize> {
+        self.nodes[i].left = None;
+        self.nodes[i].right = None;
+        Some(i)
+    }
+
+    fn first(&self, mut tree: Option<usize>) -> Option<usize> {
+        while let Some(i) = self.nodes[tree?].left {
+            tree = Some(i);
+        }
+        tree
+    }
+
+    fn last(&self, mut tree: Option<usize>) -> Option<usize> {
+        while let Some(i) = self.nodes[tree?].right {
+            tree = Some(i);
+        }
+        tree
+    }
+
+    /// Remove the first segment of the `tree`.
+    fn pop_first(&mut self, tree: Option<usize>) -> Option<usize> {
+        let i = tree?;
+        match self.nodes[i].left {
+            None => self.nodes[i].right,
+            left => {
+                self.nodes[i].left = self.pop_first(left);
+                tree
+            }
+        }
+    }
+
+    /// Remove the last segment of the `tree`.
+    fn pop_last(&mut self, tree: Option<usize>) -> Option<usize> {
+        let i = tree?;
+        match self.nodes[i].right {
+            None => self.nodes[i].left,
+            right => {
+                self.nodes[i].right = self.pop_last(right);
+                tree
+            }
+        }
+    }
+}
+
+struct Sweep {
+    segments: Vec<Segment>,
+    queue: BinaryHeap<Event>,
+    status: Status,
+}
+
+impl Sweep {
+    fn new<I: IntoIterator<Item = LineSegment>>(segments: I) -> Self {
+        let segments: Vec<_> = segments.into_iter().map(Segment::new).collect();
+        let queue = segments
+            .iter()
+            .enumerate()
+            .flat_map(|(i, s)| {
+                [
+                    Event {
+                        point: s.0,
+                        start: Some(i),
+                        cross: None,
+                    },
+                    Event {
+                        point: s.1,
+                        start: None,
+                        cross: None,
+                    },
+                ]
+            })
+            .collect();
+        Self {
+            status: Status::new(segments.len()),
+            segments,
+            queue,
+        }
+    }
+
+    /// Pop the next event point with all the segments starting and crossing at it.
+    fn next_point(&mut self) -> Option<Point> {
+        let first = self.queue.pop()?;
+        let mut point = Point {
+            pos: first.point,
+            starts: Vec::new(),
+            crosses: Vec::new(),
+        };
+        let mut add = |event: Event| {
+            point.starts.extend(event.start);
+            point
+                .crosses
+                .extend(event.cross.into_iter().flat_map(|(a, b)| [a, b]));
+        };
+        add(first);
+        while let Some(&event) = self.queue.peek().filter(|e| is_same(e.point, first.point)) {
+            add(event);
+            self.queue.pop();
+        }
+        Some(point)
+    }
+
+    fn handle(&mut self, event: Point, output: &mut Vec<(usize, usize, Vec2)>) {
+        let Point {
+            pos: point,
+            starts,
+            crosses,
+        } = event;
+        let segments = &self.segments;
+        let status = &mut self.status;
+        // Segments passing through the point are adjacent in the status
+        let (mut below, mut above) =
+            status.split(status.root, &|s| segments[s].height(point) < point.y);
+        let (mut lower, mut upper) = (Vec::new(), Vec::new());
+        while let Some(s) = status.last(below).filter(|&s| segments[s].contains(point)) {
+            below = status.pop_last(below);
+            lower.push(s);
+        }
+        while let Some(s) = status.first(above).filter(|&s| segments[s].contains(point)) {
+            above = status.pop_first(above);
+            upper.push(s);
+        }
+        // Computed crossing point may be slightly off one of its segments, so they are added explicitly
+        loop {
+            if let Some(s) = status.last(below).filter(|s| crosses.contains(s)) {
+                below = status.pop_last(below);
+                lower.push(s);
+            } else if let Some(s) = status.first(above).filter(|s| crosses.contains(s)) {
+                above = status.pop_first(above);
+                upper.push(s);
+            } else {
+                break;
+            }
+        }
+
+        let through: Vec<usize> = lower
+            .into_iter()
+            .rev()
+            .chain(upper)
+            .chain(starts.iter().copied())
+            .collect();
+        let output_point = point.as_vec2();
+        for (k, &i) in through.iter().enumerate() {
+            for &j in &through[k + 1..] {
+                output.push((i.min(j), i.max(j), output_point));
+            }
+        }
+
+        // Segments that continue to the right of the point are reinserted ordered by their slope
+        let mut next: Vec<usize> = through
+            .into_iter()
+            .filter(|&s| !is_same(segments[s].1, point))
+            .collect();
+        next.sort_by(|&a, &b| {
+            let (u, v) = (segments[a].vec(), segments[b].vec());
+            u.y.atan2(u.x).total_cmp(&v.y.atan2(v.x))
+        });
+        let middle = next.iter().fold(None, |tree, &s| {
+            let leaf = status.leaf(s);
+            status.merge(tree, leaf)
+        });
+
+        let (lowest, highest) = (status.last(below), status.first(above));
+        let tree = status.merge(below, middle);
+        status.root = status.merge(tree, above);
+        match (next.first(), next.last()) {
+            (Some(&first), Some(&last)) => {
+                if let Some(b) = lowest {
+                    self.find_event(b, first, point);
+                }
+                if let Some(a) = highest {
+                    self.find_event(last, a, point);
+                }
+            }
+            _ => {
+                if let (Some(b), Some(a)) = (lowest, highest) {
+                    self.find_event(b, a, point);
+                }
+            }
+        }
+    }
+
+    /// Schedule intersection of the `lower` and `upper` adjacent segments if it is ahead of the sweep line.
+    fn find_event(&mut self, lower: usize, upper: usize, point: DVec2) {
+        let (a, b) = (self.segments[lower], self.segments[upper]);
+        // Segments can cross ahead only if the lower one is steeper.
+        // This also accepts the crossings on the sweep line below the point,
+        // which appear due to rounding when a segment is almost vertical.
+        if a.vec().perp_dot(b.vec()) >= 0.0 {
+            return;
+        }
+        if let Some(cross) = a.intersect(&b)
+            && cross.x >= point.x - TOLERANCE * (1.0 + point.x.abs())
+            && !is_same(cross, point)
+        {
+            self.queue.push(Event {
+                point: cross,
+                start: None,
+                cross: Some((lower, upper)),
+            });
+        }
+    }
+}
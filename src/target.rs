@@ -0,0 +1,96 @@
+use crate::{
+    Aabb, Arc, ArcVertex, Bounded, CopyIterator, Disk, Edge, GenericPolygon, Integrable, Meta,
+    Moment, Unmeta, Vertex,
+    decompose::{arc_pieces, moment_of_pieces},
+};
+use either::Either;
+
+/// Representation that the result of clipping by circles and half-planes can be written to.
+///
+/// The result is passed as a sequence of vertices of a counterclockwise arc polygon
+/// which is consumed once, so the target doesn't need to store it.
+///
+/// Implemented for:
+/// + [`GenericPolygon`] with any storage that can be collected from vertices,
+///   e.g. `Vec` or `ArrayVec` (which panics if the result doesn't fit),
+/// + [`Either<T, Disk>`] where the whole disk is stored separately,
+/// + [`Moment`] and [`Aabb`] that are accumulated without storing vertices at all.
+pub trait ArcTarget<T = ArcVertex>: Sized {
+    /// Build from the vertices of arc polygon.
+    fn from_arc_vertices<I: Iterator<Item = T>>(vertices: I) -> Self;
+}
+
+/// [`ArcTarget`] that can also represent the whole disk.
+pub trait DiskTarget: ArcTarget {
+    /// Build from the disk.
+    ///
+    /// By default the disk is represented as a polygon of two arcs.
+    fn from_disk(disk: Disk) -> Self {
+        Self::from_arc_vertices(disk.polygon::<2>().vertices())
+    }
+}
+
+impl<T: Vertex, V: CopyIterator<Item = T> + FromIterator<T>> ArcTarget<T> for GenericPolygon<V, T> {
+    fn from_arc_vertices<I: Iterator<Item = T>>(vertices: I) -> Self {
+        Self::from_iter(vertices)
+    }
+}
+
+impl<V: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>> DiskTarget
+    for GenericPolygon<V, ArcVertex>
+{
+}
+
+impl<T: ArcTarget> ArcTarget for Either<T, Disk> {
+    fn from_arc_vertices<I: Iterator<Item = ArcVertex>>(vertices: I) -> Self {
+        Either::Left(T::from_arc_vertices(vertices))
+    }
+}
+
+impl<T: ArcTarget> DiskTarget for Either<T, Disk> {
+    fn from_disk(disk: Disk) -> Self {
+        Either::Right(disk)
+    }
+}
+
+impl<T, U: ArcTarget<T>> ArcTarget<Meta<T, ()>> for Unmeta<U> {
+    fn from_arc_vertices<I: Iterator<Item = Meta<T, ()>>>(vertices: I) -> Self {
+        Self(U::from_arc_vertices(vertices.map(|x| x.inner)))
+    }
+}
+
+/// Edges of the closed polygon given by a single pass over its vertices.
+fn edges<I: Iterator<Item = ArcVertex>>(mut vertices: I) -> impl Iterator<Item = Arc> {
+    let first = vertices.next();
+    let mut prev = first;
+    vertices.chain(first).map(move |curr| {
+        let edge = Arc::from_vertices(&prev.unwrap(), &curr);
+        prev = Some(curr);
+        edge
+    })
+}
+
+impl ArcTarget for Moment {
+    /// Same as [`ArcPolygon::signed_moment`](crate::ArcPolygon::signed_moment) of the collected polygon.
+    fn from_arc_vertices<I: Iterator<Item = ArcVertex>>(vertices: I) -> Self {
+        moment_of_pieces(arc_pieces(vertices))
+    }
+}
+
+impl DiskTarget for Moment {
+    fn from_disk(disk: Disk) -> Self {
+        disk.moment()
+    }
+}
+
+impl ArcTarget for Aabb {
+    fn from_arc_vertices<I: Iterator<Item = ArcVertex>>(vertices: I) -> Self {
+        edges(vertices).fold(Aabb::EMPTY, |aabb, arc| aabb.union(arc.aabb()))
+    }
+}
+
+impl DiskTarget for Aabb {
+    fn from_disk(disk: Disk) -> Self {
+        disk.aabb()
+    }
+}
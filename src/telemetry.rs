@@ -0,0 +1,99 @@
+//! Counters of fragile code paths taken by geometric operations.
+//!
+//! Counters are global and are updated only when `telemetry` feature is enabled.
+
+#[cfg(feature = "telemetry")]
+use core::{
+    ops::Sub,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[cfg(feature = "telemetry")]
+static DEGENERATE: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "telemetry")]
+static EPS_RESCUES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "telemetry")]
+static CLIPS: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "telemetry")]
+static CLIP_VERTICES: AtomicUsize = AtomicUsize::new(0);
+
+/// Degenerate case handled by a special branch, e.g. parallel lines or collapsed vertices.
+#[inline]
+pub(crate) fn degenerate() {
+    #[cfg(feature = "telemetry")]
+    DEGENERATE.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Division by near-zero value avoided by a fallback within [`EPS`](crate::EPS) tolerance.
+#[inline]
+pub(crate) fn eps_rescue() {
+    #[cfg(feature = "telemetry")]
+    EPS_RESCUES.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Clipping produced polygon with `_vertices` count.
+#[inline]
+pub(crate) fn clip_output(_vertices: usize) {
+    #[cfg(feature = "telemetry")]
+    {
+        CLIPS.fetch_add(1, Ordering::Relaxed);
+        CLIP_VERTICES.fetch_add(_vertices, Ordering::Relaxed);
+    }
+}
+
+/// Snapshot of telemetry counters.
+#[cfg(feature = "telemetry")]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Stats {
+    /// Number of degenerate branches taken.
+    pub degenerate: usize,
+    /// Number of near-zero divisions replaced by fallback values.
+    pub eps_rescues: usize,
+    /// Number of non-empty polygon clipping results.
+    pub clips: usize,
+    /// Total number of vertices in clipping results.
+    pub clip_vertices: usize,
+}
+
+/// Counters are compared field-wise, a counter which was [`reset`] in between gives zero.
+#[cfg(feature = "telemetry")]
+impl Sub for Stats {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self {
+            degenerate: self.degenerate.saturating_sub(other.degenerate),
+            eps_rescues: self.eps_rescues.saturating_sub(other.eps_rescues),
+            clips: self.clips.saturating_sub(other.clips),
+            clip_vertices: self.clip_vertices.saturating_sub(other.clip_vertices),
+        }
+    }
+}
+
+/// Current values of the global counters.
+#[cfg(feature = "telemetry")]
+pub fn stats() -> Stats {
+    Stats {
+        degenerate: DEGENERATE.load(Ordering::Relaxed),
+        eps_rescues: EPS_RESCUES.load(Ordering::Relaxed),
+        clips: CLIPS.load(Ordering::Relaxed),
+        clip_vertices: CLIP_VERTICES.load(Ordering::Relaxed),
+    }
+}
+
+/// Reset the global counters to zero.
+#[cfg(feature = "telemetry")]
+pub fn reset() {
+    for counter in [&DEGENERATE, &EPS_RESCUES, &CLIPS, &CLIP_VERTICES] {
+        counter.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Call `f` and return the counter increments made during the call.
+///
+/// Operations running concurrently in other threads are also counted.
+#[cfg(feature = "telemetry")]
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Stats) {
+    let before = stats();
+    let result = f();
+    (result, stats() - before)
+}
@@ -0,0 +1,182 @@
+//! Reproducible pseudo-random shapes for tests and benchmarks.
+//!
+//! Shapes are generated by a small built-in PCG generator, so the same seed gives the same shapes
+//! on any platform and with any version of external crates.
+//! With the `rand` feature the generator implements [`rand::RngCore`],
+//! and [`random_simple_polygon`] accepts any random number generator.
+
+#[cfg(feature = "rand")]
+use crate::Aabb;
+use crate::{ArcPolygon, ArcVertex, CopyIterator, Polygon};
+#[cfg(feature = "rand")]
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+use glam::Vec2;
+#[cfg(feature = "rand")]
+use rand::{Rng, RngCore, rand_core::impls};
+
+/// Permuted congruential generator (PCG-XSH-RR 64/32).
+#[derive(Clone, Debug)]
+pub struct Pcg32 {
+    state: u64,
+    inc: u64,
+}
+
+impl Pcg32 {
+    const MULTIPLIER: u64 = 6364136223846793005;
+
+    /// Create generator from the `seed`.
+    pub fn new(seed: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            inc: (seed << 1) | 1,
+        };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+
+    /// Next random number uniformly distributed over all `u32` values.
+    pub fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old.wrapping_mul(Self::MULTIPLIER).wrapping_add(self.inc);
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        xorshifted.rotate_right((old >> 59) as u32)
+    }
+
+    /// Random number uniformly distributed in `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Random number uniformly distributed in `[min, max)`.
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    /// Random point uniformly distributed in the unit disk.
+    pub fn in_unit_disk(&mut self) -> Vec2 {
+        let radius = self.next_f32().sqrt();
+        radius * Vec2::from_angle(self.range(0.0, 2.0 * PI))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl RngCore for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        Pcg32::next_u32(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+/// Random counterclockwise convex polygon inscribed into the unit disk.
+///
+/// The polygon is a convex hull of `count` random points, so it usually has less vertices.
+pub fn convex_polygon<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+    rng: &mut Pcg32,
+    count: usize,
+) -> Polygon<W> {
+    let points: Polygon<W> = (0..count).map(|_| rng.in_unit_disk()).collect();
+    points.convex_hull().collect()
+}
+
+/// Random counterclockwise star-shaped polygon with `count` vertices.
+///
+/// Vertices are located at increasing angles around the origin with distance from it in `[inner, 1)`,
+/// and the angle between the consecutive ones is less than `1.9 * 2 * PI / count`.
+/// So if `count >= 4` the polygon is simple, star-shaped around the origin,
+/// and contains the disk of `inner * cos(0.95 * PI / count)` radius.
+/// Note that the edges may pass inside of the disk of `inner` radius.
+pub fn star_polygon<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>>(
+    rng: &mut Pcg32,
+    count: usize,
+    inner: f32,
+) -> Polygon<W> {
+    star_points(rng, count, inner).collect()
+}
+
+fn star_points(rng: &mut Pcg32, count: usize, inner: f32) -> impl Iterator<Item = Vec2> + '_ {
+    (0..count).map(move |i| {
+        let angle = 2.0 * PI * (i as f32 + rng.range(0.0, 0.9)) / count as f32;
+        rng.range(inner, 1.0) * Vec2::from_angle(angle)
+    })
+}
+
+/// Random arc polygon with `count` vertices.
+///
+/// Vertices are generated as in [`star_polygon`], and each edge is bent by the random sagitta
+/// not exceeding `0.25` of its chord in any direction.
+pub fn arc_polygon<W: CopyIterator<Item = ArcVertex> + FromIterator<ArcVertex>>(
+    rng: &mut Pcg32,
+    count: usize,
+    inner: f32,
+) -> ArcPolygon<W> {
+    let mut bend = Pcg32::new(u64::from(rng.next_u32()) << 32 | u64::from(rng.next_u32()));
+    let mut points = star_points(rng, count, inner);
+    let first = points.next();
+    let mut prev = first;
+    points
+        .map(Some)
+        .chain([first])
+        .filter_map(move |next| {
+            let (a, b) = (prev?, next?);
+            prev = Some(b);
+            Some(ArcVertex {
+                point: a,
+                sagitta: bend.range(-0.25, 0.25) * (b - a).length(),
+            })
+        })
+        .collect()
+}
+
+/// Random counterclockwise simple polygon with `count` vertices uniformly distributed in the `bounds`.
+///
+/// Vertices are connected in random order, and then the crossing edges are untangled by 2-opt moves:
+/// each pair of crossing edges is replaced by the pair connecting their ends the other way.
+/// Each move shortens the perimeter, so the process ends, and the result has no crossing edges,
+/// unlike random star-shaped polygons it is usually far from being convex.
+/// Each pass over the edge pairs takes `O(n^2)` time and the number of passes is small in practice.
+///
+/// Random points are in general position with probability one, while touching edges are not untangled.
+#[cfg(feature = "rand")]
+pub fn random_simple_polygon<W: CopyIterator<Item = Vec2> + FromIterator<Vec2>, R: Rng + ?Sized>(
+    count: usize,
+    bounds: Aabb,
+    rng: &mut R,
+) -> Polygon<W> {
+    let size = bounds.size();
+    let mut points: Vec<Vec2> = (0..count)
+        .map(|_| bounds.min + size * Vec2::new(rng.random(), rng.random()))
+        .collect();
+    let side = |a: Vec2, b: Vec2, c: Vec2| (b - a).perp_dot(c - a);
+    let crossing = |a: Vec2, b: Vec2, c: Vec2, d: Vec2| {
+        side(a, b, c) * side(a, b, d) < 0.0 && side(c, d, a) * side(c, d, b) < 0.0
+    };
+    let n = points.len();
+    let mut untangled = false;
+    while !untangled && n > 3 {
+        untangled = true;
+        for i in 0..n - 2 {
+            // The last edge is adjacent to the first one
+            for j in (i + 2)..(if i == 0 { n - 1 } else { n }) {
+                let (a, b, c, d) = (points[i], points[i + 1], points[j], points[(j + 1) % n]);
+                if crossing(a, b, c, d) {
+                    points[i + 1..=j].reverse();
+                    untangled = false;
+                }
+            }
+        }
+    }
+    if Polygon::new(points.to_ref()).signed_area() < 0.0 {
+        points.reverse();
+    }
+    points.into_iter().collect()
+}
@@ -0,0 +1,68 @@
+use crate::{Aabb, Arc, Bounded, Capsule, Closed, Disk, Integrable, Polygon};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+
+const TEST_EPS: f32 = 1e-6;
+
+#[test]
+fn basic() {
+    let a = Aabb::from_points([
+        Vec2::new(1.0, 2.0),
+        Vec2::new(-1.0, 3.0),
+        Vec2::new(0.0, 0.0),
+    ]);
+    assert_eq!(a, Aabb::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 3.0)));
+    assert_eq!(a.size(), Vec2::new(2.0, 3.0));
+    assert_abs_diff_eq!(a.area(), 6.0);
+    assert!(a.contains(Vec2::new(0.5, 2.5)));
+    assert!(!a.contains(Vec2::new(1.5, 2.5)));
+    assert_abs_diff_eq!(a.polygon().moment(), a.moment(), epsilon = TEST_EPS);
+
+    let b = Aabb::new(Vec2::new(1.0, 3.0), Vec2::new(2.0, 4.0));
+    assert!(a.intersects(&b));
+    assert!(!a.intersects(&b.inflate(-0.1)));
+
+    assert!(Aabb::EMPTY.is_empty());
+    assert!(!a.intersects(&Aabb::EMPTY));
+    assert_eq!(Aabb::EMPTY.union(a), a);
+}
+
+#[test]
+fn shapes() {
+    let disk = Disk::new(Vec2::new(1.0, 2.0), 0.5);
+    assert_abs_diff_eq!(
+        disk.aabb(),
+        Aabb::new(Vec2::new(0.5, 1.5), Vec2::new(1.5, 2.5)),
+        epsilon = TEST_EPS
+    );
+    // Arc polygon bounds coincide with disk bounds
+    assert_abs_diff_eq!(disk.polygon::<3>().aabb(), disk.aabb(), epsilon = TEST_EPS);
+
+    // Upper semicircle
+    let arc = Arc {
+        points: (Vec2::new(1.0, 0.0), Vec2::new(-1.0, 0.0)),
+        sagitta: 1.0,
+    };
+    assert_abs_diff_eq!(
+        arc.aabb(),
+        Aabb::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 1.0)),
+        epsilon = TEST_EPS
+    );
+
+    let capsule = Capsule::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0), 1.0);
+    assert_abs_diff_eq!(
+        capsule.aabb(),
+        Aabb::new(Vec2::new(-1.0, -1.0), Vec2::new(3.0, 1.0)),
+        epsilon = TEST_EPS
+    );
+
+    let poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 3.0),
+    ]);
+    assert_eq!(
+        poly.aabb(),
+        Aabb::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 3.0))
+    );
+}
@@ -0,0 +1,131 @@
+extern crate std;
+
+use crate::{Aabb, AccretedRegion, Closed, Disk, Integrable, Polygon, testgen::Pcg32};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::{vec, vec::Vec};
+
+#[test]
+fn overlapping() {
+    let mut region = AccretedRegion::new(1e-3);
+    assert!(region.is_empty());
+    region.add_polygon(&Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon());
+    region.add_polygon(&Aabb::new(Vec2::splat(1.0), Vec2::splat(3.0)).polygon());
+    assert_eq!(region.contours().len(), 1);
+    assert_eq!(region.vertex_count(), 8);
+    assert_abs_diff_eq!(region.area(), 7.0, epsilon = 1e-4);
+    assert!(region.contains(Vec2::new(0.5, 0.5)));
+    assert!(region.contains(Vec2::new(1.5, 1.5)));
+    assert!(!region.contains(Vec2::new(2.5, 0.5)));
+
+    // Shape inside of the region changes nothing
+    region.add_polygon(&Aabb::new(Vec2::splat(0.5), Vec2::splat(1.5)).polygon());
+    assert_eq!(region.vertex_count(), 8);
+    assert_abs_diff_eq!(region.area(), 7.0, epsilon = 1e-4);
+}
+
+#[test]
+fn disjoint_and_touching() {
+    let mut region = AccretedRegion::new(1e-3);
+    region.add_polygon(&Aabb::new(Vec2::ZERO, Vec2::splat(1.0)).polygon());
+    // Clockwise polygon is added too
+    let mut far = Aabb::new(Vec2::new(3.0, 0.0), Vec2::new(4.0, 1.0)).polygon();
+    far.vertices.reverse();
+    region.add_polygon(&far);
+    assert_eq!(region.contours().len(), 2);
+    assert_abs_diff_eq!(region.area(), 2.0, epsilon = 1e-4);
+
+    // Square sharing edges with both of them glues them together
+    region.add_polygon(&Polygon::new(vec![
+        Vec2::new(1.0, 0.0),
+        Vec2::new(3.0, 0.0),
+        Vec2::new(3.0, 1.0),
+        Vec2::new(1.0, 1.0),
+    ]));
+    assert_eq!(region.contours().len(), 1);
+    assert_abs_diff_eq!(region.area(), 4.0, epsilon = 1e-4);
+    assert!(region.contains(Vec2::new(1.0, 0.5)));
+    assert!(region.contains(Vec2::new(3.0, 0.5)));
+}
+
+#[test]
+fn corner_touching() {
+    let mut region = AccretedRegion::new(1e-3);
+    region.add_polygon(&Aabb::new(Vec2::ZERO, Vec2::splat(1.0)).polygon());
+    region.add_polygon(&Aabb::new(Vec2::ONE, Vec2::splat(2.0)).polygon());
+    // Contours touching at a vertex are kept separate and simple
+    assert_eq!(region.contours().len(), 2);
+    for contour in region.contours() {
+        assert_eq!(contour.len(), 4);
+        assert_abs_diff_eq!(contour.signed_area(), 1.0, epsilon = 1e-4);
+    }
+    assert_abs_diff_eq!(region.area(), 2.0, epsilon = 1e-4);
+}
+
+#[test]
+fn hole() {
+    let mut region = AccretedRegion::new(1e-3);
+    for (x, y) in [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (2.0, 2.0)] {
+        region.add_polygon(&Aabb::new(Vec2::new(x, y), Vec2::new(x + 1.0, y + 1.0)).polygon());
+    }
+    for (x, y) in [(1.0, 2.0), (0.0, 2.0), (0.0, 1.0)] {
+        region.add_polygon(&Aabb::new(Vec2::new(x, y), Vec2::new(x + 1.0, y + 1.0)).polygon());
+    }
+    assert_eq!(region.contours().len(), 2);
+    assert_abs_diff_eq!(region.area(), 8.0, epsilon = 1e-4);
+    assert!(!region.contains(Vec2::new(1.5, 1.5)));
+    assert!(region.contains(Vec2::new(0.5, 1.5)));
+
+    // Filling the hole removes it
+    region.add_polygon(&Aabb::new(Vec2::splat(0.9), Vec2::splat(2.1)).polygon());
+    assert_eq!(region.contours().len(), 1);
+    assert_abs_diff_eq!(region.area(), 9.0, epsilon = 1e-4);
+}
+
+#[test]
+fn random_disks() {
+    let tolerance = 1e-3;
+    let mut rng = Pcg32::new(0xacc);
+    let mut region = AccretedRegion::new(tolerance);
+    let mut disks = Vec::new();
+    for _ in 0..40 {
+        let disk = Disk::new(
+            Vec2::new(rng.next_f32(), rng.next_f32()) * 10.0,
+            0.5 + rng.next_f32(),
+        );
+        region.add_disk(&disk);
+        disks.push(disk);
+    }
+    for _ in 0..1000 {
+        let point = Vec2::new(rng.next_f32(), rng.next_f32()) * 12.0 - 1.0;
+        // Points near the circles are skipped because the disks are replaced by polygons
+        let clearance = disks
+            .iter()
+            .map(|disk| (point.distance(disk.center) - disk.radius).abs())
+            .fold(f32::INFINITY, f32::min);
+        if clearance < 0.01 {
+            continue;
+        }
+        let inside = disks.iter().any(|disk| disk.contains(point));
+        assert_eq!(region.contains(point), inside, "{point}");
+    }
+}
+
+#[test]
+fn bounded_vertices() {
+    let tolerance = 1e-2;
+    let mut region = AccretedRegion::new(tolerance);
+    // Disks along a line produce a long outline of many short arcs
+    for i in 0..200 {
+        region.add_disk(&Disk::new(Vec2::new(0.05 * i as f32, 0.0), 1.0));
+    }
+    assert_eq!(region.contours().len(), 1);
+    let count = region.vertex_count();
+    region.simplify();
+    assert!(region.vertex_count() <= count);
+    assert!(count < 2 * 200, "{count}");
+    // Stadium of the same size, the outline deviates from it within the tolerance
+    let (area, perimeter) = (2.0 * 9.95 + PI, 2.0 * 9.95 + 2.0 * PI);
+    assert_abs_diff_eq!(region.area(), area, epsilon = tolerance * perimeter);
+}
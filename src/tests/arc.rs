@@ -95,3 +95,166 @@ fn numerical_segment() {
         x += dx;
     }
 }
+
+#[test]
+fn arc_geometry() {
+    // Quarter of the circle going counterclockwise
+    let arc = Arc::from_circle_points(
+        Vec2::new(1.0, 2.0),
+        R,
+        Vec2::new(1.0 + R, 2.0),
+        Vec2::new(1.0, 2.0 + R),
+        true,
+    );
+    assert!(arc.is_ccw());
+    assert_abs_diff_eq!(arc.radius(), R, epsilon = 1e-6);
+    assert_abs_diff_eq!(arc.center(), Vec2::new(1.0, 2.0), epsilon = 1e-6);
+    assert_abs_diff_eq!(arc.sweep(), PI / 2.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(arc.length(), PI * R / 2.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        arc.midpoint(),
+        Vec2::new(1.0, 2.0) + R * Vec2::from_angle(PI / 4.0),
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(arc.point_at(0.5), arc.midpoint(), epsilon = 1e-6);
+    assert_abs_diff_eq!(arc.param_of(arc.midpoint()), 0.5, epsilon = 1e-6);
+
+    // Complementary arc going clockwise
+    let arc = Arc::from_circle_points(
+        Vec2::new(1.0, 2.0),
+        R,
+        Vec2::new(1.0 + R, 2.0),
+        Vec2::new(1.0, 2.0 + R),
+        false,
+    );
+    assert!(!arc.is_ccw());
+    assert_abs_diff_eq!(arc.center(), Vec2::new(1.0, 2.0), epsilon = 1e-6);
+    assert_abs_diff_eq!(arc.sweep(), -1.5 * PI, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        arc.midpoint(),
+        Vec2::new(1.0, 2.0) - R * Vec2::from_angle(PI / 4.0),
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        arc.param_of(Vec2::new(1.0, 2.0 - R)),
+        1.0 / 3.0,
+        epsilon = 1e-6
+    );
+
+    // Flat arc
+    let arc = Arc {
+        points: (Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0)),
+        sagitta: 0.0,
+    };
+    assert!(arc.circle().is_none());
+    assert_eq!(arc.length(), 2.0);
+    assert_eq!(arc.point_at(0.25), Vec2::new(0.5, 0.0));
+}
+
+#[test]
+fn sub_arc() {
+    let arc = Arc {
+        points: (Vec2::new(R, 0.0), Vec2::new(-R, 0.0)),
+        sagitta: -R,
+    };
+    let sub = arc.sub_arc(arc.point_at(0.25), arc.point_at(0.75));
+    assert_abs_diff_eq!(sub.center(), Vec2::ZERO, epsilon = 1e-6);
+    assert_abs_diff_eq!(sub.sweep(), -PI / 2.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(sub.midpoint(), arc.midpoint(), epsilon = 1e-6);
+}
+
+#[test]
+fn negative_segment() {
+    let segment = DiskSegment(Arc {
+        points: (Vec2::new(-R, 0.0), Vec2::new(R, 0.0)),
+        sagitta: -R,
+    });
+    assert!(!segment.contains(Vec2::new(0.0, 0.5 * R)));
+    assert_eq!(segment.winding_number_2(Vec2::new(0.0, 0.5 * R)), -2);
+    assert_abs_diff_eq!(
+        segment.moment(),
+        Moment {
+            area: -PI * R.powi(2) / 2.0,
+            centroid: Vec2::new(0.0, 4.0 * R / (3.0 * PI)),
+        },
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn from_center_angles() {
+    let center = Vec2::new(1.0, -2.0);
+    for (start, sweep) in [(0.3, 0.5 * PI), (-1.0, -PI), (2.0, 1.5 * PI), (0.0, 0.0)] {
+        let arc = Arc::from_center_angles(center, R, start, sweep);
+        assert_abs_diff_eq!(arc.sweep(), sweep, epsilon = 1e-5);
+        assert_abs_diff_eq!(
+            arc.points.0,
+            center + R * Vec2::from_angle(start),
+            epsilon = 1e-5
+        );
+        if sweep != 0.0 {
+            assert_abs_diff_eq!(arc.center(), center, epsilon = 1e-5);
+            assert_abs_diff_eq!(arc.radius(), R, epsilon = 1e-5);
+            assert_abs_diff_eq!(
+                arc.midpoint(),
+                center + R * Vec2::from_angle(start + 0.5 * sweep),
+                epsilon = 1e-5
+            );
+        }
+    }
+}
+
+#[test]
+fn from_three_points() {
+    let center = Vec2::new(-0.5, 0.7);
+    let point = |angle: f32| center + R * Vec2::from_angle(angle);
+    for (a, m, b) in [(0.0, 1.0, 2.5), (1.0, -0.5, -3.0), (0.0, PI, 5.0)] {
+        let arc = Arc::from_three_points(point(a), point(m), point(b));
+        assert_abs_diff_eq!(arc.center(), center, epsilon = 1e-4);
+        assert_abs_diff_eq!(arc.param_of(point(m)), (m - a) / (b - a), epsilon = 1e-4);
+    }
+
+    let flat = Arc::from_three_points(Vec2::ZERO, Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0));
+    assert!(flat.is_flat());
+}
+
+#[test]
+fn flatten() {
+    let arc = Arc::from_center_angles(Vec2::new(1.0, 2.0), 2.0, 0.5, -2.5);
+    let tolerance = 0.01;
+    let count = arc.segment_count(tolerance);
+    // Chord of `2.5 / count` sweep is within the tolerance, the longer one is not
+    assert!(2.0 * (1.0 - (1.25 / count as f32).cos()) <= tolerance);
+    assert!(2.0 * (1.0 - (1.25 / (count - 1) as f32).cos()) > tolerance);
+
+    let mut points = arc.flatten(tolerance);
+    assert_eq!(points.next(), Some(arc.points.0));
+    let mut last = arc.points.0;
+    let mut n = 0;
+    for point in points {
+        assert_abs_diff_eq!(point.distance(Vec2::new(1.0, 2.0)), 2.0, epsilon = 1e-5);
+        let middle = arc.closest_point(0.5 * (last + point));
+        assert!(middle.distance(0.5 * (last + point)) <= tolerance + 1e-5);
+        last = point;
+        n += 1;
+    }
+    assert_eq!(n, count);
+    assert_eq!(last, arc.points.1);
+
+    let flat = Arc {
+        points: (Vec2::ZERO, Vec2::X),
+        sagitta: 0.0,
+    };
+    assert_eq!(flat.segment_count(tolerance), 1);
+    // Tolerance larger than the arc itself
+    assert_eq!(arc.segment_count(10.0), 1);
+
+    // Tolerance far below the radius
+    let large = Arc::from_center_angles(Vec2::ZERO, 1000.0, 0.0, PI);
+    let count = large.segment_count(1e-5);
+    let angle = PI / count as f32;
+    assert!(count > 1000);
+    assert!(1000.0 * 2.0 * (0.25 * angle).sin().powi(2) <= 1e-5);
+    assert!(1000.0 * 2.0 * (0.25 * PI / (count - 1) as f32).sin().powi(2) > 1e-5);
+    assert!(large.segment_count(0.0) > count);
+}
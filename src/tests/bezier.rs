@@ -0,0 +1,103 @@
+extern crate std;
+
+use crate::{Aabb, Bounded, CubicBezier, Intersect, Line, LineSegment, QuadBezier};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+use std::vec::Vec;
+
+const QUAD: QuadBezier = QuadBezier(
+    Vec2::new(0.0, 0.0),
+    Vec2::new(1.0, 2.0),
+    Vec2::new(3.0, 0.0),
+);
+const CUBIC: CubicBezier = CubicBezier(
+    Vec2::new(0.0, 0.0),
+    Vec2::new(1.0, 3.0),
+    Vec2::new(2.0, -3.0),
+    Vec2::new(3.0, 0.0),
+);
+
+#[test]
+fn evaluation() {
+    assert_eq!(QUAD.point_at(0.0), QUAD.0);
+    assert_eq!(QUAD.point_at(1.0), QUAD.2);
+    assert_abs_diff_eq!(QUAD.point_at(0.5), Vec2::new(1.25, 1.0));
+    assert_eq!(CUBIC.point_at(1.0), CUBIC.3);
+    assert_abs_diff_eq!(CUBIC.point_at(0.5), Vec2::new(1.5, 0.0));
+    // Tangents at the ends point to the control points
+    assert_abs_diff_eq!(QUAD.derivative(0.0), 2.0 * (QUAD.1 - QUAD.0));
+    assert_abs_diff_eq!(CUBIC.derivative(1.0), 3.0 * (CUBIC.3 - CUBIC.2));
+    let h = 1e-3;
+    for t in [0.1, 0.4, 0.8] {
+        let diff = (CUBIC.point_at(t + h) - CUBIC.point_at(t - h)) / (2.0 * h);
+        assert_abs_diff_eq!(CUBIC.derivative(t), diff, epsilon = 1e-2);
+        let diff = (QUAD.point_at(t + h) - QUAD.point_at(t - h)) / (2.0 * h);
+        assert_abs_diff_eq!(QUAD.derivative(t), diff, epsilon = 1e-2);
+    }
+}
+
+/// Maximal distance from the dense samples of the curve to the polyline.
+fn deviation<F: Fn(f32) -> Vec2>(curve: F, points: &[Vec2]) -> f32 {
+    (0..=1000)
+        .map(|i| curve(i as f32 / 1000.0))
+        .map(|p| {
+            points
+                .windows(2)
+                .map(|w| LineSegment(w[0], w[1]).closest_point(p).distance(p))
+                .fold(f32::INFINITY, f32::min)
+        })
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn flatten() {
+    for tolerance in [0.1, 0.01, 0.001] {
+        let points: Vec<Vec2> = QUAD.flatten(tolerance).collect();
+        assert_eq!(points[0], QUAD.0);
+        assert!(deviation(|t| QUAD.point_at(t), &points) <= tolerance);
+        let points: Vec<Vec2> = CUBIC.flatten(tolerance).collect();
+        assert_eq!(*points.last().unwrap(), CUBIC.3);
+        assert!(deviation(|t| CUBIC.point_at(t), &points) <= tolerance);
+    }
+    let flat = QuadBezier(Vec2::ZERO, Vec2::X, Vec2::new(2.0, 0.0));
+    assert_eq!(flat.flatten(0.1).count(), 2);
+}
+
+#[test]
+fn aabb() {
+    let sampled = |curve: &dyn Fn(f32) -> Vec2| {
+        Aabb::from_points((0..=1000).map(|i| curve(i as f32 / 1000.0)))
+    };
+    let expected = sampled(&|t| QUAD.point_at(t));
+    assert_abs_diff_eq!(QUAD.aabb().min, expected.min, epsilon = 1e-4);
+    assert_abs_diff_eq!(QUAD.aabb().max, Vec2::new(3.0, 1.0), epsilon = 1e-4);
+    let expected = sampled(&|t| CUBIC.point_at(t));
+    assert_abs_diff_eq!(CUBIC.aabb().min, expected.min, epsilon = 1e-4);
+    assert_abs_diff_eq!(CUBIC.aabb().max, expected.max, epsilon = 1e-4);
+}
+
+#[test]
+fn intersection() {
+    let axis = Line(Vec2::new(-1.0, 0.0), Vec2::new(4.0, 0.0));
+    let points = CUBIC.intersect(&axis).unwrap();
+    assert_eq!(points.len(), 3);
+    for (point, x) in points.iter().zip([0.0, 1.5, 3.0]) {
+        assert_abs_diff_eq!(*point, Vec2::new(x, 0.0), epsilon = 1e-4);
+    }
+    let segment = LineSegment(Vec2::new(1.0, 0.0), Vec2::new(4.0, 0.0));
+    assert_eq!(segment.intersect(&CUBIC).unwrap().len(), 2);
+
+    let line = Line(Vec2::new(0.0, 0.5), Vec2::new(1.0, 0.5));
+    let points = QUAD.intersect(&line).unwrap();
+    assert_eq!(points.len(), 2);
+    assert!(points[0].x < points[1].x);
+    for point in points {
+        assert_abs_diff_eq!(point.y, 0.5, epsilon = 1e-5);
+    }
+    assert!(
+        QUAD.intersect(&Line(Vec2::new(0.0, 2.0), Vec2::new(1.0, 2.0)))
+            .is_none()
+    );
+    let segment = LineSegment(Vec2::new(0.0, 0.5), Vec2::new(1.0, 0.5));
+    assert_eq!(QUAD.intersect(&segment).unwrap().len(), 1);
+}
@@ -0,0 +1,50 @@
+extern crate std;
+
+use crate::{ArcPolygon, ArcVertex, Closed, Disk, Integrable, Polygon, blend};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn ends() {
+    let disk = Disk::new(Vec2::new(1.0, 1.0), 2.0);
+    let square = Polygon::new([
+        Vec2::new(-1.0, -1.0),
+        Vec2::new(3.0, -1.0),
+        Vec2::new(3.0, 3.0),
+        Vec2::new(-1.0, 3.0),
+    ]);
+
+    let start: ArcPolygon<Vec<ArcVertex>> = blend(&disk, &square, 0.0);
+    assert_abs_diff_eq!(start.moment().area, 4.0 * PI, epsilon = 1e-4);
+    for vertex in start.vertices() {
+        assert_abs_diff_eq!(vertex.point.distance(disk.center), 2.0, epsilon = 1e-5);
+    }
+
+    let end: ArcPolygon<Vec<ArcVertex>> = blend(&disk, &square, 1.0);
+    assert_abs_diff_eq!(end.moment().area, 16.0, epsilon = 1e-4);
+    assert!(end.vertices().all(|vertex| vertex.sagitta == 0.0));
+}
+
+#[test]
+fn monotonic() {
+    // Square inscribed into the disk, so the blend shrinks from the disk to the square
+    let disk = Disk::new(Vec2::ZERO, 1.0);
+    let square = Polygon::new([
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+        Vec2::new(-1.0, 0.0),
+        Vec2::new(0.0, -1.0),
+    ]);
+    let mut area = f32::INFINITY;
+    for k in 0..=10 {
+        let shape: ArcPolygon<Vec<ArcVertex>> = blend(&disk, &square, 0.1 * k as f32);
+        let next = shape.moment().area;
+        assert!(next < area);
+        area = next;
+        assert!(shape.contains(Vec2::new(0.45, 0.45)));
+        assert!(!shape.contains(Vec2::new(0.75, 0.75)));
+    }
+    assert_abs_diff_eq!(area, 2.0, epsilon = 1e-4);
+}
@@ -0,0 +1,234 @@
+extern crate std;
+
+use crate::{
+    Aabb, Arc, ArcPolygon, ArcVertex, Boundary, BoundaryEdge, Capsule, Circle, Disk, DiskSegment,
+    Integrable, Polygon, Region, SampleBoundary, boundary_span,
+};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+/// Check that each edge starts where the previous one ends, assuming a single contour.
+fn assert_closed<S: Boundary>(shape: &S) {
+    let edges = shape.boundary();
+    let last = shape.boundary().last().unwrap();
+    edges.fold(last, |prev, edge| {
+        assert_abs_diff_eq!(prev.end(), edge.start(), epsilon = 1e-6);
+        edge
+    });
+}
+
+#[test]
+fn lengths() {
+    let disk = Disk::new(Vec2::new(1.0, 2.0), 1.5);
+    assert_abs_diff_eq!(disk.boundary_length(), 3.0 * PI, epsilon = 1e-5);
+    assert_closed(&disk);
+
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    assert_eq!(square.boundary_length(), square.perimeter());
+    assert!(
+        square
+            .boundary()
+            .all(|edge| matches!(edge, BoundaryEdge::Segment(_)))
+    );
+
+    let capsule = Capsule::new(Vec2::ZERO, Vec2::new(3.0, 0.0), 1.0);
+    assert_abs_diff_eq!(capsule.boundary_length(), 6.0 + 2.0 * PI, epsilon = 1e-5);
+    assert_closed(&capsule);
+
+    let aabb = Aabb::new(Vec2::ZERO, Vec2::new(3.0, 1.0));
+    assert_eq!(aabb.boundary_length(), 8.0);
+    assert_closed(&aabb);
+    assert_eq!(Aabb::EMPTY.boundary().count(), 0);
+}
+
+#[test]
+fn arcs() {
+    let disk = Disk::new(Vec2::ZERO, 1.0);
+    let half = DiskSegment(disk.polygon::<2>().edges().next().unwrap());
+    assert_abs_diff_eq!(half.boundary_length(), PI + 2.0, epsilon = 1e-5);
+    assert_closed(&half);
+
+    let slot = ArcPolygon::new([
+        ArcVertex {
+            point: Vec2::new(0.0, -1.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, -1.0),
+            sagitta: 1.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 1.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(0.0, 1.0),
+            sagitta: 1.0,
+        },
+    ]);
+    let kinds = slot
+        .boundary()
+        .map(|edge| matches!(edge, BoundaryEdge::Arc(_)));
+    assert!(kinds.eq([false, true, false, true]));
+    // Points along the edges stay on the boundary
+    for edge in slot.boundary() {
+        assert_abs_diff_eq!(edge.point_at(0.0), edge.start(), epsilon = 1e-6);
+        assert_abs_diff_eq!(edge.point_at(1.0), edge.end(), epsilon = 1e-5);
+    }
+    assert_abs_diff_eq!(slot.area(), 4.0 + PI, epsilon = 1e-5);
+}
+
+#[test]
+fn region() {
+    let region = Region::new([
+        Disk::new(Vec2::ZERO, 2.0),
+        Disk::new(Vec2::new(5.0, 0.0), 1.0),
+    ]);
+    assert_eq!(region.boundary().count(), 4);
+    assert_abs_diff_eq!(region.boundary_length(), 6.0 * PI, epsilon = 1e-5);
+}
+
+#[test]
+fn span() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let (a, b) = (Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0));
+    assert_abs_diff_eq!(boundary_span(&square, a, b), 2.0, epsilon = 1e-6);
+    // Other way around goes through the rest of the boundary
+    assert_abs_diff_eq!(boundary_span(&square, b, a), 6.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(boundary_span(&square, a, a), 0.0);
+    // Across the first vertex
+    let c = Vec2::new(0.0, 1.0);
+    assert_abs_diff_eq!(boundary_span(&square, c, a), 2.0, epsilon = 1e-6);
+
+    // Circle made of two half-circles
+    let disk = Disk::new(Vec2::ZERO, 1.0).polygon::<2>();
+    let (a, b) = (Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0));
+    assert_abs_diff_eq!(boundary_span(&disk, a, b), 0.5 * PI, epsilon = 1e-5);
+    assert_abs_diff_eq!(boundary_span(&disk, b, a), 1.5 * PI, epsilon = 1e-5);
+    // Point off the boundary is projected to it
+    let c = Vec2::new(0.0, -0.5);
+    assert_abs_diff_eq!(boundary_span(&disk, a, c), 1.5 * PI, epsilon = 1e-5);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn between() {
+    use crate::boundary_between;
+
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let (a, b) = (Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0));
+    let path = boundary_between(&square, a, b);
+    assert!(
+        path.vertices()
+            .map(|v| v.point)
+            .eq([a, Vec2::new(2.0, 0.0), b])
+    );
+    assert!(path.vertices().all(|v| v.sagitta == 0.0));
+    let path = boundary_between(&square, b, a);
+    assert_eq!(path.vertices().count(), 5);
+    assert_abs_diff_eq!(path.length(), 6.0, epsilon = 1e-6);
+    // Going backwards along the same edge takes the whole boundary
+    let path = boundary_between(&square, Vec2::new(1.5, 0.0), Vec2::new(0.5, 0.0));
+    assert_eq!(path.vertices().count(), 6);
+    assert_abs_diff_eq!(path.length(), 7.0, epsilon = 1e-6);
+    assert_eq!(boundary_between(&square, a, a).vertices().count(), 1);
+
+    // Points are projected to the circle and the arc between them is exact
+    let disk = Disk::new(Vec2::ZERO, 1.0).polygon::<2>();
+    let (a, b) = (Vec2::new(2.0, 0.0), Vec2::new(0.0, 0.5));
+    let path = boundary_between(&disk, a, b);
+    assert_abs_diff_eq!(path.length(), 0.5 * PI, epsilon = 1e-5);
+    assert_abs_diff_eq!(
+        path.vertices().next().unwrap().point,
+        Vec2::X,
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        path.vertices().last().unwrap().point,
+        Vec2::Y,
+        epsilon = 1e-6
+    );
+    for arc in path.edges() {
+        assert_abs_diff_eq!(arc.midpoint().length(), 1.0, epsilon = 1e-5);
+    }
+    let path = boundary_between(&disk, b, a);
+    assert_abs_diff_eq!(path.length(), 1.5 * PI, epsilon = 1e-5);
+}
+
+#[test]
+fn sampling() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    assert_abs_diff_eq!(square.point_at_length(3.0).unwrap(), Vec2::new(2.0, 1.0));
+    assert_abs_diff_eq!(square.point_at_length(-1.0).unwrap(), Vec2::new(0.0, 1.0));
+    // Spacing is adjusted to divide the perimeter of 8 into 11 steps
+    let points: Vec<Vec2> = square.sample_boundary(0.7).collect();
+    assert_eq!(points.len(), 11);
+    assert_eq!(points[0], Vec2::ZERO);
+    for (k, point) in points.iter().enumerate() {
+        let expected = square.point_at_length(8.0 * k as f32 / 11.0).unwrap();
+        assert_abs_diff_eq!(*point, expected, epsilon = 1e-5);
+    }
+    assert_eq!(Polygon::<[Vec2; 0]>::new([]).point_at_length(1.0), None);
+    assert_eq!(
+        Polygon::<[Vec2; 0]>::new([]).sample_boundary(1.0).count(),
+        0
+    );
+
+    let circle = Circle {
+        center: Vec2::new(1.0, 2.0),
+        radius: 1.5,
+    };
+    let arcs = ArcPolygon::<[_; 3]>::from_circle(circle);
+    let points: Vec<Vec2> = circle.sample_boundary(0.1).collect();
+    assert_eq!(points.len(), 94);
+    for (k, (point, arc_point)) in points.iter().zip(arcs.sample_boundary(0.1)).enumerate() {
+        assert_abs_diff_eq!(point.distance(circle.center), 1.5, epsilon = 1e-5);
+        assert_abs_diff_eq!(*point, arc_point, epsilon = 1e-4);
+        assert_abs_diff_eq!(
+            *point,
+            arcs.point_at_length(k as f32 * 3.0 * PI / 94.0).unwrap(),
+            epsilon = 1e-4
+        );
+        assert_abs_diff_eq!(
+            *point,
+            circle.point_at_length(k as f32 * 3.0 * PI / 94.0).unwrap(),
+            epsilon = 1e-5
+        );
+    }
+    assert_eq!(arcs.sample_boundary(0.1).count(), 94);
+
+    // Open arc includes both ends
+    let arc = Arc::from_circle_points(Vec2::ZERO, 1.0, Vec2::X, Vec2::Y, true);
+    let points: Vec<Vec2> = arc.sample_boundary(0.5).collect();
+    assert_eq!(points.len(), 4);
+    assert_abs_diff_eq!(points[0], Vec2::X, epsilon = 1e-6);
+    assert_abs_diff_eq!(points[3], Vec2::Y, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        points[1].distance(points[2]),
+        points[2].distance(points[3]),
+        epsilon = 1e-5
+    );
+    assert_abs_diff_eq!(arc.point_at_length(10.0).unwrap(), Vec2::Y, epsilon = 1e-6);
+}
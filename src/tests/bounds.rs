@@ -0,0 +1,56 @@
+use crate::{Aabb, BoundsAccumulator, Closed, Disk, testgen::Pcg32};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+
+const TEST_EPS: f32 = 1e-5;
+
+#[test]
+fn points() {
+    let mut rng = Pcg32::new(550);
+    let center = Vec2::new(3.0, -1.0);
+    let mut bounds = BoundsAccumulator::new();
+    assert!(bounds.is_empty());
+    assert!(bounds.disk().is_none());
+
+    let mut min = Vec2::INFINITY;
+    let mut max = Vec2::NEG_INFINITY;
+    let mut points = [Vec2::ZERO; 256];
+    for p in &mut points {
+        *p = center + 2.0 * rng.in_unit_disk();
+        bounds.add_point(*p);
+        min = min.min(*p);
+        max = max.max(*p);
+    }
+    assert_eq!(bounds.aabb(), Aabb::new(min, max));
+
+    let disk = bounds.disk().unwrap();
+    for p in points {
+        assert!(p.distance(disk.center) <= disk.radius + TEST_EPS);
+    }
+    assert!(disk.radius <= 2.0 * 2.0f32.sqrt());
+}
+
+#[test]
+fn shapes() {
+    let mut a = BoundsAccumulator::new();
+    a.add_disk(Disk::new(Vec2::new(-2.0, 0.0), 1.0));
+    let mut b: BoundsAccumulator = [Vec2::new(2.0, 1.0), Vec2::new(2.0, -1.0)]
+        .into_iter()
+        .collect();
+    b.add(&Disk::new(Vec2::new(3.0, 0.0), 0.5));
+    a.merge(&b);
+
+    assert_abs_diff_eq!(
+        a.aabb(),
+        Aabb::new(Vec2::new(-3.0, -1.0), Vec2::new(3.5, 1.0)),
+        epsilon = TEST_EPS
+    );
+    let disk = a.disk().unwrap();
+    for p in [
+        Vec2::new(-3.0, 0.0),
+        Vec2::new(3.5, 0.5),
+        Vec2::new(2.0, -1.0),
+    ] {
+        assert!(disk.contains(p));
+    }
+}
@@ -0,0 +1,50 @@
+extern crate std;
+
+use crate::{
+    Bounded, Closed, Integrable, LineSegment, Polygon, crossing_parameters,
+    testgen::{Pcg32, star_polygon},
+};
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn matches_polygon() {
+    let mut rng = Pcg32::new(555);
+    let polygon: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 2000, 0.2);
+    let index = polygon.build_index();
+    assert_eq!(index.aabb(), polygon.aabb());
+    assert_eq!(index.moment(), polygon.moment());
+
+    for _ in 0..256 {
+        let point = 1.2 * rng.in_unit_disk();
+        assert_eq!(index.contains(point), polygon.contains(point));
+        assert_eq!(index.closest_point(point), polygon.closest_point(point));
+
+        let segment = LineSegment(point, 1.2 * rng.in_unit_disk());
+        assert_eq!(
+            crossing_parameters::<_, 64>(&segment, &index),
+            crossing_parameters::<_, 64>(&segment, &polygon)
+        );
+    }
+}
+
+#[test]
+fn small() {
+    let empty = Polygon::new(Vec::<Vec2>::new()).build_index();
+    assert!(!empty.contains(Vec2::ZERO));
+    assert_eq!(empty.closest_point(Vec2::ZERO), None);
+
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ])
+    .build_index();
+    assert!(square.contains(Vec2::new(0.5, 0.5)));
+    assert!(!square.contains(Vec2::new(1.5, 0.5)));
+    assert_eq!(
+        square.closest_point(Vec2::new(2.0, 0.5)),
+        Some(Vec2::new(1.0, 0.5))
+    );
+}
@@ -0,0 +1,93 @@
+extern crate std;
+
+use crate::{ArcPolygon, ArcVertex, Capsule, Closed, Disk, HalfPlane, Integrable, IntersectTo};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+const TEST_EPS: f32 = 1e-5;
+
+#[test]
+fn distance() {
+    let capsule = Capsule::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0), 1.0);
+    assert_abs_diff_eq!(
+        capsule.distance(Vec2::new(2.0, 3.0)),
+        2.0,
+        epsilon = TEST_EPS
+    );
+    assert_abs_diff_eq!(
+        capsule.distance(Vec2::new(2.0, 0.5)),
+        -0.5,
+        epsilon = TEST_EPS
+    );
+    assert_abs_diff_eq!(
+        capsule.distance(Vec2::new(7.0, 4.0)),
+        4.0,
+        epsilon = TEST_EPS
+    );
+
+    assert!(capsule.contains(Vec2::new(-0.5, 0.5)));
+    assert!(!capsule.contains(Vec2::new(-0.9, 0.9)));
+}
+
+#[test]
+fn moment() {
+    let capsule = Capsule::new(Vec2::new(1.0, 1.0), Vec2::new(4.0, 5.0), 2.0);
+    assert_abs_diff_eq!(capsule.area(), 20.0 + 4.0 * PI, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(capsule.centroid(), Vec2::new(2.5, 3.0), epsilon = TEST_EPS);
+    assert_abs_diff_eq!(
+        capsule.polygon().moment(),
+        capsule.moment(),
+        epsilon = TEST_EPS
+    );
+
+    // Degenerate segment is a disk
+    let capsule = Capsule::new(Vec2::new(1.0, 1.0), Vec2::new(1.0, 1.0), 2.0);
+    assert_abs_diff_eq!(
+        capsule.polygon().moment(),
+        Disk::new(Vec2::new(1.0, 1.0), 2.0).moment(),
+        epsilon = TEST_EPS
+    );
+}
+
+#[test]
+fn intersect_plane() {
+    let capsule = Capsule::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0), 1.0);
+
+    // Cut along the axis
+    let plane = HalfPlane::from_normal(Vec2::ZERO, Vec2::new(0.0, 1.0));
+    let half: ArcPolygon<Vec<ArcVertex>> = capsule.intersect_to(&plane).unwrap();
+    assert_abs_diff_eq!(half.area(), capsule.area() / 2.0, epsilon = TEST_EPS);
+
+    // Cut off one cap
+    let plane = HalfPlane::from_normal(Vec2::new(4.0, 0.0), Vec2::new(1.0, 0.0));
+    let cut: ArcPolygon<Vec<ArcVertex>> = plane.intersect_to(&capsule).unwrap();
+    assert_abs_diff_eq!(cut.area(), 8.0 + PI / 2.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(
+        cut.centroid(),
+        Vec2::new(
+            (8.0 * 2.0 - (PI / 2.0) * 4.0 / (3.0 * PI)) / (8.0 + PI / 2.0),
+            0.0
+        ),
+        epsilon = TEST_EPS
+    );
+}
+
+#[test]
+fn intersect_disk() {
+    let capsule = Capsule::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0), 1.0);
+
+    // Disk containing the right cap
+    let disk = Disk::new(Vec2::new(4.0, 0.0), 1.5);
+    let part: ArcPolygon<Vec<ArcVertex>> = capsule.intersect_to(&disk).unwrap();
+    let half_width = (1.5f32.powi(2) - 1.0).sqrt();
+    assert!(part.contains(Vec2::new(4.9, 0.0)));
+    assert!(!part.contains(Vec2::new(2.0, 0.0)));
+    assert!(part.area() > PI / 2.0 + 2.0 * half_width);
+
+    // Disk inside the capsule
+    let disk = Disk::new(Vec2::new(2.0, 0.0), 0.5);
+    let part: ArcPolygon<Vec<ArcVertex>> = disk.intersect_to(&capsule).unwrap();
+    assert_abs_diff_eq!(part.moment(), disk.moment(), epsilon = TEST_EPS);
+}
@@ -0,0 +1,88 @@
+use crate::{Disk, LineSegment, Polygon, ShapeCast};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+
+const TEST_EPS: f32 = 1e-6;
+
+#[test]
+fn disk_disk() {
+    let disk = Disk::new(Vec2::new(0.0, 0.0), 1.0);
+    let other = Disk::new(Vec2::new(5.0, 0.0), 2.0);
+
+    let impact = disk.shape_cast(Vec2::new(4.0, 0.0), &other).unwrap();
+    assert_abs_diff_eq!(impact.time, 0.5, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(impact.normal, Vec2::new(-1.0, 0.0), epsilon = TEST_EPS);
+    assert_abs_diff_eq!(impact.point, Vec2::new(3.0, 0.0), epsilon = TEST_EPS);
+
+    // Too short motion
+    assert!(disk.shape_cast(Vec2::new(1.0, 0.0), &other).is_none());
+    // Missing motion
+    assert!(disk.shape_cast(Vec2::new(0.0, 4.0), &other).is_none());
+    // Moving away
+    assert!(disk.shape_cast(Vec2::new(-4.0, 0.0), &other).is_none());
+
+    // Already overlapping
+    let impact = Disk::new(Vec2::new(3.0, 0.0), 1.0)
+        .shape_cast(Vec2::new(1.0, 0.0), &other)
+        .unwrap();
+    assert_eq!(impact.time, 0.0);
+    assert_abs_diff_eq!(impact.normal, Vec2::new(-1.0, 0.0), epsilon = TEST_EPS);
+}
+
+#[test]
+fn disk_segment_side() {
+    let disk = Disk::new(Vec2::new(0.0, 3.0), 1.0);
+    let segment = LineSegment(Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0));
+
+    let impact = disk.shape_cast(Vec2::new(1.0, -4.0), &segment).unwrap();
+    assert_abs_diff_eq!(impact.time, 0.5, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(impact.normal, Vec2::new(0.0, 1.0), epsilon = TEST_EPS);
+    assert_abs_diff_eq!(impact.point, Vec2::new(0.5, 0.0), epsilon = TEST_EPS);
+}
+
+#[test]
+fn disk_segment_endpoint() {
+    let disk = Disk::new(Vec2::new(-4.0, 0.0), 1.0);
+    let segment = LineSegment(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+
+    // Moving along the segment line hits its endpoint
+    let impact = disk.shape_cast(Vec2::new(6.0, 0.0), &segment).unwrap();
+    assert_abs_diff_eq!(impact.time, 0.5, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(impact.point, Vec2::new(0.0, 0.0), epsilon = TEST_EPS);
+    assert_abs_diff_eq!(impact.normal, Vec2::new(-1.0, 0.0), epsilon = TEST_EPS);
+
+    // Passing by the endpoint
+    assert!(
+        Disk::new(Vec2::new(-4.0, 1.5), 1.0)
+            .shape_cast(Vec2::new(0.0, -3.0), &segment)
+            .is_none()
+    );
+}
+
+#[test]
+fn disk_polygon() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+
+    let disk = Disk::new(Vec2::new(-3.0, 1.0), 0.5);
+    let impact = disk.shape_cast(Vec2::new(5.0, 0.0), &square).unwrap();
+    assert_abs_diff_eq!(impact.time, 0.5, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(impact.normal, Vec2::new(-1.0, 0.0), epsilon = TEST_EPS);
+    assert_abs_diff_eq!(impact.point, Vec2::new(0.0, 1.0), epsilon = TEST_EPS);
+
+    // Hitting the corner diagonally
+    let disk = Disk::new(Vec2::new(4.0, 4.0), 2.0f32.sqrt());
+    let impact = disk.shape_cast(Vec2::new(-2.0, -2.0), &square).unwrap();
+    assert_abs_diff_eq!(impact.time, 0.5, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(impact.point, Vec2::new(2.0, 2.0), epsilon = TEST_EPS);
+
+    // Starting inside
+    let disk = Disk::new(Vec2::new(1.5, 1.0), 0.1);
+    let impact = disk.shape_cast(Vec2::new(-5.0, 0.0), &square).unwrap();
+    assert_eq!(impact.time, 0.0);
+    assert_abs_diff_eq!(impact.normal, Vec2::new(1.0, 0.0), epsilon = TEST_EPS);
+}
@@ -1,7 +1,11 @@
 extern crate std;
 
-use crate::{Closed, Disk, HalfPlane, Integrable, Intersect};
+use crate::{
+    Arc, ArcPolygon, Circle, Closed, Disk, HalfPlane, Integrable, Intersect, Line, Polygon,
+    testgen::Pcg32,
+};
 use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
 use either::Either;
 use glam::Vec2;
 use std::vec::Vec;
@@ -357,3 +361,203 @@ fn intersect_disk_negative_apothem() {
         Either::Right(_) => panic!("Expected commutative property to hold"),
     }
 }
+
+#[test]
+fn lens() {
+    let disk1 = Disk::new(Vec2::new(0.0, 0.0), 2.0);
+    let disk2 = Disk::new(Vec2::new(3.0, 0.0), 2.0);
+
+    let lens = disk1.lens(&disk2).unwrap();
+    assert_eq!(
+        lens.moment(),
+        disk1.intersect(&disk2).unwrap().unwrap_left().moment()
+    );
+    assert!(lens.contains(Vec2::new(1.2, 0.0)));
+    assert!(!lens.contains(Vec2::new(0.0, 0.0)));
+
+    // Nested disks produce the smaller one
+    let inner = Disk::new(Vec2::new(0.5, 0.0), 1.0);
+    assert_abs_diff_eq!(
+        disk1.lens(&inner).unwrap().moment(),
+        inner.moment(),
+        epsilon = TEST_EPS
+    );
+
+    assert!(disk1.lens(&Disk::new(Vec2::new(5.0, 0.0), 1.0)).is_none());
+}
+
+#[test]
+fn crescent() {
+    let disk1 = Disk::new(Vec2::new(0.0, 0.0), 2.0);
+    let disk2 = Disk::new(Vec2::new(1.5, 0.5), 1.5);
+
+    let crescent: ArcPolygon<Vec<_>> = disk1.crescent(&disk2).unwrap();
+    assert_eq!(crescent.len(), 2);
+    assert!(crescent.contains(Vec2::new(-1.0, 0.0)));
+    assert!(!crescent.contains(Vec2::new(1.5, 0.5)));
+    assert!(!crescent.contains(Vec2::new(3.0, 0.0)));
+
+    // Crescent and lens compose the whole disk
+    let lens = disk1.lens(&disk2).unwrap();
+    assert_abs_diff_eq!(
+        crescent.moment().merge(lens.moment()),
+        disk1.moment(),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn crescent_disjoint() {
+    let disk1 = Disk::new(Vec2::new(0.0, 0.0), 1.0);
+    let disk2 = Disk::new(Vec2::new(3.0, 0.0), 1.0);
+    let crescent: ArcPolygon<Vec<_>> = disk1.crescent(&disk2).unwrap();
+    assert_abs_diff_eq!(crescent.moment(), disk1.moment(), epsilon = TEST_EPS);
+
+    // Disk fully covered by the other one
+    assert!(
+        disk2
+            .crescent::<Vec<_>>(&Disk::new(Vec2::new(3.5, 0.0), 2.0))
+            .is_none()
+    );
+}
+
+#[test]
+fn crescent_hole() {
+    let outer = Disk::new(Vec2::new(0.0, 0.0), 2.0);
+    let inner = Disk::new(Vec2::new(0.5, 0.5), 1.0);
+
+    let ring: ArcPolygon<Vec<_>> = outer.crescent(&inner).unwrap();
+    assert_eq!(ring.len(), 6);
+    assert!(ring.contains(Vec2::new(-1.5, 0.0)));
+    assert!(!ring.contains(inner.center));
+    // Points on the bridge line are consistent
+    assert!(ring.contains(Vec2::new(1.3, 1.25)));
+    assert!(ring.contains(Vec2::new(1.25, 1.3)));
+    assert!(!ring.contains(Vec2::new(1.2, 1.2)));
+
+    let moment = ring.moment();
+    assert_abs_diff_eq!(moment.area, 3.0 * PI, epsilon = 1e-5);
+    assert_abs_diff_eq!(
+        moment.centroid,
+        -inner.center * inner.area() / moment.area,
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn from_points() {
+    let (a, b, c) = (
+        Vec2::new(3.0, 1.0),
+        Vec2::new(1.0, 3.0),
+        Vec2::new(-1.0, 1.0),
+    );
+    let circle = Circle::from_points(a, b, c).left().unwrap();
+    assert_abs_diff_eq!(circle.center, Vec2::new(1.0, 1.0), epsilon = TEST_EPS);
+    assert_abs_diff_eq!(circle.radius, 2.0, epsilon = TEST_EPS);
+
+    // Collinear points give the line through the outer ones
+    let (a, b, c) = (
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 0.5),
+    );
+    assert_eq!(Circle::from_points(a, b, c), Either::Right(Line(a, b)));
+    // Points on `y = 7x` which coordinates are rounded
+    let (a, b, c) = (
+        Vec2::new(0.1, 0.7),
+        Vec2::new(0.3, 2.1),
+        Vec2::new(0.7, 4.9),
+    );
+    assert_eq!(Circle::from_points(a, b, c), Either::Right(Line(a, c)));
+    assert_eq!(Arc::from_three_points(a, b, c).sagitta, 0.0);
+    let point = Vec2::new(1.0, -2.0);
+    let circle = Circle::from_points(point, point, point).left().unwrap();
+    assert_eq!((circle.center, circle.radius), (point, 0.0));
+}
+
+#[test]
+fn fit() {
+    let center = Vec2::new(-2.0, 5.0);
+    let exact = (0..7).map(|i| center + 3.0 * Vec2::from_angle(0.4 * i as f32));
+    let circle = Circle::fit(exact).unwrap();
+    assert_abs_diff_eq!(circle.center, center, epsilon = 1e-4);
+    assert_abs_diff_eq!(circle.radius, 3.0, epsilon = 1e-4);
+
+    // Noisy points all around the circle
+    let mut rng = Pcg32::new(5652);
+    let noisy: Vec<Vec2> = (0..200)
+        .map(|_| {
+            let angle = rng.range(0.0, 2.0 * PI);
+            center + (3.0 + rng.range(-0.05, 0.05)) * Vec2::from_angle(angle)
+        })
+        .collect();
+    let circle = Circle::fit(noisy.iter().copied()).unwrap();
+    assert_abs_diff_eq!(circle.center, center, epsilon = 0.02);
+    assert_abs_diff_eq!(circle.radius, 3.0, epsilon = 0.02);
+
+    assert!(Circle::fit([Vec2::ZERO, Vec2::ONE]).is_none());
+    assert!(Circle::fit((0..5).map(|i| Vec2::splat(i as f32))).is_none());
+}
+
+#[test]
+fn to_polygon() {
+    let circle = Circle {
+        center: Vec2::new(-3.0, 1.0),
+        radius: 5.0,
+    };
+    for tolerance in [1e-3, 1e-1, 1.0, 10.0] {
+        let polygon: Polygon<Vec<Vec2>> = circle.to_polygon(tolerance);
+        let n = polygon.len();
+        assert_eq!(n, circle.segment_count(tolerance));
+        assert!(n >= 3);
+        for edge in polygon.edges() {
+            assert_abs_diff_eq!(edge.0.distance(circle.center), 5.0, epsilon = 1e-5);
+            assert!(5.0 - edge.center().distance(circle.center) <= tolerance + 1e-5);
+        }
+        // Polygon with one vertex less is out of the tolerance
+        if n > 3 {
+            assert!(5.0 * (1.0 - (PI / (n - 1) as f32).cos()) > tolerance);
+        }
+    }
+    let disk = circle.fill();
+    let polygon: Polygon<Vec<Vec2>> = disk.to_polygon(1e-2);
+    assert!(polygon.area() < disk.area());
+    assert!(polygon.area() > disk.area() - 2.0 * PI * 5.0 * 1e-2);
+}
+
+#[test]
+fn segment_count_small_tolerance() {
+    let circle = Circle {
+        center: Vec2::ZERO,
+        radius: 1000.0,
+    };
+    // `1 - tolerance / radius` rounds to one here
+    let n = circle.segment_count(1e-5);
+    assert!(n > 3);
+    assert!(1000.0 * 2.0 * (0.5 * PI / n as f32).sin().powi(2) <= 1e-5);
+    assert!(1000.0 * 2.0 * (0.5 * PI / (n - 1) as f32).sin().powi(2) > 1e-5);
+    // Zero tolerance saturates
+    assert_eq!(circle.segment_count(0.0), 1 << 20);
+    assert_eq!(circle.segment_count(1e4), 3);
+}
+
+#[test]
+fn split() {
+    let disk = Disk::new(Vec2::new(1.0, 1.0), 2.0);
+    let line = Line(Vec2::new(0.0, 2.0), Vec2::new(1.0, 2.0));
+    let (Some(Either::Left(above)), Some(Either::Left(below))) = disk.split(&line) else {
+        panic!("Line must cut the disk into segments");
+    };
+    assert_abs_diff_eq!(above.area() + below.area(), disk.area(), epsilon = 1e-4);
+    assert!(below.area() > above.area());
+    assert!(above.contains(Vec2::new(1.0, 2.5)));
+    assert!(below.contains(Vec2::new(1.0, 1.5)));
+    assert!(!below.contains(Vec2::new(1.0, 2.5)));
+    // Segments share the chord
+    assert_eq!(above.points, (below.points.1, below.points.0));
+
+    let line = Line(Vec2::new(0.0, 5.0), Vec2::new(1.0, 5.0));
+    assert_eq!(disk.split(&line), (None, Some(Either::Right(disk))));
+    let line = Line(line.1, line.0);
+    assert_eq!(disk.split(&line), (Some(Either::Right(disk)), None));
+}
@@ -0,0 +1,201 @@
+use crate::{
+    ArcPolygon, BoundaryCrossingTracker, Closed, Crossings, Disk, LineSegment, Polygon,
+    crossing_parameters, first_exit, stays_inside,
+};
+use glam::Vec2;
+
+#[test]
+fn polygon_crossings() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let segment = LineSegment(Vec2::new(-1.0, 0.5), Vec2::new(2.0, 0.5));
+    assert_eq!(square.crossings(&segment).count(), 2);
+    // Passing through a vertex is counted once
+    let segment = LineSegment(Vec2::new(-1.0, -1.0), Vec2::new(0.5, 0.5));
+    assert_eq!(square.crossings(&segment).count(), 1);
+    let segment = LineSegment(Vec2::new(0.25, 0.25), Vec2::new(0.75, 0.75));
+    assert_eq!(square.crossings(&segment).count(), 0);
+    // Touching a vertex keeps the parity
+    let segment = LineSegment(Vec2::new(0.5, 1.5), Vec2::new(1.5, 0.5));
+    assert_eq!(square.crossings(&segment).count() % 2, 0);
+    let segment = LineSegment(Vec2::new(1.5, 0.5), Vec2::new(0.5, 1.5));
+    assert_eq!(square.crossings(&segment).count() % 2, 0);
+}
+
+#[test]
+fn curved_crossings() {
+    let disk = Disk::new(Vec2::new(0.0, 0.0), 1.0);
+    let segment = LineSegment(Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0));
+    let ts = crossing_parameters::<_, 2>(&segment, &disk);
+    assert_eq!(ts.len(), 2);
+    assert!((ts[0] - 0.25).abs() < 1e-6 && (ts[1] - 0.75).abs() < 1e-6);
+
+    let poly: ArcPolygon<_> = disk.polygon::<3>();
+    assert_eq!(poly.crossings(&segment).count(), 2);
+    let segment = LineSegment(Vec2::new(0.0, 0.0), Vec2::new(0.0, 2.0));
+    assert_eq!(poly.crossings(&segment).count(), 1);
+    assert_eq!(disk.crossings(&segment).count(), 1);
+}
+
+#[test]
+fn tracker() {
+    // Thin vertical wall
+    let wall = Polygon::new([
+        Vec2::new(0.0, -1.0),
+        Vec2::new(0.1, -1.0),
+        Vec2::new(0.1, 1.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let mut tracker = BoundaryCrossingTracker::new(wall, Vec2::new(-1.0, 0.0));
+    assert!(!tracker.is_inside());
+
+    // Tunneling through the wall
+    let report = tracker.update(Vec2::new(1.0, 0.0));
+    assert_eq!(report.count, 2);
+    assert!(report.crossed() && !report.entered() && !report.escaped());
+
+    let report = tracker.update(Vec2::new(0.05, 0.0));
+    assert!(report.entered());
+    assert!(tracker.is_inside());
+
+    let report = tracker.update(Vec2::new(0.05, 2.0));
+    assert!(report.escaped());
+    assert_eq!(tracker.position(), Vec2::new(0.05, 2.0));
+
+    let report = tracker.update(Vec2::new(0.05, 3.0));
+    assert!(!report.crossed());
+
+    // Passing by the corner doesn't get inside
+    tracker.update(Vec2::new(-0.5, 1.5));
+    let report = tracker.update(Vec2::new(0.5, 0.5));
+    assert!(!report.entered());
+    assert!(!tracker.is_inside());
+}
+
+#[test]
+fn sorted_parameters() {
+    // Comb with three teeth pointing up
+    let comb = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(5.0, 0.0),
+        Vec2::new(5.0, 2.0),
+        Vec2::new(4.0, 2.0),
+        Vec2::new(4.0, 1.0),
+        Vec2::new(3.0, 1.0),
+        Vec2::new(3.0, 2.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let segment = LineSegment(Vec2::new(6.0, 1.5), Vec2::new(-1.0, 1.5));
+    let ts = crossing_parameters::<_, 8>(&segment, &comb);
+    assert_eq!(ts.len(), 6);
+    assert!(ts.windows(2).all(|w| w[0] <= w[1]));
+    assert!((ts[0] - 1.0 / 7.0).abs() < 1e-6);
+    assert!((ts[5] - 6.0 / 7.0).abs() < 1e-6);
+
+    // Only the first crossings are kept
+    let first = crossing_parameters::<_, 3>(&segment, &comb);
+    assert_eq!(first.as_slice(), &ts[..3]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn walk() {
+    use crate::{BoundaryEvent, walk_boundary};
+
+    let subject = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let clip = Polygon::new([
+        Vec2::new(1.0, -1.0),
+        Vec2::new(3.0, -1.0),
+        Vec2::new(3.0, 1.0),
+        Vec2::new(1.0, 1.0),
+    ]);
+    let events = walk_boundary(&subject, &clip);
+    let vertex = |index: usize, point: Vec2, inside: bool| BoundaryEvent::Vertex {
+        index,
+        point,
+        inside,
+    };
+    assert!(events.eq([
+        vertex(0, Vec2::new(0.0, 0.0), false),
+        BoundaryEvent::Entry {
+            edge: 0,
+            t: 0.5,
+            point: Vec2::new(1.0, 0.0),
+        },
+        vertex(1, Vec2::new(2.0, 0.0), true),
+        BoundaryEvent::Exit {
+            edge: 1,
+            t: 0.5,
+            point: Vec2::new(2.0, 1.0),
+        },
+        vertex(2, Vec2::new(2.0, 2.0), false),
+        vertex(3, Vec2::new(0.0, 2.0), false),
+    ]));
+
+    // Touching the other shape doesn't enter it
+    let touching = Polygon::new([
+        Vec2::new(0.5, -1.0),
+        Vec2::new(1.5, -1.0),
+        Vec2::new(1.0, 0.0),
+    ]);
+    assert_eq!(walk_boundary(&subject, &touching).count(), 4);
+
+    // Entries and exits alternate around the disk
+    let disk = Disk::new(Vec2::new(2.0, 2.0), 1.0);
+    let kinds: alloc::vec::Vec<bool> = walk_boundary(&subject, &disk)
+        .filter_map(|event| match event {
+            BoundaryEvent::Entry { point, .. } | BoundaryEvent::Exit { point, .. } => {
+                assert!((point.distance(disk.center) - 1.0).abs() < 1e-5);
+                Some(matches!(event, BoundaryEvent::Entry { .. }))
+            }
+            BoundaryEvent::Vertex { .. } => None,
+        })
+        .collect();
+    assert_eq!(kinds, [true, false]);
+}
+
+#[test]
+fn staying_inside() {
+    // Square with a notch at the top
+    let notched = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 4.0),
+    ]);
+    let segment = LineSegment(Vec2::new(0.5, 1.0), Vec2::new(3.5, 1.0));
+    assert!(stays_inside(&notched, &segment));
+    // Touching the notch vertex from inside is not an exit
+    let segment = LineSegment(Vec2::new(0.5, 2.0), Vec2::new(3.5, 2.0));
+    assert_eq!(first_exit(&notched, &segment), None);
+    // Both ends are inside, but the motion passes through the notch
+    let segment = LineSegment(Vec2::new(0.5, 3.0), Vec2::new(3.5, 3.0));
+    assert!(notched.contains(segment.0) && notched.contains(segment.1));
+    let t = first_exit(&notched, &segment).unwrap();
+    assert!((t - 1.0 / 6.0).abs() < 1e-6);
+    assert!(!stays_inside(&notched, &segment));
+    // Starting outside
+    let segment = LineSegment(Vec2::new(-1.0, 1.0), Vec2::new(1.0, 1.0));
+    assert_eq!(first_exit(&notched, &segment), Some(0.0));
+
+    let circle: ArcPolygon<_> = Disk::new(Vec2::ZERO, 1.0).polygon::<3>();
+    let segment = LineSegment(Vec2::new(-0.5, 0.5), Vec2::new(0.5, 0.5));
+    assert!(stays_inside(&circle, &segment));
+    let segment = LineSegment(Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0));
+    let t = first_exit(&circle, &segment).unwrap();
+    assert!((t - 0.5).abs() < 1e-6);
+}
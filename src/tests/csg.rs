@@ -0,0 +1,128 @@
+use crate::{
+    Aabb, Bounded, Capsule, Closed, Complement, CopyIterator, Decompose, Difference, Disk,
+    HalfPlane, Integrable, Intersection, Polygon, Union,
+};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+
+#[test]
+fn combinators() {
+    let a = Disk::new(Vec2::new(0.0, 0.0), 1.0);
+    let b = Disk::new(Vec2::new(1.0, 0.0), 1.0);
+
+    let union = Union(a, b);
+    assert!(union.contains(Vec2::new(-0.5, 0.0)));
+    assert!(union.contains(Vec2::new(1.5, 0.0)));
+    assert!(!union.contains(Vec2::new(0.5, 1.5)));
+
+    let lens = Intersection(a, b);
+    assert!(lens.contains(Vec2::new(0.5, 0.0)));
+    assert!(!lens.contains(Vec2::new(-0.5, 0.0)));
+
+    let crescent = Intersection(a, Complement(b));
+    assert!(crescent.contains(Vec2::new(-0.5, 0.0)));
+    assert!(!crescent.contains(Vec2::new(0.5, 0.0)));
+    assert!(!crescent.contains(Vec2::new(-1.5, 0.0)));
+}
+
+#[test]
+fn tree() {
+    // Square plate with a slot, cut by a half-plane
+    let plate = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(0.0, 4.0),
+    ]);
+    let slot = Capsule::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 2.0), 0.5);
+    let plane = HalfPlane::from_normal(Vec2::new(0.0, 3.0), Vec2::new(0.0, 1.0));
+    let shape = Intersection(Intersection(&plate, Complement(&slot)), plane);
+
+    assert!(shape.contains(Vec2::new(2.0, 1.0)));
+    assert!(!shape.contains(Vec2::new(2.0, 2.0)));
+    assert!(!shape.contains(Vec2::new(2.0, 3.5)));
+    assert!(!shape.contains(Vec2::new(5.0, 1.0)));
+    assert_eq!(Complement(shape).winding_number_2(Vec2::new(2.0, 2.0)), 2);
+}
+
+#[test]
+fn operators() {
+    let corners = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ];
+    // Polygon that is not `Copy` is used by reference
+    let square = Polygon::new(corners.to_ref());
+    let disk = Disk::new(Vec2::new(2.0, 1.0), 0.5);
+    let plane = HalfPlane::from_normal(Vec2::new(0.0, 1.0), Vec2::new(0.0, -1.0));
+
+    let notched = &square - disk;
+    assert!(notched.contains(Vec2::new(1.0, 1.0)));
+    assert!(!notched.contains(Vec2::new(1.9, 1.0)));
+    assert_abs_diff_eq!(notched.area(), 4.0 - 0.125 * PI, epsilon = 1e-4);
+    assert_abs_diff_eq!(notched.centroid().y, 1.0, epsilon = 1e-4);
+    assert!(notched.centroid().x < 1.0);
+
+    let bumped = &square | disk;
+    assert!(bumped.contains(Vec2::new(2.4, 1.0)));
+    assert_abs_diff_eq!(bumped.area(), 4.0 + 0.125 * PI, epsilon = 1e-4);
+    assert_eq!(bumped.aabb(), Aabb::new(Vec2::ZERO, Vec2::new(2.5, 2.0)));
+
+    let lens = &square & disk;
+    assert_abs_diff_eq!(lens.area(), 0.125 * PI, epsilon = 1e-4);
+    assert_eq!(
+        lens.aabb(),
+        Aabb::new(Vec2::new(1.5, 0.5), Vec2::new(2.0, 1.5))
+    );
+
+    // Half-plane composites are only tested for containment
+    let half = disk & plane;
+    assert!(half.contains(Vec2::new(2.0, 1.2)));
+    assert!(!half.contains(Vec2::new(2.0, 0.8)));
+    assert!((!half).contains(Vec2::new(2.0, 0.8)));
+    let shape = (&square - disk) | (disk & plane);
+    assert!(shape.contains(Vec2::new(2.2, 1.2)));
+    assert!(!shape.contains(Vec2::new(1.8, 0.8)));
+}
+
+#[test]
+fn nested() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(0.0, 4.0),
+    ]);
+    let hole = Disk::new(Vec2::new(2.0, 2.0), 1.0);
+    let bump = Disk::new(Vec2::new(4.0, 2.0), 0.5);
+    let half_bump = 0.125 * PI;
+
+    let shape = (square - hole) | bump;
+    assert_abs_diff_eq!(shape.area(), 16.0 - PI + half_bump, epsilon = 1e-4);
+    assert_abs_diff_eq!(shape.centroid().y, 2.0, epsilon = 1e-4);
+    let cut = (square - hole) & bump;
+    assert_abs_diff_eq!(cut.area(), half_bump, epsilon = 1e-4);
+    assert_abs_diff_eq!(Difference(shape, cut).area(), 16.0 - PI, epsilon = 1e-4);
+
+    // Pieces of the composites sum up to their winding numbers
+    for point in [
+        Vec2::new(0.5, 0.5),
+        Vec2::new(2.0, 2.3),
+        Vec2::new(4.3, 2.1),
+        Vec2::new(3.7, 1.9),
+        Vec2::new(4.5, 0.5),
+        Vec2::new(-1.0, 2.1),
+    ] {
+        assert_eq!(
+            shape.piecewise_winding_number_2(point) > 0,
+            shape.contains(point)
+        );
+        assert_eq!(
+            cut.piecewise_winding_number_2(point) > 0,
+            cut.contains(point)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+extern crate std;
+
+use crate::{
+    Arc, ArcPolygon, ArcVertex, Capsule, Disk, HalfPlane, LineSegment, Polygon, Region,
+    debug::{DebugCanvas, DebugDraw, DebugStyle, Labeled, debug_svg, explain_mismatch},
+};
+use glam::Vec2;
+use std::string::{String, ToString};
+
+#[test]
+fn svg() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let disk = Disk::new(Vec2::new(3.0, 1.0), 1.0);
+    let label = Labeled(
+        "a < b",
+        LineSegment(Vec2::new(-1.0, 0.0), Vec2::new(-1.0, 2.0)),
+    );
+    let point = Vec2::new(1.0, 1.0);
+
+    let shapes: [&dyn DebugDraw; 4] = [&square, &disk, &label, &point];
+    let mut svg = String::new();
+    debug_svg(shapes, &mut svg).unwrap();
+
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(svg.matches("marker-end").count(), 4 + 2 + 1);
+    // Counterclockwise semicircles
+    assert_eq!(svg.matches(" A 1 1 0 0 1 ").count(), 2);
+    assert!(svg.contains(">a &lt; b</text>"));
+    // Point and disk center
+    assert_eq!(svg.matches("<circle").count(), 2);
+}
+
+#[test]
+fn empty() {
+    let mut svg = String::new();
+    debug_svg([], &mut svg).unwrap();
+    assert!(svg.contains("viewBox"));
+}
+
+#[derive(Default)]
+struct Counter {
+    segments: usize,
+    arcs: usize,
+    points: usize,
+    auxiliary: usize,
+}
+
+impl DebugCanvas for Counter {
+    fn segment(&mut self, _: LineSegment, style: DebugStyle) {
+        self.segments += 1;
+        self.auxiliary += (style == DebugStyle::Auxiliary) as usize;
+    }
+    fn arc(&mut self, _: Arc, style: DebugStyle) {
+        self.arcs += 1;
+        self.auxiliary += (style == DebugStyle::Auxiliary) as usize;
+    }
+    fn point(&mut self, _: Vec2, style: DebugStyle) {
+        self.points += 1;
+        self.auxiliary += (style == DebugStyle::Auxiliary) as usize;
+    }
+    fn label(&mut self, _: Vec2, _: &str) {}
+}
+
+#[test]
+fn custom_canvas() {
+    let capsule = Capsule::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0), 0.5);
+    let plane = HalfPlane::from_normal(Vec2::ZERO, Vec2::Y);
+    let region = Region::new([Disk::new(Vec2::ZERO, 1.0), Disk::new(Vec2::X * 3.0, 1.0)]);
+
+    let mut counter = Counter::default();
+    for shape in [&capsule as &dyn DebugDraw, &plane, &region] {
+        shape.debug_draw(&mut counter);
+    }
+    assert_eq!(counter.segments, 1 + 2);
+    assert_eq!(counter.arcs, 4 + 2 * 2);
+    assert_eq!(counter.points, 2);
+    assert_eq!(counter.auxiliary, 1 + 1 + 2);
+}
+
+#[test]
+fn mismatch() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    // Same square starting from another vertex and with a redundant vertex
+    let same = Polygon::new([
+        Vec2::new(2.0, 2.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 2.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+    ]);
+    let report = explain_mismatch(&square, &same, 1e-5);
+    assert!(report.is_match());
+    assert_eq!(report.edge_counts, (4, 5));
+    assert_eq!(report.first_differing_edge, None);
+
+    let moved = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.5, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let report = explain_mismatch(&square, &moved, 1e-3);
+    assert!(!report.is_match());
+    assert_eq!(report.first_differing_edge, Some(1));
+    assert_eq!(report.max_deviation_point, Some(Vec2::new(2.5, 2.0)));
+    assert!((report.area_delta - 0.5).abs() < 1e-5);
+    let message = report.to_string();
+    assert!(message.contains("at (2.5, 2)"));
+    assert!(message.contains("first differing edge: 1"));
+
+    // Bulged edge is found by its midpoint
+    let bulged = ArcPolygon::new([
+        ArcVertex {
+            point: Vec2::new(0.0, 0.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 0.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 2.0),
+            sagitta: 0.1,
+        },
+        ArcVertex {
+            point: Vec2::new(0.0, 2.0),
+            sagitta: 0.0,
+        },
+    ]);
+    let report = explain_mismatch(&bulged, &square, 1e-3);
+    assert_eq!(report.first_differing_edge, Some(2));
+    assert!((report.max_deviation - 0.1).abs() < 1e-5);
+}
@@ -0,0 +1,76 @@
+extern crate std;
+
+use crate::{
+    Aabb, ArcPolygon, ArcVertex, Capsule, Closed, Decompose, Disk, Integrable, Piece, Polygon,
+    testgen::{Pcg32, star_polygon},
+};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn polygon() {
+    let mut rng = Pcg32::new(5552);
+    let star: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 17, 0.3);
+    assert_eq!(star.pieces().count(), 15);
+    assert_abs_diff_eq!(star.piecewise_moment(), star.moment(), epsilon = 1e-5);
+
+    for _ in 0..256 {
+        let point = 1.2 * rng.in_unit_disk();
+        assert_eq!(
+            star.piecewise_winding_number_2(point),
+            star.winding_number_2(point)
+        );
+    }
+    // Points on the fan diagonals are not on the boundary
+    let origin = star.vertices[0];
+    for &v in &star.vertices[2..16] {
+        let point = origin.lerp(v, 0.5);
+        assert_eq!(
+            star.piecewise_winding_number_2(point),
+            star.winding_number_2(point)
+        );
+    }
+}
+
+#[test]
+fn arc_polygon() {
+    let disk = Disk::new(Vec2::new(1.0, 2.0), 1.5);
+    let crescent: ArcPolygon<Vec<ArcVertex>> =
+        disk.crescent(&Disk::new(Vec2::new(2.0, 2.0), 1.0)).unwrap();
+    assert!(
+        crescent
+            .pieces()
+            .all(|piece| matches!(piece, Piece::Segment(_)))
+    );
+    assert_abs_diff_eq!(
+        crescent.piecewise_moment(),
+        crescent.moment(),
+        epsilon = 1e-5
+    );
+
+    let mut rng = Pcg32::new(5553);
+    for _ in 0..256 {
+        let point = disk.center + 2.0 * rng.in_unit_disk();
+        assert_eq!(
+            crescent.piecewise_winding_number_2(point),
+            crescent.winding_number_2(point)
+        );
+    }
+}
+
+#[test]
+fn primitives() {
+    let disk = Disk::new(Vec2::new(1.0, 2.0), 1.5);
+    assert_eq!(disk.pieces().count(), 2);
+    assert_abs_diff_eq!(disk.piecewise_moment(), disk.moment(), epsilon = 1e-5);
+
+    let aabb = Aabb::new(Vec2::new(-1.0, 0.0), Vec2::new(2.0, 1.0));
+    assert_abs_diff_eq!(aabb.piecewise_moment(), aabb.moment(), epsilon = 1e-5);
+    assert_eq!(Aabb::EMPTY.pieces().count(), 0);
+
+    let capsule = Capsule::new(Vec2::ZERO, Vec2::new(2.0, 1.0), 0.5);
+    assert_abs_diff_eq!(capsule.piecewise_moment(), capsule.moment(), epsilon = 1e-5);
+    assert!(capsule.piecewise_winding_number_2(Vec2::new(1.0, 0.5)) > 0);
+    assert_eq!(capsule.piecewise_winding_number_2(Vec2::new(1.0, -1.0)), 0);
+}
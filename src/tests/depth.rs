@@ -0,0 +1,59 @@
+extern crate std;
+
+use crate::{Closed, Integrable, Polygon, depth_contour, halfspace_depth, testgen::Pcg32};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+use std::vec::Vec;
+
+fn grid() -> Vec<Vec2> {
+    (0..3)
+        .flat_map(|y| (0..3).map(move |x| Vec2::new(x as f32, y as f32)))
+        .collect()
+}
+
+#[test]
+fn grid_depth() {
+    let points = grid();
+    let depth = |x: f32, y: f32| halfspace_depth(Vec2::new(x, y), points.iter().copied());
+    assert_eq!(depth(1.0, 1.0), 5);
+    assert_eq!(depth(0.0, 0.0), 1);
+    assert_eq!(depth(1.0, 0.0), 2);
+    assert_eq!(depth(0.5, 0.5), 2);
+    assert_eq!(depth(3.0, 1.0), 0);
+    assert_eq!(halfspace_depth(Vec2::ZERO, []), 0);
+
+    let hull: Polygon<Vec<Vec2>> = depth_contour(&points, 1).unwrap();
+    assert_abs_diff_eq!(hull.area(), 4.0, epsilon = 1e-5);
+    let inner: Polygon<Vec<Vec2>> = depth_contour(&points, 3).unwrap();
+    assert!(inner.contains(Vec2::ONE));
+    assert!(!inner.contains(Vec2::new(0.2, 0.2)));
+    // The deepest region is the single point, it may be lost by clipping
+    if let Some(center) = depth_contour::<Vec<Vec2>>(&points, 5) {
+        assert!(center.vertices().all(|v| v.distance(Vec2::ONE) < 1e-5));
+    }
+    assert!(depth_contour::<Vec<Vec2>>(&points, 6).is_none());
+    assert!(depth_contour::<Vec<Vec2>>(&points, 0).is_none());
+}
+
+#[test]
+fn random_contours() {
+    let mut rng = Pcg32::new(583);
+    let points: Vec<Vec2> = (0..40)
+        .map(|_| Vec2::new(rng.next_f32(), rng.next_f32()))
+        .collect();
+    for depth in [1, 4, 10] {
+        let contour: Polygon<Vec<Vec2>> = depth_contour(&points, depth).unwrap();
+        for _ in 0..200 {
+            let point = Vec2::new(rng.next_f32(), rng.next_f32());
+            // Points near the contour are skipped
+            if contour
+                .edges()
+                .any(|edge| edge.closest_point(point).distance(point) < 1e-3)
+            {
+                continue;
+            }
+            let inside = halfspace_depth(point, points.iter().copied()) >= depth;
+            assert_eq!(contour.contains(point), inside, "{depth} {point}");
+        }
+    }
+}
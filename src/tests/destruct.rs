@@ -0,0 +1,131 @@
+extern crate std;
+
+use crate::{
+    Aabb, Closed, DestructibleRegion, Disk, Integrable, Moment, Polygon, Region, testgen::Pcg32,
+};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::{vec, vec::Vec};
+
+/// Sum of the triangle moments computed from scratch.
+fn recompute(region: &DestructibleRegion) -> Moment {
+    region
+        .triangles()
+        .iter()
+        .fold(Moment::default(), |moment, triangle| {
+            moment.merge(triangle.moment())
+        })
+}
+
+#[test]
+fn disk() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(10.0)).polygon();
+    let mut region = DestructibleRegion::new(&Region::new([square]));
+    assert_eq!(region.triangles().len(), 2);
+    assert_abs_diff_eq!(region.area(), 100.0, epsilon = 1e-4);
+
+    let disk = Disk::new(Vec2::new(5.0, 5.0), 2.0);
+    region.subtract_disk(&disk, 1e-3);
+    // Circumscribed polygon is a bit larger than the disk
+    assert!(region.area() < 100.0 - disk.area());
+    assert!(region.area() > 100.0 - disk.area() - 2.0 * PI * 2.0 * 1e-3);
+    assert_abs_diff_eq!(region.centroid(), Vec2::new(5.0, 5.0), epsilon = 1e-3);
+    assert_abs_diff_eq!(region.moment(), recompute(&region), epsilon = 1e-3);
+    assert!(region.triangles().iter().all(|t| t.is_ccw()));
+
+    assert!(!region.contains(disk.center));
+    assert!(!region.contains(Vec2::new(6.99, 5.0)));
+    assert!(region.contains(Vec2::new(7.01, 5.0)));
+    assert!(region.contains(Vec2::new(0.5, 9.0)));
+}
+
+#[test]
+fn polygons() {
+    // Square with a clockwise hole
+    let mut hole = Aabb::new(Vec2::new(2.0, 2.0), Vec2::splat(4.0)).polygon();
+    hole.vertices.reverse();
+    let mut region = DestructibleRegion::new(&Region::new([
+        Aabb::new(Vec2::ZERO, Vec2::splat(10.0)).polygon(),
+        hole,
+    ]));
+    assert_abs_diff_eq!(region.area(), 96.0, epsilon = 1e-4);
+    assert!(!region.contains(Vec2::new(3.0, 3.0)));
+
+    // Non-convex cut
+    let notch = Polygon::new(vec![
+        Vec2::new(6.0, -1.0),
+        Vec2::new(11.0, -1.0),
+        Vec2::new(11.0, 4.0),
+        Vec2::new(9.0, 4.0),
+        Vec2::new(9.0, 1.0),
+        Vec2::new(6.0, 1.0),
+    ]);
+    region.subtract_polygon(&notch);
+    assert_abs_diff_eq!(region.area(), 96.0 - 4.0 - 3.0, epsilon = 1e-4);
+    assert!(!region.contains(Vec2::new(9.5, 3.5)));
+    assert!(region.contains(Vec2::new(8.5, 3.5)));
+    assert_abs_diff_eq!(region.moment(), recompute(&region), epsilon = 1e-3);
+
+    // Nothing is left
+    region.subtract_polygon(&Aabb::new(Vec2::splat(-1.0), Vec2::splat(11.0)).polygon());
+    assert!(region.is_empty());
+    assert_eq!(region.area(), 0.0);
+}
+
+#[test]
+fn random() {
+    let mut rng = Pcg32::new(0x577);
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(10.0)).polygon();
+    let mut region = DestructibleRegion::new(&Region::new([square]));
+    let mut disks = Vec::new();
+    for _ in 0..50 {
+        let disk = Disk::new(
+            Vec2::new(rng.next_f32(), rng.next_f32()) * 10.0,
+            0.2 + 0.8 * rng.next_f32(),
+        );
+        region.subtract_disk(&disk, 1e-2);
+        disks.push(disk);
+    }
+    assert_abs_diff_eq!(region.moment(), recompute(&region), epsilon = 1e-2);
+    assert!(region.triangles().iter().all(|t| t.is_ccw()));
+
+    for _ in 0..1000 {
+        let point = Vec2::new(rng.next_f32(), rng.next_f32()) * 10.0;
+        // Cut outlines are within the tolerance from the circles
+        let distance = disks
+            .iter()
+            .map(|disk| disk.center.distance(point) - disk.radius)
+            .fold(f32::INFINITY, f32::min);
+        if distance < 0.0 {
+            assert!(!region.contains(point));
+        } else if distance > 2e-2 {
+            assert!(region.contains(point));
+        }
+    }
+}
+
+#[test]
+fn slivers() {
+    // Moment of nearly zero area is reset, so the triangles are summed directly
+    let area = |region: &DestructibleRegion| -> f32 {
+        region.triangles().iter().map(|t| t.signed_area()).sum()
+    };
+
+    // Whole polygon is thinner than the tolerance
+    let thin = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.5, 1e-8),
+    ]);
+    let region = DestructibleRegion::new(&Region::new([thin]));
+    assert_eq!(region.triangles().len(), 1);
+    assert_abs_diff_eq!(area(&region), thin.area(), epsilon = 1e-12);
+
+    // Cut leaving a thin strip along the edge
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(0.1)).polygon();
+    let mut region = DestructibleRegion::new(&Region::new([square]));
+    region.subtract_polygon(&Aabb::new(Vec2::new(-1.0, 1e-7), Vec2::splat(1.0)).polygon());
+    assert!(!region.is_empty());
+    assert_abs_diff_eq!(area(&region), 1e-8, epsilon = 1e-11);
+}
@@ -0,0 +1,131 @@
+extern crate std;
+
+use crate::{
+    Aabb, ArcPolygon, ArcVertex, Closed, Disk, IntersectTo, Moment, Polygon, difference_profile,
+    intersection_area, intersection_over_union, overlap_fraction, symmetric_difference_area,
+    testgen::{Pcg32, star_polygon},
+};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::{Affine2, Vec2};
+use std::vec::Vec;
+
+/// Intersection area estimated by sampling the grid over the `bounds`.
+fn sampled_area<A: Closed, B: Closed>(a: &A, b: &B, bounds: Aabb) -> f32 {
+    const N: usize = 400;
+    let step = bounds.size() / N as f32;
+    let count = (0..N * N)
+        .map(|i| bounds.min + step * Vec2::new((i % N) as f32 + 0.5, (i / N) as f32 + 0.5))
+        .filter(|&p| a.contains(p) && b.contains(p))
+        .count();
+    count as f32 * step.x * step.y
+}
+
+#[test]
+fn polygons() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    let shifted = Aabb::new(Vec2::splat(1.0), Vec2::splat(3.0)).polygon();
+    assert_abs_diff_eq!(intersection_area(&square, &shifted), 1.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(
+        symmetric_difference_area(&square, &shifted),
+        6.0,
+        epsilon = 1e-5
+    );
+    assert_abs_diff_eq!(
+        symmetric_difference_area(&square, &square),
+        0.0,
+        epsilon = 1e-5
+    );
+
+    let mut rng = Pcg32::new(5580);
+    let star: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 13, 0.3);
+    let bounds = Aabb::new(Vec2::splat(-1.0), Vec2::splat(1.0));
+    let box_ = Aabb::new(Vec2::new(-0.3, -0.9), Vec2::new(0.8, 0.4));
+    assert_abs_diff_eq!(
+        intersection_area(&star, &box_),
+        sampled_area(&star, &box_, bounds),
+        epsilon = 1e-2
+    );
+}
+
+#[test]
+fn arcs() {
+    let a = Disk::new(Vec2::ZERO, 1.0);
+    let b = Disk::new(Vec2::new(1.0, 0.0), 1.0);
+    // Area of the lens of two unit circles at unit distance
+    let lens = 2.0 * PI / 3.0 - 3.0f32.sqrt() / 2.0;
+    assert_abs_diff_eq!(intersection_area(&a, &b), lens, epsilon = 1e-5);
+    assert_abs_diff_eq!(
+        symmetric_difference_area(&a, &b),
+        2.0 * (PI - lens),
+        epsilon = 1e-5
+    );
+
+    let mut rng = Pcg32::new(5581);
+    let star: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 11, 0.4);
+    let disk = Disk::new(Vec2::new(0.3, -0.2), 0.6);
+    let moment: Moment = star.intersect_to(&disk).unwrap();
+    assert_abs_diff_eq!(intersection_area(&star, &disk), moment.area, epsilon = 1e-5);
+
+    let crescent: ArcPolygon<Vec<ArcVertex>> = a.crescent(&b).unwrap();
+    let bounds = Aabb::new(Vec2::splat(-1.5), Vec2::splat(1.5));
+    assert_abs_diff_eq!(
+        intersection_area(&crescent, &star),
+        sampled_area(&crescent, &star, bounds),
+        epsilon = 1e-2
+    );
+}
+
+#[test]
+fn profile() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    let profile: Vec<f32> = difference_profile(
+        &square,
+        &square,
+        |t| Affine2::from_translation(Vec2::new(t, 0.0)),
+        [-1.0, -0.5, 0.0, 0.5, 1.0, 3.0],
+    )
+    .collect();
+    for (value, expected) in profile.into_iter().zip([4.0, 2.0, 0.0, 2.0, 4.0, 8.0]) {
+        assert_abs_diff_eq!(value, expected, epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn ratios() {
+    let square = |min: Vec2| {
+        Polygon::new([
+            min,
+            min + Vec2::new(2.0, 0.0),
+            min + Vec2::splat(2.0),
+            min + Vec2::new(0.0, 2.0),
+        ])
+    };
+    let (a, b) = (square(Vec2::ZERO), square(Vec2::new(1.0, 0.0)));
+    assert_abs_diff_eq!(intersection_over_union(&a, &b), 1.0 / 3.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(overlap_fraction(&a, &b), 0.5, epsilon = 1e-5);
+    assert_abs_diff_eq!(intersection_over_union(&a, &a), 1.0, epsilon = 1e-5);
+    assert_eq!(intersection_over_union(&a, &square(Vec2::splat(5.0))), 0.0);
+
+    // Orientation doesn't matter
+    let mut reversed = b;
+    reversed.vertices.reverse();
+    assert_abs_diff_eq!(
+        intersection_over_union(&a, &reversed),
+        1.0 / 3.0,
+        epsilon = 1e-5
+    );
+
+    // Disk inside of the square
+    let disk = Disk::new(Vec2::splat(1.0), 0.5);
+    assert_abs_diff_eq!(overlap_fraction(&disk, &a), 1.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(overlap_fraction(&a, &disk), 0.25 * PI / 4.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(
+        intersection_over_union(&disk, &a),
+        0.25 * PI / 4.0,
+        epsilon = 1e-5
+    );
+    let empty = Polygon::new([Vec2::ZERO; 3]);
+    assert_eq!(overlap_fraction(&empty, &a), 0.0);
+    assert_eq!(intersection_over_union(&empty, &empty), 0.0);
+}
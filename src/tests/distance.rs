@@ -0,0 +1,131 @@
+use crate::{Aabb, ArcPolygon, Circle, Clearance, Disk, Distance, LineSegment, Polygon};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+
+const TEST_EPS: f32 = 1e-6;
+
+#[test]
+fn clearance_separated() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    match square.clearance(&Disk::new(Vec2::new(4.0, 1.0), 1.0), TEST_EPS) {
+        Clearance::Separated(gap) => assert_abs_diff_eq!(gap, 1.0, epsilon = TEST_EPS),
+        other => panic!("Expected separated shapes, got {other:?}"),
+    }
+    // Corner is the closest point
+    match square.clearance(&Disk::new(Vec2::new(5.0, 6.0), 3.0), TEST_EPS) {
+        Clearance::Separated(gap) => assert_abs_diff_eq!(gap, 2.0, epsilon = TEST_EPS),
+        other => panic!("Expected separated shapes, got {other:?}"),
+    }
+}
+
+#[test]
+fn clearance_tangent() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    match square.clearance(&Disk::new(Vec2::new(1.0, -0.5), 0.5), TEST_EPS) {
+        Clearance::Tangent(point) => {
+            assert_abs_diff_eq!(point, Vec2::new(1.0, 0.0), epsilon = TEST_EPS)
+        }
+        other => panic!("Expected tangent shapes, got {other:?}"),
+    }
+    // Gap within tolerance
+    assert!(matches!(
+        square.clearance(&Disk::new(Vec2::new(3.01, 1.0), 1.0), 0.1),
+        Clearance::Tangent(_)
+    ));
+}
+
+#[test]
+fn clearance_overlapping() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    assert_eq!(
+        square.clearance(&Disk::new(Vec2::new(2.5, 1.0), 1.0), TEST_EPS),
+        Clearance::Overlapping
+    );
+    // Disk inside the polygon touching its edge
+    assert_eq!(
+        square.clearance(&Disk::new(Vec2::new(1.0, 1.5), 0.5), TEST_EPS),
+        Clearance::Overlapping
+    );
+}
+
+#[test]
+fn segments() {
+    let a = LineSegment(Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0));
+    // Crossing
+    let b = LineSegment(Vec2::new(1.0, -1.0), Vec2::new(1.0, 1.0));
+    assert_eq!(a.distance_to(&b), 0.0);
+    let (p, q) = a.closest_points(&b).unwrap();
+    assert_abs_diff_eq!(p, Vec2::new(1.0, 0.0), epsilon = TEST_EPS);
+    assert_eq!(p, q);
+    // End of one segment is the closest to the interior of the other
+    let c = LineSegment(Vec2::new(1.0, 3.0), Vec2::new(1.5, 1.0));
+    assert_eq!(
+        a.closest_points(&c),
+        Some((Vec2::new(1.5, 0.0), Vec2::new(1.5, 1.0)))
+    );
+    assert_eq!(
+        c.closest_points(&a),
+        Some((Vec2::new(1.5, 1.0), Vec2::new(1.5, 0.0)))
+    );
+    // Parallel
+    let d = LineSegment(Vec2::new(3.0, 1.0), Vec2::new(5.0, 1.0));
+    assert_abs_diff_eq!(a.distance_to(&d), 2.0f32.sqrt(), epsilon = TEST_EPS);
+}
+
+#[test]
+fn disk_polygon() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    let disk = Disk::new(Vec2::new(4.0, 1.0), 1.0);
+    assert_eq!(
+        disk.closest_points(&square),
+        Some((Vec2::new(3.0, 1.0), Vec2::new(2.0, 1.0)))
+    );
+    assert_eq!(square.distance_to(&disk), 1.0);
+    // Overlapping and contained
+    assert_eq!(
+        square.distance_to(&Disk::new(Vec2::new(2.5, 1.0), 1.0)),
+        0.0
+    );
+    let inner = Disk::new(Vec2::new(1.0, 1.0), 0.5);
+    assert_eq!(
+        inner.closest_points(&square),
+        Some((inner.center, inner.center))
+    );
+}
+
+#[test]
+fn polygons() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    let far = Polygon::new([
+        Vec2::new(3.0, 3.0),
+        Vec2::new(5.0, 3.0),
+        Vec2::new(4.0, 5.0),
+    ]);
+    // Corner to corner
+    assert_eq!(
+        square.closest_points(&far),
+        Some((Vec2::new(2.0, 2.0), Vec2::new(3.0, 3.0)))
+    );
+    assert_abs_diff_eq!(far.distance_to(&square), 2.0f32.sqrt(), epsilon = TEST_EPS);
+    // Crossing edges and nested polygons
+    let shifted = Polygon::new(square.vertices.map(|v| v + Vec2::splat(1.0)));
+    assert_eq!(square.distance_to(&shifted), 0.0);
+    let inner = Polygon::new(square.vertices.map(|v| v * 0.25 + Vec2::splat(0.5)));
+    assert_eq!(square.distance_to(&inner), 0.0);
+    assert_eq!(inner.distance_to(&square), 0.0);
+}
+
+#[test]
+fn point_arc_polygon() {
+    let polygon = ArcPolygon::<[_; 3]>::from_circle(Circle {
+        center: Vec2::new(1.0, 0.0),
+        radius: 1.0,
+    });
+    let point = Vec2::new(1.0, 3.0);
+    let (p, q) = point.closest_points(&polygon).unwrap();
+    assert_eq!(p, point);
+    assert_abs_diff_eq!(q, Vec2::new(1.0, 1.0), epsilon = TEST_EPS);
+    // Trait method is not shadowed by `Vec2::distance`
+    assert_abs_diff_eq!(point.distance_to(&polygon), 2.0, epsilon = TEST_EPS);
+    assert_eq!(polygon.distance_to(&Vec2::new(1.5, 0.0)), 0.0);
+}
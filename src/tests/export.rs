@@ -0,0 +1,105 @@
+extern crate std;
+
+use crate::{
+    Aabb, ArcPolygon, ArcVertex, Disk, Path, Region,
+    export::{DxfWriter, GerberWriter},
+};
+use glam::Vec2;
+use std::string::String;
+
+fn slot() -> ArcPolygon<[ArcVertex; 4]> {
+    ArcPolygon::new([
+        ArcVertex {
+            point: Vec2::new(0.0, -1.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, -1.0),
+            sagitta: 1.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 1.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(0.0, 1.0),
+            sagitta: 1.0,
+        },
+    ])
+}
+
+#[test]
+fn dxf() {
+    let mut dxf = DxfWriter::new(String::new()).unwrap();
+    dxf.polygon(&slot()).unwrap();
+    dxf.path(&Path::new([
+        ArcVertex {
+            point: Vec2::new(0.0, 0.0),
+            sagitta: -0.5,
+        },
+        ArcVertex {
+            point: Vec2::new(1.0, 0.0),
+            sagitta: 0.0,
+        },
+    ]))
+    .unwrap();
+    let text = dxf.finish().unwrap();
+
+    let lines: std::vec::Vec<_> = text.lines().collect();
+    assert_eq!(
+        &lines[..10],
+        [
+            "0", "SECTION", "2", "HEADER", "9", "$ACADVER", "1", "AC1015", "0", "ENDSEC"
+        ]
+    );
+    assert_eq!(&lines[10..14], ["0", "SECTION", "2", "ENTITIES"]);
+    assert_eq!(&lines[lines.len() - 4..], ["0", "ENDSEC", "0", "EOF"]);
+    assert_eq!(
+        text.matches("0\nLWPOLYLINE\n100\nAcDbEntity\n8\n0\n100\nAcDbPolyline\n")
+            .count(),
+        2
+    );
+    // Semicircles have bulge of 1
+    assert!(text.contains("90\n4\n70\n1\n"));
+    assert_eq!(text.matches("42\n1\n").count(), 2);
+    assert!(text.contains("90\n2\n70\n0\n"));
+    assert!(text.contains("42\n-1\n"));
+}
+
+#[test]
+fn gerber() {
+    let mut gerber = GerberWriter::new(String::new(), 0.1).unwrap();
+    gerber.polygon(&slot()).unwrap();
+    gerber
+        .polygon(&Disk::new(Vec2::new(5.0, 0.0), 1.0).polygon::<2>())
+        .unwrap();
+    let text = gerber.finish().unwrap();
+
+    assert!(text.starts_with("%FSLAX46Y46*%\n%MOMM*%\n%ADD10C,0.1*%\n"));
+    assert!(text.contains(
+        "X0Y-1000000D02*\nG01X2000000Y-1000000D01*\nG03X2000000Y1000000I0J1000000D01*\n"
+    ));
+    assert_eq!(text.matches("G03").count(), 4);
+    assert!(text.ends_with("M02*\n"));
+}
+
+#[test]
+fn gerber_boundary() {
+    let mut gerber = GerberWriter::new(String::new(), 0.1).unwrap();
+    let mut hole = Aabb::new(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0)).polygon();
+    hole.reverse();
+    let region = Region::new([
+        Aabb::new(Vec2::new(-2.0, -2.0), Vec2::new(2.0, 2.0)).polygon(),
+        hole,
+    ]);
+    gerber.boundary(&region).unwrap();
+    gerber
+        .boundary(&Disk::new(Vec2::new(5.0, 0.0), 1.0))
+        .unwrap();
+    let text = gerber.finish().unwrap();
+
+    // Each contour starts with a move
+    assert_eq!(text.matches("D02").count(), 3);
+    assert_eq!(text.matches("G01").count(), 8);
+    assert_eq!(text.matches("G03").count(), 2);
+}
@@ -0,0 +1,76 @@
+use crate::{Aabb, AreaGradient, Disk, IntersectTo, Moment, Polygon};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+
+/// Intersection area of the polygon and the disk moved by the `offset`.
+fn overlap(polygon: &Polygon<[Vec2; 4]>, disk: &Disk, offset: Vec2) -> f32 {
+    let disk = Disk::new(disk.center + offset, disk.radius);
+    let moment: Option<Moment> = polygon.intersect_to(&disk);
+    moment.map_or(0.0, |moment| moment.area)
+}
+
+#[test]
+fn disk_polygon() {
+    let polygon = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    // Disk on the right edge, moving it to the left increases the overlap by the chord length
+    let disk = Disk::new(Vec2::new(2.0, 1.0), 0.5);
+    assert_abs_diff_eq!(
+        polygon.area_gradient(&disk),
+        Vec2::new(-1.0, 0.0),
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        disk.area_gradient(&polygon),
+        Vec2::new(1.0, 0.0),
+        epsilon = 1e-6
+    );
+    // Disk inside and outside
+    assert_eq!(
+        polygon.area_gradient(&Disk::new(Vec2::ONE, 0.5)),
+        Vec2::ZERO
+    );
+    assert_eq!(
+        polygon.area_gradient(&Disk::new(Vec2::splat(5.0), 0.5)),
+        Vec2::ZERO
+    );
+
+    // Disk over the corner is compared with the finite differences
+    let disk = Disk::new(Vec2::new(1.8, 1.7), 0.6);
+    let h = 1e-3;
+    let numeric = Vec2::new(
+        overlap(&polygon, &disk, Vec2::new(h, 0.0)) - overlap(&polygon, &disk, Vec2::new(-h, 0.0)),
+        overlap(&polygon, &disk, Vec2::new(0.0, h)) - overlap(&polygon, &disk, Vec2::new(0.0, -h)),
+    ) / (2.0 * h);
+    assert_abs_diff_eq!(polygon.area_gradient(&disk), numeric, epsilon = 1e-2);
+    // Orientation of the polygon doesn't matter
+    let mut reversed = polygon;
+    reversed.vertices.reverse();
+    assert_abs_diff_eq!(
+        reversed.area_gradient(&disk),
+        polygon.area_gradient(&disk),
+        epsilon = 1e-6
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn polygons() {
+    let a = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    // Overlap of the shifted square is `(2 - x) * (2 - y)`
+    let b = Aabb::new(Vec2::new(0.5, 1.5), Vec2::new(2.5, 3.5)).polygon();
+    assert_abs_diff_eq!(a.area_gradient(&b), Vec2::new(-0.5, -1.5), epsilon = 1e-6);
+    assert_abs_diff_eq!(b.area_gradient(&a), Vec2::new(0.5, 1.5), epsilon = 1e-6);
+    // Concave polygon with a notch the square is moving out of
+    let c = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(3.0, 4.0),
+        Vec2::new(3.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 4.0),
+        Vec2::new(0.0, 4.0),
+    ]);
+    let d = Aabb::new(Vec2::new(1.5, 0.5), Vec2::new(2.5, 1.5)).polygon();
+    assert_abs_diff_eq!(c.area_gradient(&d), Vec2::new(0.0, -1.0), epsilon = 1e-6);
+}
@@ -0,0 +1,110 @@
+extern crate std;
+
+use crate::{Aabb, Closed, GridIndex, testgen::Pcg32};
+use glam::{UVec2, Vec2};
+use std::vec::Vec;
+
+#[test]
+fn queries() {
+    let mut rng = Pcg32::new(5562);
+    let mut boxes: Vec<Aabb> = (0..200)
+        .map(|_| {
+            let center = Vec2::new(rng.range(-1.0, 11.0), rng.range(-1.0, 11.0));
+            let half = Vec2::new(rng.range(0.0, 1.5), rng.range(0.0, 1.5));
+            Aabb::new(center - half, center + half)
+        })
+        .collect();
+    boxes.push(Aabb::EMPTY);
+
+    let bounds = Aabb::new(Vec2::ZERO, Vec2::splat(10.0));
+    let size = UVec2::new(8, 6);
+    let (offsets_len, entries_len) = GridIndex::storage_len(bounds, size, &boxes);
+    let mut offsets = [0; 49];
+    assert_eq!(offsets_len, offsets.len());
+    let mut entries = std::vec![0; entries_len];
+    assert!(GridIndex::new(bounds, size, &boxes, &mut offsets, &mut entries[1..]).is_none());
+    let index = GridIndex::new(bounds, size, &boxes, &mut offsets, &mut entries).unwrap();
+
+    for _ in 0..100 {
+        let point = Vec2::new(rng.range(-2.0, 12.0), rng.range(-2.0, 12.0));
+        let found: Vec<usize> = index.query_point(point).collect();
+        let naive: Vec<usize> = (0..boxes.len())
+            .filter(|&i| boxes[i].contains(point))
+            .collect();
+        assert_eq!(found, naive);
+
+        let region = Aabb::new(
+            point,
+            point + Vec2::new(rng.range(0.0, 4.0), rng.range(0.0, 4.0)),
+        );
+        let mut found: Vec<usize> = index.query_region(region).collect();
+        found.sort();
+        let naive: Vec<usize> = (0..boxes.len())
+            .filter(|&i| boxes[i].intersects(&region))
+            .collect();
+        assert_eq!(found, naive);
+    }
+    assert_eq!(index.query_region(Aabb::EMPTY).count(), 0);
+}
+
+#[test]
+fn degenerate_bounds() {
+    let boxes = [
+        Aabb::new(Vec2::ZERO, Vec2::ONE),
+        Aabb::new(Vec2::splat(2.0), Vec2::splat(3.0)),
+    ];
+    let (mut offsets, mut entries) = ([0; 2], [0; 2]);
+    let bounds = Aabb::new(Vec2::ZERO, Vec2::ZERO);
+    let index = GridIndex::new(bounds, UVec2::ZERO, &boxes, &mut offsets, &mut entries).unwrap();
+    assert!(index.query_point(Vec2::splat(2.5)).eq([1]));
+    assert!(
+        index
+            .query_region(Aabb::new(Vec2::ZERO, Vec2::splat(5.0)))
+            .eq([0, 1])
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn spatial_join() {
+    use crate::{Disk, Polygon, spatial_join, testgen::star_polygon};
+
+    let mut rng = Pcg32::new(562);
+    let disks: Vec<Disk> = (0..50)
+        .map(|_| {
+            Disk::new(
+                Vec2::new(rng.range(0.0, 10.0), rng.range(0.0, 10.0)),
+                rng.range(0.2, 1.5),
+            )
+        })
+        .collect();
+    let points: Vec<Vec2> = (0..500)
+        .map(|_| Vec2::new(rng.range(-1.0, 11.0), rng.range(-1.0, 11.0)))
+        .collect();
+    let pairs: Vec<(usize, usize)> = spatial_join(&disks, &points).collect();
+    let mut naive = Vec::new();
+    for (j, &p) in points.iter().enumerate() {
+        for (i, d) in disks.iter().enumerate() {
+            if d.contains(p) {
+                naive.push((i, j));
+            }
+        }
+    }
+    assert!(!naive.is_empty());
+    assert_eq!(pairs, naive);
+
+    let stars: Vec<Polygon<Vec<Vec2>>> = (0..3)
+        .map(|k| {
+            let star: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 9, 0.4);
+            Polygon::new(
+                star.vertices()
+                    .map(|v| v + Vec2::new(k as f32, 0.0))
+                    .collect(),
+            )
+        })
+        .collect();
+    for (i, j) in spatial_join(&stars, &points) {
+        assert!(stars[i].contains(points[j]));
+    }
+    assert_eq!(spatial_join::<Disk>(&[], &points).count(), 0);
+}
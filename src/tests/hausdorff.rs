@@ -0,0 +1,91 @@
+use crate::{Aabb, Polyline};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+
+const TOLERANCE: f32 = 1e-4;
+
+#[test]
+fn polygons() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    assert_eq!(square.hausdorff_distance(&square, TOLERANCE), 0.0);
+    // Corner of the larger square is the farthest from the smaller one
+    let (small, large) = (Aabb::new(Vec2::ZERO, Vec2::splat(1.0)).polygon(), square);
+    assert_abs_diff_eq!(
+        small.directed_hausdorff(&large, TOLERANCE),
+        1.0,
+        epsilon = TOLERANCE
+    );
+    assert_abs_diff_eq!(
+        large.directed_hausdorff(&small, TOLERANCE),
+        2.0f32.sqrt(),
+        epsilon = TOLERANCE
+    );
+    assert_abs_diff_eq!(
+        small.hausdorff_distance(&large, TOLERANCE),
+        2.0f32.sqrt(),
+        epsilon = TOLERANCE
+    );
+}
+
+#[test]
+fn edge_interior() {
+    // The farthest point lies in the middle of the edge, between the vertices of the other polyline
+    let straight = Polyline::new([Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0)]);
+    let bent = Polyline::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(3.0, 1.0),
+        Vec2::new(4.0, 0.0),
+    ]);
+    assert_abs_diff_eq!(
+        bent.directed_hausdorff(&straight, TOLERANCE),
+        1.0,
+        epsilon = TOLERANCE
+    );
+    let valley = Polyline::new([Vec2::new(0.0, 1.0), Vec2::new(4.0, 1.0)]);
+    let peaks = Polyline::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(3.0, 0.0),
+        Vec2::new(4.0, 0.0),
+    ]);
+    assert_abs_diff_eq!(
+        valley.hausdorff_distance(&peaks, TOLERANCE),
+        1.0,
+        epsilon = TOLERANCE
+    );
+    let sawtooth = Polyline::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(4.0, 0.0),
+    ]);
+    let ends = Polyline::new([Vec2::new(0.0, 0.0)]);
+    assert_abs_diff_eq!(
+        sawtooth.directed_hausdorff(&ends, TOLERANCE),
+        4.0,
+        epsilon = TOLERANCE
+    );
+    assert!(
+        ends.directed_hausdorff(&Polyline::new([]), TOLERANCE)
+            .is_infinite()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn arcs() {
+    use crate::{ArcPolygon, Circle};
+
+    let circle = |radius| {
+        ArcPolygon::<[_; 4]>::from_circle(Circle {
+            center: Vec2::ZERO,
+            radius,
+        })
+    };
+    let tolerance = 1e-3;
+    assert_abs_diff_eq!(
+        circle(1.0).hausdorff_distance(&circle(1.5), tolerance),
+        0.5,
+        epsilon = tolerance
+    );
+}
@@ -0,0 +1,146 @@
+extern crate std;
+
+use crate::Polygon;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn concave() {
+    let poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let hull: Vec<_> = poly.convex_hull().collect();
+    assert_eq!(
+        hull,
+        [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ]
+    );
+}
+
+#[test]
+fn degenerate() {
+    // Clockwise with collinear and duplicate vertices
+    let poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.5, 0.0),
+    ]);
+    let hull: Vec<_> = poly.convex_hull().collect();
+    assert_eq!(
+        hull,
+        [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ]
+    );
+
+    let point = Polygon::new([Vec2::ONE; 3]);
+    assert_eq!(point.convex_hull().collect::<Vec<_>>(), [Vec2::ONE]);
+    assert_eq!(Polygon::new([]).convex_hull().count(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn incremental() {
+    use crate::{Closed, IncrementalHull, testgen::Pcg32};
+
+    let mut rng = Pcg32::new(549);
+    let points: Vec<_> = (0..100).map(|_| rng.in_unit_disk()).collect();
+    let mut hull = IncrementalHull::new();
+    for (i, &p) in points.iter().enumerate() {
+        hull.insert(p);
+        if i < 2 {
+            continue;
+        }
+        // Gift wrapping starts from the leftmost vertex
+        let expected: Vec<_> = Polygon::new(points[..=i].to_vec()).convex_hull().collect();
+        let mut actual: Vec<_> = hull.vertices().collect();
+        let start = actual.iter().position(|&v| v == expected[0]).unwrap();
+        actual.rotate_left(start);
+        assert_eq!(actual, expected);
+    }
+    assert!(!hull.insert(Vec2::ZERO));
+    assert!(hull.as_polygon().contains(Vec2::new(0.1, 0.1)));
+
+    // Collinear points
+    let mut line: IncrementalHull = [1.0, 0.0, 3.0, 2.0]
+        .map(|x| Vec2::new(x, x))
+        .into_iter()
+        .collect();
+    assert_eq!(
+        line.vertices().collect::<Vec<_>>(),
+        [Vec2::splat(3.0), Vec2::ZERO]
+    );
+    assert!(line.insert(Vec2::new(3.0, 0.0)));
+    assert_eq!(line.len(), 3);
+    assert!(line.as_polygon().is_ccw());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn incremental_nearly_collinear() {
+    use crate::IncrementalHull;
+
+    // Orientation tests disagree on these points due to rounding
+    let points = [
+        Vec2::new(-9.086857, 0.3604195),
+        Vec2::new(0.8026185, 6.24084),
+        Vec2::new(-0.019268513, 5.752135),
+        Vec2::new(-0.14241695, 5.678909),
+        Vec2::new(-3.5468612, 3.6545784),
+        Vec2::new(-6.4568815, 1.9242394),
+    ];
+    let hull: IncrementalHull = points.into_iter().collect();
+    assert!(hull.len() >= 2);
+    assert!(hull.vertices().all(|v| points.contains(&v)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn layers() {
+    use crate::{convex_layers, in_convex_position};
+
+    // Two nested squares with the center and a point on the outer edge
+    let outer = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0), (2.0, 0.0)];
+    let inner = [(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)];
+    let points: Vec<Vec2> = outer
+        .into_iter()
+        .chain(inner)
+        .chain([(2.0, 2.0)])
+        .map(Vec2::from)
+        .collect();
+    let layers = convex_layers(points.iter().copied());
+    assert_eq!(layers.len(), 3);
+    assert_eq!(layers[0].len(), 4);
+    assert!(layers[0].is_ccw());
+    assert_eq!(layers[1].len(), 4);
+    assert!(layers[1].vertices().all(|v| inner.contains(&v.into())));
+    assert!(layers[2].vertices().eq([Vec2::splat(2.0)]));
+
+    assert!(!in_convex_position(points.iter().copied()));
+    assert!(in_convex_position(inner.map(Vec2::from)));
+    // Point on the edge is not a hull vertex
+    assert!(!in_convex_position(outer.map(Vec2::from)));
+    assert!(in_convex_position([Vec2::ZERO, Vec2::ZERO, Vec2::X]));
+
+    // Collinear points lie on the single segment layer
+    let line = (0..5).map(|i| Vec2::new(i as f32, 0.0));
+    let layers = convex_layers(line);
+    assert_eq!(layers.len(), 1);
+    assert!(layers[0].vertices().eq([Vec2::ZERO, Vec2::new(4.0, 0.0)]));
+}
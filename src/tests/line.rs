@@ -1,5 +1,5 @@
-use crate::{EPS, Intersect, Line, LineSegment};
-use approx::assert_relative_eq;
+use crate::{Disk, EPS, HalfPlane, Intersect, IntersectTo, Line, LineSegment, Polygon};
+use approx::{assert_abs_diff_eq, assert_relative_eq};
 use glam::Vec2;
 
 // Helper macro for approximate equality
@@ -441,3 +441,144 @@ fn intersection_symmetry() {
         }
     }
 }
+
+#[test]
+fn fit() {
+    // Points on a steep line with offsets across it not correlated with the position along it
+    let dir = Vec2::new(1.0, 3.0).normalize();
+    let points = (0..20).map(|i| {
+        let offset = if i % 4 == 0 || i % 4 == 3 { 0.1 } else { -0.1 };
+        Vec2::new(5.0, -2.0) + dir * i as f32 + dir.perp() * offset
+    });
+    let line = Line::fit(points).unwrap();
+    assert_relative_eq!((line.1 - line.0).length(), 1.0, epsilon = 1e-6);
+    assert!((line.1 - line.0).perp_dot(dir).abs() < 1e-4);
+    // Offsets cancel out, so the line goes through the middle
+    assert!(line.signed_distance(Vec2::new(5.0, -2.0)).abs() < 1e-3);
+
+    assert!(Line::fit([]).is_none());
+    assert!(Line::fit([Vec2::ONE; 3]).is_none());
+}
+
+#[test]
+fn segment_clip() {
+    let segment = LineSegment(Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0));
+
+    let plane = HalfPlane::from_normal(Vec2::new(1.0, 0.0), Vec2::X);
+    let inside = segment.intersect(&plane).unwrap();
+    assert_abs_diff_eq!(
+        inside,
+        LineSegment(Vec2::new(-2.0, 0.0), Vec2::new(1.0, 0.0))
+    );
+    // Direction of the segment is kept
+    let reversed = LineSegment(segment.1, segment.0);
+    let inside = plane.intersect(&reversed).unwrap();
+    assert_abs_diff_eq!(
+        inside,
+        LineSegment(Vec2::new(1.0, 0.0), Vec2::new(-2.0, 0.0))
+    );
+    assert!(segment.intersect(&plane.offset(-5.0)).is_none());
+
+    let disk = Disk::new(Vec2::new(1.0, 0.5), 1.0);
+    let inside = segment.intersect(&disk).unwrap();
+    let h = 0.75f32.sqrt();
+    assert_abs_diff_eq!(
+        inside,
+        LineSegment(Vec2::new(1.0 - h, 0.0), Vec2::new(1.0 + h, 0.0)),
+        epsilon = 1e-6
+    );
+    // Segment ending inside of the disk
+    let short = LineSegment(Vec2::new(-2.0, 0.0), Vec2::new(1.0, 0.0));
+    let inside: LineSegment = short.intersect_to(&disk).unwrap();
+    assert_abs_diff_eq!(inside.1, short.1);
+    assert!(
+        segment
+            .intersect(&Disk::new(Vec2::new(0.0, 2.0), 1.0))
+            .is_none()
+    );
+
+    let diamond = Polygon::new([
+        Vec2::new(0.0, -1.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+        Vec2::new(-1.0, 0.0),
+    ]);
+    let inside: LineSegment = segment.intersect_to(&diamond).unwrap();
+    assert_abs_diff_eq!(
+        inside,
+        LineSegment(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0))
+    );
+    let diagonal = LineSegment(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+    let inside: LineSegment = diamond.intersect_to(&diagonal).unwrap();
+    assert_abs_diff_eq!(inside, LineSegment(Vec2::ZERO, Vec2::splat(0.5)));
+    let outside = LineSegment(Vec2::new(1.0, 1.0), Vec2::new(2.0, 0.0));
+    assert!(IntersectTo::<_, LineSegment>::intersect_to(&outside, &diamond).is_none());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn concave_clip() {
+    extern crate std;
+    use std::vec::Vec;
+
+    // U-shaped polygon opened upwards
+    let u = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(3.0, 0.0),
+        Vec2::new(3.0, 3.0),
+        Vec2::new(2.0, 3.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 3.0),
+        Vec2::new(0.0, 3.0),
+    ]);
+    let segment = LineSegment(Vec2::new(-1.0, 2.0), Vec2::new(4.0, 2.0));
+    let parts: Vec<LineSegment> = segment.clip(&u).collect();
+    assert_eq!(parts.len(), 2);
+    assert_abs_diff_eq!(
+        parts[0],
+        LineSegment(Vec2::new(0.0, 2.0), Vec2::new(1.0, 2.0))
+    );
+    assert_abs_diff_eq!(
+        parts[1],
+        LineSegment(Vec2::new(2.0, 2.0), Vec2::new(3.0, 2.0))
+    );
+    // Reversed segment gives the same parts in reversed order
+    let reversed: Vec<LineSegment> = LineSegment(segment.1, segment.0).clip(&u).collect();
+    assert_abs_diff_eq!(
+        reversed[0],
+        LineSegment(Vec2::new(3.0, 2.0), Vec2::new(2.0, 2.0))
+    );
+
+    // Parts touching at the reflex vertex are merged
+    let diagonal = LineSegment(Vec2::new(-0.5, 2.5), Vec2::new(2.5, -0.5));
+    let parts: Vec<LineSegment> = diagonal.clip(&u).collect();
+    assert_eq!(parts.len(), 1);
+    assert_abs_diff_eq!(
+        parts[0],
+        LineSegment(Vec2::new(0.0, 2.0), Vec2::new(2.0, 0.0)),
+        epsilon = 1e-6
+    );
+
+    let inside = LineSegment(Vec2::new(0.5, 0.5), Vec2::new(2.5, 0.5));
+    assert!(inside.clip(&u).eq([inside]));
+    let outside = LineSegment(Vec2::new(1.2, 2.0), Vec2::new(1.8, 2.0));
+    assert_eq!(outside.clip(&u).count(), 0);
+    // Touching the convex corner from outside
+    let corner = LineSegment(Vec2::new(2.0, 4.0), Vec2::new(4.0, 2.0));
+    assert_eq!(corner.clip(&u).count(), 0);
+    // Passing through the vertices
+    let through = LineSegment(Vec2::new(-1.0, -1.0), Vec2::new(4.0, 4.0));
+    let parts: Vec<LineSegment> = through.clip(&u).collect();
+    assert_eq!(parts.len(), 2);
+    assert_abs_diff_eq!(
+        parts[0],
+        LineSegment(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)),
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        parts[1],
+        LineSegment(Vec2::new(2.0, 2.0), Vec2::new(3.0, 3.0)),
+        epsilon = 1e-6
+    );
+}
@@ -0,0 +1,126 @@
+extern crate std;
+
+use crate::{
+    Aabb, ArcPolygon, Circle, Closed, Disk, Integrable, Polygon, Region, from_mask, to_coverage,
+    to_mask,
+};
+use glam::{UVec2, Vec2};
+use std::vec;
+
+#[test]
+fn round_trip() {
+    let square = |half: f32| {
+        Polygon::new(vec![
+            Vec2::new(-half, -half),
+            Vec2::new(half, -half),
+            Vec2::new(half, half),
+            Vec2::new(-half, half),
+        ])
+    };
+    let outer = square(3.0);
+    let hole = Polygon::new(square(1.0).vertices().rev().collect());
+    let shape = Region::new(vec![outer, hole]);
+
+    let bounds = Aabb::new(Vec2::splat(-4.0), Vec2::splat(4.0));
+    let size = UVec2::splat(32);
+    let mask = to_mask(&shape, bounds, size);
+    assert!(!mask.get(UVec2::new(16, 16)));
+    assert!(mask.get(UVec2::new(6, 6)));
+    assert!((mask.area() - 32.0).abs() < 1e-4);
+
+    let region = from_mask(&mask, 0.01);
+    let contours: std::vec::Vec<_> = region.components().collect();
+    assert_eq!(contours.len(), 2);
+    // Collinear vertices are simplified out, leaving squares with chamfered corners
+    assert!(
+        contours
+            .iter()
+            .all(|contour| contour.vertices().count() == 8)
+    );
+    let areas = contours.iter().map(|contour| contour.moment().area);
+    assert_eq!(areas.clone().filter(|&area| area > 0.0).count(), 1);
+    assert!((areas.sum::<f32>() - 32.0).abs() < 1e-4);
+    assert!(!region.contains(Vec2::ZERO));
+    assert!(region.contains(Vec2::new(2.0, 0.0)));
+    assert_eq!(to_mask(&region, bounds, size), mask);
+}
+
+#[test]
+fn disk() {
+    let disk = Disk::new(Vec2::new(0.3, -0.2), 2.5);
+    let bounds = Aabb::new(Vec2::splat(-3.0), Vec2::splat(3.0));
+    let size = UVec2::new(60, 48);
+    let mask = to_mask(&disk, bounds, size);
+    let cell = mask.cell_size();
+    let region = from_mask(&mask, 0.25 * cell.min_element());
+
+    assert_eq!(region.components().count(), 1);
+    assert!((region.moment().area - disk.moment().area).abs() < 0.1);
+    assert_eq!(to_mask(&region, bounds, size), mask);
+}
+
+#[test]
+fn diagonal() {
+    let bounds = Aabb::new(Vec2::ZERO, Vec2::splat(4.0));
+    let mut mask = to_mask(&Aabb::EMPTY, bounds, UVec2::splat(4));
+    mask.set(UVec2::new(1, 1), true);
+    mask.set(UVec2::new(2, 2), true);
+    assert_eq!(mask.count(), 2);
+
+    // Diagonal neighbours are joined into a single contour
+    let region = from_mask(&mask, 0.0);
+    assert_eq!(region.components().count(), 1);
+    assert!(region.contains(Vec2::new(1.5, 1.5)));
+    assert!(region.contains(Vec2::new(2.5, 2.5)));
+    assert!(!region.contains(Vec2::new(2.5, 1.5)));
+}
+
+#[test]
+fn coverage() {
+    let bounds = Aabb::new(Vec2::ZERO, Vec2::splat(4.0));
+    let square = Aabb::new(Vec2::splat(0.5), Vec2::splat(2.5));
+    let grid = to_coverage(&square, bounds, UVec2::splat(4));
+
+    let expected = [
+        [0.25, 0.5, 0.25, 0.0],
+        [0.5, 1.0, 0.5, 0.0],
+        [0.25, 0.5, 0.25, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+    ];
+    for (y, row) in expected.iter().enumerate() {
+        for (x, &value) in row.iter().enumerate() {
+            let cell = UVec2::new(x as u32, y as u32);
+            assert!((grid.get(cell) - value).abs() < 1e-5);
+        }
+    }
+    assert!((grid.area() - 4.0).abs() < 1e-4);
+}
+
+#[test]
+fn coverage_curved() {
+    let bounds = Aabb::new(Vec2::splat(-3.0), Vec2::splat(3.0));
+    let size = UVec2::new(30, 24);
+    let disk = Disk::new(Vec2::new(0.3, -0.2), 2.5);
+    let grid = to_coverage(&disk, bounds, size);
+    assert!((grid.area() - disk.moment().area).abs() < 1e-3);
+    assert_eq!(grid.get(UVec2::new(15, 12)), 1.0);
+    assert_eq!(grid.get(UVec2::ZERO), 0.0);
+
+    // Only the part inside of the bounds is covered
+    let arcs = ArcPolygon::<[_; 4]>::from_circle(Circle {
+        center: Vec2::new(3.0, 0.0),
+        radius: 1.0,
+    });
+    let grid = to_coverage(&arcs, bounds, size);
+    assert!((grid.area() - 0.5 * core::f32::consts::PI).abs() < 1e-3);
+    for y in 0..size.y {
+        for x in 0..size.x {
+            let cell = UVec2::new(x, y);
+            let value = grid.get(cell);
+            assert!((0.0..=1.0).contains(&value));
+            if value > 0.0 {
+                assert!(grid.cell_center(cell).x > 1.5);
+            }
+        }
+    }
+}
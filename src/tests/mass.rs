@@ -0,0 +1,154 @@
+use crate::{
+    Aabb, Arc, ArcPolygon, ArcVertex, Disk, DiskSegment, Inertial, MassProperties, Moment, Polygon,
+};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+
+#[test]
+fn polygon() {
+    let properties = Aabb::new(Vec2::new(1.0, 2.0), Vec2::new(5.0, 4.0))
+        .polygon()
+        .mass_properties();
+    assert_abs_diff_eq!(properties.area, 8.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(properties.centroid, Vec2::new(3.0, 3.0), epsilon = 1e-5);
+    assert_abs_diff_eq!(properties.ixx, 4.0 * 8.0 / 12.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(properties.iyy, 64.0 * 2.0 / 12.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(properties.ixy, 0.0, epsilon = 1e-5);
+
+    // Right triangle with the legs along the axes
+    let triangle = Polygon::new([Vec2::ZERO, Vec2::new(3.0, 0.0), Vec2::new(0.0, 6.0)]);
+    let properties = triangle.mass_properties();
+    assert_abs_diff_eq!(properties.ixx, 3.0 * 216.0 / 36.0, epsilon = 1e-4);
+    assert_abs_diff_eq!(properties.iyy, 27.0 * 6.0 / 36.0, epsilon = 1e-4);
+    assert_abs_diff_eq!(properties.ixy, -9.0 * 36.0 / 72.0, epsilon = 1e-4);
+    assert_abs_diff_eq!(
+        properties.moment(),
+        triangle.signed_moment(),
+        epsilon = 1e-5
+    );
+
+    // Rotation by a quarter turn swaps the axes
+    let rotated = Polygon::new([Vec2::ZERO, Vec2::new(0.0, 3.0), Vec2::new(-6.0, 0.0)]);
+    let other = rotated.mass_properties();
+    assert_abs_diff_eq!(other.ixx, properties.iyy, epsilon = 1e-4);
+    assert_abs_diff_eq!(other.iyy, properties.ixx, epsilon = 1e-4);
+}
+
+#[test]
+fn merge_remove() {
+    let left = Aabb::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0))
+        .polygon()
+        .mass_properties();
+    let right = Aabb::new(Vec2::new(2.0, 0.0), Vec2::new(4.0, 2.0))
+        .polygon()
+        .mass_properties();
+    let whole = Aabb::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 2.0))
+        .polygon()
+        .mass_properties();
+    assert_abs_diff_eq!(left.merge(right), whole, epsilon = 1e-5);
+    assert_abs_diff_eq!(whole.remove(right), left, epsilon = 1e-5);
+
+    // Hole given by the clockwise polygon
+    let mut hole = Aabb::new(Vec2::new(2.5, 0.5), Vec2::new(3.5, 1.5)).polygon();
+    let removed = whole.remove(hole.mass_properties());
+    hole.vertices.reverse();
+    assert_abs_diff_eq!(whole.merge(hole.mass_properties()), removed, epsilon = 1e-5);
+    assert_abs_diff_eq!(removed.area, 7.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(
+        Moment::from(removed),
+        whole.moment().merge(hole.signed_moment()),
+        epsilon = 1e-5
+    );
+
+    assert_eq!(left.remove(left), MassProperties::default());
+}
+
+#[test]
+fn disk() {
+    let disk = Disk::new(Vec2::new(1.0, -2.0), 2.0);
+    let properties = disk.mass_properties();
+    assert_abs_diff_eq!(properties.polar(), 0.5 * PI * 16.0, epsilon = 1e-4);
+    assert_abs_diff_eq!(
+        properties.radius_of_gyration(),
+        2.0 / 2.0f32.sqrt(),
+        epsilon = 1e-5
+    );
+
+    // Half disk with the chord going down along the Y axis
+    let half = DiskSegment(Arc {
+        points: (Vec2::new(0.0, 1.0), Vec2::new(0.0, -1.0)),
+        sagitta: 1.0,
+    })
+    .mass_properties();
+    assert_abs_diff_eq!(half.area, 0.5 * PI, epsilon = 1e-5);
+    assert_abs_diff_eq!(half.ixx, PI / 8.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(half.iyy, PI / 8.0 - 8.0 / (9.0 * PI), epsilon = 1e-5);
+    assert_abs_diff_eq!(half.ixy, 0.0, epsilon = 1e-5);
+
+    // Disk made of two arcs, the frame has no area
+    let arcs = ArcPolygon::new([
+        ArcVertex {
+            point: Vec2::new(3.0, -2.0),
+            sagitta: 2.0,
+        },
+        ArcVertex {
+            point: Vec2::new(-1.0, -2.0),
+            sagitta: 2.0,
+        },
+    ]);
+    assert_abs_diff_eq!(arcs.mass_properties(), properties, epsilon = 1e-4);
+
+    // Same disk made of the quarter and the rest of it
+    let c = 0.5f32.sqrt();
+    let arcs = ArcPolygon::new([
+        ArcVertex {
+            point: Vec2::new(3.0, -2.0),
+            sagitta: 2.0 * (1.0 - c),
+        },
+        ArcVertex {
+            point: Vec2::new(1.0, 0.0),
+            sagitta: 2.0 * (1.0 + c),
+        },
+    ]);
+    assert_abs_diff_eq!(arcs.mass_properties(), properties, epsilon = 1e-4);
+
+    // Nearly flat arcs are approximated by parabolas
+    let flat = ArcPolygon::new([
+        ArcVertex {
+            point: Vec2::new(0.0, 0.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 0.0),
+            sagitta: 1e-5,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 2.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(0.0, 2.0),
+            sagitta: 0.0,
+        },
+    ]);
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0))
+        .polygon()
+        .mass_properties();
+    assert_abs_diff_eq!(flat.mass_properties(), square, epsilon = 1e-4);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let properties = Aabb::new(Vec2::new(1.0, 2.0), Vec2::new(5.0, 4.0))
+        .polygon()
+        .mass_properties();
+    let json = serde_json::to_string(&properties).unwrap();
+    assert_eq!(
+        serde_json::from_str::<MassProperties>(&json).unwrap(),
+        properties
+    );
+    let moment: Moment = serde_json::from_str(r#"{"area":2.0,"centroid":[1.0,3.0]}"#).unwrap();
+    assert_eq!(moment.centroid, Vec2::new(1.0, 3.0));
+}
@@ -0,0 +1,64 @@
+use crate::{Polygon, ShapeMetrics, metrics};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::{Mat2, Vec2};
+
+const TEST_EPS: f32 = 1e-5;
+
+#[test]
+fn square() {
+    let rotation = Mat2::from_angle(0.3);
+    let square = Polygon::new(
+        [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ]
+        .map(|v| rotation * v),
+    );
+    assert_abs_diff_eq!(
+        metrics(&square).unwrap(),
+        ShapeMetrics {
+            compactness: PI / 4.0,
+            convexity: 1.0,
+            solidity: 1.0,
+            eccentricity: 0.0,
+            rectangularity: 1.0,
+        },
+        epsilon = 1e-3
+    );
+}
+
+#[test]
+fn concave() {
+    // Clockwise L-shape
+    let poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.0, 2.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(2.0, 0.0),
+    ]);
+    let m = metrics(&poly).unwrap();
+    assert_abs_diff_eq!(m.solidity, 3.0 / 3.5, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(m.convexity, (6.0 + 2.0f32.sqrt()) / 8.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(m.rectangularity, 0.75, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(m.compactness, 4.0 * PI * 3.0 / 64.0, epsilon = TEST_EPS);
+
+    // Thin rectangle is highly eccentric
+    let thin = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 0.0),
+        Vec2::new(10.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    assert_abs_diff_eq!(
+        metrics(&thin).unwrap().eccentricity,
+        (0.99f32).sqrt(),
+        epsilon = TEST_EPS
+    );
+
+    assert!(metrics(&Polygon::new([Vec2::ZERO, Vec2::X, Vec2::X * 2.0])).is_none());
+}
@@ -1,6 +1,81 @@
+mod aabb;
+#[cfg(feature = "alloc")]
+mod accrete;
 mod arc;
+mod bezier;
+mod blend;
+mod boundary;
+mod bounds;
+#[cfg(feature = "alloc")]
+mod bvh;
+mod capsule;
+mod cast;
 mod circle;
+mod crossing;
+mod csg;
+mod debug;
+mod decompose;
+#[cfg(feature = "alloc")]
+mod depth;
+#[cfg(feature = "alloc")]
+mod destruct;
+mod difference;
+mod distance;
+mod export;
+mod gradient;
+mod grid;
+mod hausdorff;
+mod hull;
 mod line;
+#[cfg(feature = "alloc")]
+mod mask;
+mod mass;
+mod metrics;
 mod moment;
+#[cfg(feature = "alloc")]
+mod morphology;
+#[cfg(feature = "alloc")]
+mod nesting;
+mod normalize;
+mod outline;
+mod overlap;
+#[cfg(feature = "alloc")]
+mod pairwise;
+mod path;
 mod plane;
 mod polygon;
+#[cfg(feature = "alloc")]
+mod prepared;
+#[cfg(feature = "alloc")]
+mod profile;
+mod region;
+#[cfg(feature = "alloc")]
+mod rings;
+#[cfg(feature = "alloc")]
+mod scanline;
+#[cfg(feature = "alloc")]
+mod scene;
+mod simplify;
+#[cfg(feature = "alloc")]
+mod skeleton;
+#[cfg(feature = "alloc")]
+mod slab;
+mod slice;
+#[cfg(feature = "alloc")]
+mod store;
+#[cfg(feature = "alloc")]
+mod subtract;
+#[cfg(feature = "alloc")]
+mod sweep;
+mod target;
+#[cfg(feature = "telemetry")]
+mod telemetry;
+mod testgen;
+#[cfg(feature = "alloc")]
+mod toolpath;
+mod transform;
+mod triangle;
+#[cfg(feature = "alloc")]
+mod union;
+#[cfg(feature = "alloc")]
+mod visibility;
@@ -1,4 +1,6 @@
-use crate::{Closed, Disk, EPS, Integrable, Moment};
+extern crate std;
+
+use crate::{Closed, Disk, EPS, Integrable, Moment, MomentAccumulator, Polygon, testgen::Pcg32};
 use approx::assert_abs_diff_eq;
 use either::Either;
 use glam::Vec2;
@@ -141,3 +143,146 @@ fn either_closed_integrable() {
     assert!(!right_mixed.contains(Vec2::new(0.0, 0.0)));
     assert_abs_diff_eq!(right_mixed.area(), 0.0, epsilon = TEST_EPS);
 }
+
+#[test]
+fn signed_moment() {
+    let outer = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    // Clockwise hole in the right half
+    let hole = Polygon::new([
+        Vec2::new(2.5, 0.5),
+        Vec2::new(2.5, 1.5),
+        Vec2::new(3.5, 1.5),
+        Vec2::new(3.5, 0.5),
+    ]);
+    let hole_moment = hole.signed_moment();
+    assert_abs_diff_eq!(hole_moment.area, -1.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(
+        hole_moment.centroid,
+        Vec2::new(3.0, 1.0),
+        epsilon = TEST_EPS
+    );
+
+    let moment = outer.signed_moment().merge(hole_moment);
+    assert_abs_diff_eq!(moment.area, 7.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(
+        moment.centroid,
+        Vec2::new((8.0 * 2.0 - 3.0) / 7.0, 1.0),
+        epsilon = 1e-6
+    );
+
+    let disk = Disk::new(Vec2::new(1.0, 1.0), 0.5);
+    let mut curved = disk.polygon::<3>();
+    curved.reverse();
+    let curved_moment = curved.signed_moment();
+    assert_abs_diff_eq!(curved_moment.area, -disk.area(), epsilon = TEST_EPS);
+    assert_abs_diff_eq!(curved_moment.centroid, disk.center, epsilon = 1e-5);
+}
+
+#[test]
+fn moment_remove() {
+    let moment1 = Moment {
+        area: 10.0,
+        centroid: Vec2::new(1.0, 2.0),
+    };
+    let moment2 = Moment {
+        area: 20.0,
+        centroid: Vec2::new(3.0, 4.0),
+    };
+    let merged = moment1.merge(moment2);
+    assert_abs_diff_eq!(merged.remove(moment2), moment1, epsilon = 1e-5);
+    assert_abs_diff_eq!(merged.remove(moment1), moment2, epsilon = 1e-5);
+
+    // Nothing remains, the centroid is undefined
+    assert_eq!(merged.remove(merged), Moment::default());
+    let almost = Moment {
+        area: merged.area * (1.0 + f32::EPSILON),
+        centroid: Vec2::new(-5.0, 7.0),
+    };
+    assert_eq!(merged.remove(almost), Moment::default());
+}
+
+#[test]
+fn moment_accumulator() {
+    let mut rng = Pcg32::new(0x576);
+    let disks: std::vec::Vec<Disk> = (0..100)
+        .map(|_| {
+            Disk::new(
+                Vec2::new(rng.next_f32(), rng.next_f32()) * 1000.0,
+                1.0 + rng.next_f32(),
+            )
+        })
+        .collect();
+
+    let mut accumulator = MomentAccumulator::new();
+    assert!(accumulator.is_empty());
+    for disk in &disks {
+        accumulator.add_shape(disk);
+    }
+    // Remove every other disk, then add and remove it back and forth
+    for disk in disks.iter().step_by(2) {
+        accumulator.remove_shape(disk);
+    }
+    for _ in 0..1000 {
+        accumulator.add_shape(&disks[0]);
+        accumulator.remove_shape(&disks[0]);
+    }
+    let expected = disks
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .fold(Moment::default(), |moment, disk| {
+            moment.merge(disk.moment())
+        });
+    assert_abs_diff_eq!(accumulator.moment(), expected, epsilon = 1e-2);
+    assert_abs_diff_eq!(
+        accumulator.moment().centroid,
+        expected.centroid,
+        epsilon = 1e-3
+    );
+
+    let mut doubled: MomentAccumulator = disks
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .map(|disk| disk.moment())
+        .collect();
+    doubled.merge(&accumulator);
+    assert_abs_diff_eq!(doubled.moment().area, 2.0 * expected.area, epsilon = 1e-2);
+
+    // Removing everything gives exactly nothing
+    for disk in disks.iter().skip(1).step_by(2) {
+        accumulator.remove_shape(disk);
+    }
+    assert!(accumulator.is_empty());
+    assert_eq!(accumulator.moment(), Moment::default());
+}
+
+#[test]
+fn moment_accumulator_hole() {
+    let body = Disk::new(Vec2::new(1.0, 0.0), 2.0);
+    let hole = Disk::new(Vec2::new(2.0, 0.0), 0.5);
+    let mut accumulator = MomentAccumulator::new();
+    accumulator.add_shape(&body);
+    accumulator.remove_shape(&hole);
+    // Counts balance each other, but the body with the hole is left
+    assert!(accumulator.is_empty());
+    let moment = accumulator.moment();
+    assert_abs_diff_eq!(
+        moment.area,
+        body.moment().area - hole.moment().area,
+        epsilon = 1e-5
+    );
+    assert!(moment.centroid.x < 1.0);
+
+    let mut merged = MomentAccumulator::new();
+    merged.add_shape(&body);
+    let mut removed = MomentAccumulator::new();
+    removed.remove_shape(&hole);
+    merged.merge(&removed);
+    assert_abs_diff_eq!(merged.moment(), moment, epsilon = 1e-5);
+}
@@ -0,0 +1,60 @@
+extern crate std;
+
+use crate::{Aabb, Closed, Integrable, Polygon, Region, close, open};
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::{vec, vec::Vec};
+
+#[test]
+fn opening() {
+    let square = Region::new(vec![Aabb::new(Vec2::ZERO, Vec2::splat(4.0)).polygon()]);
+    let opened = open(&square, 1.0);
+    assert_eq!(opened.components().count(), 1);
+    assert!((opened.moment().area - (12.0 + PI)).abs() < 1e-4);
+    assert!(!opened.contains(Vec2::new(0.1, 0.1)));
+    assert!(opened.contains(Vec2::new(2.0, 0.05)));
+    assert!(opened.contains(Vec2::new(3.2, 3.2)));
+
+    // Island thinner than the disk is removed
+    let noisy = Region::new(vec![
+        Aabb::new(Vec2::ZERO, Vec2::splat(4.0)).polygon(),
+        Aabb::new(Vec2::new(6.0, 0.0), Vec2::new(7.0, 1.0)).polygon(),
+    ]);
+    let opened = open(&noisy, 0.6);
+    assert_eq!(opened.components().count(), 1);
+    assert!(!opened.contains(Vec2::new(6.5, 0.5)));
+
+    // Zero radius keeps the region
+    let same = open(&square, 0.0);
+    assert!((same.moment().area - 16.0).abs() < 1e-4);
+}
+
+#[test]
+fn closing() {
+    let corner = Region::new(vec![Polygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 2.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(2.0, 4.0),
+        Vec2::new(0.0, 4.0),
+    ])]);
+    let closed = close(&corner, 1.0);
+    assert_eq!(closed.components().count(), 1);
+    // Only the concave corner is filled by the rolling disk
+    assert!((closed.moment().area - (13.0 - 0.25 * PI)).abs() < 1e-4);
+    assert!(closed.contains(Vec2::new(2.1, 2.1)));
+    assert!(!closed.contains(Vec2::new(2.9, 2.9)));
+    assert!(closed.contains(Vec2::new(3.95, 0.05)));
+
+    // Narrow gap is bridged
+    let pair = Region::new(vec![
+        Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon(),
+        Aabb::new(Vec2::new(2.5, 0.0), Vec2::new(4.5, 2.0)).polygon(),
+    ]);
+    let closed = close(&pair, 0.5);
+    let contours: Vec<_> = closed.components().collect();
+    assert_eq!(contours.len(), 1);
+    assert!(closed.contains(Vec2::new(2.25, 1.0)));
+    assert!(closed.moment().area > 8.9 && closed.moment().area < 9.0 + 1e-4);
+}
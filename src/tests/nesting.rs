@@ -0,0 +1,54 @@
+extern crate std;
+
+use crate::{Aabb, Closed, Region, nesting_tree};
+use glam::Vec2;
+use std::{vec, vec::Vec};
+
+#[test]
+fn levels() {
+    let mut polygons = vec![
+        Aabb::new(Vec2::splat(2.0), Vec2::splat(4.0)).polygon(),
+        Aabb::new(Vec2::ZERO, Vec2::splat(10.0)).polygon(),
+        Aabb::new(Vec2::splat(1.0), Vec2::splat(9.0)).polygon(),
+        Aabb::new(Vec2::new(20.0, 0.0), Vec2::new(21.0, 1.0)).polygon(),
+        Aabb::new(Vec2::new(6.0, 6.0), Vec2::splat(7.0)).polygon(),
+        Aabb::new(Vec2::splat(2.5), Vec2::splat(3.5)).polygon(),
+    ];
+    // Orientation doesn't matter
+    polygons[2].reverse();
+    polygons[0].reverse();
+    let tree = nesting_tree(&polygons);
+    assert_eq!(tree.len(), 6);
+    assert_eq!(tree.roots().collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(tree.children(1).collect::<Vec<_>>(), [2]);
+    assert_eq!(tree.children(2).collect::<Vec<_>>(), [0, 4]);
+    assert_eq!(tree.parent(5), Some(0));
+    assert_eq!(
+        (0..6).map(|i| tree.depth(i)).collect::<Vec<_>>(),
+        [2, 0, 1, 0, 2, 3]
+    );
+    assert!(tree.is_hole(2) && tree.is_hole(5));
+    assert!(!tree.is_hole(0) && !tree.is_hole(3));
+
+    tree.orient(&mut polygons);
+    let region = Region::new(polygons);
+    assert!(region.contains(Vec2::new(0.5, 0.5)));
+    assert!(!region.contains(Vec2::new(1.5, 1.5)));
+    assert!(region.contains(Vec2::new(2.2, 2.2)));
+    assert!(!region.contains(Vec2::new(3.0, 3.0)));
+    assert!(region.contains(Vec2::new(20.5, 0.5)));
+}
+
+#[test]
+fn touching() {
+    // Hole touching the outer ring at its corner, and a ring touching it from outside
+    let polygons = [
+        Aabb::new(Vec2::ZERO, Vec2::splat(4.0)).polygon(),
+        Aabb::new(Vec2::ZERO, Vec2::splat(1.0)).polygon(),
+        Aabb::new(Vec2::new(4.0, 0.0), Vec2::new(5.0, 1.0)).polygon(),
+    ];
+    let tree = nesting_tree(&polygons);
+    assert_eq!(tree.parent(1), Some(0));
+    assert_eq!(tree.parent(2), None);
+    assert!(nesting_tree::<Vec<Vec2>>(&[]).is_empty());
+}
@@ -0,0 +1,50 @@
+extern crate std;
+
+use crate::{Integrable, Polygon, normalize};
+use approx::assert_abs_diff_eq;
+use glam::{Affine2, Vec2};
+use std::vec::Vec;
+
+const TEST_EPS: f32 = 1e-4;
+
+#[test]
+fn canonical_frame() {
+    let poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(6.0, 0.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let (canonical, transform): (Polygon<Vec<Vec2>>, _) = normalize(&poly).unwrap();
+
+    assert_abs_diff_eq!(canonical.centroid(), Vec2::ZERO, epsilon = TEST_EPS);
+    let inertia = canonical.second_moment() / canonical.area();
+    assert_abs_diff_eq!(inertia.x_axis.x + inertia.y_axis.y, 1.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(inertia.x_axis.y, 0.0, epsilon = TEST_EPS);
+    assert!(inertia.x_axis.x > inertia.y_axis.y);
+    assert!(canonical.area() > 0.0);
+
+    for (v, w) in poly.vertices().zip(canonical.vertices()) {
+        assert_abs_diff_eq!(transform.transform_point2(v), w, epsilon = TEST_EPS);
+    }
+}
+
+#[test]
+fn invariance() {
+    let poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(3.0, 0.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let moved = Affine2::from_scale_angle_translation(Vec2::splat(2.5), 1.2, Vec2::new(-4.0, 7.0));
+    let other = Polygon::new(poly.vertices.map(|v| moved.transform_point2(v)));
+
+    let (a, _): (Polygon<Vec<Vec2>>, _) = normalize(&poly).unwrap();
+    let (b, _): (Polygon<Vec<Vec2>>, _) = normalize(&other).unwrap();
+    for (v, w) in a.vertices().zip(b.vertices()) {
+        assert_abs_diff_eq!(v, w, epsilon = TEST_EPS);
+    }
+
+    let flat = Polygon::new([Vec2::ZERO, Vec2::X, Vec2::X * 2.0]);
+    assert!(normalize::<_, Vec<Vec2>>(&flat).is_none());
+}
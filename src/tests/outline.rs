@@ -0,0 +1,104 @@
+extern crate std;
+
+use crate::{ArcVertex, Path, VertexKind, detect_corners, vectorize};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+/// Square outline with `k` points per side and slight noise.
+fn noisy_square(k: usize) -> Vec<Vec2> {
+    let corners = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ];
+    (0..4)
+        .flat_map(|side| {
+            let (a, b) = (corners[side], corners[(side + 1) % 4]);
+            (0..k).map(move |i| {
+                let noise = if i % 2 == 0 { 1e-3 } else { -1e-3 };
+                a.lerp(b, i as f32 / k as f32) + (b - a).perp() * noise
+            })
+        })
+        .collect()
+}
+
+fn corners(points: &[Vec2], closed: bool, threshold: f32, window: usize) -> Vec<usize> {
+    detect_corners(points, closed, threshold, window)
+        .filter(|(_, kind)| *kind == VertexKind::Corner)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[test]
+fn square_corners() {
+    let points = noisy_square(10);
+    assert_eq!(corners(&points, true, PI / 4.0, 3), [0, 10, 20, 30]);
+    assert_eq!(detect_corners(&points, true, PI / 4.0, 3).count(), 40);
+}
+
+#[test]
+fn circle_has_no_corners() {
+    let points: Vec<_> = (0..64)
+        .map(|i| Vec2::from_angle(2.0 * PI * i as f32 / 64.0))
+        .collect();
+    assert!(corners(&points, true, PI / 8.0, 2).is_empty());
+}
+
+#[test]
+fn open_polyline() {
+    let points: Vec<_> = (0..=10)
+        .map(|i| Vec2::new(i as f32, 0.0))
+        .chain((1..=10).map(|i| Vec2::new(10.0, i as f32)))
+        .collect();
+    assert_eq!(corners(&points, false, PI / 4.0, 2), [0, 10, 20]);
+}
+
+#[test]
+fn vectorize_lines_and_arc() {
+    // Line, then a quarter of the circle, then a line again, joined smoothly
+    let points: Vec<_> = (0..100)
+        .map(|i| Vec2::new(0.1 * i as f32, 0.0))
+        .chain((0..50).map(|i| {
+            Vec2::new(10.0, 2.0) + 2.0 * Vec2::from_angle(-PI / 2.0 + PI / 2.0 * i as f32 / 50.0)
+        }))
+        .chain((0..=80).map(|i| Vec2::new(12.0, 2.0 + 0.1 * i as f32)))
+        .collect();
+
+    let path: Path<Vec<ArcVertex>> = vectorize(&points, 1e-3);
+    let vertices: Vec<_> = path.vertices().collect();
+    assert_eq!(vertices.len(), 4);
+    assert_abs_diff_eq!(vertices[0].point, Vec2::new(0.0, 0.0));
+    assert_abs_diff_eq!(vertices[1].point, Vec2::new(10.0, 0.0), epsilon = 0.2);
+    assert_abs_diff_eq!(vertices[2].point, Vec2::new(12.0, 2.0), epsilon = 0.2);
+    assert_abs_diff_eq!(vertices[3].point, Vec2::new(12.0, 10.0));
+    assert_eq!(vertices[0].sagitta, 0.0);
+    assert!(vertices[1].sagitta > 0.0);
+    assert_eq!(vertices[2].sagitta, 0.0);
+    assert_abs_diff_eq!(path.length(), 18.0 + PI, epsilon = 1e-2);
+}
+
+#[test]
+fn vectorize_polyline_corners() {
+    // Zigzag with sharp corners
+    let corners = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(5.0, 5.0),
+        Vec2::new(10.0, 0.0),
+        Vec2::new(15.0, 5.0),
+    ];
+    let points: Vec<_> = corners
+        .windows(2)
+        .flat_map(|w| (0..20).map(move |i| w[0].lerp(w[1], i as f32 / 20.0)))
+        .chain([corners[3]])
+        .collect();
+
+    let path: Path<Vec<ArcVertex>> = vectorize(&points, 1e-3);
+    assert_eq!(
+        path.vertices().map(|v| v.point).collect::<Vec<_>>(),
+        corners
+    );
+    assert!(path.vertices().all(|v| v.sagitta == 0.0));
+}
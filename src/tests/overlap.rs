@@ -0,0 +1,149 @@
+extern crate std;
+
+use crate::{
+    Aabb, CertifyOverlap, Closed, Disk, OverlapCertificate, Overlaps, Polygon,
+    testgen::{Pcg32, convex_polygon},
+};
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn polygon_polygon() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    assert!(square.overlaps(&Aabb::new(Vec2::new(1.0, 1.0), Vec2::splat(3.0)).polygon()));
+    assert!(square.overlaps(&Aabb::new(Vec2::new(0.5, 0.5), Vec2::splat(2.5)).polygon()));
+    assert!(!square.overlaps(&Aabb::new(Vec2::new(3.0, 0.0), Vec2::new(5.0, 2.0)).polygon()));
+    // Touching
+    assert!(square.overlaps(&Aabb::new(Vec2::new(2.0, 2.0), Vec2::splat(4.0)).polygon()));
+
+    // Separated only along the diagonal axis of the triangle
+    let triangle = Polygon::new([
+        Vec2::new(3.0, 1.5),
+        Vec2::new(1.5, 3.0),
+        Vec2::new(3.0, 3.0),
+    ]);
+    assert!(!square.overlaps(&triangle));
+    assert!(!triangle.overlaps(&square));
+
+    // Clockwise polygons
+    let reversed = Polygon::new(
+        Aabb::new(Vec2::new(1.0, 1.0), Vec2::splat(3.0))
+            .polygon()
+            .vertices
+            .map(|v| -v),
+    );
+    assert!(!square.overlaps(&reversed));
+    assert!(
+        Aabb::new(Vec2::new(-2.5, -2.5), Vec2::splat(-0.5))
+            .polygon()
+            .overlaps(&reversed)
+    );
+}
+
+#[test]
+fn polygon_disk() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    assert!(square.overlaps(&Disk::new(Vec2::new(1.0, 1.0), 0.1)));
+    assert!(square.overlaps(&Disk::new(Vec2::new(2.5, 1.0), 1.0)));
+    assert!(!square.overlaps(&Disk::new(Vec2::new(3.5, 1.0), 1.0)));
+    // Near the corner, separated only by the vertex axis
+    assert!(!square.overlaps(&Disk::new(Vec2::new(2.8, 2.8), 1.0)));
+    assert!(Disk::new(Vec2::new(2.6, 2.6), 1.0).overlaps(&square));
+}
+
+#[test]
+fn disk_disk() {
+    let disk = Disk::new(Vec2::new(0.0, 0.0), 1.0);
+    assert!(disk.overlaps(&Disk::new(Vec2::new(1.5, 0.0), 1.0)));
+    assert!(disk.overlaps(&Disk::new(Vec2::new(2.0, 0.0), 1.0)));
+    assert!(!disk.overlaps(&Disk::new(Vec2::new(2.5, 0.0), 1.0)));
+}
+
+/// Check that the certificate is valid for the polygon and the shape given by its distance function.
+fn check(
+    polygon: &Polygon<Vec<Vec2>>,
+    distance: impl Fn(Vec2) -> f32,
+    certificate: OverlapCertificate,
+) {
+    match certificate {
+        OverlapCertificate::Witness(point) => {
+            assert!(
+                polygon.winding_number_2(point) != 0
+                    || polygon.closest_point(point).unwrap().distance(point) < 1e-4
+            );
+            assert!(distance(point) < 1e-4);
+        }
+        OverlapCertificate::Separated(plane) => {
+            assert!(polygon.vertices().all(|v| plane.distance(v) <= 1e-5));
+            assert!(distance(plane.boundary_point() - plane.normal * 1e-5) > 0.0);
+        }
+    }
+}
+
+#[test]
+fn certificates() {
+    let mut rng = Pcg32::new(591);
+    let (mut overlapping, mut separated) = (0, 0);
+    for _ in 0..256 {
+        let mut a: Polygon<Vec<Vec2>> = convex_polygon(&mut rng, 6);
+        let mut b: Polygon<Vec<Vec2>> = convex_polygon(&mut rng, 5);
+        let offset = 2.5 * rng.in_unit_disk();
+        b.vertices.iter_mut().for_each(|v| *v += offset);
+        if rng.range(0.0, 1.0) < 0.5 {
+            a.reverse();
+        }
+        let certificate = a.overlap_certificate(&b).unwrap();
+        assert_eq!(certificate.is_overlap(), a.overlaps(&b));
+        assert_eq!(
+            b.overlap_certificate(&a).unwrap().is_overlap(),
+            b.overlaps(&a)
+        );
+        let b_distance = |p: Vec2| {
+            if b.winding_number_2(p) != 0 {
+                0.0
+            } else {
+                b.closest_point(p).unwrap().distance(p)
+            }
+        };
+        check(&a, b_distance, certificate);
+
+        let disk = Disk::new(offset, rng.range(0.1, 1.0));
+        let certificate = a.overlap_certificate(&disk).unwrap();
+        assert_eq!(certificate.is_overlap(), a.overlaps(&disk));
+        assert_eq!(disk.overlap_certificate(&a).unwrap(), certificate.swap());
+        check(
+            &a,
+            |p| (p.distance(disk.center) - disk.radius).max(0.0),
+            certificate,
+        );
+
+        if certificate.is_overlap() {
+            overlapping += 1;
+        } else {
+            separated += 1;
+        }
+    }
+    assert!(overlapping > 10 && separated > 10);
+
+    let disk = Disk::new(Vec2::ZERO, 1.0);
+    for (other, overlap) in [
+        (Disk::new(Vec2::new(1.5, 0.0), 1.0), true),
+        (Disk::new(Vec2::new(0.2, 0.1), 0.3), true),
+        (Disk::new(Vec2::ZERO, 2.0), true),
+        (Disk::new(Vec2::new(0.0, 2.5), 1.0), false),
+    ] {
+        match disk.overlap_certificate(&other).unwrap() {
+            OverlapCertificate::Witness(point) => {
+                assert!(overlap);
+                assert!(disk.contains(point) && other.contains(point));
+            }
+            OverlapCertificate::Separated(plane) => {
+                assert!(!overlap);
+                assert!(plane.distance(disk.center) <= -disk.radius);
+                assert!(plane.distance(other.center) >= other.radius);
+            }
+        }
+    }
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    assert_eq!(square.overlap_certificate(&Polygon::new([])), None);
+}
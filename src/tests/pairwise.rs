@@ -0,0 +1,123 @@
+extern crate std;
+
+use crate::{
+    Disk, Intersect, LineSegment, Moment, Overlaps, pairwise_intersections, testgen::Pcg32,
+};
+use either::Either;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn segments() {
+    let mut rng = Pcg32::new(548);
+    let segments: Vec<_> = (0..64)
+        .map(|_| {
+            let a = Vec2::new(rng.range(0.0, 10.0), rng.range(0.0, 10.0));
+            let b = a + Vec2::new(rng.range(-1.0, 1.0), rng.range(-1.0, 1.0));
+            LineSegment(a, b)
+        })
+        .collect();
+
+    let mut fast: Vec<(usize, usize, Vec2)> = pairwise_intersections(&segments).collect();
+    fast.sort_by_key(|&(i, j, _)| (i, j));
+
+    let mut naive = Vec::new();
+    for (i, a) in segments.iter().enumerate() {
+        for (j, b) in segments.iter().enumerate().skip(i + 1) {
+            if let Some(p) = a.intersect(b) {
+                naive.push((i, j, p));
+            }
+        }
+    }
+    assert!(!naive.is_empty());
+    assert_eq!(fast, naive);
+}
+
+#[test]
+fn disks() {
+    let disks = [
+        Disk::new(Vec2::new(0.0, 0.0), 1.0),
+        Disk::new(Vec2::new(5.0, 0.0), 1.0),
+        Disk::new(Vec2::new(1.5, 0.0), 1.0),
+        Disk::new(Vec2::new(0.0, 0.5), 0.25),
+    ];
+    let mut pairs: Vec<_> = pairwise_intersections(&disks)
+        .map(|(i, j, shape): (_, _, Either<Moment, Disk>)| (i, j, shape.is_right()))
+        .collect();
+    pairs.sort_by_key(|&(i, j, _)| (i, j));
+    // Small disk is inside the first one and doesn't touch the third one
+    assert_eq!(pairs, [(0, 2, false), (0, 3, true)]);
+}
+
+#[test]
+fn components_touching() {
+    let disks = [
+        Disk::new(Vec2::new(0.0, 0.0), 1.0),
+        Disk::new(Vec2::new(4.0, 0.0), 1.0),
+        Disk::new(Vec2::new(1.5, 0.0), 1.0),
+        Disk::new(Vec2::new(0.0, 0.5), 0.25),
+        // Touches the second and the third disks
+        Disk::new(Vec2::new(3.0, 0.0), 0.5),
+    ];
+    assert_eq!(crate::components(&disks), [[0, 1, 2, 3, 4]]);
+    assert_eq!(
+        crate::components(&disks[..4]),
+        [std::vec![0, 2, 3], std::vec![1]]
+    );
+}
+
+#[test]
+fn components() {
+    let mut rng = Pcg32::new(560);
+    let disks: Vec<Disk> = (0..100)
+        .map(|_| {
+            Disk::new(
+                Vec2::new(rng.range(0.0, 20.0), rng.range(0.0, 20.0)),
+                rng.range(0.1, 1.0),
+            )
+        })
+        .collect();
+    let groups = crate::components(&disks);
+    assert!(groups.len() > 1 && groups.len() < disks.len());
+
+    let mut labels = std::vec![usize::MAX; disks.len()];
+    for (k, group) in groups.iter().enumerate() {
+        assert!(group.is_sorted());
+        for &i in group {
+            assert_eq!(labels[i], usize::MAX);
+            labels[i] = k;
+        }
+    }
+    assert!(groups.is_sorted_by_key(|group| group[0]));
+    // Overlapping disks are in the same group
+    for (i, a) in disks.iter().enumerate() {
+        for (j, b) in disks.iter().enumerate() {
+            if a.overlaps(b) {
+                assert_eq!(labels[i], labels[j]);
+            }
+        }
+    }
+    // Each group is connected
+    for group in &groups {
+        let mut reached = std::vec![group[0]];
+        let mut k = 0;
+        while k < reached.len() {
+            let i = reached[k];
+            for &j in group {
+                if !reached.contains(&j) && disks[i].overlaps(&disks[j]) {
+                    reached.push(j);
+                }
+            }
+            k += 1;
+        }
+        assert_eq!(reached.len(), group.len());
+    }
+
+    // Touching shapes are connected
+    let chain = [
+        Disk::new(Vec2::ZERO, 1.0),
+        Disk::new(Vec2::new(4.0, 0.0), 1.0),
+        Disk::new(Vec2::new(2.0, 0.0), 1.0),
+    ];
+    assert_eq!(crate::components(&chain), [std::vec![0, 1, 2]]);
+}
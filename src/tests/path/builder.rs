@@ -0,0 +1,111 @@
+use crate::{Arc, ArcVertex, Integrable, PathBuilder, Polygon};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+
+#[test]
+fn lines() {
+    let polygon = PathBuilder::<Vec2>::new()
+        .move_to(Vec2::new(0.0, 0.0))
+        .line_to(Vec2::new(2.0, 0.0))
+        .line_to(Vec2::new(2.0, 2.0))
+        .line_to(Vec2::new(0.0, 2.0))
+        .line_to(Vec2::new(0.0, 0.0))
+        .close();
+    assert_eq!(
+        polygon,
+        Polygon::new([
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ])
+    );
+
+    let polyline = PathBuilder::<Vec2>::new()
+        .move_to(Vec2::new(5.0, 5.0))
+        .move_to(Vec2::new(0.0, 0.0))
+        .line_to(Vec2::new(3.0, 4.0))
+        .line_to(Vec2::new(3.0, 0.0))
+        .finish();
+    assert_eq!(polyline.len(), 3);
+    assert_abs_diff_eq!(polyline.length(), 9.0);
+}
+
+#[test]
+fn arcs() {
+    // Disk made of two half circles
+    let disk = PathBuilder::<ArcVertex>::new()
+        .move_to(Vec2::new(1.0, 0.0))
+        .arc_to(Vec2::new(0.0, 1.0), Vec2::new(-1.0, 0.0))
+        .arc_around(Vec2::ZERO, Vec2::new(2.0, 0.0), true)
+        .close();
+    assert_eq!(disk.len(), 2);
+    assert_abs_diff_eq!(disk.area(), PI, epsilon = 1e-5);
+
+    let path = PathBuilder::<ArcVertex>::new()
+        .move_to(Vec2::new(-1.0, 0.0))
+        .line_to(Vec2::new(1.0, 0.0))
+        .arc_around(Vec2::new(1.0, 1.0), Vec2::new(2.0, 1.0), true)
+        .finish();
+    assert_abs_diff_eq!(path.length(), 2.0 + 0.5 * PI, epsilon = 1e-5);
+}
+
+#[test]
+fn tangent_arcs() {
+    // Quarter of the unit circle continuing the line
+    let path = PathBuilder::<ArcVertex>::new()
+        .move_to(Vec2::new(0.0, 0.0))
+        .line_to(Vec2::new(1.0, 0.0))
+        .tangent_arc_to(Vec2::new(2.0, 1.0))
+        .finish();
+    let arc = path.edges().nth(1).unwrap();
+    assert_abs_diff_eq!(arc.center(), Vec2::new(1.0, 1.0), epsilon = 1e-5);
+    assert_abs_diff_eq!(arc.sweep(), 0.5 * PI, epsilon = 1e-5);
+
+    // Stadium, the second arc continues the line following the first one
+    let stadium = PathBuilder::<ArcVertex>::new()
+        .move_to(Vec2::new(0.0, 0.0))
+        .line_to(Vec2::new(2.0, 0.0))
+        .tangent_arc_to(Vec2::new(2.0, 2.0))
+        .line_to(Vec2::new(0.0, 2.0))
+        .tangent_arc_to(Vec2::new(0.0, 0.0))
+        .close();
+    assert_eq!(stadium.len(), 4);
+    assert_abs_diff_eq!(stadium.area(), 4.0 + PI, epsilon = 1e-5);
+
+    // Arc following an arc goes on along the same circle
+    let path = PathBuilder::<ArcVertex>::new()
+        .arc_to(Vec2::ZERO, Vec2::new(1.0, 0.0))
+        .arc_to(Vec2::new(0.0, 1.0), Vec2::new(-1.0, 0.0))
+        .tangent_arc_to(Vec2::new(0.0, -1.0))
+        .finish();
+    let arc: Arc = path.edges().last().unwrap();
+    assert_abs_diff_eq!(arc.center(), Vec2::ZERO, epsilon = 1e-5);
+    assert_abs_diff_eq!(arc.sweep(), 0.5 * PI, epsilon = 1e-5);
+}
+
+#[test]
+fn tangent_arc_behind() {
+    // Point right behind the line, the path turns back by the half circle
+    let path = PathBuilder::<ArcVertex>::new()
+        .move_to(Vec2::new(0.0, 0.0))
+        .line_to(Vec2::new(2.0, 0.0))
+        .tangent_arc_to(Vec2::new(0.0, 0.0))
+        .finish();
+    assert_eq!(path.len(), 4);
+    assert!(
+        path.vertices()
+            .all(|v| v.point.is_finite() && v.sagitta.is_finite())
+    );
+    assert_abs_diff_eq!(path.length(), 2.0 + PI, epsilon = 1e-5);
+    assert_abs_diff_eq!(
+        path.vertices().last().unwrap().point,
+        Vec2::ZERO,
+        epsilon = 1e-5
+    );
+    for arc in path.edges().skip(1) {
+        assert_abs_diff_eq!(arc.center(), Vec2::new(1.0, 0.0), epsilon = 1e-5);
+        assert_abs_diff_eq!(arc.sweep().abs(), 0.5 * PI, epsilon = 1e-5);
+    }
+}
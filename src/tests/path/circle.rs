@@ -0,0 +1,59 @@
+extern crate std;
+
+use crate::{ArcVertex, Path, Polyline};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn edges() {
+    let path = Path::new([
+        ArcVertex {
+            point: Vec2::new(0.0, 0.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 0.0),
+            sagitta: 1.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 2.0),
+            sagitta: 0.0,
+        },
+    ]);
+    assert_eq!(path.len(), 3);
+    assert_eq!(path.edges().count(), 2);
+    assert_abs_diff_eq!(path.length(), 2.0 + PI, epsilon = 1e-6);
+
+    let single = Path::new([ArcVertex {
+        point: Vec2::ZERO,
+        sagitta: 1.0,
+    }]);
+    assert_eq!(single.edges().count(), 0);
+    assert_eq!(single.length(), 0.0);
+}
+
+#[test]
+fn flatten() {
+    let path = Path::new([
+        ArcVertex {
+            point: Vec2::new(0.0, 0.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 0.0),
+            sagitta: 1.0,
+        },
+        ArcVertex {
+            point: Vec2::new(4.0, 0.0),
+            sagitta: 0.0,
+        },
+    ]);
+    let polyline: Polyline<Vec<Vec2>> = path.flatten(1e-3);
+    assert_eq!(polyline.vertices().next(), Some(Vec2::new(0.0, 0.0)));
+    assert_eq!(polyline.vertices().nth(1), Some(Vec2::new(2.0, 0.0)));
+    assert_eq!(polyline.vertices().last(), Some(Vec2::new(4.0, 0.0)));
+    assert!(polyline.length() < path.length());
+    assert!(polyline.length() > path.length() - 1e-2);
+}
@@ -0,0 +1,107 @@
+extern crate std;
+
+use crate::{Line, LineSegment, Polyline};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+use std::vec::Vec;
+
+fn zigzag() -> Polyline<[Vec2; 4]> {
+    Polyline::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(3.0, 4.0),
+        Vec2::new(6.0, 0.0),
+        Vec2::new(6.0, 2.0),
+    ])
+}
+
+#[test]
+fn length() {
+    let path = zigzag();
+    assert_abs_diff_eq!(path.length(), 12.0);
+    assert_eq!(path.point_at_length(-1.0), Some(Vec2::ZERO));
+    assert_abs_diff_eq!(path.point_at_length(2.5).unwrap(), Vec2::new(1.5, 2.0));
+    assert_abs_diff_eq!(path.point_at_length(7.5).unwrap(), Vec2::new(4.5, 2.0));
+    assert_eq!(path.point_at_length(20.0), Some(Vec2::new(6.0, 2.0)));
+    assert_eq!(
+        Polyline::new([Vec2::ONE]).point_at_length(1.0),
+        Some(Vec2::ONE)
+    );
+    assert_eq!(Polyline::<[Vec2; 0]>::new([]).point_at_length(1.0), None);
+
+    assert_abs_diff_eq!(
+        path.closest_point(Vec2::new(3.0, 0.0)).unwrap(),
+        Vec2::new(1.08, 1.44),
+        epsilon = 1e-5
+    );
+    assert_eq!(
+        path.closest_point(Vec2::new(7.0, 3.0)),
+        Some(Vec2::new(6.0, 2.0))
+    );
+}
+
+#[test]
+fn intersection() {
+    let path = zigzag();
+    let points: Vec<Vec2> = path
+        .intersect_line(&Line(Vec2::new(0.0, 2.0), Vec2::new(1.0, 2.0)))
+        .collect();
+    assert_eq!(points.len(), 3);
+    assert_abs_diff_eq!(points[0], Vec2::new(1.5, 2.0), epsilon = 1e-5);
+    assert_abs_diff_eq!(points[1], Vec2::new(4.5, 2.0), epsilon = 1e-5);
+    assert_abs_diff_eq!(points[2], Vec2::new(6.0, 2.0), epsilon = 1e-5);
+
+    // Vertex on the line is crossed once
+    let points: Vec<Vec2> = path
+        .intersect_line(&Line(Vec2::new(0.0, 4.0), Vec2::new(1.0, 4.0)))
+        .collect();
+    assert_eq!(points.len(), 1);
+
+    let segment = LineSegment(Vec2::new(0.0, 1.0), Vec2::new(5.5, 1.0));
+    assert_eq!(path.intersect_segment(&segment).count(), 2);
+}
+
+#[test]
+fn resample() {
+    let path = zigzag();
+    let points: Vec<Vec2> = path.resample(1.0).collect();
+    assert_eq!(points.len(), 13);
+    assert_eq!(points[0], Vec2::ZERO);
+    assert_eq!(points[12], Vec2::new(6.0, 2.0));
+    assert_abs_diff_eq!(points[5], Vec2::new(3.0, 4.0), epsilon = 1e-5);
+    assert_abs_diff_eq!(points[11], Vec2::new(6.0, 1.0), epsilon = 1e-5);
+    // Spacing is shrunk to divide the length evenly
+    assert_eq!(path.resample(5.0).count(), 4);
+    assert_eq!(path.resample(0.0).count(), 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn clip() {
+    use crate::{Disk, Polygon};
+
+    let path = zigzag();
+    let square = Polygon::new([
+        Vec2::new(1.0, -1.0),
+        Vec2::new(5.0, -1.0),
+        Vec2::new(5.0, 3.0),
+        Vec2::new(1.0, 3.0),
+    ]);
+    let parts = path.clip(&square);
+    assert_eq!(parts.len(), 2);
+    assert_abs_diff_eq!(
+        parts[0].vertices[0],
+        Vec2::new(1.0, 4.0 / 3.0),
+        epsilon = 1e-5
+    );
+    assert_abs_diff_eq!(parts[0].vertices[1], Vec2::new(2.25, 3.0), epsilon = 1e-5);
+    assert_abs_diff_eq!(
+        parts[1].vertices[1],
+        Vec2::new(5.0, 4.0 / 3.0),
+        epsilon = 1e-5
+    );
+
+    let parts = path.clip(&Disk::new(Vec2::new(3.0, 4.0), 2.5));
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].len(), 3);
+    assert_abs_diff_eq!(parts[0].length(), 5.0, epsilon = 1e-4);
+}
@@ -0,0 +1,4 @@
+#[cfg(feature = "alloc")]
+mod builder;
+mod circle;
+mod line;
@@ -1,10 +1,18 @@
-use crate::{Closed, HalfPlane, Line};
+extern crate std;
+
+use crate::{
+    Aabb, ArcPolygon, ArcVertex, Closed, ConvexRegion, Disk, HalfPlane, Integrable, IntersectTo,
+    Line, Polygon,
+};
 use approx::assert_abs_diff_eq;
 use core::f32::consts::PI;
 use glam::Vec2;
+use std::vec::Vec;
 
 const TEST_EPS: f32 = 1e-6;
 
+type Clip = ArcPolygon<Vec<ArcVertex>>;
+
 #[test]
 fn is_inside() {
     let plane = HalfPlane::from_edge(Line(Vec2::new(0.0, 1.0), Vec2::new(1.0, 0.0)));
@@ -128,3 +136,113 @@ fn edge() {
     // Check that second point is also on boundary
     assert_abs_diff_eq!(plane.distance(line.1), 0.0, epsilon = TEST_EPS);
 }
+
+#[test]
+fn offset() {
+    let plane = HalfPlane::from_normal(Vec2::new(1.0, 0.0), Vec2::X);
+    let point = Vec2::new(1.5, 7.0);
+    assert!(!plane.contains(point));
+    assert!(plane.offset(1.0).contains(point));
+    assert!(!plane.offset(-1.0).contains(Vec2::new(0.5, 0.0)));
+    assert_abs_diff_eq!(plane.offset(2.0).distance(point), -1.5, epsilon = TEST_EPS);
+
+    let complement = plane.complement();
+    assert!(complement.contains(point));
+    assert_abs_diff_eq!(
+        complement.distance(point),
+        -plane.distance(point),
+        epsilon = TEST_EPS
+    );
+}
+
+#[test]
+fn clip_with_margin() {
+    let plane = HalfPlane::from_normal(Vec2::new(2.0, 0.0), Vec2::X);
+
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(0.0, 4.0),
+    ]);
+    let (core, margin): (Option<Polygon<Vec<Vec2>>>, _) = plane.clip_with_margin(&square, 0.5);
+    assert_abs_diff_eq!(core.unwrap().area(), 6.0, epsilon = TEST_EPS);
+    let margin = margin.unwrap();
+    assert_abs_diff_eq!(margin.area(), 4.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(margin.centroid(), Vec2::new(2.0, 2.0), epsilon = TEST_EPS);
+
+    // Shape lying within the band has no core
+    let (core, margin): (Option<Polygon<Vec<Vec2>>>, _) =
+        plane.offset(-1.5).clip_with_margin(&square, 2.0);
+    assert!(core.is_none());
+    assert_abs_diff_eq!(margin.unwrap().area(), 10.0, epsilon = TEST_EPS);
+
+    // Disk is clipped to the arc polygons, their total area is the area of the dilated clip
+    let disk = Disk::new(Vec2::new(2.0, 0.0), 1.0);
+    let (core, margin): (Option<Clip>, Option<Clip>) = plane.clip_with_margin(&disk, 0.5);
+    let (core, margin) = (core.unwrap(), margin.unwrap());
+    assert!(core.contains(Vec2::new(1.0, 0.0)));
+    assert!(margin.contains(Vec2::new(2.4, 0.0)));
+    assert!(!margin.contains(Vec2::new(1.4, 0.0)));
+    let dilated: Clip = plane.offset(0.5).intersect_to(&disk).unwrap();
+    assert_abs_diff_eq!(
+        core.area() + margin.area(),
+        dilated.area(),
+        epsilon = TEST_EPS
+    );
+}
+
+#[test]
+fn intersect_half_planes() {
+    let square = [
+        HalfPlane::from_normal(Vec2::new(1.0, 0.0), Vec2::X),
+        HalfPlane::from_normal(Vec2::new(0.0, 1.0), Vec2::Y),
+        HalfPlane::from_normal(Vec2::new(-1.0, 0.0), -Vec2::X),
+        HalfPlane::from_normal(Vec2::new(0.0, -1.0), -Vec2::Y),
+        // Redundant one
+        HalfPlane::from_normal(Vec2::new(2.0, 2.0), Vec2::ONE.normalize()),
+    ];
+    match crate::intersect_half_planes::<_, Vec<Vec2>>(square, None) {
+        ConvexRegion::Polygon(polygon) => {
+            assert_abs_diff_eq!(polygon.signed_area(), 4.0, epsilon = TEST_EPS);
+            assert_abs_diff_eq!(polygon.centroid(), Vec2::ZERO, epsilon = TEST_EPS);
+        }
+        other => panic!("Unexpected {other:?}"),
+    }
+
+    // Corner of the plane is unbounded, unless clipped
+    let corner = &square[..2];
+    assert_eq!(
+        crate::intersect_half_planes::<_, Vec<Vec2>>(corner.iter().copied(), None),
+        ConvexRegion::Unbounded
+    );
+    let bounds = Aabb::new(Vec2::splat(-3.0), Vec2::splat(3.0));
+    match crate::intersect_half_planes::<_, Vec<Vec2>>(corner.iter().copied(), Some(bounds)) {
+        ConvexRegion::Polygon(polygon) => {
+            assert_abs_diff_eq!(polygon.area(), 16.0, epsilon = TEST_EPS)
+        }
+        other => panic!("Unexpected {other:?}"),
+    }
+
+    // Strip is unbounded too
+    let strip = [square[0], square[2]];
+    assert_eq!(
+        crate::intersect_half_planes::<_, Vec<Vec2>>(strip, None),
+        ConvexRegion::Unbounded
+    );
+    assert_eq!(
+        crate::intersect_half_planes::<_, Vec<Vec2>>([], None),
+        ConvexRegion::Unbounded
+    );
+
+    // Disjoint half-planes
+    let disjoint = [square[0].offset(-3.0), square[2]];
+    assert_eq!(
+        crate::intersect_half_planes::<_, Vec<Vec2>>(disjoint, None),
+        ConvexRegion::Empty
+    );
+    assert_eq!(
+        crate::intersect_half_planes::<_, Vec<Vec2>>(square, Some(bounds.inflate(-5.0))),
+        ConvexRegion::Empty
+    );
+}
@@ -1,6 +1,9 @@
 extern crate std;
 
-use crate::{ArcPolygon, ArcVertex, Circle, Integrable, IntersectTo, GenericPolygon};
+use crate::{
+    ArcPolygon, ArcVertex, BulgeError, Circle, Closed, Disk, GenericPolygon, HalfPlane, Integrable,
+    Intersect, IntersectTo,
+};
 use approx::assert_abs_diff_eq;
 use core::f32::consts::PI;
 use glam::Vec2;
@@ -145,3 +148,164 @@ fn intersect_circle_polygon_stripe() {
         epsilon = TEST_EPS
     );
 }
+
+#[test]
+fn arc_polygon_intersect_plane() {
+    let disk = Circle {
+        center: Vec2::new(1.0, 2.0),
+        radius: 1.5,
+    }
+    .fill();
+    let poly = disk.polygon::<3>();
+
+    // Half of the disk
+    let plane = HalfPlane::from_normal(disk.center, Vec2::new(1.0, 1.0).normalize());
+    let half: ArcPolygon<Vec<ArcVertex>> = poly.intersect_to(&plane).unwrap();
+    assert_abs_diff_eq!(half.area(), disk.area() / 2.0, epsilon = 1e-5);
+    assert!(half.contains(disk.center - Vec2::new(0.5, 0.5)));
+    assert!(!half.contains(disk.center + Vec2::new(0.5, 0.5)));
+
+    // Disk segment cut out from a single edge
+    let plane = HalfPlane::from_normal(Vec2::new(1.0 + 1.4, 0.0), Vec2::new(-1.0, 0.0));
+    let segment: ArcPolygon<Vec<ArcVertex>> = poly.intersect_to(&plane).unwrap();
+    let reference = disk.intersect(&plane).unwrap().unwrap_left();
+    assert_eq!(segment.len(), 3);
+    assert_abs_diff_eq!(segment.moment(), reference.moment(), epsilon = 1e-5);
+
+    // Outside
+    let plane = HalfPlane::from_normal(Vec2::new(3.0, 0.0), Vec2::new(-1.0, 0.0));
+    assert!(IntersectTo::<_, ArcPolygon<Vec<ArcVertex>>>::intersect_to(&poly, &plane).is_none());
+}
+
+#[test]
+fn arc_polygon_intersect_disk() {
+    let a = Disk::new(Vec2::new(0.0, 0.0), 2.0);
+    let b = Disk::new(Vec2::new(3.0, 0.0), 2.0);
+
+    let lens: ArcPolygon<Vec<ArcVertex>> = a.polygon::<4>().intersect_to(&b).unwrap();
+    assert_abs_diff_eq!(lens.moment(), a.lens(&b).unwrap().moment(), epsilon = 1e-5);
+
+    // Disk inside the polygon
+    let small = Disk::new(Vec2::new(0.5, 0.0), 1.0);
+    let inner: ArcPolygon<Vec<ArcVertex>> = a.polygon::<2>().intersect_to(&small).unwrap();
+    assert_abs_diff_eq!(inner.moment(), small.moment(), epsilon = 1e-5);
+    // Polygon inside the disk
+    let inner: ArcPolygon<Vec<ArcVertex>> = small.polygon::<2>().intersect_to(&a).unwrap();
+    assert_abs_diff_eq!(inner.moment(), small.moment(), epsilon = 1e-5);
+
+    // Concave edges are clipped as well
+    let crescent: ArcPolygon<Vec<ArcVertex>> = a.crescent(&b).unwrap();
+    let c = Disk::new(Vec2::new(0.0, 2.0), 1.0);
+    let part: ArcPolygon<Vec<ArcVertex>> = crescent.intersect_to(&c).unwrap();
+    assert!(part.contains(Vec2::new(0.0, 1.5)));
+    assert!(!part.contains(Vec2::new(0.0, 2.5)));
+    assert!(part.area() < c.area() / 2.0);
+}
+
+#[test]
+fn from_bulges() {
+    let disk = Disk::new(Vec2::new(1.0, 2.0), 1.5);
+    let poly = disk.polygon::<3>();
+    let bulges: Vec<_> = poly
+        .edges()
+        .map(|arc| (arc.points.0, arc.bulge()))
+        .collect();
+
+    let closed: ArcPolygon<Vec<ArcVertex>> = ArcPolygon::from_bulges(bulges.clone(), true).unwrap();
+    assert_abs_diff_eq!(closed.moment(), disk.moment(), epsilon = TEST_EPS);
+    for (a, b) in closed.vertices().zip(poly.vertices()) {
+        assert_abs_diff_eq!(a.sagitta, b.sagitta, epsilon = TEST_EPS);
+    }
+
+    // Open polyline with duplicated first vertex
+    let first = poly.vertices[0].point;
+    let open: ArcPolygon<Vec<ArcVertex>> =
+        ArcPolygon::from_bulges(bulges.iter().copied().chain([(first, 0.0)]), false).unwrap();
+    assert_eq!(open.len(), 3);
+    assert_abs_diff_eq!(open.area(), disk.area(), epsilon = TEST_EPS);
+
+    // Coincident vertices are merged
+    let doubled: ArcPolygon<Vec<ArcVertex>> = ArcPolygon::from_bulges(
+        bulges
+            .iter()
+            .flat_map(|&(point, bulge)| [(point, 1.0), (point, bulge)]),
+        true,
+    )
+    .unwrap();
+    assert_eq!(doubled.len(), 3);
+    assert!(doubled.vertices().all(|v| v.sagitta.is_finite()));
+    assert_abs_diff_eq!(doubled.moment(), disk.moment(), epsilon = TEST_EPS);
+
+    assert_eq!(
+        ArcPolygon::<Vec<ArcVertex>>::from_bulges(bulges, false),
+        Err(BulgeError::NotClosed)
+    );
+    assert_eq!(
+        ArcPolygon::<Vec<ArcVertex>>::from_bulges([(first, 1.0), (first, 1.0)], true),
+        Err(BulgeError::TooFewVertices)
+    );
+    assert_eq!(
+        ArcPolygon::<Vec<ArcVertex>>::from_bulges([(first, f32::NAN), (Vec2::ZERO, 0.0)], true),
+        Err(BulgeError::NonFinite)
+    );
+}
+
+#[test]
+fn orientation() {
+    let disk = Disk::new(Vec2::new(1.0, 2.0), 1.5);
+    let mut crescent: ArcPolygon<Vec<ArcVertex>> =
+        disk.crescent(&Disk::new(Vec2::new(2.0, 2.0), 1.0)).unwrap();
+    let area = crescent.signed_area();
+    assert!(crescent.is_ccw());
+    assert_abs_diff_eq!(area, crescent.area(), epsilon = TEST_EPS);
+
+    crescent.reverse();
+    assert!(!crescent.is_ccw());
+    assert_abs_diff_eq!(crescent.signed_area(), -area, epsilon = TEST_EPS);
+    // Interior is the same, only the winding sign changes
+    assert!(crescent.winding_number_2(Vec2::new(0.0, 2.0)) < 0);
+    assert_eq!(crescent.winding_number_2(Vec2::new(2.0, 2.0)), 0);
+
+    // Frame of two-vertex polygon is degenerate
+    let mut two = disk.polygon::<2>();
+    assert!(two.is_ccw());
+    two.reverse();
+    assert_abs_diff_eq!(two.signed_area(), -disk.area(), epsilon = TEST_EPS);
+}
+
+#[test]
+fn flatten() {
+    let disk = Disk::new(Vec2::new(-1.0, 3.0), 2.0);
+    let polygon = disk.polygon::<3>();
+    let tolerance = 1e-3;
+    let flat = polygon.flatten::<Vec<Vec2>>(tolerance);
+    assert_eq!(
+        flat.len(),
+        3 * polygon.edges().next().unwrap().segment_count(tolerance)
+    );
+    // Original vertices are kept
+    for vertex in polygon.vertices() {
+        assert!(flat.vertices().any(|p| p == vertex.point));
+    }
+    for edge in flat.edges() {
+        assert_abs_diff_eq!(edge.0.distance(disk.center), 2.0, epsilon = 1e-5);
+        assert!(disk.radius - edge.center().distance(disk.center) <= tolerance + 1e-5);
+    }
+    assert!(flat.area() < disk.area());
+    assert!(flat.area() > disk.area() - 2.0 * PI * disk.radius * tolerance);
+
+    // Half disk, the straight edge is not split
+    let mixed = ArcPolygon::new([
+        ArcVertex {
+            point: Vec2::new(0.0, 0.0),
+            sagitta: 0.0,
+        },
+        ArcVertex {
+            point: Vec2::new(2.0, 0.0),
+            sagitta: 1.0,
+        },
+    ]);
+    let flat = mixed.flatten::<Vec<Vec2>>(0.1);
+    assert_eq!(flat.vertices().next(), Some(Vec2::ZERO));
+    assert_eq!(flat.vertices().nth(1), Some(Vec2::new(2.0, 0.0)));
+}
@@ -0,0 +1,83 @@
+extern crate std;
+
+use crate::{IntegerPolygon, Polygon};
+use glam::{IVec2, Vec2};
+use std::vec::Vec;
+
+#[test]
+fn quantize() {
+    let poly = Polygon::new([
+        Vec2::new(0.02, -0.01),
+        Vec2::new(0.99, 0.04),
+        // Collapses into the previous vertex
+        Vec2::new(1.04, -0.03),
+        Vec2::new(1.0, 1.26),
+        Vec2::new(-0.5, 0.97),
+    ]);
+    let grid: IntegerPolygon<Vec<IVec2>> = poly.quantize(0.5).unwrap();
+    assert_eq!(
+        grid.vertices,
+        [
+            IVec2::new(2, 0),
+            IVec2::new(2, 3),
+            IVec2::new(-1, 2),
+            IVec2::new(0, 0),
+        ]
+    );
+    assert_eq!(grid.double_area(), 13);
+
+    let back: Polygon<Vec<Vec2>> = grid.dequantize(0.5);
+    assert_eq!(back.vertices[0], Vec2::new(1.0, 0.0));
+    assert_eq!(back.vertices[2], Vec2::new(-0.5, 1.0));
+}
+
+#[test]
+fn collapse() {
+    let tiny = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.1, 0.0),
+        Vec2::new(0.1, 0.1),
+        Vec2::new(1.0, 0.1),
+    ]);
+    assert!(tiny.quantize::<Vec<IVec2>>(1.0).is_none());
+    assert_eq!(tiny.quantize::<Vec<IVec2>>(0.1).unwrap().len(), 4);
+}
+
+#[test]
+fn spike() {
+    // Thin spike on the top edge snaps to a zero-area one
+    let poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(2.1, 4.0),
+        Vec2::new(2.0, 8.0),
+        Vec2::new(1.9, 4.0),
+        Vec2::new(0.0, 4.0),
+    ]);
+    let grid: IntegerPolygon<Vec<IVec2>> = poly.quantize(1.0).unwrap();
+    assert_eq!(
+        grid.vertices,
+        [
+            IVec2::new(4, 0),
+            IVec2::new(4, 4),
+            IVec2::new(0, 4),
+            IVec2::new(0, 0),
+        ]
+    );
+
+    // Spike and collinear vertices at the start of the contour
+    let poly = Polygon::new([
+        Vec2::new(2.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(0.0, 4.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.1, 0.0),
+        Vec2::new(1.0, -5.0),
+        Vec2::new(0.9, 0.0),
+    ]);
+    let grid: IntegerPolygon<Vec<IVec2>> = poly.quantize(1.0).unwrap();
+    assert_eq!(grid.len(), 4);
+    assert_eq!(grid.double_area(), 32);
+}
@@ -1,6 +1,11 @@
 extern crate std;
 
-use crate::{Closed, HalfPlane, Integrable, IntersectTo, Moment, Polygon};
+use crate::{
+    ArcPolygon, ArcVertex, Circle, Closed, HalfPlane, Integrable, IntersectTo, Line, Moment,
+    Polygon,
+};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
 use glam::Vec2;
 use std::vec::Vec;
 
@@ -55,6 +60,29 @@ fn is_inside() {
     assert!(concave.contains(Vec2::new(1.5, 0.5)));
 }
 
+#[test]
+fn shared_edge() {
+    // Point lies on the diagonal within the rounding error, so that the sign of the cross product
+    // computed from different ends of the diagonal differs
+    let (a, c) = (
+        Vec2::new(5.774_467, -8.122_808),
+        Vec2::new(-1.010_178_7, 3.031_859_4),
+    );
+    let point = Vec2::new(-0.817_851_1, 2.715_652_7);
+    let (b, d) = (Vec2::new(8.0, 5.0), Vec2::new(-6.0, -5.0));
+    let quad = Polygon::new([a, b, c, d]);
+    assert_eq!(quad.winding_number_2(point), 1);
+    // The diagonal is passed in opposite directions by the halves, so its crossings cancel out exactly
+    let halves = [Polygon::new([a, b, c]), Polygon::new([c, d, a])];
+    assert_eq!(
+        halves
+            .iter()
+            .map(|half| half.winding_number_2(point))
+            .sum::<i32>(),
+        1
+    );
+}
+
 #[test]
 fn is_convex() {
     // Convex polygon (triangle)
@@ -148,3 +176,242 @@ fn intersect_polygon() {
         ])
     )
 }
+
+#[test]
+fn orientation() {
+    let mut poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    assert_eq!(poly.signed_area(), 2.0);
+    assert!(poly.is_ccw());
+
+    poly.reverse();
+    assert_eq!(poly.vertices[0], Vec2::new(0.0, 1.0));
+    assert_eq!(poly.signed_area(), -2.0);
+    assert!(!poly.is_ccw());
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn simd_winding_number() {
+    use crate::{polygon::line::scalar_winding_number_2, testgen::Pcg32};
+
+    let mut rng = Pcg32::new(554);
+    for count in [3, 4, 7, 64, 101] {
+        let star: Polygon<Vec<Vec2>> = crate::testgen::star_polygon(&mut rng, count, 0.3);
+        for _ in 0..64 {
+            let point = 1.2 * rng.in_unit_disk();
+            assert_eq!(
+                star.winding_number_2(point),
+                scalar_winding_number_2(star.edges(), point)
+            );
+        }
+        // Vertices lie exactly on the sweep line
+        for point in star.vertices() {
+            assert_eq!(
+                star.winding_number_2(point),
+                scalar_winding_number_2(star.edges(), point)
+            );
+        }
+    }
+}
+
+#[test]
+fn regular() {
+    let hexagon = Polygon::<Vec<Vec2>>::regular(Vec2::new(1.0, 2.0), 2.0, 6, 0.5 * PI);
+    assert_eq!(hexagon.len(), 6);
+    assert_abs_diff_eq!(hexagon.vertices[0], Vec2::new(1.0, 4.0), epsilon = 1e-6);
+    assert!(hexagon.is_ccw());
+    assert!(hexagon.is_convex());
+    assert_abs_diff_eq!(hexagon.area(), 1.5 * 3.0f32.sqrt() * 4.0, epsilon = 1e-5);
+    assert_abs_diff_eq!(hexagon.centroid(), Vec2::new(1.0, 2.0), epsilon = 1e-5);
+
+    let square = Polygon::<Vec<Vec2>>::from_circle(
+        Circle {
+            center: Vec2::ZERO,
+            radius: 2.0f32.sqrt(),
+        },
+        4,
+    );
+    assert_abs_diff_eq!(square.vertices[0], Vec2::new(2.0f32.sqrt(), 0.0));
+    assert_abs_diff_eq!(square.area(), 4.0, epsilon = 1e-5);
+}
+
+#[test]
+fn fillet() {
+    let rectangle = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let rounded: ArcPolygon<Vec<ArcVertex>> = rectangle.fillet(0.5);
+    assert_eq!(rounded.len(), 8);
+    assert_abs_diff_eq!(rounded.area(), 8.0 - (4.0 - PI) * 0.25, epsilon = 1e-5);
+    assert!(rounded.contains(Vec2::new(0.5, 0.5)));
+    assert!(!rounded.contains(Vec2::new(0.1, 0.1)));
+    // Arcs are tangent to the edges
+    let arc = rounded.edges().find(|arc| arc.sagitta != 0.0).unwrap();
+    assert_abs_diff_eq!(arc.radius(), 0.5, epsilon = 1e-5);
+
+    // Radius is reduced to fit the short edges, so the whole edge is rounded
+    let stadium: ArcPolygon<Vec<ArcVertex>> = rectangle.fillet(10.0);
+    assert_eq!(stadium.len(), 6);
+    assert_abs_diff_eq!(stadium.area(), 4.0 + PI, epsilon = 1e-5);
+
+    // Reflex corner is filled
+    let l_shape = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let rounded: ArcPolygon<Vec<ArcVertex>> = l_shape.fillet(0.25);
+    assert_eq!(rounded.len(), 12);
+    assert_abs_diff_eq!(
+        rounded.area(),
+        3.0 - 4.0 * (1.0 - 0.25 * PI) * 0.0625,
+        epsilon = 1e-5
+    );
+    assert!(rounded.contains(Vec2::new(1.02, 1.02)));
+
+    // Collinear vertex is kept
+    let triangle = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let rounded: ArcPolygon<Vec<ArcVertex>> = triangle.fillet(0.1);
+    assert_eq!(rounded.len(), 7);
+    assert!(rounded.vertices().any(|v| v.point == Vec2::new(1.0, 0.0)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn chaikin() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let once: Polygon<Vec<Vec2>> = square.smooth_chaikin(1);
+    assert_eq!(once.len(), 8);
+    assert_abs_diff_eq!(once.vertices[0], Vec2::new(0.25, 0.0));
+    // Each corner is cut by a triangle with legs of a quarter of the edge
+    assert_abs_diff_eq!(once.area(), 1.0 - 4.0 * 0.5 * 0.25 * 0.25, epsilon = 1e-6);
+
+    let smooth: Polygon<Vec<Vec2>> = square.smooth_chaikin(6);
+    assert_eq!(smooth.len(), 4 << 6);
+    assert!(smooth.is_convex());
+    assert!(
+        smooth
+            .vertices()
+            .all(|v| v.cmpge(Vec2::ZERO).all() && v.cmple(Vec2::ONE).all())
+    );
+    // Quadratic B-spline of the square encloses 5/6 of its area
+    assert_abs_diff_eq!(smooth.area(), 5.0 / 6.0, epsilon = 1e-3);
+
+    let same: Polygon<Vec<Vec2>> = square.smooth_chaikin(0);
+    assert!(same.vertices().eq(square.vertices()));
+}
+
+#[test]
+fn catmull_rom() {
+    let hexagon = Polygon::<Vec<Vec2>>::regular(Vec2::ZERO, 1.0, 6, 0.0);
+    let spline: Polygon<Vec<Vec2>> = hexagon.catmull_rom(8);
+    assert_eq!(spline.len(), 48);
+    // Original vertices are kept
+    for (i, v) in hexagon.vertices().enumerate() {
+        assert_abs_diff_eq!(spline.vertices[8 * i], v, epsilon = 1e-6);
+    }
+    // Spline of the regular polygon goes close to its circumcircle
+    for v in spline.vertices() {
+        assert!((v.length() - 1.0).abs() < 0.05, "{v}");
+    }
+
+    // Coincident vertices don't produce NaNs
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let spline: Polygon<Vec<Vec2>> = square.catmull_rom(4);
+    assert!(spline.vertices().all(|v| v.is_finite()));
+}
+
+#[test]
+fn split() {
+    // U-shaped polygon opened upwards
+    let u = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(3.0, 0.0),
+        Vec2::new(3.0, 3.0),
+        Vec2::new(2.0, 3.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 3.0),
+        Vec2::new(0.0, 3.0),
+    ]);
+    let line = Line(Vec2::new(0.0, 2.0), Vec2::new(1.0, 2.0));
+    let (above, below) = u.split::<Vec<Vec2>>(&line);
+    let (above, below) = (above.unwrap(), below.unwrap());
+    // Both arms of the U are above the line
+    assert_abs_diff_eq!(above.area(), 2.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(below.area(), 5.0, epsilon = 1e-6);
+    assert!(above.contains(Vec2::new(0.5, 2.5)));
+    assert!(above.contains(Vec2::new(2.5, 2.5)));
+    assert!(!above.contains(Vec2::new(1.5, 2.5)));
+    assert!(below.contains(Vec2::new(1.5, 0.5)));
+
+    // Line missing the polygon leaves it whole on one side
+    let line = Line(Vec2::new(0.0, 5.0), Vec2::new(1.0, 5.0));
+    let (above, below) = u.split::<Vec<Vec2>>(&line);
+    assert!(above.is_none());
+    assert_abs_diff_eq!(below.unwrap().area(), 7.0, epsilon = 1e-6);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn line_crossings() {
+    let u = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(3.0, 0.0),
+        Vec2::new(3.0, 3.0),
+        Vec2::new(2.0, 3.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 3.0),
+        Vec2::new(0.0, 3.0),
+    ]);
+    // Points are ordered along the line regardless of the edge order
+    let line = Line(Vec2::new(5.0, 2.0), Vec2::new(4.0, 2.0));
+    let points: Vec<Vec2> = line.intersect_to(&u).unwrap();
+    let expected = [3.0, 2.0, 1.0, 0.0].map(|x| Vec2::new(x, 2.0));
+    assert_eq!(points.len(), expected.len());
+    for (p, q) in points.iter().zip(expected) {
+        assert_abs_diff_eq!(*p, q, epsilon = 1e-6);
+    }
+    // Each pair is a chord lying inside
+    for pair in points.chunks(2) {
+        assert!(u.contains(pair[0].lerp(pair[1], 0.5)));
+    }
+
+    // Line going through vertices
+    let diagonal = Line(Vec2::ZERO, Vec2::ONE);
+    let points: Vec<Vec2> = u.intersect_to(&diagonal).unwrap();
+    assert_eq!(points.len() % 2, 0);
+    assert_abs_diff_eq!(points[0], Vec2::ZERO, epsilon = 1e-6);
+    assert_abs_diff_eq!(points[points.len() - 1], Vec2::splat(3.0), epsilon = 1e-6);
+
+    let outside = Line(Vec2::new(0.0, 4.0), Vec2::new(1.0, 4.0));
+    assert!(IntersectTo::<_, Vec<Vec2>>::intersect_to(&outside, &u).is_none());
+}
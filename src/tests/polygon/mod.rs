@@ -1,2 +1,4 @@
 mod circle;
+#[cfg(feature = "alloc")]
+mod integer;
 mod line;
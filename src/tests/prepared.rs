@@ -0,0 +1,61 @@
+extern crate std;
+
+use crate::{
+    ArcPolygon, Boundary, Circle, Polygon, PreparedBoundary,
+    testgen::{Pcg32, star_polygon},
+};
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn matches_brute_force() {
+    let mut rng = Pcg32::new(590);
+    let polygon: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 1000, 0.3);
+    let prepared = PreparedBoundary::new(&polygon);
+    assert_eq!(prepared.edges().len(), 1000);
+
+    let radius = 0.02;
+    for _ in 0..1024 {
+        let point = 1.2 * rng.in_unit_disk();
+        let closest = polygon
+            .boundary()
+            .map(|edge| edge.closest_point(point).distance(point))
+            .fold(f32::INFINITY, f32::min);
+        assert_eq!(prepared.is_within(point, radius), closest <= radius);
+        match prepared.closest_within(point, radius) {
+            Some((i, snapped)) => {
+                assert_eq!(prepared.edges()[i].closest_point(point), snapped);
+                assert_eq!(snapped.distance(point), closest);
+            }
+            None => assert!(closest > radius),
+        }
+    }
+    for edge in polygon.edges().step_by(7) {
+        assert!(prepared.is_near(edge.0));
+        assert!(prepared.is_within(edge.center(), 1e-5));
+    }
+}
+
+#[test]
+fn arc_polygon() {
+    let circle = ArcPolygon::<[_; 3]>::from_circle(Circle {
+        center: Vec2::new(1.0, 2.0),
+        radius: 2.0,
+    });
+    let prepared = PreparedBoundary::new(&circle);
+    for k in 0..16 {
+        let direction = Vec2::from_angle(k as f32 * 0.4);
+        let point = Vec2::new(1.0, 2.0) + 2.0 * direction;
+        assert!(prepared.is_within(point, 1e-5));
+        assert!(!prepared.is_within(point + 0.01 * direction, 1e-3));
+        let (_, snapped) = prepared
+            .closest_within(point + 0.1 * direction, 0.2)
+            .unwrap();
+        assert!(snapped.distance(point) < 1e-4);
+    }
+    for edge in prepared.edges() {
+        assert!(prepared.is_near(edge.start()));
+    }
+    assert!(!prepared.is_within(Vec2::new(1.0, 2.0), 1.9));
+    assert!(prepared.is_within(Vec2::new(1.0, 2.0), 2.0));
+}
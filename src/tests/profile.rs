@@ -0,0 +1,106 @@
+extern crate std;
+
+use crate::{
+    HalfPlane, Integrable, IntersectTo, Line, Polygon, area_profile, bisect, bisect_two,
+    equal_area_partition,
+    testgen::{Pcg32, star_polygon},
+};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn triangle() {
+    let triangle = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let profile = area_profile(&triangle, Vec2::new(0.0, 3.0));
+    assert_eq!(profile.range(), Some((0.0, 2.0)));
+    assert_abs_diff_eq!(profile.total(), 2.0, epsilon = 1e-6);
+    assert_eq!(profile.area(-1.0), 0.0);
+    assert_abs_diff_eq!(profile.area(1.0), 1.5, epsilon = 1e-6);
+    assert_abs_diff_eq!(profile.width(1.0), 1.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(profile.area(5.0), 2.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        profile.offset_of(1.0).unwrap(),
+        2.0 - 2.0f32.sqrt(),
+        epsilon = 1e-6
+    );
+    assert_eq!(profile.offset_of(-1.0), Some(0.0));
+    assert_eq!(profile.offset_of(3.0), Some(2.0));
+}
+
+#[test]
+fn random() {
+    let mut rng = Pcg32::new(0x571);
+    for _ in 0..20 {
+        let polygon: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 12, 0.3);
+        let normal = Vec2::from_angle(rng.next_f32() * 6.0);
+        let profile = area_profile(&polygon, normal);
+        assert_abs_diff_eq!(profile.total(), polygon.area(), epsilon = 1e-4);
+        let (min, max) = profile.range().unwrap();
+        for k in 0..=10 {
+            let offset = min + (max - min) * k as f32 / 10.0;
+            let clipped: Option<Polygon<Vec<Vec2>>> =
+                polygon.intersect_to(&HalfPlane { normal, offset });
+            let area = clipped.map_or(0.0, |part| part.area());
+            assert_abs_diff_eq!(profile.area(offset), area, epsilon = 1e-4);
+        }
+        let half = profile.offset_of(0.5 * profile.total()).unwrap();
+        assert_abs_diff_eq!(profile.area(half), 0.5 * profile.total(), epsilon = 1e-4);
+    }
+}
+
+#[test]
+fn bisection() {
+    let mut rng = Pcg32::new(0x572);
+    for _ in 0..20 {
+        let polygon: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 16, 0.4);
+        let direction = Vec2::from_angle(rng.next_f32() * 6.0) * 2.0;
+        let area = polygon.area();
+        let line = bisect(&polygon, direction).unwrap();
+        assert_abs_diff_eq!(
+            (line.1 - line.0).normalize(),
+            direction.normalize(),
+            epsilon = 1e-6
+        );
+        let left: Option<Polygon<Vec<Vec2>>> = polygon.intersect_to(&HalfPlane::from_edge(line));
+        assert_abs_diff_eq!(left.unwrap().area(), 0.5 * area, epsilon = 1e-4);
+
+        let lines: Vec<Line> = equal_area_partition(&polygon, 5, direction).collect();
+        assert_eq!(lines.len(), 4);
+        for (i, line) in lines.iter().enumerate() {
+            let left: Option<Polygon<Vec<Vec2>>> =
+                polygon.intersect_to(&HalfPlane::from_edge(*line));
+            assert_abs_diff_eq!(
+                left.unwrap().area(),
+                (i + 1) as f32 / 5.0 * area,
+                epsilon = 1e-4
+            );
+        }
+    }
+    let empty = Polygon::new(Vec::new());
+    assert!(bisect(&empty, Vec2::X).is_none());
+    let square = Polygon::new([Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y]);
+    assert!(bisect(&square, Vec2::ZERO).is_none());
+}
+
+#[test]
+fn ham_sandwich() {
+    let mut rng = Pcg32::new(0x573);
+    for _ in 0..20 {
+        let a: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 10, 0.5);
+        let shift = Vec2::new(rng.next_f32(), rng.next_f32()) * 4.0 - 2.0;
+        let b: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 14, 0.3);
+        let b = Polygon::new(b.vertices().map(|v| v + shift).collect::<Vec<_>>());
+        let line = bisect_two(&a, &b).unwrap();
+        for polygon in [&a, &b] {
+            let left: Option<Polygon<Vec<Vec2>>> =
+                polygon.intersect_to(&HalfPlane::from_edge(line));
+            let left = left.map_or(0.0, |part| part.area());
+            assert_abs_diff_eq!(left, 0.5 * polygon.area(), epsilon = 1e-3);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+extern crate std;
+
+use crate::{Aabb, ArcPolygon, ArcVertex, Closed, Disk, Integrable, Polygon, Region};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+const TEST_EPS: f32 = 1e-5;
+
+#[test]
+fn components() {
+    let region = Region::new([
+        Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon(),
+        Aabb::new(Vec2::new(4.0, 0.0), Vec2::new(6.0, 2.0)).polygon(),
+    ]);
+    assert_eq!(region.len(), 2);
+    assert!(region.contains(Vec2::new(1.0, 1.0)));
+    assert!(region.contains(Vec2::new(5.0, 1.0)));
+    assert!(!region.contains(Vec2::new(3.0, 1.0)));
+
+    assert_abs_diff_eq!(region.area(), 8.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(region.centroid(), Vec2::new(3.0, 1.0), epsilon = TEST_EPS);
+}
+
+#[test]
+fn holes() {
+    let outer: Polygon<Vec<Vec2>> = Aabb::new(Vec2::ZERO, Vec2::splat(4.0))
+        .polygon()
+        .vertices()
+        .collect();
+    // Clockwise contour
+    let hole: Polygon<Vec<Vec2>> = Aabb::new(Vec2::new(1.0, 1.0), Vec2::splat(3.0))
+        .polygon()
+        .vertices()
+        .rev()
+        .collect();
+    let region = Region::new([outer, hole]);
+    assert!(region.contains(Vec2::new(0.5, 0.5)));
+    assert!(!region.contains(Vec2::new(2.0, 2.0)));
+    assert_abs_diff_eq!(region.area(), 12.0, epsilon = TEST_EPS);
+}
+
+#[test]
+fn curved() {
+    let region: Region<Vec<ArcPolygon<[ArcVertex; 2]>>> = [
+        Disk::new(Vec2::new(0.0, 0.0), 1.0),
+        Disk::new(Vec2::new(3.0, 0.0), 1.0),
+    ]
+    .iter()
+    .map(|disk| disk.polygon::<2>())
+    .collect();
+    assert!(!region.is_empty());
+    assert!(region.contains(Vec2::new(3.5, 0.0)));
+    assert!(!region.contains(Vec2::new(1.5, 0.0)));
+    assert_abs_diff_eq!(region.area(), 2.0 * PI, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(region.centroid(), Vec2::new(1.5, 0.0), epsilon = TEST_EPS);
+}
@@ -0,0 +1,69 @@
+use crate::{Aabb, Closed, FillRule, Integrable, Polygon, resolve_rings};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+
+#[test]
+fn nested() {
+    // Three nested squares of the same orientation, as exported by many editors
+    let rings = [
+        Aabb::new(Vec2::ZERO, Vec2::splat(6.0)).polygon(),
+        Aabb::new(Vec2::splat(1.0), Vec2::splat(5.0)).polygon(),
+        Aabb::new(Vec2::splat(2.0), Vec2::splat(4.0)).polygon(),
+    ];
+    let even_odd = resolve_rings(&rings, FillRule::EvenOdd);
+    assert_eq!(even_odd.len(), 2);
+    let area: f32 = even_odd.iter().map(|region| region.area()).sum();
+    assert_abs_diff_eq!(area, 36.0 - 16.0 + 4.0, epsilon = 1e-4);
+    let outer = even_odd.iter().find(|region| region.len() == 2).unwrap();
+    assert!(outer.contours[0].is_ccw() && !outer.contours[1].is_ccw());
+    assert!(outer.contains(Vec2::splat(0.5)));
+    assert!(!outer.contains(Vec2::splat(1.5)));
+
+    // Nonzero rule fills everything, unless the inner rings are reversed
+    let non_zero = resolve_rings(&rings, FillRule::NonZero);
+    assert_eq!(non_zero.len(), 1);
+    assert_abs_diff_eq!(non_zero[0].area(), 36.0, epsilon = 1e-4);
+    let mut reversed = rings;
+    reversed[1].reverse();
+    let non_zero = resolve_rings(&reversed, FillRule::NonZero);
+    assert_eq!(non_zero.len(), 2);
+    let area: f32 = non_zero.iter().map(|region| region.area()).sum();
+    assert_abs_diff_eq!(area, 20.0 + 4.0, epsilon = 1e-4);
+}
+
+#[test]
+fn overlapping() {
+    let rings = [
+        Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon(),
+        Aabb::new(Vec2::splat(1.0), Vec2::splat(3.0)).polygon(),
+        // Bow tie crossing itself
+        Polygon::new([
+            Vec2::new(5.0, 0.0),
+            Vec2::new(7.0, 2.0),
+            Vec2::new(7.0, 0.0),
+            Vec2::new(5.0, 2.0),
+        ]),
+    ];
+    let regions = resolve_rings(&rings, FillRule::NonZero);
+    assert_eq!(regions.len(), 3);
+    let area: f32 = regions.iter().map(|region| region.area()).sum();
+    assert_abs_diff_eq!(area, 7.0 + 2.0, epsilon = 1e-4);
+    assert!(regions.iter().all(|region| region.len() == 1));
+
+    // Overlap of the squares is wound twice, so it is a hole
+    let regions = resolve_rings(&rings[..2], FillRule::EvenOdd);
+    assert_eq!(regions.len(), 2);
+    let area: f32 = regions.iter().map(|region| region.area()).sum();
+    assert_abs_diff_eq!(area, 6.0, epsilon = 1e-4);
+
+    // Shared edge is dissolved
+    let regions = resolve_rings(
+        &[
+            Aabb::new(Vec2::ZERO, Vec2::splat(1.0)).polygon(),
+            Aabb::new(Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0)).polygon(),
+        ],
+        FillRule::NonZero,
+    );
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].contours[0].len(), 6);
+}
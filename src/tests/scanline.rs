@@ -0,0 +1,48 @@
+extern crate std;
+
+use crate::{
+    Closed, Polygon,
+    testgen::{Pcg32, star_polygon},
+};
+use glam::Vec2;
+use std::{vec, vec::Vec};
+
+#[test]
+fn rectangle() {
+    let mut rectangle = Polygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    let spans: Vec<_> = rectangle.scanlines(1.0).collect();
+    assert_eq!(spans, [(0.5, 0.0, 4.0), (1.5, 0.0, 4.0)]);
+    // Rows are aligned to zero rather than to the polygon
+    let spans: Vec<_> = rectangle.scanlines(0.8).collect();
+    assert_eq!(spans.len(), 2);
+    assert!((spans[1].0 - 1.2).abs() < 1e-6);
+
+    rectangle.reverse();
+    assert_eq!(rectangle.scanlines(1.0).count(), 0);
+}
+
+#[test]
+fn star() {
+    let mut rng = Pcg32::new(5902);
+    let polygon: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 200, 0.3);
+    let spacing = 1e-3;
+    let mut area = 0.0;
+    let mut last = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+    for (y, start, end) in polygon.scanlines(spacing) {
+        assert!(start < end);
+        assert!(last.0 < y || last.1 < start);
+        last = (y, end);
+        assert!(polygon.contains(Vec2::new(0.5 * (start + end), y)));
+        for x in [start, end] {
+            let point = Vec2::new(x, y);
+            assert!(polygon.closest_point(point).unwrap().distance(point) < 1e-5);
+        }
+        area += (end - start) * spacing;
+    }
+    assert!((area - polygon.signed_area()).abs() < 1e-3);
+}
@@ -0,0 +1,61 @@
+use crate::{Aabb, Closed, Disk, FillRule, LayerRole, Polygon, SceneBuilder};
+use core::f32::consts::PI;
+use glam::{UVec2, Vec2};
+
+#[test]
+fn layers() {
+    let mut builder = SceneBuilder::new();
+    builder
+        .push("base", Aabb::new(Vec2::ZERO, Vec2::new(10.0, 4.0)))
+        .layer("holes", FillRule::NonZero, LayerRole::Subtractive)
+        .push("holes", Disk::new(Vec2::new(2.0, 2.0), 1.0))
+        .push("holes", Disk::new(Vec2::new(8.0, 2.0), 1.0))
+        .push("patch", Aabb::new(Vec2::new(7.5, 1.5), Vec2::new(8.5, 2.5)));
+    let scene = builder.build();
+    assert!(scene.layers().eq(["base", "holes", "patch"]));
+
+    assert!(scene.contains(Vec2::new(5.0, 2.0)));
+    assert!(!scene.contains(Vec2::new(2.0, 2.0)));
+    // Later additive layer fills the hole back
+    assert!(scene.contains(Vec2::new(8.0, 2.0)));
+    assert!(!scene.contains(Vec2::new(8.0, 2.8)));
+    assert_eq!(
+        scene.layer_contains("holes", Vec2::new(2.0, 2.0)),
+        Some(true)
+    );
+    assert_eq!(scene.layer_contains("missing", Vec2::ZERO), None);
+
+    let area = scene.area(UVec2::new(500, 200));
+    assert!((area - (40.0 - 2.0 * PI + 1.0)).abs() < 0.05);
+}
+
+#[test]
+fn fill_rules() {
+    let square = |min: f32, max: f32| {
+        Polygon::new([
+            Vec2::new(min, min),
+            Vec2::new(max, min),
+            Vec2::new(max, max),
+            Vec2::new(min, max),
+        ])
+    };
+    let mut builder = SceneBuilder::new();
+    builder
+        .layer("nonzero", FillRule::NonZero, LayerRole::Additive)
+        .push("nonzero", square(0.0, 4.0))
+        .push("nonzero", square(1.0, 3.0))
+        .layer("evenodd", FillRule::EvenOdd, LayerRole::Additive)
+        .push("evenodd", square(10.0, 14.0))
+        .push("evenodd", square(11.0, 13.0))
+        .push("evenodd", Disk::new(Vec2::new(12.0, 12.0), 0.5));
+    let scene = builder.build();
+
+    assert!(scene.contains(Vec2::new(2.0, 2.0)));
+    assert!(scene.contains(Vec2::new(10.5, 10.5)));
+    assert!(!scene.contains(Vec2::new(11.5, 11.5)));
+    assert!(scene.contains(Vec2::new(12.0, 12.0)));
+    assert!(!scene.contains(Vec2::new(7.0, 7.0)));
+
+    let grid = scene.rasterize(UVec2::splat(140));
+    assert!((grid.area() - (16.0 + 12.0 + 0.25 * PI)).abs() < 0.1);
+}
@@ -0,0 +1,147 @@
+extern crate std;
+
+use crate::{Closed, Integrable, Polygon};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn noisy_square() {
+    // Square with jittered points on its sides
+    let corners = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(0.0, 4.0),
+    ];
+    let mut vertices = Vec::new();
+    for (k, &a) in corners.iter().enumerate() {
+        let b = corners[(k + 1) % 4];
+        let normal = (b - a).perp().normalize();
+        for i in 0..10 {
+            let jitter = if i % 2 == 0 { 0.01 } else { -0.01 };
+            vertices.push(a.lerp(b, i as f32 / 10.0) + normal * if i == 0 { 0.0 } else { jitter });
+        }
+    }
+    let poly = Polygon::new(vertices);
+
+    let simple: Polygon<Vec<Vec2>> = poly.simplify(0.05);
+    assert_eq!(simple.len(), 4);
+    for v in corners {
+        assert!(simple.vertices().any(|u| u == v));
+    }
+    assert_abs_diff_eq!(simple.area(), 16.0, epsilon = 1e-5);
+
+    // Nothing is removed with small tolerance
+    let same: Polygon<Vec<Vec2>> = poly.simplify(0.001);
+    assert_eq!(same.len(), poly.len());
+}
+
+#[test]
+fn circle() {
+    let poly: Polygon<Vec<Vec2>> = (0..360)
+        .map(|i| Vec2::from_angle(i as f32 * PI / 180.0))
+        .collect();
+    let simple: Polygon<Vec<Vec2>> = poly.simplify(0.01);
+    assert!(simple.len() < 40);
+    assert!(simple.is_ccw());
+    assert_abs_diff_eq!(simple.area(), poly.area(), epsilon = 0.05);
+    assert!(simple.contains(Vec2::new(0.9, 0.0)));
+
+    let triangle = Polygon::new([Vec2::ZERO, Vec2::X, Vec2::Y]);
+    assert_eq!(triangle.simplify::<Vec<_>>(1.0), triangle);
+}
+
+#[test]
+fn long_contour() {
+    // Traced contour with many nearly collinear vertices
+    let mut rng = crate::testgen::Pcg32::new(550);
+    let poly: Polygon<Vec<Vec2>> = (0..20000)
+        .map(|i| {
+            let angle = i as f32 * 2.0 * PI / 20000.0;
+            Vec2::from_angle(angle) * (100.0 + rng.range(-0.01, 0.01))
+        })
+        .collect();
+    let simple: Polygon<Vec<Vec2>> = poly.simplify(0.1);
+    assert!(simple.len() < 200);
+    assert!(simple.is_ccw());
+    assert_abs_diff_eq!(simple.area() / poly.area(), 1.0, epsilon = 1e-3);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn preserving_moments() {
+    let mut rng = crate::testgen::Pcg32::new(5590);
+    let star: Polygon<Vec<Vec2>> = crate::testgen::star_polygon(&mut rng, 200, 0.5);
+    let star = Polygon::new(
+        star.vertices()
+            .map(|v| 3.0 * v + Vec2::new(1.0, -2.0))
+            .collect::<Vec<_>>(),
+    );
+    let simple: Polygon<Vec<Vec2>> = star.simplify_preserving_moments(24);
+    assert_eq!(simple.len(), 24);
+    assert!(simple.is_ccw());
+    assert_abs_diff_eq!(simple.area(), star.area(), epsilon = 1e-4);
+    assert_abs_diff_eq!(simple.centroid(), star.centroid(), epsilon = 1e-5);
+
+    // Plain simplification drifts
+    let reduced: Polygon<Vec<Vec2>> = star.simplify(0.3);
+    assert!((reduced.area() - star.area()).abs() > 1e-2);
+
+    let circle: Polygon<Vec<Vec2>> = (0..360)
+        .map(|i| Vec2::from_angle(i as f32 * PI / 180.0))
+        .collect();
+    let square: Polygon<Vec<Vec2>> = circle.simplify_preserving_moments(4);
+    assert_eq!(square.len(), 4);
+    assert_abs_diff_eq!(square.area(), circle.area(), epsilon = 1e-4);
+    assert_abs_diff_eq!(square.centroid(), Vec2::ZERO, epsilon = 1e-5);
+
+    // Nothing to remove
+    let triangle = Polygon::new([Vec2::ZERO, Vec2::X, Vec2::Y]);
+    assert_eq!(triangle.simplify_preserving_moments::<Vec<_>>(2), triangle);
+}
+
+#[cfg(feature = "alloc")]
+/// Recursive Douglas–Peucker over the vertices from `a` to `b`, pushing the kept ones except `a`.
+fn douglas_peucker(points: &[Vec2], a: usize, b: usize, tolerance: f32, kept: &mut Vec<Vec2>) {
+    let n = points.len();
+    let segment = crate::LineSegment(points[a % n], points[b % n]);
+    let farthest = (a + 1..b)
+        .map(|i| {
+            (
+                i,
+                segment.closest_point(points[i % n]).distance(points[i % n]),
+            )
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b));
+    match farthest {
+        Some((i, distance)) if distance > tolerance => {
+            douglas_peucker(points, a, i, tolerance, kept);
+            douglas_peucker(points, i, b, tolerance, kept);
+        }
+        _ => kept.push(points[b % n]),
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn matches_recursion() {
+    let mut rng = crate::testgen::Pcg32::new(5502);
+    for _ in 0..16 {
+        let star: Polygon<Vec<Vec2>> = crate::testgen::star_polygon(&mut rng, 300, 0.4);
+        let points = &star.vertices;
+        let split = (0..points.len())
+            .map(|i| (i, points[i].distance_squared(points[0])))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap()
+            .0;
+        for tolerance in [0.01, 0.05, 0.2] {
+            let mut expected = Vec::new();
+            douglas_peucker(points, 0, split, tolerance, &mut expected);
+            douglas_peucker(points, split, points.len(), tolerance, &mut expected);
+            let simple: Polygon<Vec<Vec2>> = star.simplify(tolerance);
+            assert_eq!(simple.vertices, expected);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+extern crate std;
+
+use crate::{
+    Aabb, Closed, Integrable, OffsetEvent, OffsetOptions, Polygon, Region,
+    testgen::{Pcg32, star_polygon},
+};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+use std::{vec, vec::Vec};
+
+#[test]
+fn rectangle() {
+    let rect = Aabb::new(Vec2::ZERO, Vec2::new(6.0, 2.0)).polygon();
+    let skeleton = rect.straight_skeleton();
+    assert!(!skeleton.incomplete);
+    // Four corner bisectors and the ridge
+    assert_eq!(skeleton.nodes.len(), 6);
+    assert_eq!(skeleton.arcs.len(), 5);
+    let deepest = skeleton.deepest().unwrap();
+    assert_abs_diff_eq!(deepest.time, 1.0, epsilon = 1e-4);
+    assert_abs_diff_eq!(deepest.point.y, 1.0, epsilon = 1e-4);
+
+    let inset = rect.inset(0.5);
+    assert_eq!(inset.len(), 1);
+    assert_abs_diff_eq!(inset.moment().area, 5.0, epsilon = 1e-4);
+    assert!(rect.inset(1.5).is_empty());
+}
+
+/// Two squares joined by a narrow corridor, the corridor end at the right square has the given bottom.
+fn dumbbell(bottom: f32) -> Polygon<Vec<Vec2>> {
+    Polygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 1.0),
+        Vec2::new(6.0, bottom),
+        Vec2::new(6.0, 0.0),
+        Vec2::new(10.0, 0.0),
+        Vec2::new(10.0, 4.0),
+        Vec2::new(6.0, 4.0),
+        Vec2::new(6.0, 3.0),
+        Vec2::new(4.0, 3.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(0.0, 4.0),
+    ])
+}
+
+#[test]
+fn split() {
+    // Symmetric corridor makes reflex vertices meet simultaneously
+    for bottom in [1.2, 1.0] {
+        let dumbbell = dumbbell(bottom);
+        let inset = dumbbell.inset(0.5);
+        assert_eq!(inset.len(), 1);
+        let inset = dumbbell.inset(1.5);
+        assert_eq!(inset.len(), 2);
+        assert_abs_diff_eq!(inset.moment().area, 2.0, epsilon = 1e-3);
+        assert!(inset.contains(Vec2::new(2.0, 2.0)));
+        assert!(inset.contains(Vec2::new(8.0, 2.0)));
+        assert!(!inset.contains(Vec2::new(5.0, 2.0)));
+    }
+}
+
+#[test]
+fn hole() {
+    let square = |min: f32, max: f32| Aabb::new(Vec2::splat(min), Vec2::splat(max)).polygon();
+    let mut hole = square(4.0, 6.0);
+    hole.reverse();
+    let region = Region::new([square(0.0, 10.0), hole]);
+
+    let inset = region.inset(1.0);
+    assert_eq!(inset.len(), 2);
+    assert_abs_diff_eq!(inset.moment().area, 64.0 - 16.0, epsilon = 1e-3);
+    // Outer contour and hole meet in the middle of the ring
+    assert!(region.inset(2.5).is_empty());
+    let skeleton = region.straight_skeleton();
+    assert_abs_diff_eq!(skeleton.deepest().unwrap().time, 2.0, epsilon = 1e-3);
+}
+
+#[test]
+fn random() {
+    let mut rng = Pcg32::new(5632);
+    for _ in 0..40 {
+        let poly: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 32, 0.1);
+        let edge_distance = |point: Vec2| {
+            poly.edges()
+                .map(|edge| {
+                    let (a, b) = (edge.0, edge.1);
+                    let t = ((point - a).dot(b - a) / (b - a).length_squared()).clamp(0.0, 1.0);
+                    point.distance(a.lerp(b, t))
+                })
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        let skeleton = poly.straight_skeleton();
+        assert!(!skeleton.incomplete);
+        for node in &skeleton.nodes {
+            assert!(node.time == 0.0 || poly.contains(node.point));
+        }
+        let depth = skeleton.deepest().unwrap().time;
+        // The deepest node is no closer to the boundary than its time
+        assert!(edge_distance(skeleton.deepest().unwrap().point) >= depth - 1e-3);
+
+        let mut area = poly.moment().area;
+        for k in 1..8 {
+            let distance = 0.1 * k as f32;
+            let inset = poly.inset(distance);
+            let inset_area = inset.moment().area;
+            assert!(inset_area <= area + 1e-5);
+            area = inset_area;
+            if distance > depth {
+                assert!(inset.is_empty());
+            }
+            for contour in inset.components() {
+                for vertex in contour.vertices() {
+                    assert!(poly.contains(vertex));
+                    assert!(edge_distance(vertex) >= distance - 1e-3);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn outset() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon();
+    let offset = square.offset(1.0, OffsetOptions::default());
+    assert_eq!(offset.region.len(), 1);
+    assert!(offset.events.is_empty());
+    assert_abs_diff_eq!(offset.region.moment().area, 16.0, epsilon = 1e-4);
+
+    // Corners are cut at the offset distance from the original vertices
+    let beveled = square.offset(1.0, OffsetOptions { miter_limit: 1.0 });
+    let contour = beveled.region.components().next().unwrap();
+    assert_eq!(contour.vertices().count(), 8);
+    let cut = (2.0f32.sqrt() - 1.0).powi(2);
+    assert_abs_diff_eq!(
+        beveled.region.moment().area,
+        16.0 - 4.0 * cut,
+        epsilon = 1e-4
+    );
+    assert!(!beveled.region.contains(Vec2::new(-0.9, -0.9)));
+    assert!(beveled.region.contains(Vec2::new(-0.6, -0.6)));
+}
+
+#[test]
+fn topology_events() {
+    let squares = Region::new([
+        Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon(),
+        Aabb::new(Vec2::new(3.0, 0.0), Vec2::new(5.0, 2.0)).polygon(),
+    ]);
+    let merged = squares.offset(0.75, OffsetOptions::default());
+    assert!(!merged.incomplete);
+    assert_eq!(merged.region.len(), 1);
+    assert!(matches!(
+        merged.events[..],
+        [OffsetEvent::Merge { distance, point }] if (distance - 0.5).abs() < 1e-4 && (point.x - 2.5).abs() < 1e-4
+    ));
+    assert_abs_diff_eq!(merged.region.moment().area, 6.5 * 3.5, epsilon = 1e-3);
+
+    // Outset closes the slot of the cavity, so the cavity becomes a hole
+    let cavity = Polygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(6.0, 0.0),
+        Vec2::new(6.0, 6.0),
+        Vec2::new(3.5, 6.0),
+        Vec2::new(3.5, 5.0),
+        Vec2::new(5.0, 5.0),
+        Vec2::new(5.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 5.0),
+        Vec2::new(2.5, 5.0),
+        Vec2::new(2.5, 6.0),
+        Vec2::new(0.0, 6.0),
+    ]);
+    let closed = cavity.offset(0.75, OffsetOptions::default());
+    assert_eq!(closed.region.len(), 2);
+    assert!(matches!(closed.events[..], [OffsetEvent::Split { .. }]));
+    assert!(!closed.region.contains(Vec2::new(3.0, 3.0)));
+    assert!(closed.region.contains(Vec2::new(3.0, 5.5)));
+
+    // Inset splits the dumbbell and then both parts vanish
+    for bottom in [1.2, 1.0] {
+        let events = dumbbell(bottom)
+            .offset(-3.0, OffsetOptions::default())
+            .events;
+        let count = |f: fn(&OffsetEvent) -> bool| events.iter().filter(|e| f(e)).count();
+        assert_eq!(count(|e| matches!(e, OffsetEvent::Split { .. })), 1);
+        assert_eq!(count(|e| matches!(e, OffsetEvent::Vanish { .. })), 2);
+        assert_eq!(count(|e| matches!(e, OffsetEvent::Merge { .. })), 0);
+    }
+}
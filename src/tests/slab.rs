@@ -0,0 +1,67 @@
+extern crate std;
+
+use crate::{
+    Closed, Polygon,
+    testgen::{Pcg32, star_polygon},
+};
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn matches_polygon() {
+    let mut rng = Pcg32::new(589);
+    let mut polygon: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 500, 0.2);
+    for reversed in [false, true] {
+        if reversed {
+            polygon.reverse();
+        }
+        let index = polygon.build_slab_index();
+        for _ in 0..512 {
+            let point = 1.2 * rng.in_unit_disk();
+            assert_eq!(
+                index.winding_number_2(point),
+                polygon.winding_number_2(point),
+                "{point}"
+            );
+            // Crossings alternate the inside and outside, and the rays hit the neighbouring ones
+            let crossings: Vec<f32> = index.crossings(point.x).collect();
+            assert_eq!(crossings.len() % 2, 0);
+            assert!(crossings.is_sorted());
+            let below = crossings.partition_point(|&y| y < point.y);
+            assert_eq!(polygon.winding_number_2(point) != 0, below % 2 == 1);
+            assert_eq!(
+                index.above(point).map(|p| p.y),
+                crossings.get(below).copied()
+            );
+            assert_eq!(
+                index.below(point).map(|p| p.y),
+                below.checked_sub(1).map(|k| crossings[k])
+            );
+        }
+    }
+}
+
+#[test]
+fn square() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let index = square.build_slab_index();
+    assert_eq!(index.slab_count(), 2);
+    assert!(index.contains(Vec2::new(0.5, 1.2)));
+    assert!(!index.contains(Vec2::new(0.5, 1.8)));
+    assert!(!index.contains(Vec2::new(-0.5, 0.5)));
+    assert_eq!(index.crossings(0.5).collect::<Vec<_>>(), [0.0, 1.5]);
+    assert_eq!(index.crossings(3.0).count(), 0);
+    assert_eq!(index.above(Vec2::new(1.5, 0.5)), Some(Vec2::new(1.5, 1.5)));
+    assert_eq!(index.below(Vec2::new(1.5, 0.5)), Some(Vec2::new(1.5, 0.0)));
+    assert_eq!(index.above(Vec2::new(1.5, 3.0)), None);
+
+    let empty = Polygon::new(Vec::<Vec2>::new()).build_slab_index();
+    assert_eq!(empty.slab_count(), 0);
+    assert!(!empty.contains(Vec2::ZERO));
+}
@@ -0,0 +1,98 @@
+extern crate std;
+
+use crate::{ArcPolygon, ArcVertex, Disk, Integrable, Polygon, Region, angular_decompose, slice};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+use std::vec::Vec;
+
+const TEST_EPS: f32 = 1e-5;
+
+type Strip = Region<Option<Polygon<Vec<Vec2>>>>;
+
+#[test]
+fn square() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(0.0, 4.0),
+    ]);
+    let strips: Vec<Strip> = slice(&square, Vec2::X, 1.0).collect();
+    assert_eq!(strips.len(), 4);
+    for (k, strip) in strips.iter().enumerate() {
+        assert_abs_diff_eq!(strip.area(), 4.0, epsilon = TEST_EPS);
+        assert_abs_diff_eq!(
+            strip.centroid(),
+            Vec2::new(k as f32 + 0.5, 2.0),
+            epsilon = TEST_EPS
+        );
+    }
+
+    // Strips are aligned to the origin, so outer ones are partial
+    let strips: Vec<Strip> = slice(&square, Vec2::new(-1.0, -1.0), 1.5).collect();
+    let total: f32 = strips.iter().map(|s| s.area()).sum();
+    assert_abs_diff_eq!(total, 16.0, epsilon = TEST_EPS);
+    assert!(strips.first().unwrap().centroid().x > strips.last().unwrap().centroid().x);
+}
+
+#[test]
+fn concave() {
+    let poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(3.0, 0.0),
+        Vec2::new(3.0, 3.0),
+        Vec2::new(2.0, 3.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 3.0),
+        Vec2::new(0.0, 3.0),
+    ]);
+    let areas: Vec<f32> = slice::<_, Vec<Vec2>>(&poly, Vec2::Y, 1.0)
+        .map(|s| s.area())
+        .collect();
+    assert_eq!(areas.len(), 3);
+    assert_abs_diff_eq!(areas[0], 3.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(areas[1], 2.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(areas[2], 2.0, epsilon = TEST_EPS);
+}
+
+#[test]
+fn sectors() {
+    let square = Polygon::new([
+        Vec2::new(-1.0, -1.0),
+        Vec2::new(1.0, -1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(-1.0, 1.0),
+    ]);
+    let areas: Vec<f32> = angular_decompose::<_, Polygon<Vec<Vec2>>>(&square, Vec2::ZERO, 8)
+        .map(|s| s.area())
+        .collect();
+    assert_eq!(areas.len(), 8);
+    for area in areas {
+        assert_abs_diff_eq!(area, 0.5, epsilon = TEST_EPS);
+    }
+
+    // Origin outside of the disk
+    let disk = Disk::new(Vec2::new(2.0, 0.0), 1.0);
+    let regions: Vec<Region<Option<ArcPolygon<Vec<ArcVertex>>>>> =
+        angular_decompose(&disk.polygon::<4>(), Vec2::ZERO, 4).collect();
+    assert!(regions[1].is_empty() && regions[2].is_empty());
+    assert_abs_diff_eq!(regions[0].area(), disk.area() / 2.0, epsilon = TEST_EPS);
+    assert_abs_diff_eq!(regions[3].area(), disk.area() / 2.0, epsilon = TEST_EPS);
+}
+
+#[test]
+fn sectors_too_few() {
+    let square = Polygon::new([
+        Vec2::new(-1.0, -1.0),
+        Vec2::new(1.0, -1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(-1.0, 1.0),
+    ]);
+    for count in [0, 1] {
+        assert_eq!(
+            angular_decompose::<_, Polygon<Vec<Vec2>>>(&square, Vec2::ZERO, count).count(),
+            0
+        );
+    }
+}
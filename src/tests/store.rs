@@ -0,0 +1,101 @@
+extern crate std;
+
+use crate::{Closed, Disk, GeometryStore, GeometryStoreBuilder, Overlaps, testgen::Pcg32};
+use alloc::sync::Arc;
+use glam::Vec2;
+use std::{thread, vec::Vec};
+
+fn random_disks(seed: u64, count: usize) -> Vec<Disk> {
+    let mut rng = Pcg32::new(seed);
+    (0..count)
+        .map(|_| {
+            let center = Vec2::new(rng.range(0.0, 20.0), rng.range(0.0, 20.0));
+            Disk::new(center, rng.range(0.1, 1.5))
+        })
+        .collect()
+}
+
+#[test]
+fn interning() {
+    let mut builder = GeometryStoreBuilder::new();
+    let a = builder.insert(Disk::new(Vec2::ZERO, 1.0));
+    let b = builder.intern(Disk::new(Vec2::ZERO, 1.0));
+    assert_eq!(a, b);
+    let shared = Arc::new(Disk::new(Vec2::ONE, 2.0));
+    let c = builder.insert_shared(shared.clone());
+    assert_ne!(a, c);
+    assert_eq!(builder.insert_shared(shared.clone()), c);
+    // Equal but separately allocated shape is a different one
+    assert_ne!(builder.insert(*shared), c);
+    assert_eq!(builder.len(), 3);
+
+    let store = builder.build();
+    assert_eq!(store.len(), 3);
+    assert!(Arc::ptr_eq(&store.shared(c).unwrap(), &shared));
+    assert_eq!(store.get(a).unwrap().radius, 1.0);
+}
+
+#[test]
+fn queries() {
+    let disks = random_disks(564, 300);
+    let store: GeometryStore<Disk> = disks.iter().copied().collect();
+    assert_eq!(store.len(), disks.len());
+
+    let mut rng = Pcg32::new(5640);
+    let points: Vec<Vec2> = (0..200)
+        .map(|_| Vec2::new(rng.range(-1.0, 21.0), rng.range(-1.0, 21.0)))
+        .collect();
+    let expected: Vec<(usize, usize)> = (0..points.len())
+        .flat_map(|j| {
+            let disks = &disks;
+            let point = points[j];
+            (0..disks.len())
+                .filter(move |&i| disks[i].contains(point))
+                .map(move |i| (i, j))
+        })
+        .collect();
+    let found: Vec<(usize, usize)> = store
+        .containing_points(&points)
+        .map(|(id, j)| (id.index(), j))
+        .collect();
+    assert_eq!(found, expected);
+
+    let probe = Disk::new(Vec2::new(10.0, 10.0), 3.0);
+    let mut overlapping: Vec<usize> = store.overlapping(&probe).map(|id| id.index()).collect();
+    overlapping.sort();
+    let expected: Vec<usize> = (0..disks.len())
+        .filter(|&i| disks[i].overlaps(&probe))
+        .collect();
+    assert_eq!(overlapping, expected);
+}
+
+#[test]
+fn concurrent() {
+    let store: GeometryStore<Disk> = random_disks(5641, 500).into_iter().collect();
+    let counts: Vec<usize> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|k| {
+                let store = store.clone();
+                scope.spawn(move || {
+                    (0..100)
+                        .map(|i| Vec2::new(0.2 * i as f32, 5.0 * k as f32))
+                        .map(|point| store.containing(point).count())
+                        .sum()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    for (k, count) in counts.into_iter().enumerate() {
+        let expected: usize = (0..100)
+            .map(|i| Vec2::new(0.2 * i as f32, 5.0 * k as f32))
+            .map(|point| {
+                store
+                    .iter()
+                    .filter(|(_, disk)| disk.contains(point))
+                    .count()
+            })
+            .sum();
+        assert_eq!(count, expected);
+    }
+}
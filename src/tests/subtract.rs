@@ -0,0 +1,68 @@
+extern crate std;
+
+use crate::{Aabb, ArcPolygon, ArcVertex, Closed, Disk, Integrable, IntersectTo, Polygon, Region};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+const TEST_EPS: f32 = 1e-4;
+
+type Result = Region<Vec<ArcPolygon<Vec<ArcVertex>>>>;
+
+#[test]
+fn hole() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(4.0)).polygon();
+    let disk = Disk::new(Vec2::new(2.0, 2.0), 1.0);
+    let region: Result = square.subtract_disk(&disk);
+    assert_eq!(region.len(), 2);
+    assert_abs_diff_eq!(region.area(), 16.0 - PI, epsilon = TEST_EPS);
+    assert!(!region.contains(Vec2::new(2.0, 2.0)));
+    assert!(region.contains(Vec2::new(0.5, 0.5)));
+
+    // Disjoint and covering disks
+    let region: Result = square.subtract_disk(&Disk::new(Vec2::new(10.0, 2.0), 1.0));
+    assert_abs_diff_eq!(region.area(), 16.0, epsilon = TEST_EPS);
+    let region: Result = square.subtract_disk(&Disk::new(Vec2::new(2.0, 2.0), 3.0));
+    assert!(region.is_empty());
+}
+
+#[test]
+fn notch() {
+    let square = Aabb::new(Vec2::ZERO, Vec2::splat(4.0)).polygon();
+    // Quarter of the disk is cut from the corner
+    let disk = Disk::new(Vec2::new(4.0, 4.0), 2.0);
+    let region: Result = square.subtract_disk(&disk);
+    assert_eq!(region.len(), 1);
+    assert_abs_diff_eq!(region.area(), 16.0 - PI, epsilon = TEST_EPS);
+    assert!(!region.contains(Vec2::new(3.5, 3.5)));
+    assert!(region.contains(Vec2::new(2.5, 2.5)));
+
+    // Half of the disk is cut from the side
+    let disk = Disk::new(Vec2::new(2.0, 0.0), 1.0);
+    let region: Result = square.subtract_disk(&disk);
+    assert_eq!(region.len(), 1);
+    assert_abs_diff_eq!(region.area(), 16.0 - PI / 2.0, epsilon = TEST_EPS);
+}
+
+#[test]
+fn split() {
+    // Disk cuts the stripe into two parts
+    let stripe = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let disk = Disk::new(Vec2::new(2.0, 0.5), 1.0);
+    let region: Result = stripe.subtract_disk(&disk);
+    assert_eq!(region.len(), 2);
+    let lens: ArcPolygon<Vec<ArcVertex>> = stripe.intersect_to(&disk).unwrap();
+    assert_abs_diff_eq!(region.area(), 4.0 - lens.area(), epsilon = TEST_EPS);
+    for part in region.components() {
+        assert!(part.is_ccw());
+    }
+    assert!(region.contains(Vec2::new(0.5, 0.5)));
+    assert!(region.contains(Vec2::new(3.5, 0.5)));
+    assert!(!region.contains(Vec2::new(2.0, 0.5)));
+}
@@ -0,0 +1,87 @@
+extern crate std;
+
+use crate::{Intersect, LineSegment, Polygon, segment_intersections, testgen::Pcg32};
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+fn naive(segments: &[LineSegment]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (i, a) in segments.iter().enumerate() {
+        for (j, b) in segments.iter().enumerate().skip(i + 1) {
+            if a.intersect(b).is_some() {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+#[test]
+fn random() {
+    let mut rng = Pcg32::new(551);
+    let segments: Vec<_> = (0..200)
+        .map(|_| {
+            let a = Vec2::new(rng.range(0.0, 10.0), rng.range(0.0, 10.0));
+            let b = a + 2.0 * rng.in_unit_disk();
+            LineSegment(a, b)
+        })
+        .collect();
+
+    let found: Vec<_> = segment_intersections(segments.iter().copied()).collect();
+    for &(i, j, p) in &found {
+        assert!(segments[i].closest_point(p).distance(p) < 1e-5);
+        assert!(segments[j].closest_point(p).distance(p) < 1e-5);
+    }
+    let mut pairs: Vec<_> = found.iter().map(|&(i, j, _)| (i, j)).collect();
+    pairs.sort();
+    let expected = naive(&segments);
+    assert!(expected.len() > 50);
+    assert_eq!(pairs, expected);
+}
+
+#[test]
+fn pentagram() {
+    let star = Polygon::new(core::array::from_fn::<_, 5, _>(|i| {
+        Vec2::from_angle(PI / 2.0 + 4.0 * PI / 5.0 * i as f32)
+    }));
+    let mut pairs: Vec<_> = segment_intersections(star.edges())
+        .map(|(i, j, _)| (i, j))
+        .collect();
+    pairs.sort();
+    // Each edge touches two adjacent ones and crosses the other two
+    assert_eq!(pairs.len(), 10);
+    assert_eq!(pairs, naive(&star.edges().collect::<Vec<_>>()));
+
+    // Vertical and horizontal segments meeting at a single point
+    let cross = [
+        LineSegment(Vec2::new(0.0, -1.0), Vec2::new(0.0, 1.0)),
+        LineSegment(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0)),
+        LineSegment(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0)),
+        LineSegment(Vec2::new(2.0, 2.0), Vec2::new(3.0, 3.0)),
+    ];
+    let found: Vec<_> = segment_intersections(cross).collect();
+    assert_eq!(found.len(), 3);
+    assert!(found.iter().all(|&(_, _, p)| p == Vec2::ZERO));
+}
+
+#[test]
+fn long_status() {
+    // Many segments crossing the sweep line at once
+    let mut segments: Vec<_> = (0..500)
+        .map(|i| {
+            let y = i as f32 * 0.1;
+            LineSegment(Vec2::new(0.0, y), Vec2::new(10.0, y + 0.05))
+        })
+        .collect();
+    segments.extend((0..4).map(|i| {
+        let x = 1.0 + 2.0 * i as f32;
+        LineSegment(Vec2::new(x, -1.0), Vec2::new(x + 1.0, 51.0))
+    }));
+    let mut pairs: Vec<_> = segment_intersections(segments.iter().copied())
+        .map(|(i, j, _)| (i, j))
+        .collect();
+    pairs.sort();
+    assert_eq!(pairs.len(), 2000);
+    assert_eq!(pairs, naive(&segments));
+}
@@ -0,0 +1,97 @@
+extern crate std;
+
+use crate::{
+    Aabb, ArcPolygon, ArcVertex, Bounded, Disk, HalfPlane, Integrable, Intersect, IntersectTo,
+    Line, Moment, Polygon,
+};
+use approx::assert_abs_diff_eq;
+use arrayvec::ArrayVec;
+use either::Either;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn disk_plane() {
+    let disk = Disk::new(Vec2::new(1.0, 2.0), 1.5);
+    let plane = HalfPlane::from_normal(Vec2::new(2.0, 0.0), Vec2::new(1.0, 1.0).normalize());
+    let reference = disk.intersect(&plane).unwrap().unwrap_left();
+
+    let moment: Moment = disk.intersect_to(&plane).unwrap();
+    assert_abs_diff_eq!(moment, reference.moment(), epsilon = 1e-5);
+    let segment: ArcPolygon<ArrayVec<ArcVertex, 2>> = plane.intersect_to(&disk).unwrap();
+    assert_abs_diff_eq!(segment.moment(), reference.moment(), epsilon = 1e-5);
+
+    // Whole disk is stored as is
+    let plane = HalfPlane::from_normal(Vec2::new(5.0, 0.0), Vec2::new(1.0, 0.0));
+    let whole: Either<Moment, Disk> = disk.intersect_to(&plane).unwrap();
+    assert_eq!(whole, Either::Right(disk));
+    let moment: Moment = disk.intersect_to(&plane).unwrap();
+    assert_abs_diff_eq!(moment, disk.moment(), epsilon = 1e-5);
+}
+
+#[test]
+fn disk_disk() {
+    let a = Disk::new(Vec2::new(0.0, 0.0), 2.0);
+    let b = Disk::new(Vec2::new(3.0, 0.0), 2.0);
+    let lens = a.lens(&b).unwrap();
+
+    let moment: Moment = a.intersect_to(&b).unwrap();
+    assert_abs_diff_eq!(moment, lens.moment(), epsilon = 1e-5);
+    let aabb: Aabb = a.intersect_to(&b).unwrap();
+    assert_abs_diff_eq!(aabb, lens.aabb(), epsilon = 1e-5);
+    let polygon: Either<ArcPolygon<Vec<ArcVertex>>, Disk> = a.intersect_to(&b).unwrap();
+    assert_eq!(polygon.unwrap_left().len(), 2);
+
+    assert!(
+        IntersectTo::<_, Moment>::intersect_to(&a, &Disk::new(Vec2::new(5.0, 0.0), 1.0)).is_none()
+    );
+}
+
+#[test]
+fn polygon_disk() {
+    let polygon = Polygon::new([
+        Vec2::new(-2.0, -0.5),
+        Vec2::new(2.0, -0.5),
+        Vec2::new(2.0, 0.5),
+        Vec2::new(-2.0, 0.5),
+    ]);
+    let disk = Disk::new(Vec2::ZERO, 1.0);
+
+    let stripe: ArcPolygon<Vec<ArcVertex>> = polygon.intersect_to(&disk).unwrap();
+    let moment: Moment = polygon.intersect_to(&disk).unwrap();
+    assert_abs_diff_eq!(moment, stripe.moment(), epsilon = 1e-5);
+    let aabb: Aabb = disk.intersect_to(&polygon).unwrap();
+    assert_abs_diff_eq!(aabb, stripe.aabb(), epsilon = 1e-5);
+
+    // Disk inside the polygon
+    let small = Disk::new(Vec2::new(0.5, 0.0), 0.25);
+    let inner: Either<ArcPolygon<Vec<ArcVertex>>, Disk> = polygon.intersect_to(&small).unwrap();
+    assert_eq!(inner, Either::Right(small));
+}
+
+/// Shape defined outside of the crate.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Interval(f32, f32);
+
+impl Intersect<Interval> for Interval {
+    type Output = Interval;
+    fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let (start, end) = (self.0.max(other.0), self.1.min(other.1));
+        if start <= end {
+            Some(Interval(start, end))
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn from_intersect() {
+    // Any `Intersect` implementation provides `IntersectTo` for its output
+    let result: Option<Interval> = Interval(0.0, 2.0).intersect_to(&Interval(1.0, 3.0));
+    assert_eq!(result, Some(Interval(1.0, 2.0)));
+    let point: Vec2 = Line(Vec2::ZERO, Vec2::X)
+        .intersect_to(&Line(Vec2::Y, Vec2::ZERO))
+        .unwrap();
+    assert_abs_diff_eq!(point, Vec2::ZERO);
+}
@@ -0,0 +1,51 @@
+extern crate std;
+
+use crate::{HalfPlane, Intersect, IntersectTo, Line, Polygon, telemetry, telemetry::Stats};
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn counters() {
+    let square = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ]);
+    // Plane passing through the vertices
+    let plane = HalfPlane::from_normal(Vec2::new(2.0, 0.0), Vec2::new(1.0, 0.0));
+    let (clipped, stats) = telemetry::measure(|| {
+        IntersectTo::<_, Polygon<Vec<Vec2>>>::intersect_to(&square, &plane).unwrap()
+    });
+    assert!(stats.clips >= 1);
+    assert!(stats.clip_vertices >= clipped.len());
+
+    let (_, stats) =
+        telemetry::measure(|| Line(Vec2::ZERO, Vec2::X).intersect(&Line(Vec2::Y, Vec2::ONE)));
+    assert!(stats.degenerate >= 1);
+}
+
+#[test]
+fn stats_difference() {
+    let before = Stats {
+        degenerate: 3,
+        eps_rescues: 1,
+        clips: 5,
+        clip_vertices: 20,
+    };
+    let after = Stats {
+        clips: 7,
+        clip_vertices: 28,
+        ..before
+    };
+    assert_eq!(
+        after - before,
+        Stats {
+            clips: 2,
+            clip_vertices: 8,
+            ..Stats::default()
+        }
+    );
+    // Counters reset between the snapshots don't underflow
+    assert_eq!(Stats::default() - before, Stats::default());
+}
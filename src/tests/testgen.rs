@@ -0,0 +1,72 @@
+extern crate std;
+
+use crate::{
+    ArcPolygon, ArcVertex, Closed, Polygon,
+    testgen::{Pcg32, arc_polygon, convex_polygon, star_polygon},
+};
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn reproducible() {
+    let mut a = Pcg32::new(42);
+    let mut b = Pcg32::new(42);
+    let xs: Vec<u32> = (0..8).map(|_| a.next_u32()).collect();
+    assert!(xs.iter().all(|&x| x == b.next_u32()));
+    assert_ne!(Pcg32::new(43).next_u32(), xs[0]);
+
+    let p: Polygon<Vec<Vec2>> = star_polygon(&mut Pcg32::new(7), 16, 0.5);
+    let q: Polygon<Vec<Vec2>> = star_polygon(&mut Pcg32::new(7), 16, 0.5);
+    assert_eq!(p, q);
+
+    for _ in 0..100 {
+        let x = a.range(-1.0, 2.0);
+        assert!((-1.0..2.0).contains(&x));
+    }
+}
+
+#[test]
+fn shapes() {
+    let mut rng = Pcg32::new(1);
+    for _ in 0..16 {
+        let convex: Polygon<Vec<Vec2>> = convex_polygon(&mut rng, 32);
+        assert!(convex.len() >= 3 && convex.len() <= 32);
+        assert!(convex.is_convex() && convex.is_ccw());
+        assert!(convex.vertices().all(|v| v.length() <= 1.0));
+
+        let star: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 12, 0.3);
+        assert_eq!(star.len(), 12);
+        assert!(star.is_ccw());
+        assert!(star.contains(Vec2::ZERO));
+        let radius = 0.3 * (0.95 * PI / 12.0).cos();
+        assert!(
+            star.edges()
+                .all(|edge| edge.closest_point(Vec2::ZERO).length() >= radius)
+        );
+
+        let arcs: ArcPolygon<Vec<ArcVertex>> = arc_polygon(&mut rng, 12, 0.5);
+        assert_eq!(arcs.len(), 12);
+        assert!(arcs.contains(Vec2::ZERO));
+        assert!(arcs.is_ccw());
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn simple_polygon() {
+    use crate::{Aabb, segment_intersections, testgen::random_simple_polygon};
+
+    let bounds = Aabb::from_points([Vec2::new(-2.0, 1.0), Vec2::new(3.0, 2.0)]);
+    let mut rng = Pcg32::new(584);
+    for count in [3, 4, 10, 50] {
+        let polygon: Polygon<Vec<Vec2>> = random_simple_polygon(count, bounds, &mut rng);
+        assert_eq!(polygon.len(), count);
+        assert!(polygon.is_ccw());
+        assert!(polygon.vertices().all(|v| bounds.contains(v)));
+        // Only adjacent edges meet at their common vertices
+        for (i, j, _) in segment_intersections(polygon.edges()) {
+            assert!(j == i + 1 || (i == 0 && j == count - 1), "{count}: {i} {j}");
+        }
+    }
+}
@@ -0,0 +1,130 @@
+extern crate std;
+
+use crate::{
+    Aabb, ArcVertex, Closed, Integrable, Path, Polygon, Region, concentric_offsets,
+    testgen::{Pcg32, star_polygon},
+    zigzag_fill,
+};
+use approx::assert_abs_diff_eq;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn square() {
+    let square = Region::new([Aabb::new(Vec2::ZERO, Vec2::splat(10.0)).polygon()]);
+    let rings: Vec<_> = concentric_offsets(&square, 1.0).collect();
+    assert_eq!(rings.len(), 4);
+    for (k, ring) in rings.iter().enumerate() {
+        let side = 8.0 - 2.0 * k as f32;
+        assert_eq!(ring.len(), 1);
+        assert_abs_diff_eq!(ring.moment().area, side * side, epsilon = 1e-3);
+    }
+    assert_eq!(concentric_offsets(&square, 6.0).count(), 0);
+    for step in [0.0, -1.0, f32::NAN] {
+        assert_eq!(concentric_offsets(&square, step).count(), 0);
+    }
+}
+
+#[test]
+fn hole() {
+    // Frame collapses when the outer and the inner contours meet in the middle
+    let frame = Region::new([
+        Aabb::new(Vec2::ZERO, Vec2::splat(10.0)).polygon(),
+        Polygon::new([
+            Vec2::new(3.0, 3.0),
+            Vec2::new(3.0, 7.0),
+            Vec2::new(7.0, 7.0),
+            Vec2::new(7.0, 3.0),
+        ]),
+    ]);
+    let rings: Vec<_> = concentric_offsets(&frame, 0.5).collect();
+    assert_eq!(rings.len(), 2);
+    assert_eq!(rings[0].len(), 2);
+    assert!(rings[1].contains(Vec2::new(1.5, 5.0)));
+    assert!(!rings[1].contains(Vec2::new(5.0, 5.0)));
+}
+
+#[test]
+fn random() {
+    let mut rng = Pcg32::new(0x568);
+    for _ in 0..20 {
+        let star: Polygon<Vec<Vec2>> = star_polygon(&mut rng, 24, 0.2);
+        let star = Region::new([star]);
+        let mut last = star.moment().area;
+        for ring in concentric_offsets(&star, 0.05) {
+            let area = ring.moment().area;
+            assert!(area > 0.0 && area < last);
+            last = area;
+        }
+    }
+}
+
+fn points(path: &Path<Vec<ArcVertex>>) -> Vec<Vec2> {
+    path.vertices().map(|v| v.point).collect()
+}
+
+#[test]
+fn zigzag_square() {
+    let square = Region::new([Aabb::new(Vec2::ZERO, Vec2::splat(10.0)).polygon()]);
+    let path = zigzag_fill(&square, Vec2::X, 1.0);
+    // Ten lines joined by the boundary links
+    assert_abs_diff_eq!(path.length(), 10.0 * 10.0 + 9.0, epsilon = 1e-3);
+    let points = points(&path);
+    assert_eq!(points.len(), 20);
+    assert_abs_diff_eq!(points[0], Vec2::new(0.0, 0.5), epsilon = 1e-5);
+    assert_abs_diff_eq!(points[1], Vec2::new(10.0, 0.5), epsilon = 1e-5);
+    assert_abs_diff_eq!(points[2], Vec2::new(10.0, 1.5), epsilon = 1e-5);
+    assert_abs_diff_eq!(points[3], Vec2::new(0.0, 1.5), epsilon = 1e-5);
+
+    assert!(zigzag_fill(&square, Vec2::ZERO, 1.0).is_empty());
+    for spacing in [0.0, -1.0, f32::NAN] {
+        assert!(zigzag_fill(&square, Vec2::X, spacing).is_empty());
+    }
+}
+
+#[test]
+fn zigzag_hole() {
+    let frame = Region::new([
+        Aabb::new(Vec2::ZERO, Vec2::splat(10.0)).polygon(),
+        Polygon::new([
+            Vec2::new(3.0, 3.0),
+            Vec2::new(3.0, 7.0),
+            Vec2::new(7.0, 7.0),
+            Vec2::new(7.0, 3.0),
+        ]),
+    ]);
+    let hole = Aabb::new(Vec2::splat(3.01), Vec2::splat(6.99));
+    for direction in [Vec2::X, Vec2::new(1.0, 2.0)] {
+        let path = zigzag_fill(&frame, direction, 0.5);
+        let points = points(&path);
+        // Path never crosses the hole and covers each hatch line
+        for edge in points.windows(2) {
+            for t in [0.25, 0.5, 0.75] {
+                let point = edge[0].lerp(edge[1], t);
+                assert!(!hole.contains(point));
+                assert!(point.cmpge(Vec2::splat(-1e-3)).all());
+                assert!(point.cmple(Vec2::splat(10.0 + 1e-3)).all());
+            }
+        }
+        let area = frame.moment().area;
+        assert!(path.length() > 0.9 * area / 0.5);
+    }
+}
+
+#[test]
+fn zigzag_disjoint() {
+    let squares = Region::new([
+        Aabb::new(Vec2::ZERO, Vec2::splat(2.0)).polygon(),
+        Aabb::new(Vec2::new(5.0, 0.0), Vec2::new(7.0, 2.0)).polygon(),
+    ]);
+    let path = zigzag_fill(&squares, Vec2::Y, 0.5);
+    let points = points(&path);
+    // Each square is filled by one serpentine, and they are joined by a single jump
+    let jumps = points
+        .windows(2)
+        .filter(|edge| edge[0].x <= 2.0 && edge[1].x >= 5.0 || edge[1].x <= 2.0 && edge[0].x >= 5.0)
+        .count();
+    assert_eq!(jumps, 1);
+    assert_abs_diff_eq!(path.length(), 2.0 * (4.0 * 2.0 + 1.5) + 3.5, epsilon = 1e-3);
+    assert!(zigzag_fill(&squares, Vec2::Y, 20.0).is_empty());
+}
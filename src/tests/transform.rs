@@ -0,0 +1,97 @@
+use crate::{ArcPolygon, ArcVertex, Closed, Disk, HalfPlane, Integrable, Line, Polygon, Transform};
+use approx::assert_abs_diff_eq;
+use glam::{Affine2, Vec2};
+
+const TEST_EPS: f32 = 1e-4;
+
+fn similarity() -> Affine2 {
+    Affine2::from_scale_angle_translation(Vec2::splat(2.0), 0.7, Vec2::new(3.0, -1.0))
+}
+
+fn reflection() -> Affine2 {
+    Affine2::from_translation(Vec2::new(1.0, 2.0)) * Affine2::from_scale(Vec2::new(-1.0, 1.0))
+}
+
+#[test]
+fn curved() {
+    let disk = Disk::new(Vec2::new(1.0, 0.5), 1.5);
+    let poly: ArcPolygon<[ArcVertex; 3]> = disk.polygon();
+    for m in [similarity(), reflection()] {
+        let det = m.matrix2.determinant();
+        let moved = poly.transform(m);
+        let moved_disk = disk.transform(m);
+        assert_abs_diff_eq!(moved.area(), disk.area() * det, epsilon = TEST_EPS);
+        assert_abs_diff_eq!(moved.centroid(), moved_disk.center, epsilon = TEST_EPS);
+        assert_abs_diff_eq!(
+            moved_disk.area(),
+            disk.area() * det.abs(),
+            epsilon = TEST_EPS
+        );
+
+        for edge in moved.edges() {
+            assert_abs_diff_eq!(edge.center(), moved_disk.center, epsilon = TEST_EPS);
+            assert_abs_diff_eq!(edge.radius(), moved_disk.radius, epsilon = TEST_EPS);
+        }
+        let inner = m.transform_point2(disk.center + Vec2::new(1.0, 0.0));
+        assert_eq!(moved.winding_number_2(inner).signum(), det.signum() as i32);
+    }
+}
+
+#[test]
+fn straight() {
+    let poly = Polygon::new([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let moved = poly.transform(reflection());
+    assert_eq!(moved.vertices[1], Vec2::new(-1.0, 2.0));
+    assert_abs_diff_eq!(moved.area(), -poly.area(), epsilon = TEST_EPS);
+
+    let line = Line(Vec2::ZERO, Vec2::X).transform(similarity());
+    assert_abs_diff_eq!((line.1 - line.0).length(), 2.0, epsilon = TEST_EPS);
+
+    // Shear
+    let m = Affine2::from_cols_array(&[1.0, 0.0, 1.0, 1.0, 0.5, 0.0]);
+    let plane = HalfPlane::from_normal(Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0));
+    let moved = plane.transform(m);
+    for point in [
+        Vec2::new(0.5, 3.0),
+        Vec2::new(1.5, -2.0),
+        Vec2::new(0.9, 0.0),
+    ] {
+        assert_eq!(
+            moved.contains(m.transform_point2(point)),
+            plane.contains(point)
+        );
+    }
+}
+
+#[test]
+fn mirror() {
+    let mirror = Line(Vec2::new(0.0, 1.0), Vec2::new(1.0, 2.0));
+    assert_abs_diff_eq!(
+        Vec2::new(2.0, 0.0).mirror(&mirror),
+        Vec2::new(-1.0, 3.0),
+        epsilon = TEST_EPS
+    );
+    // Points on the line are fixed
+    assert_abs_diff_eq!(
+        Vec2::new(3.0, 4.0).mirror(&mirror),
+        Vec2::new(3.0, 4.0),
+        epsilon = TEST_EPS
+    );
+
+    let poly: ArcPolygon<[ArcVertex; 4]> = Disk::new(Vec2::new(2.0, 0.0), 1.0).polygon();
+    let mirrored = poly.mirror(&mirror);
+    for (a, b) in poly.vertices().zip(mirrored.vertices()) {
+        assert_abs_diff_eq!(b.sagitta, -a.sagitta, epsilon = TEST_EPS);
+    }
+    assert_abs_diff_eq!(mirrored.area(), -poly.area(), epsilon = TEST_EPS);
+    for (a, b) in poly.vertices().zip(mirrored.mirror(&mirror).vertices()) {
+        assert_abs_diff_eq!(a.point, b.point, epsilon = TEST_EPS);
+        assert_abs_diff_eq!(a.sagitta, b.sagitta, epsilon = TEST_EPS);
+    }
+    let center = mirrored.edges().next().unwrap().center();
+    assert_abs_diff_eq!(center, Vec2::new(-1.0, 3.0), epsilon = TEST_EPS);
+}
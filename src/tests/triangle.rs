@@ -0,0 +1,60 @@
+use crate::{Closed, Integrable, Polygon, Triangle};
+use approx::assert_abs_diff_eq;
+use glam::{Vec2, Vec3};
+
+const TEST_EPS: f32 = 1e-5;
+
+fn right_triangle() -> Triangle {
+    Triangle(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(0.0, 3.0),
+    )
+}
+
+#[test]
+fn barycentric() {
+    let tri = right_triangle();
+    assert_abs_diff_eq!(
+        tri.barycentric(tri.1).unwrap(),
+        Vec3::new(0.0, 1.0, 0.0),
+        epsilon = TEST_EPS
+    );
+    let point = Vec2::new(1.0, 1.0);
+    let coords = tri.barycentric(point).unwrap();
+    assert!(coords.min_element() > 0.0);
+    assert_abs_diff_eq!(tri.from_barycentric(coords), point, epsilon = TEST_EPS);
+    assert!(tri.barycentric(Vec2::new(3.0, 3.0)).unwrap().min_element() < 0.0);
+    assert!(
+        Triangle(Vec2::ZERO, Vec2::X, Vec2::X * 2.0)
+            .barycentric(Vec2::Y)
+            .is_none()
+    );
+}
+
+#[test]
+fn circles() {
+    let tri = right_triangle();
+    // Hypotenuse is a diameter of the circumcircle
+    let circ = tri.circumcircle().unwrap();
+    assert_abs_diff_eq!(circ.center, Vec2::new(2.0, 1.5), epsilon = TEST_EPS);
+    assert_abs_diff_eq!(circ.radius, 2.5, epsilon = TEST_EPS);
+    // Inradius of the right triangle is (a + b - c) / 2
+    let inc = tri.incircle();
+    assert_abs_diff_eq!(inc.center, Vec2::new(1.0, 1.0), epsilon = TEST_EPS);
+    assert_abs_diff_eq!(inc.radius, 1.0, epsilon = TEST_EPS);
+}
+
+#[test]
+fn closed_and_moment() {
+    let tri = right_triangle();
+    assert!(tri.is_ccw());
+    assert!(tri.contains(Vec2::new(1.0, 1.0)));
+    assert!(!tri.contains(Vec2::new(3.0, 3.0)));
+    assert_abs_diff_eq!(tri.moment(), tri.polygon().moment(), epsilon = TEST_EPS);
+
+    let cw = Triangle(tri.0, tri.2, tri.1);
+    assert_eq!(cw.winding_number_2(Vec2::new(1.0, 1.0)), -2);
+    assert_abs_diff_eq!(cw.area(), -6.0, epsilon = TEST_EPS);
+    assert_eq!(tri.polygon(), Polygon::new(tri.vertices()));
+}
@@ -0,0 +1,73 @@
+extern crate std;
+
+use crate::{Closed, Disk, Integrable, testgen::Pcg32, union_outline};
+use approx::assert_abs_diff_eq;
+use core::f32::consts::PI;
+use glam::Vec2;
+use std::vec::Vec;
+
+#[test]
+fn two_disks() {
+    let disks = [
+        Disk::new(Vec2::ZERO, 1.0),
+        Disk::new(Vec2::new(1.0, 0.0), 1.0),
+        // Hidden inside the first one
+        Disk::new(Vec2::new(-0.2, 0.1), 0.5),
+        // Separate one
+        Disk::new(Vec2::new(5.0, 0.0), 0.5),
+    ];
+    let outline = union_outline(&disks);
+    assert_eq!(outline.len(), 2);
+    let lens = 2.0 * PI / 3.0 - 3.0f32.sqrt() / 2.0;
+    assert_abs_diff_eq!(outline[0].area(), 2.0 * PI - lens, epsilon = 1e-5);
+    assert_abs_diff_eq!(outline[1].area(), 0.25 * PI, epsilon = 1e-5);
+}
+
+#[test]
+fn ring() {
+    // Ring of disks surrounding a hole
+    let disks: Vec<Disk> = (0..8)
+        .map(|k| Disk::new(2.0 * Vec2::from_angle(k as f32 * PI / 4.0), 1.0))
+        .collect();
+    let outline = union_outline(&disks);
+    assert_eq!(outline.len(), 2);
+    assert_eq!(outline.iter().filter(|c| c.area() < 0.0).count(), 1);
+    let total: i32 = outline.iter().map(|c| c.winding_number_2(Vec2::ZERO)).sum();
+    assert_eq!(total, 0);
+}
+
+#[test]
+fn random() {
+    let mut rng = Pcg32::new(561);
+    let disks: Vec<Disk> = (0..40)
+        .map(|_| {
+            Disk::new(
+                Vec2::new(rng.range(0.0, 8.0), rng.range(0.0, 8.0)),
+                rng.range(0.3, 1.2),
+            )
+        })
+        .collect();
+    let outline = union_outline(&disks);
+
+    // Vertices lie on the union boundary
+    for v in outline
+        .iter()
+        .flat_map(|c| c.edges())
+        .flat_map(|arc| [arc.points.0, arc.point_at(0.5)])
+    {
+        let distances = disks.iter().map(|d| (v - d.center).length() - d.radius);
+        let nearest = distances.fold(f32::INFINITY, f32::min);
+        assert_abs_diff_eq!(nearest, 0.0, epsilon = 1e-4);
+    }
+
+    const N: usize = 600;
+    let step = 12.0 / N as f32;
+    let sampled = (0..N * N)
+        .map(|i| Vec2::new((i % N) as f32 + 0.5, (i / N) as f32 + 0.5) * step - 2.0)
+        .filter(|&p| disks.iter().any(|d| d.contains(p)))
+        .count() as f32
+        * step
+        * step;
+    let area: f32 = outline.iter().map(|c| c.area()).sum();
+    assert_abs_diff_eq!(area, sampled, epsilon = 0.05);
+}
@@ -0,0 +1,51 @@
+use crate::{Aabb, LineSegment, Polygon, testgen::Pcg32, visibility_grid};
+use glam::{UVec2, Vec2};
+
+/// Check that the segments cross each other.
+fn crosses(LineSegment(a, b): LineSegment, LineSegment(c, d): LineSegment) -> bool {
+    let side = |p: Vec2, q: Vec2, r: Vec2| (q - p).perp_dot(r - p);
+    side(a, b, c) * side(a, b, d) <= 0.0 && side(c, d, a) * side(c, d, b) <= 0.0
+}
+
+#[test]
+fn wall() {
+    let aabb = Aabb::new(Vec2::ZERO, Vec2::splat(10.0));
+    let wall = LineSegment(Vec2::new(5.0, 3.0), Vec2::new(5.0, 7.0));
+    let grid = visibility_grid(Vec2::new(1.0, 5.0), [wall], aabb, UVec2::splat(10));
+    assert!(grid.get(UVec2::new(4, 5)));
+    assert!(!grid.get(UVec2::new(5, 5)));
+    assert!(!grid.get(UVec2::new(9, 8)));
+    assert!(grid.get(UVec2::new(9, 9)));
+    assert!(grid.get(UVec2::new(9, 0)));
+
+    // Viewer inside of a room sees only the room
+    let room = Polygon::new([
+        Vec2::new(2.0, 2.0),
+        Vec2::new(8.0, 2.0),
+        Vec2::new(8.0, 8.0),
+        Vec2::new(2.0, 8.0),
+    ]);
+    let grid = visibility_grid(Vec2::new(3.0, 4.0), room.edges(), aabb, UVec2::splat(10));
+    assert_eq!(grid.count(), 36);
+}
+
+#[test]
+fn random() {
+    let mut rng = Pcg32::new(0x570);
+    let aabb = Aabb::new(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0));
+    let size = UVec2::new(37, 23);
+    for _ in 0..20 {
+        let mut point = || Vec2::new(rng.next_f32(), rng.next_f32()) * 3.0 - 1.5;
+        let origin = point() / 2.0;
+        let obstacles: [LineSegment; 6] = core::array::from_fn(|_| LineSegment(point(), point()));
+        let grid = visibility_grid(origin, obstacles, aabb, size);
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let cell = UVec2::new(x, y);
+                let ray = LineSegment(origin, grid.cell_center(cell));
+                let visible = !obstacles.iter().any(|&edge| crosses(ray, edge));
+                assert_eq!(grid.get(cell), visible);
+            }
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use crate::{Aabb, ArcVertex, CopyIterator, Path, Polygon, Region, skeleton::Wavefront};
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::{cmp::Reverse, iter};
+use glam::Vec2;
+
+/// Successive insets of the `region` by multiples of the `step` until it collapses.
+///
+/// The `k`-th item is the region inset by `k * step`, starting from `k = 1`.
+/// All insets are taken from the same wavefront, so they are exactly `step` apart and the rounding errors don't accumulate.
+/// This gives the rings of a pocketing toolpath with the tool of `step` width.
+///
+/// The iterator always terminates: the inset distance never exceeds a half of the smaller side of the region bounding box.
+/// Nothing is yielded if the `step` is not positive.
+/// Contours must not intersect, components must be counterclockwise and holes clockwise.
+pub fn concentric_offsets<C: ?Sized, V: CopyIterator<Item = Vec2> + ?Sized>(
+    region: &Region<C>,
+    step: f32,
+) -> impl Iterator<Item = Region<Vec<Polygon<Vec<Vec2>>>>> + use<C, V>
+where
+    for<'a> &'a C: IntoIterator<Item = &'a Polygon<V>>,
+{
+    let bounds = region.components().fold(Aabb::EMPTY, |a, contour| {
+        a.union(Aabb::from_points(contour.vertices()))
+    });
+    // Inscribed disk of any region fits into its bounding box
+    let depth = if step > 0.0 && !bounds.is_empty() {
+        0.5 * bounds.size().min_element()
+    } else {
+        0.0
+    };
+    let mut wavefront = Wavefront::new(region.components().map(|contour| contour.vertices()));
+    let mut k = 0;
+    iter::from_fn(move || {
+        k += 1;
+        let distance = k as f32 * step;
+        if depth == 0.0 || distance > depth {
+            return None;
+        }
+        wavefront.advance(distance);
+        let contours = wavefront.contours(f32::INFINITY);
+        if contours.is_empty() {
+            None
+        } else {
+            Some(Region::new(contours))
+        }
+    })
+}
+
+/// Point where a hatch line crosses the region boundary.
+struct Crossing {
+    /// Index of the hatch line.
+    line: i64,
+    /// Position along the hatch line.
+    along: f32,
+    contour: usize,
+    edge: usize,
+    /// Position along the boundary edge.
+    t: f32,
+    point: Vec2,
+}
+
+/// Hatch lines of a region together with the boundary between them.
+struct Hatching {
+    contours: Vec<Vec<Vec2>>,
+    crossings: Vec<Crossing>,
+    /// Crossings of each contour in the boundary order.
+    rings: Vec<Vec<usize>>,
+    /// Position of each crossing in its ring.
+    positions: Vec<usize>,
+    /// Other end of the hatch segment starting at each crossing.
+    partners: Vec<usize>,
+}
+
+impl Hatching {
+    fn new(contours: Vec<Vec<Vec2>>, direction: Vec2, spacing: f32) -> Self {
+        let normal = direction.perp();
+        let mut crossings = Vec::new();
+        let mut rings = Vec::new();
+        for (contour, points) in contours.iter().enumerate() {
+            let start = crossings.len();
+            let n = points.len();
+            for edge in 0..n {
+                let (a, b) = (points[edge], points[(edge + 1) % n]);
+                let (an, bn) = (a.dot(normal), b.dot(normal));
+                let (min, max) = (an.min(bn), an.max(bn));
+                let first = (min / spacing - 0.5).ceil() as i64;
+                for line in first..=(max / spacing - 0.5).floor() as i64 {
+                    let offset = (line as f32 + 0.5) * spacing;
+                    // Edge includes its lower end only, so the lines passing through vertices are crossed once
+                    if (an < offset) == (bn < offset) {
+                        continue;
+                    }
+                    let t = (offset - an) / (bn - an);
+                    let point = a.lerp(b, t);
+                    crossings.push(Crossing {
+                        line,
+                        along: point.dot(direction),
+                        contour,
+                        edge,
+                        t,
+                        point,
+                    });
+                }
+            }
+            crossings[start..].sort_by(|p, q| p.edge.cmp(&q.edge).then(p.t.total_cmp(&q.t)));
+            rings.push((start..crossings.len()).collect::<Vec<_>>());
+        }
+        let mut positions = vec![0; crossings.len()];
+        for ring in &rings {
+            for (k, &i) in ring.iter().enumerate() {
+                positions[i] = k;
+            }
+        }
+        // Each closed contour crosses each line an even number of times, so the crossings pair up along the lines
+        let mut order: Vec<usize> = (0..crossings.len()).collect();
+        order.sort_by(|&i, &j| {
+            let (p, q) = (&crossings[i], &crossings[j]);
+            p.line.cmp(&q.line).then(p.along.total_cmp(&q.along))
+        });
+        let mut partners = vec![0; crossings.len()];
+        for pair in order.chunks_exact(2) {
+            partners[pair[0]] = pair[1];
+            partners[pair[1]] = pair[0];
+        }
+        Self {
+            contours,
+            crossings,
+            rings,
+            positions,
+            partners,
+        }
+    }
+
+    /// Crossing next to the `i`-th one along the boundary, forward or backward.
+    fn neighbour(&self, i: usize, forward: bool) -> usize {
+        let ring = &self.rings[self.crossings[i].contour];
+        let n = ring.len();
+        let k = self.positions[i];
+        ring[if forward {
+            (k + 1) % n
+        } else {
+            (k + n - 1) % n
+        }]
+    }
+
+    /// Boundary vertices between the `i`-th crossing and its neighbour, excluding the crossings themselves.
+    fn link(&self, i: usize, forward: bool) -> Vec<Vec2> {
+        let j = self.neighbour(i, forward);
+        let (from, to) = if forward { (i, j) } else { (j, i) };
+        let (p, q) = (&self.crossings[from], &self.crossings[to]);
+        let points = &self.contours[p.contour];
+        let mut vertices = Vec::new();
+        // Single crossing pair on the same edge is linked the long way around in one of the directions
+        if !(p.edge == q.edge && q.t > p.t) {
+            let mut edge = p.edge;
+            loop {
+                edge = (edge + 1) % points.len();
+                vertices.push(points[edge]);
+                if edge == q.edge {
+                    break;
+                }
+            }
+        }
+        if !forward {
+            vertices.reverse();
+        }
+        vertices
+    }
+
+    fn link_length(&self, i: usize, forward: bool) -> f32 {
+        let j = self.neighbour(i, forward);
+        let mut length = 0.0;
+        let mut last = self.crossings[i].point;
+        for point in self
+            .link(i, forward)
+            .into_iter()
+            .chain([self.crossings[j].point])
+        {
+            length += last.distance(point);
+            last = point;
+        }
+        length
+    }
+
+    /// Shortest way from the `i`-th crossing to a crossing satisfying the `target` going along the hatch segments and the boundary.
+    ///
+    /// Returns the crossings on the way excluding the `i`-th one, each with the direction of the boundary link leading to it,
+    /// or `None` for the hatch segment.
+    fn route(
+        &self,
+        i: usize,
+        target: impl Fn(usize) -> bool,
+    ) -> Option<Vec<(usize, Option<bool>)>> {
+        let n = self.crossings.len();
+        let mut distances = vec![f32::INFINITY; n];
+        let mut previous = vec![(usize::MAX, None); n];
+        let mut queue = BinaryHeap::new();
+        distances[i] = 0.0;
+        // Non-negative floats are ordered as their bits
+        queue.push(Reverse((0.0f32.to_bits(), i)));
+        while let Some(Reverse((distance, j))) = queue.pop() {
+            let distance = f32::from_bits(distance);
+            if distance > distances[j] {
+                continue;
+            }
+            if target(j) {
+                let mut route = Vec::new();
+                let mut k = j;
+                while k != i {
+                    route.push((k, previous[k].1));
+                    k = previous[k].0;
+                }
+                route.reverse();
+                return Some(route);
+            }
+            let partner = self.partners[j];
+            let hatch = self.crossings[j]
+                .point
+                .distance(self.crossings[partner].point);
+            let steps = [
+                (partner, None, hatch),
+                (
+                    self.neighbour(j, true),
+                    Some(true),
+                    self.link_length(j, true),
+                ),
+                (
+                    self.neighbour(j, false),
+                    Some(false),
+                    self.link_length(j, false),
+                ),
+            ];
+            for (k, forward, length) in steps {
+                let distance = distance + length;
+                if distance < distances[k] {
+                    distances[k] = distance;
+                    previous[k] = (j, forward);
+                    queue.push(Reverse((distance.to_bits(), k)));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Fill the `region` with hatch lines running along the `direction` and joined into a serpentine path.
+///
+/// Hatch lines go through the middles of the strips given by [`slice`](crate::slice) with `direction.perp()` and the same `spacing`.
+/// Each line is cut by the region into segments, and the path goes along a segment,
+/// follows the boundary to the end of an unvisited segment on the next line, and goes along that segment backward.
+/// If there's no such segment nearby, the path goes along the boundary and the visited segments by the shortest way to the nearest unvisited one,
+/// so the whole path stays within the region. Disjoint parts of the region are joined by straight jumps.
+///
+/// Path edges are straight, so all sagittas are zero.
+/// The path is empty if no lines cross the region, the `spacing` is not positive or the `direction` is zero.
+/// Contours must not intersect, components must be counterclockwise and holes clockwise.
+pub fn zigzag_fill<C: ?Sized, V: CopyIterator<Item = Vec2> + ?Sized>(
+    region: &Region<C>,
+    direction: Vec2,
+    spacing: f32,
+) -> Path<Vec<ArcVertex>>
+where
+    for<'a> &'a C: IntoIterator<Item = &'a Polygon<V>>,
+{
+    let direction = match direction.try_normalize() {
+        Some(direction) if spacing > 0.0 => direction,
+        _ => return Path::new(Vec::new()),
+    };
+    let contours = region
+        .components()
+        .map(|contour| contour.vertices().collect())
+        .collect();
+    let hatching = Hatching::new(contours, direction, spacing);
+    let crossings = &hatching.crossings;
+
+    let mut visited = vec![false; crossings.len()];
+    let mut points = Vec::new();
+    // Segment ends in the order of lines, the first unvisited one starts the next run
+    let mut order: Vec<usize> = (0..crossings.len()).collect();
+    order.sort_by(|&i, &j| {
+        let (p, q) = (&crossings[i], &crossings[j]);
+        p.line.cmp(&q.line).then(p.along.total_cmp(&q.along))
+    });
+    let mut next = order.first().copied();
+    while let Some(i) = next {
+        // Go along the segment
+        let j = hatching.partners[i];
+        visited[i] = true;
+        visited[j] = true;
+        points.extend([crossings[i].point, crossings[j].point]);
+
+        // Prefer the boundary link to the next line, then any link to an unvisited segment
+        let line = crossings[j].line;
+        let link = [true, false]
+            .into_iter()
+            .map(|forward| (hatching.neighbour(j, forward), forward))
+            .filter(|&(k, _)| !visited[k])
+            .min_by_key(|&(k, forward)| {
+                (
+                    crossings[k].line == line,
+                    hatching.link_length(j, forward).to_bits(),
+                )
+            });
+        next = match link {
+            Some((k, forward)) => {
+                points.extend(hatching.link(j, forward));
+                Some(k)
+            }
+            None => match hatching.route(j, |k| !visited[k]) {
+                Some(route) => {
+                    let mut last = j;
+                    for (k, forward) in route {
+                        if let Some(forward) = forward {
+                            points.extend(hatching.link(last, forward));
+                        }
+                        points.push(crossings[k].point);
+                        last = k;
+                    }
+                    Some(last)
+                }
+                None => order.iter().copied().find(|&k| !visited[k]),
+            },
+        };
+    }
+
+    points.dedup();
+    Path::new(
+        points
+            .into_iter()
+            .map(|point| ArcVertex {
+                point,
+                sagitta: 0.0,
+            })
+            .collect(),
+    )
+}
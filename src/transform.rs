@@ -0,0 +1,135 @@
+use crate::{
+    Arc, ArcVertex, Capsule, Circle, CopyIterator, Disk, GenericPath, GenericPolygon, HalfPlane,
+    Line, LineSegment, Triangle, Vertex,
+};
+use glam::{Affine2, Vec2};
+
+/// Shape that can be mapped by affine transformation.
+pub trait Transform: Sized {
+    /// Apply affine transformation `m` to the shape.
+    ///
+    /// Circular shapes support only similarity transformations (uniform scale, rotation, reflection and translation).
+    /// For other transformations they are scaled by the square root of the transformation determinant.
+    ///
+    /// Reflection (negative determinant) reverses orientation of the shape boundary.
+    fn transform(&self, m: Affine2) -> Self;
+
+    /// Mirror the shape across the `line`.
+    ///
+    /// Arcs are mirrored by negating their sagittas, and the boundary orientation is reversed.
+    fn mirror(&self, line: &Line) -> Self {
+        self.transform(line.reflection())
+    }
+}
+
+/// Scale factor of the circular shapes.
+fn uniform_scale(m: &Affine2) -> f32 {
+    m.matrix2.determinant().abs().sqrt()
+}
+
+/// Scale factor of sagitta which sign is flipped on reflection.
+fn sagitta_scale(m: &Affine2) -> f32 {
+    uniform_scale(m) * m.matrix2.determinant().signum()
+}
+
+impl Transform for Vec2 {
+    fn transform(&self, m: Affine2) -> Self {
+        m.transform_point2(*self)
+    }
+}
+
+impl Transform for Line {
+    fn transform(&self, m: Affine2) -> Self {
+        Line(self.0.transform(m), self.1.transform(m))
+    }
+}
+
+impl Transform for LineSegment {
+    fn transform(&self, m: Affine2) -> Self {
+        LineSegment(self.0.transform(m), self.1.transform(m))
+    }
+}
+
+impl Transform for Circle {
+    fn transform(&self, m: Affine2) -> Self {
+        Circle {
+            center: self.center.transform(m),
+            radius: self.radius * uniform_scale(&m),
+        }
+    }
+}
+
+impl Transform for Disk {
+    fn transform(&self, m: Affine2) -> Self {
+        Disk(self.edge().transform(m))
+    }
+}
+
+impl Transform for Arc {
+    fn transform(&self, m: Affine2) -> Self {
+        Arc {
+            points: (self.points.0.transform(m), self.points.1.transform(m)),
+            sagitta: self.sagitta * sagitta_scale(&m),
+        }
+    }
+}
+
+impl Transform for ArcVertex {
+    fn transform(&self, m: Affine2) -> Self {
+        ArcVertex {
+            point: self.point.transform(m),
+            sagitta: self.sagitta * sagitta_scale(&m),
+        }
+    }
+}
+
+impl Transform for HalfPlane {
+    fn transform(&self, m: Affine2) -> Self {
+        // Normals are transformed by inverse transpose matrix
+        let normal = (m.matrix2.inverse().transpose() * self.normal).normalize();
+        HalfPlane::from_normal(self.boundary_point().transform(m), normal)
+    }
+}
+
+impl Transform for Triangle {
+    fn transform(&self, m: Affine2) -> Self {
+        Triangle(
+            self.0.transform(m),
+            self.1.transform(m),
+            self.2.transform(m),
+        )
+    }
+}
+
+impl Transform for Capsule {
+    fn transform(&self, m: Affine2) -> Self {
+        Capsule {
+            segment: self.segment.transform(m),
+            radius: self.radius * uniform_scale(&m),
+        }
+    }
+}
+
+impl<T: Vertex + Transform, V: CopyIterator<Item = T> + Clone + AsMut<[T]>> Transform
+    for GenericPolygon<V, T>
+{
+    fn transform(&self, m: Affine2) -> Self {
+        let mut vertices = self.vertices.clone();
+        for v in vertices.as_mut() {
+            *v = v.transform(m);
+        }
+        GenericPolygon::new(vertices)
+    }
+}
+
+impl<T: Vertex + Transform, V: CopyIterator<Item = T> + Clone + AsMut<[T]>> Transform
+    for GenericPath<V, T>
+{
+    fn transform(&self, m: Affine2) -> Self {
+        let mut vertices = self.vertices.clone();
+        for v in vertices.as_mut() {
+            *v = v.transform(m);
+        }
+        GenericPath::new(vertices)
+    }
+}
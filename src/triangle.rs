@@ -0,0 +1,136 @@
+use crate::{Circle, Closed, EPS, Integrable, LineSegment, Moment, Polygon, impl_approx_eq};
+use glam::{Vec2, Vec3};
+
+/// Triangle defined by its vertices.
+///
+/// ```text
+///         c
+///        / \
+///       /   \
+///      /     \
+///     a-------b
+/// ```
+///
+/// Triangle is counterclockwise if `c` is on the left of `(a, b)`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Triangle(pub Vec2, pub Vec2, pub Vec2);
+
+impl Triangle {
+    /// Vertices of the triangle.
+    pub fn vertices(&self) -> [Vec2; 3] {
+        [self.0, self.1, self.2]
+    }
+
+    /// Edges of the triangle: `(a, b)`, `(b, c)` and `(c, a)`.
+    pub fn edges(&self) -> [LineSegment; 3] {
+        [
+            LineSegment(self.0, self.1),
+            LineSegment(self.1, self.2),
+            LineSegment(self.2, self.0),
+        ]
+    }
+
+    /// Area of the triangle, positive if it is counterclockwise.
+    pub fn signed_area(&self) -> f32 {
+        0.5 * (self.1 - self.0).perp_dot(self.2 - self.0)
+    }
+
+    /// Check that the triangle is counterclockwise.
+    pub fn is_ccw(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    /// Check that the triangle has zero area.
+    pub fn is_degenerate(&self) -> bool {
+        self.signed_area().abs() < EPS
+    }
+
+    /// Barycentric coordinates of the `point`.
+    ///
+    /// The point is equal to `a * x + b * y + c * z`, where `x + y + z == 1`.
+    /// All coordinates are non-negative for points inside the triangle.
+    ///
+    /// Returns `None` if the triangle is degenerate.
+    pub fn barycentric(&self, point: Vec2) -> Option<Vec3> {
+        let area = self.signed_area();
+        if area.abs() < EPS {
+            return None;
+        }
+        let x = 0.5 * (self.1 - point).perp_dot(self.2 - point) / area;
+        let y = 0.5 * (self.2 - point).perp_dot(self.0 - point) / area;
+        Some(Vec3::new(x, y, 1.0 - x - y))
+    }
+
+    /// Point at the given barycentric `coords`.
+    pub fn from_barycentric(&self, coords: Vec3) -> Vec2 {
+        self.0 * coords.x + self.1 * coords.y + self.2 * coords.z
+    }
+
+    /// Circle passing through all vertices.
+    ///
+    /// Returns `None` if the triangle is degenerate.
+    pub fn circumcircle(&self) -> Option<Circle> {
+        let (b, c) = (self.1 - self.0, self.2 - self.0);
+        let den = 2.0 * b.perp_dot(c);
+        if den.abs() < EPS {
+            return None;
+        }
+        let offset = (b.perp() * c.length_squared() - c.perp() * b.length_squared()) / den;
+        Some(Circle {
+            center: self.0 + offset,
+            radius: offset.length(),
+        })
+    }
+
+    /// Largest circle inscribed into the triangle.
+    ///
+    /// Degenerate triangle gives a circle of zero radius.
+    pub fn incircle(&self) -> Circle {
+        let la = (self.2 - self.1).length();
+        let lb = (self.0 - self.2).length();
+        let lc = (self.1 - self.0).length();
+        let perimeter = la + lb + lc;
+        if perimeter < EPS {
+            return Circle {
+                center: self.0,
+                radius: 0.0,
+            };
+        }
+        Circle {
+            center: (self.0 * la + self.1 * lb + self.2 * lc) / perimeter,
+            radius: 2.0 * self.signed_area().abs() / perimeter,
+        }
+    }
+
+    /// Representation of the triangle as a generic polygon.
+    pub fn polygon(&self) -> Polygon<[Vec2; 3]> {
+        Polygon::new(self.vertices())
+    }
+}
+
+impl Closed for Triangle {
+    fn winding_number_2(&self, point: Vec2) -> i32 {
+        // Point is inside if it is on the same side of all edges
+        let signs = self
+            .edges()
+            .map(|LineSegment(a, b)| (b - a).perp_dot(point - a));
+        if signs.iter().all(|&s| s >= 0.0) && signs.iter().any(|&s| s > 0.0) {
+            2
+        } else if signs.iter().all(|&s| s <= 0.0) && signs.iter().any(|&s| s < 0.0) {
+            -2
+        } else {
+            0
+        }
+    }
+}
+
+impl Integrable for Triangle {
+    fn moment(&self) -> Moment {
+        Moment {
+            area: self.signed_area(),
+            centroid: (self.0 + self.1 + self.2) / 3.0,
+        }
+    }
+}
+
+impl_approx_eq!(Triangle, f32, 0, 1, 2);
@@ -0,0 +1,161 @@
+use crate::{ArcPolygon, ArcVertex, Disk, EPS, pairwise::candidate_pairs};
+use alloc::{vec, vec::Vec};
+use core::f32::consts::{PI, TAU};
+use glam::Vec2;
+
+/// Part of a circle boundary that isn't covered by other disks.
+#[derive(Clone, Copy, Debug)]
+struct FreeArc {
+    /// Index of the disk the arc lies on.
+    disk: usize,
+    /// Angle of the arc start.
+    start: f32,
+    /// Counterclockwise angular length of the arc.
+    sweep: f32,
+    /// Disk which boundary the outline follows after the arc end.
+    next: usize,
+}
+
+/// Point of the `disk` circle at the `angle`.
+fn point_at(disk: &Disk, angle: f32) -> Vec2 {
+    disk.center + disk.radius * Vec2::from_angle(angle)
+}
+
+/// Arcs of each disk circle that lie outside of all other disks.
+///
+/// Disks lying inside other ones have no free arcs.
+fn free_arcs(disks: &[Disk]) -> Vec<FreeArc> {
+    // Angular intervals covered by other disks, as the start angle, the length and the covering disk
+    let mut covered: Vec<Vec<(f32, f32, usize)>> = vec![Vec::new(); disks.len()];
+    let mut hidden = vec![false; disks.len()];
+    for (i, j) in candidate_pairs(disks) {
+        let (a, b) = (&disks[i], &disks[j]);
+        let r = b.center - a.center;
+        let d = r.length();
+        if d >= a.radius + b.radius {
+            continue;
+        }
+        // Coinciding disks are merged into the first one
+        if d + a.radius <= b.radius && (d + a.radius < b.radius || d > EPS) {
+            hidden[i] = true;
+            continue;
+        }
+        if d + b.radius <= a.radius {
+            hidden[j] = true;
+            continue;
+        }
+        let angle = r.to_angle();
+        for (k, disk, other, angle) in [(i, a, j, angle), (j, b, i, angle + PI)] {
+            let other_radius = disks[other].radius;
+            let cos =
+                (disk.radius.powi(2) + d.powi(2) - other_radius.powi(2)) / (2.0 * disk.radius * d);
+            let half = cos.clamp(-1.0, 1.0).acos();
+            covered[k].push(((angle - half).rem_euclid(TAU), 2.0 * half, other));
+        }
+    }
+
+    let mut arcs = Vec::new();
+    for (i, intervals) in covered.iter_mut().enumerate() {
+        if hidden[i] {
+            continue;
+        }
+        if intervals.is_empty() {
+            // The whole circle is free, it is split in two halves to form a closed outline
+            arcs.push(FreeArc {
+                disk: i,
+                start: 0.0,
+                sweep: PI,
+                next: i,
+            });
+            arcs.push(FreeArc {
+                disk: i,
+                start: PI,
+                sweep: PI,
+                next: i,
+            });
+            continue;
+        }
+        intervals.sort_by(|a, b| a.0.total_cmp(&b.0));
+        // Go around the circle once starting from the first interval,
+        // intervals wrapping over the full turn may cover its end, and the first one is repeated to close the circle
+        let first = intervals[0].0;
+        let mut end = intervals
+            .iter()
+            .map(|&(start, length, _)| start + length - TAU)
+            .fold(first + intervals[0].1, f32::max);
+        for &(start, length, disk) in
+            intervals[1..]
+                .iter()
+                .chain(&[(first + TAU, 0.0, intervals[0].2)])
+        {
+            if start > end {
+                arcs.push(FreeArc {
+                    disk: i,
+                    start: end,
+                    sweep: start - end,
+                    next: disk,
+                });
+            }
+            end = end.max(start + length);
+        }
+    }
+    arcs
+}
+
+/// Outline of the union of the `disks`.
+///
+/// The boundary of the union is formed by the parts of circles that aren't covered by other disks,
+/// these arcs are chained into closed contours.
+/// Each connected island of overlapping disks gives a counterclockwise outer contour,
+/// and each hole inside an island gives a clockwise contour, so the union is the sum of contours
+/// and its area is the sum of their signed areas.
+/// Disks that only touch each other are considered disjoint.
+///
+/// Arcs longer than a half of the circle are split in two, so each edge is less than a half-circle.
+pub fn union_outline(disks: &[Disk]) -> Vec<ArcPolygon<Vec<ArcVertex>>> {
+    let arcs = free_arcs(disks);
+    let start = |arc: &FreeArc| point_at(&disks[arc.disk], arc.start);
+    let end = |arc: &FreeArc| point_at(&disks[arc.disk], arc.start + arc.sweep);
+
+    let mut used = vec![false; arcs.len()];
+    let mut contours = Vec::new();
+    for first in 0..arcs.len() {
+        if used[first] {
+            continue;
+        }
+        let mut vertices = Vec::new();
+        let mut current = first;
+        loop {
+            used[current] = true;
+            let arc = arcs[current];
+            let disk = &disks[arc.disk];
+            let pieces = if arc.sweep > PI { 2 } else { 1 };
+            for k in 0..pieces {
+                let sweep = arc.sweep / pieces as f32;
+                let a = arc.start + k as f32 * sweep;
+                vertices.push(ArcVertex {
+                    point: point_at(disk, a),
+                    sagitta: disk.radius * (1.0 - (0.5 * sweep).cos()),
+                });
+            }
+
+            // The outline continues on the next disk from the point where the arc ends,
+            // if the point is covered by some other disk then the closest free arc is taken
+            let point = end(&arc);
+            let candidates = |on_next: bool| {
+                (0..arcs.len())
+                    .filter(|&k| !used[k] || k == first)
+                    .filter(|&k| !on_next || arcs[k].disk == arc.next)
+                    .map(|k| (k, start(&arcs[k]).distance_squared(point)))
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(k, _)| k)
+            };
+            match candidates(true).or_else(|| candidates(false)) {
+                Some(next) if next != first => current = next,
+                _ => break,
+            }
+        }
+        contours.push(ArcPolygon::new(vertices));
+    }
+    contours
+}
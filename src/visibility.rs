@@ -0,0 +1,94 @@
+use crate::{Aabb, BitGrid, EPS, LineSegment};
+use glam::{UVec2, Vec2};
+
+/// Compute which cells of the grid of `resolution` cells covering the `aabb` are visible from the `origin`.
+///
+/// The cell is set if the segment from the `origin` to the cell center doesn't cross any of the `obstacles` edges.
+/// To use polygons as obstacles pass their [`edges`](crate::GenericPolygon::edges).
+///
+/// Each obstacle edge casts a shadow, the convex area behind it as seen from the `origin`,
+/// and the cells which centers are in the shadow are unset row by row,
+/// so the time is proportional to the number of edges multiplied by the grid height plus the number of shadowed cells.
+/// Cell centers lying exactly on the edge are shadowed.
+/// Edges lying on lines through the `origin` cast no shadow.
+pub fn visibility_grid<I: IntoIterator<Item = LineSegment>>(
+    origin: Vec2,
+    obstacles: I,
+    aabb: Aabb,
+    resolution: UVec2,
+) -> BitGrid {
+    let mut grid = BitGrid::new(aabb, resolution);
+    for y in 0..resolution.y {
+        for x in 0..resolution.x {
+            grid.set(UVec2::new(x, y), true);
+        }
+    }
+    if aabb.is_empty() {
+        return grid;
+    }
+    // Shadows must reach the farthest corner of the grid
+    let reach = aabb
+        .corners()
+        .into_iter()
+        .map(|corner| corner.distance(origin))
+        .fold(0.0, f32::max);
+    for LineSegment(a, b) in obstacles {
+        let (u, v) = (a - origin, b - origin);
+        if u.perp_dot(v).abs() < EPS {
+            continue;
+        }
+        let (u, v) = (u.normalize(), v.normalize());
+        // Rays through the edge ends diverge by less than a half turn,
+        // so the far side made of two chords at this distance stays beyond the reach
+        let far = 2.0 * reach;
+        let shadow = [
+            a,
+            b,
+            origin + far * v,
+            origin + far * (u + v).normalize(),
+            origin + far * u,
+        ];
+        cast(&mut grid, &shadow);
+    }
+    grid
+}
+
+/// Unset the cells which centers are inside of the convex polygon.
+fn cast(grid: &mut BitGrid, polygon: &[Vec2]) {
+    let (bounds, size) = (grid.bounds(), grid.size());
+    let cell = grid.cell_size();
+    // Range of cells which centers are within the coordinate range
+    let range = |min: f32, max: f32, axis: usize| {
+        let index = |value: f32| (value - bounds.min[axis]) / cell[axis] - 0.5;
+        let end = size[axis] as f32;
+        let first = index(min).ceil().clamp(0.0, end) as u32;
+        let last = (index(max).floor() + 1.0).clamp(0.0, end) as u32;
+        first..last
+    };
+    let (min, max) = polygon
+        .iter()
+        .fold((Vec2::INFINITY, Vec2::NEG_INFINITY), |(min, max), &p| {
+            (min.min(p), max.max(p))
+        });
+    for y in range(min.y, max.y, 1) {
+        let row = grid.cell_center(UVec2::new(0, y)).y;
+        let (mut left, mut right) = (f32::INFINITY, f32::NEG_INFINITY);
+        for (i, &p) in polygon.iter().enumerate() {
+            let q = polygon[(i + 1) % polygon.len()];
+            if row < p.y.min(q.y) || row > p.y.max(q.y) {
+                continue;
+            }
+            let (x0, x1) = if p.y == q.y {
+                (p.x.min(q.x), p.x.max(q.x))
+            } else {
+                let x = p.x + (q.x - p.x) * (row - p.y) / (q.y - p.y);
+                (x, x)
+            };
+            left = left.min(x0);
+            right = right.max(x1);
+        }
+        for x in range(left, right, 0) {
+            grid.set(UVec2::new(x, y), false);
+        }
+    }
+}